@@ -0,0 +1,57 @@
+//! Loop vs iterator [performance] of the minigrep search
+//!
+//! The iterator version compiles down to the same code as the loop,
+//! which is the zero-cost abstraction of chapter 13.  It's the plain
+//! `main`, not the nightly `test::Bencher`, to run on stable:
+//!
+//! ```sh
+//! $ cargo bench --bench ch13_search
+//! search loop: 2379516 ns/iter
+//! search iter: 2430001 ns/iter
+//! ```
+//!
+//! [performance]: https://doc.rust-lang.org/book/ch13-04-performance.html
+use std::hint::black_box;
+use std::time::Instant;
+
+use the_book::ch12::{search, search_iter};
+
+const LINES: usize = 100_000;
+const ITERS: u32 = 20;
+
+/// Returns the haystack of `LINES` lines, with "needle" in every
+/// tenth line.
+fn haystack() -> String {
+    (0..LINES)
+        .map(|i| {
+            if i % 10 == 0 {
+                format!("line {} has the needle in it\n", i)
+            } else {
+                format!("line {} has nothing to see here\n", i)
+            }
+        })
+        .collect()
+}
+
+fn bench(name: &str, f: impl Fn() -> usize) {
+    assert_eq!(LINES / 10, f());
+    let start = Instant::now();
+    for _ in 0..ITERS {
+        black_box(f());
+    }
+    println!(
+        "{}: {} ns/iter",
+        name,
+        start.elapsed().as_nanos() / ITERS as u128
+    );
+}
+
+fn main() {
+    let contents = haystack();
+    bench("search loop", || {
+        search(black_box("needle"), black_box(&contents)).len()
+    });
+    bench("search iter", || {
+        search_iter(black_box("needle"), black_box(&contents)).len()
+    });
+}
@@ -10,6 +10,7 @@ fn main() {
     };
     let article = Article {
         headline: String::from("HEADLINE!!!!"),
+        author: String::from("me"),
         content: String::from("yep, it's really important, indeed"),
     };
 
@@ -17,5 +18,5 @@ fn main() {
         String::from("This is the most important tweet @yep, this is me"),
         tweet.summarize(),
     );
-    assert_eq!(String::from("(Read more...)"), article.summarize(),);
+    assert_eq!(String::from("(Read more from me...)"), article.summarize(),);
 }
@@ -0,0 +1,30 @@
+//! An I/O Project: Building a Command Line Program
+//!
+//! ```sh
+//! $ cargo run --example ch12-minigrep -- to tests/fixtures/poem.txt
+//! $ IGNORE_CASE=1 cargo run --example ch12-minigrep -- to tests/fixtures/poem.txt
+//! $ cargo run --example ch12-minigrep -- to tests/fixtures/poem.txt --ignore-case
+//! ```
+use std::{env, process};
+
+use the_book::ch12::{self, Config};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {}", err);
+        eprintln!("usage: {} <query> <file_path> [--ignore-case]", args[0]);
+        process::exit(1);
+    });
+    match ch12::run(config) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Err(err) => {
+            eprintln!("Application error: {}", err);
+            process::exit(1);
+        }
+    }
+}
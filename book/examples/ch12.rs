@@ -1,25 +1,28 @@
 //! An I/O Project: Building a Command Line Program
-use std::{env, io, process};
+use std::{env, error::Error, io, process};
 
-use the_book::ch09::Error;
 use the_book::ch12::{self, Config};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let cfg = Config::new(&args).unwrap_or_else(|err| usage(err));
-    if let Err(err) = ch12::run(cfg) {
-        usage(err);
+    let cfg = Config::build(&args).unwrap_or_else(|err| usage(err.into()));
+    match ch12::run(cfg) {
+        Ok(lines) => {
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Err(err) => usage(err),
     }
 }
 
-fn usage(err: Error) -> ! {
-    match err {
-        Error::Io(err) => match err.kind() {
-            io::ErrorKind::InvalidInput => eprintln!("{:?}", err),
+fn usage(err: Box<dyn Error>) -> ! {
+    match err.downcast_ref::<io::Error>() {
+        Some(err) => match err.kind() {
             io::ErrorKind::NotFound => eprintln!("{:?}", err),
             _ => eprintln!("oops {:?}", err),
         },
-        _ => eprintln!("unexpected error"),
+        None => eprintln!("{}", err),
     }
     process::exit(1);
 }
@@ -5,7 +5,7 @@ use the_book::ch13::sec02::Counter;
 
 fn main() {
     let limit = cmdline();
-    let counter = Counter::new(limit + 1);
+    let counter = Counter::with_limit(limit);
 
     let mut got = 0;
     for i in counter {
@@ -19,7 +19,7 @@ fn main() {
 fn cmdline() -> u32 {
     let argv: Vec<String> = std::env::args().collect();
     match argv.len() {
-        0..=1 => 6,
+        0..=1 => 5,
         _ => argv[1].parse().expect("limit should be {integer}"),
     }
 }
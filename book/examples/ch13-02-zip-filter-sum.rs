@@ -4,10 +4,9 @@
 use the_book::ch13::sec02::Counter;
 
 fn main() {
-    let limit = 6;
     let want = 18;
-    let got: u32 = Counter::new(limit)
-        .zip(Counter::new(limit).skip(1))
+    let got: u32 = Counter::new()
+        .zip(Counter::new().skip(1))
         .map(|(a, b)| a * b)
         .filter(|x| x % 3 == 0)
         .sum();
@@ -0,0 +1,20 @@
+//! [Turning Our Single-Threaded Server into a Multithreaded Server]
+//!
+//! [turning our single-threaded server into a multithreaded server]: https://doc.rust-lang.org/book/ch20-02-multithreaded.html
+use std::{error::Error, net::TcpListener};
+
+use the_book::ch20::{thread_pool::ThreadPool, web_server::handle_connection};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:7878")?;
+    let pool = ThreadPool::new(4);
+    for stream in listener.incoming() {
+        let stream = stream?;
+        pool.execute(|| {
+            if let Err(err) = handle_connection(stream) {
+                eprintln!("connection error: {}", err);
+            }
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! [Control Flow]
+//!
+//! `loop` returns the value passed to `break`.
+//!
+//! ```
+//! let mut counter = 0;
+//! let result = loop {
+//!     counter += 1;
+//!     if counter == 10 {
+//!         break counter * 2;
+//!     }
+//! };
+//! assert_eq!(20, result);
+//! ```
+//!
+//! The loop label breaks out of the outer loop from the inner one.
+//!
+//! ```
+//! let mut found = None;
+//! 'outer: for i in 0..10 {
+//!     for j in 0..10 {
+//!         if i * j == 42 {
+//!             found = Some((i, j));
+//!             break 'outer;
+//!         }
+//!     }
+//! }
+//! assert_eq!(Some((6, 7)), found);
+//! ```
+//!
+//! `while let` loops as long as the pattern matches.
+//!
+//! ```
+//! let mut stack = vec![1, 2, 3];
+//! let mut popped = Vec::new();
+//! while let Some(top) = stack.pop() {
+//!     popped.push(top);
+//! }
+//! assert_eq!(vec![3, 2, 1], popped);
+//! ```
+//! [control flow]: https://doc.rust-lang.org/book/ch03-05-control-flow.html
+
+/// Returns the `n`th Fibonacci number, with `fibonacci(0) == 0`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch03::control_flow::fibonacci;
+///
+/// assert_eq!(55, fibonacci(10));
+/// ```
+pub fn fibonacci(n: u32) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    let (mut a, mut b) = (0u64, 1u64);
+    let mut i = 1;
+    loop {
+        if i == n {
+            break b;
+        }
+        let next = a + b;
+        a = b;
+        b = next;
+        i += 1;
+    }
+}
+
+/// Returns the first pair of `i` and `j` in `0..10` with `i * j == product`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch03::control_flow::find_factors;
+///
+/// assert_eq!(Some((2, 6)), find_factors(12));
+/// assert_eq!(None, find_factors(11));
+/// ```
+pub fn find_factors(product: u32) -> Option<(u32, u32)> {
+    let mut found = None;
+    'outer: for i in 0..10 {
+        for j in 0..10 {
+            if i * j == product {
+                found = Some((i, j));
+                break 'outer;
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn fibonacci() {
+        struct Test {
+            name: &'static str,
+            data: u32,
+            want: u64,
+        }
+        let tests = [
+            Test {
+                name: "zero",
+                data: 0,
+                want: 0,
+            },
+            Test {
+                name: "one",
+                data: 1,
+                want: 1,
+            },
+            Test {
+                name: "ten",
+                data: 10,
+                want: 55,
+            },
+            Test {
+                name: "largest u64",
+                data: 93,
+                want: 12_200_160_415_121_876_738,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, super::fibonacci(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn find_factors() {
+        assert_eq!(Some((0, 0)), super::find_factors(0));
+        assert_eq!(Some((6, 7)), super::find_factors(42));
+        assert_eq!(Some((9, 9)), super::find_factors(81));
+        assert_eq!(None, super::find_factors(13));
+    }
+    #[test]
+    fn continue_with_label() {
+        let mut pairs = Vec::new();
+        'outer: for i in 0..3 {
+            for j in 0..3 {
+                if j > i {
+                    continue 'outer;
+                }
+                pairs.push((i, j));
+            }
+        }
+        assert_eq!(vec![(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2)], pairs);
+    }
+    #[test]
+    fn while_let_pop() {
+        let mut stack = vec![1, 2, 3];
+        let mut sum = 0;
+        while let Some(top) = stack.pop() {
+            sum = sum * 10 + top;
+        }
+        assert_eq!(321, sum);
+        assert!(stack.is_empty());
+    }
+    #[test]
+    fn for_enumerate() {
+        let v = ['a', 'b', 'c'];
+        let mut got = Vec::new();
+        for (i, c) in v.iter().enumerate() {
+            got.push(format!("{}{}", i, c));
+        }
+        assert_eq!(vec!["0a", "1b", "2c"], got);
+    }
+    #[test]
+    fn for_rev() {
+        let mut got = Vec::new();
+        for n in (1..=5).rev() {
+            got.push(n);
+        }
+        assert_eq!(vec![5, 4, 3, 2, 1], got);
+    }
+}
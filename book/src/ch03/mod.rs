@@ -0,0 +1,5 @@
+//! [Common Programming Concepts]
+//!
+//! [common programming concepts]: https://doc.rust-lang.org/book/ch03-00-common-programming-concepts.html
+pub mod control_flow;
+pub mod overflow;
@@ -0,0 +1,138 @@
+//! [Integer Overflow] and [Floating-Point Types]
+//!
+//! `255u8 + 1` panics in the debug build and wraps to `0` in the release
+//! build.  Use one of the explicit strategies instead:
+//!
+//! ```
+//! let x = u8::MAX;
+//! assert_eq!(None, x.checked_add(1));
+//! assert_eq!(u8::MAX, x.saturating_add(1));
+//! assert_eq!(0, x.wrapping_add(1));
+//! assert_eq!((0, true), x.overflowing_add(1));
+//! ```
+//!
+//! `f32` has the less precision than `f64`, and neither of them is
+//! exact for `0.1` or `0.2`.
+//!
+//! ```
+//! assert_eq!(0.3_f32, 0.1_f32 + 0.2_f32);
+//! assert_ne!(0.3_f64, 0.1_f64 + 0.2_f64);
+//! assert!((0.3_f64 - (0.1_f64 + 0.2_f64)).abs() < f64::EPSILON);
+//! ```
+//!
+//! The signed `n` bits integer covers `-(2^(n - 1))..=2^(n - 1) - 1`.
+//!
+//! ```
+//! assert_eq!((-128, 127), (i8::MIN, i8::MAX));
+//! assert_eq!((-32_768, 32_767), (i16::MIN, i16::MAX));
+//! ```
+//! [integer overflow]: https://doc.rust-lang.org/book/ch03-02-data-types.html#integer-overflow
+//! [floating-point types]: https://doc.rust-lang.org/book/ch03-02-data-types.html#floating-point-types
+
+/// Returns the name, the number of bits and the `MIN..=MAX` range of all
+/// the signed integer types.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch03::overflow::signed_ranges;
+///
+/// let (name, bits, min, max) = signed_ranges()[0];
+/// assert_eq!(("i8", 8, -128, 127), (name, bits, min, max));
+/// ```
+pub fn signed_ranges() -> [(&'static str, u32, i128, i128); 5] {
+    [
+        ("i8", i8::BITS, i8::MIN.into(), i8::MAX.into()),
+        ("i16", i16::BITS, i16::MIN.into(), i16::MAX.into()),
+        ("i32", i32::BITS, i32::MIN.into(), i32::MAX.into()),
+        ("i64", i64::BITS, i64::MIN.into(), i64::MAX.into()),
+        ("i128", i128::BITS, i128::MIN, i128::MAX),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn add() {
+        struct Test {
+            name: &'static str,
+            data: (u8, u8),
+            checked: Option<u8>,
+            saturating: u8,
+            wrapping: u8,
+            overflowing: (u8, bool),
+        }
+        let tests = [
+            Test {
+                name: "no overflow",
+                data: (254, 1),
+                checked: Some(255),
+                saturating: 255,
+                wrapping: 255,
+                overflowing: (255, false),
+            },
+            Test {
+                name: "overflow by one",
+                data: (255, 1),
+                checked: None,
+                saturating: 255,
+                wrapping: 0,
+                overflowing: (0, true),
+            },
+            Test {
+                name: "overflow by max",
+                data: (255, 255),
+                checked: None,
+                saturating: 255,
+                wrapping: 254,
+                overflowing: (254, true),
+            },
+            Test {
+                name: "zero",
+                data: (0, 0),
+                checked: Some(0),
+                saturating: 0,
+                wrapping: 0,
+                overflowing: (0, false),
+            },
+        ];
+        for t in &tests {
+            let (a, b) = t.data;
+            assert_eq!(t.checked, a.checked_add(b), "{}", t.name);
+            assert_eq!(t.saturating, a.saturating_add(b), "{}", t.name);
+            assert_eq!(t.wrapping, a.wrapping_add(b), "{}", t.name);
+            assert_eq!(t.overflowing, a.overflowing_add(b), "{}", t.name);
+        }
+    }
+    #[test]
+    fn sub_underflow() {
+        assert_eq!(None, 0u8.checked_sub(1));
+        assert_eq!(0, 0u8.saturating_sub(1));
+        assert_eq!(u8::MAX, 0u8.wrapping_sub(1));
+        assert_eq!((u8::MAX, true), 0u8.overflowing_sub(1));
+    }
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    #[allow(arithmetic_overflow)]
+    fn add_overflow_panics_in_debug() {
+        // Tests are built with the overflow checks enabled.
+        let x = std::hint::black_box(u8::MAX);
+        let _ = x + 1;
+    }
+    #[test]
+    fn float_precision() {
+        assert_eq!(0.3_f32, 0.1_f32 + 0.2_f32);
+        assert_ne!(0.3_f64, 0.1_f64 + 0.2_f64);
+        assert_eq!(0.300_000_000_000_000_04_f64, 0.1_f64 + 0.2_f64);
+        // `f32` rounds the `f64` error away.
+        assert_eq!(0.3_f32, (0.1_f64 + 0.2_f64) as f32);
+    }
+    #[test]
+    fn signed_ranges() {
+        for &(name, bits, min, max) in &super::signed_ranges() {
+            assert_eq!(i128::MIN >> (128 - bits), min, "{}", name);
+            assert_eq!(-(min + 1), max, "{}", name);
+            assert_eq!(name[1..].parse::<u32>().unwrap(), bits, "{}", name);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! [Understanding Ownership]
+//!
+//! [understanding ownership]: https://doc.rust-lang.org/book/ch04-00-understanding-ownership.html
+pub mod ownership;
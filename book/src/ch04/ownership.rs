@@ -0,0 +1,252 @@
+//! [What Is Ownership?] and [References and Borrowing]
+//!
+//! `let y = x` moves the `String`, so `x` can't be used after that.
+//!
+//! ```compile_fail
+//! let x = String::from("hello");
+//! let y = x;
+//! println!("{}, world", x); // borrow of moved value: `x`
+//! # drop(y);
+//! ```
+//!
+//! while it copies the `i32`, which is `Copy`, and both are valid.
+//!
+//! ```
+//! let x = 5;
+//! let y = x;
+//! assert_eq!(x, y);
+//! ```
+//!
+//! The same happens to `Vec<T>` passed to the function by value.
+//!
+//! ```compile_fail
+//! use the_book::ch04::ownership::take;
+//!
+//! let v = vec![1, 2, 3];
+//! assert_eq!(3, take(v));
+//! assert_eq!(3, v.len()); // borrow of moved value: `v`
+//! ```
+//!
+//! [`first_word_index`] returns the index which is not tied to the
+//! `String`, so it silently becomes stale after the `String` is cleared,
+//! while the slice returned by [`first_word`] keeps the `String` borrowed.
+//!
+//! ```compile_fail
+//! use the_book::ch04::ownership::first_word;
+//!
+//! let mut s = String::from("hello world");
+//! let word = first_word(&s);
+//! s.clear(); // cannot borrow `s` as mutable because it is also borrowed as immutable
+//! assert_eq!("hello", word);
+//! ```
+//! [what is ownership?]: https://doc.rust-lang.org/book/ch04-01-what-is-ownership.html
+//! [references and borrowing]: https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html
+
+/// Takes the ownership of `v` and returns its length.  `v` is dropped
+/// when it goes out of scope at the end of the function.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch04::ownership::take;
+///
+/// let v = vec![1, 2, 3];
+/// assert_eq!(3, take(v.clone()));
+/// assert_eq!(3, v.len());
+/// ```
+pub fn take<T>(v: Vec<T>) -> usize {
+    v.len()
+}
+
+/// Borrows `v` and returns its length.  The caller keeps the ownership.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch04::ownership::borrow;
+///
+/// let v = vec![1, 2, 3];
+/// assert_eq!(3, borrow(&v));
+/// assert_eq!(3, v.len());
+/// ```
+pub fn borrow<T>(v: &[T]) -> usize {
+    v.len()
+}
+
+/// Takes the ownership of `s` and gives it back to the caller.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch04::ownership::takes_and_gives_back;
+///
+/// let s1 = String::from("hello");
+/// let s2 = takes_and_gives_back(s1);
+/// assert_eq!("hello", s2);
+/// ```
+pub fn takes_and_gives_back(s: String) -> String {
+    s
+}
+
+/// Returns the byte index of the end of the first word in `s`.
+///
+/// The index has no connection to `s` and becomes meaningless once `s`
+/// is changed.  Use [`first_word`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch04::ownership::first_word_index;
+///
+/// let mut s = String::from("hello world");
+/// let word = first_word_index(&s);
+/// s.clear();
+/// // `word` is still 5, but there is no word in `s` anymore.
+/// assert_eq!(5, word);
+/// assert!(s.get(..word).is_none());
+/// ```
+pub fn first_word_index(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// Returns the first word in `s` as the string slice, which borrows `s`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch04::ownership::first_word;
+///
+/// let s = String::from("hello world");
+/// assert_eq!("hello", first_word(&s));
+/// assert_eq!("hello", first_word("hello"));
+/// ```
+pub fn first_word(s: &str) -> &str {
+    let bytes = s.as_bytes();
+
+    for (i, &item) in bytes.iter().enumerate() {
+        if item == b' ' {
+            return &s[..i];
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_word() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "two words",
+                data: "hello world",
+                want: "hello",
+            },
+            Test {
+                name: "one word",
+                data: "hello",
+                want: "hello",
+            },
+            Test {
+                name: "leading space",
+                data: " hello",
+                want: "",
+            },
+            Test {
+                name: "empty",
+                data: "",
+                want: "",
+            },
+            Test {
+                name: "multi-byte",
+                data: "héllo wörld",
+                want: "héllo",
+            },
+        ];
+        for t in &tests {
+            let s = String::from(t.data);
+            assert_eq!(t.want, super::first_word(&s), "{}", t.name);
+            assert_eq!(t.want.len(), first_word_index(&s), "{}", t.name);
+        }
+    }
+    #[test]
+    fn first_word_index_is_stale_after_change() {
+        let mut s = String::from("hello world");
+        let word = first_word_index(&s);
+        s = String::from("hi");
+        // This would not compile with `first_word`, which borrows `s`:
+        // let word = first_word(&s);
+        // s = String::from("hi"); // cannot assign to `s` because it is borrowed
+        // println!("{}", word);
+        assert_eq!(5, word);
+        assert_eq!(None, s.get(..word));
+    }
+    #[test]
+    fn string_move() {
+        let x = String::from("hello");
+        let ptr = x.as_ptr();
+        let y = x;
+        // `x` is moved to `y` without copying the heap data.
+        assert_eq!(ptr, y.as_ptr());
+        assert_eq!("hello", y);
+    }
+    #[test]
+    fn string_clone() {
+        let x = String::from("hello");
+        let y = x.clone();
+        // Both are valid and own the different heap data.
+        assert_eq!(x, y);
+        assert_ne!(x.as_ptr(), y.as_ptr());
+    }
+    #[test]
+    fn copy() {
+        let x = 5;
+        let mut y = x;
+        y += 1;
+        assert_eq!(5, x);
+        assert_eq!(6, y);
+
+        let (a, b) = ((1, 'a', true), 2.0);
+        let (c, d) = (a, b);
+        assert_eq!((a, b), (c, d));
+    }
+    #[test]
+    fn str_is_borrowed() {
+        let s = String::from("hello");
+        let slice: &str = &s;
+        let literal: &'static str = "hello";
+        // `&str` doesn't own the data, so `s` is still valid.
+        assert_eq!(slice, literal);
+        assert_eq!(s.as_ptr(), slice.as_ptr());
+    }
+    #[test]
+    fn vec_move_and_borrow() {
+        let v = vec![String::from("a"), String::from("b")];
+        assert_eq!(2, borrow(&v));
+        assert_eq!(2, take(v.clone()));
+        let ptr = v.as_ptr();
+        let w = v;
+        assert_eq!(ptr, w.as_ptr());
+        assert_eq!(2, take(w));
+    }
+    #[test]
+    fn takes_and_gives_back() {
+        let s1 = String::from("hello");
+        let ptr = s1.as_ptr();
+        let s2 = super::takes_and_gives_back(s1);
+        assert_eq!(ptr, s2.as_ptr());
+    }
+}
@@ -0,0 +1,4 @@
+//! [Using Structs to Structure Related Data]
+//!
+//! [using structs to structure related data]: https://doc.rust-lang.org/book/ch05-00-structs.html
+pub mod rectangle;
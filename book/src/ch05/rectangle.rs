@@ -0,0 +1,213 @@
+//! [Method Syntax] with `Rectangle`
+//!
+//! The struct update syntax copies the rest of the fields from the other
+//! instance.  Those are `f64`, which is `Copy`, so `r1` is still valid.
+//!
+//! ```
+//! use the_book::ch05::rectangle::Rectangle;
+//!
+//! let r1 = Rectangle {
+//!     width: 30.0,
+//!     height: 50.0,
+//! };
+//! let r2 = Rectangle { width: 5.0, ..r1 };
+//! assert_eq!(50.0, r2.height);
+//! assert_eq!(30.0, r1.width);
+//! ```
+//! [method syntax]: https://doc.rust-lang.org/book/ch05-03-method-syntax.html
+
+/// # Examples
+///
+/// ```
+/// use the_book::ch05::rectangle::Rectangle;
+///
+/// let rect = Rectangle {
+///     width: 30.0,
+///     height: 50.0,
+/// };
+/// assert_eq!(
+///     "Rectangle { width: 30.0, height: 50.0 }",
+///     format!("{r:?}", r = rect),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    /// Associated function to create the square `Rectangle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch05::rectangle::Rectangle;
+    ///
+    /// let sq = Rectangle::square(3.0);
+    /// assert_eq!(3.0, sq.width);
+    /// assert_eq!(3.0, sq.height);
+    /// ```
+    pub fn square(size: f64) -> Self {
+        Self {
+            width: size,
+            height: size,
+        }
+    }
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch05::rectangle::Rectangle;
+    ///
+    /// let rect = Rectangle {
+    ///     width: 30.0,
+    ///     height: 50.0,
+    /// };
+    /// assert_eq!(1500.0, rect.area());
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.width * self.height
+    }
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch05::rectangle::Rectangle;
+    ///
+    /// let rect = Rectangle {
+    ///     width: 30.0,
+    ///     height: 50.0,
+    /// };
+    /// assert_eq!(160.0, rect.perimeter());
+    /// ```
+    pub fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+    /// Returns `true` if the width and the height are exactly the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch05::rectangle::Rectangle;
+    ///
+    /// assert!(Rectangle::square(3.0).is_square());
+    /// let rect = Rectangle {
+    ///     width: 3.0,
+    ///     height: 4.0,
+    /// };
+    /// assert!(!rect.is_square());
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+    /// Returns `true` if `other` fits in `self` without rotating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch05::rectangle::Rectangle;
+    ///
+    /// let rect1 = Rectangle {
+    ///     width: 30.0,
+    ///     height: 50.0,
+    /// };
+    /// let rect2 = Rectangle {
+    ///     width: 10.0,
+    ///     height: 40.0,
+    /// };
+    /// assert!(rect1.can_hold(&rect2));
+    /// assert!(!rect2.can_hold(&rect1));
+    /// ```
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_and_perimeter() {
+        struct Test {
+            name: &'static str,
+            data: Rectangle,
+            want: (f64, f64),
+        }
+        let tests = [
+            Test {
+                name: "rectangle",
+                data: Rectangle {
+                    width: 30.0,
+                    height: 50.0,
+                },
+                want: (1500.0, 160.0),
+            },
+            Test {
+                name: "square",
+                data: Rectangle::square(2.5),
+                want: (6.25, 10.0),
+            },
+            Test {
+                name: "empty",
+                data: Rectangle::square(0.0),
+                want: (0.0, 0.0),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want.0, t.data.area(), "{}", t.name);
+            assert_eq!(t.want.1, t.data.perimeter(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn can_hold() {
+        let rect = Rectangle {
+            width: 8.0,
+            height: 7.0,
+        };
+        assert!(rect.can_hold(&Rectangle::square(5.0)));
+        assert!(!rect.can_hold(&Rectangle::square(7.0)));
+        assert!(!rect.can_hold(&rect));
+        assert!(!Rectangle::square(5.0).can_hold(&rect));
+    }
+    #[test]
+    fn is_square() {
+        assert!(Rectangle::square(1.0).is_square());
+        let rect = Rectangle {
+            height: 2.0,
+            ..Rectangle::square(1.0)
+        };
+        assert!(!rect.is_square());
+    }
+    #[test]
+    fn struct_update() {
+        let r1 = Rectangle {
+            width: 30.0,
+            height: 50.0,
+        };
+        let r2 = Rectangle { width: 5.0, ..r1 };
+        assert_eq!(
+            Rectangle {
+                width: 5.0,
+                height: 50.0
+            },
+            r2
+        );
+        // `r1` is still valid, because the fields are `Copy`.
+        assert_eq!(30.0, r1.width);
+    }
+    #[test]
+    fn debug() {
+        let rect = Rectangle {
+            width: 1.5,
+            height: 2.0,
+        };
+        assert_eq!(
+            "Rectangle { width: 1.5, height: 2.0 }",
+            format!("{r:?}", r = rect)
+        );
+        assert_eq!(
+            "Rectangle {\n    width: 1.5,\n    height: 2.0,\n}",
+            format!("{:#?}", rect)
+        );
+    }
+}
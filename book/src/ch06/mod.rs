@@ -0,0 +1,4 @@
+//! [Enums and Pattern Matching]
+//!
+//! [enums and pattern matching]: https://doc.rust-lang.org/book/ch06-00-enums.html
+pub mod option_combinators;
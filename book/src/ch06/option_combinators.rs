@@ -0,0 +1,206 @@
+//! [The Option Enum] Combinators
+//!
+//! The combinators chain the `Option` values without `match`, e.g. the
+//! parse pipeline below falls back to `0` for the absent, malformed or
+//! out of range input.
+//!
+//! ```
+//! use the_book::ch06::option_combinators::{clamp_to_byte, parse_u32};
+//!
+//! let input = Some("42");
+//! assert_eq!(Some(42), input.and_then(parse_u32).and_then(clamp_to_byte).or(Some(0)));
+//! let input = Some("256");
+//! assert_eq!(Some(0), input.and_then(parse_u32).and_then(clamp_to_byte).or(Some(0)));
+//! ```
+//!
+//! `.map(f).flatten()` is the same as `.and_then(f)`.
+//!
+//! ```
+//! let x = Some(2);
+//! assert_eq!(x.map(|x| Some(x * 2)).flatten(), x.and_then(|x| Some(x * 2)));
+//! ```
+//! [the option enum]: https://doc.rust-lang.org/book/ch06-01-defining-an-enum.html#the-option-enum-and-its-advantages-over-null-values
+use std::convert::TryFrom;
+use std::num::ParseIntError;
+
+/// Parse the decimal `u32`, ignoring the surrounding whitespaces.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch06::option_combinators::parse_u32;
+///
+/// assert_eq!(Some(42), parse_u32(" 42 "));
+/// assert_eq!(None, parse_u32("-1"));
+/// ```
+pub fn parse_u32(s: &str) -> Option<u32> {
+    s.trim().parse().ok()
+}
+
+/// Returns `n` as `u8`, or `None` in case it doesn't fit.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch06::option_combinators::clamp_to_byte;
+///
+/// assert_eq!(Some(255), clamp_to_byte(255));
+/// assert_eq!(None, clamp_to_byte(256));
+/// ```
+pub fn clamp_to_byte(n: u32) -> Option<u8> {
+    u8::try_from(n).ok()
+}
+
+/// Parse the `input` as the byte, falling back to `0`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch06::option_combinators::parse_byte;
+///
+/// assert_eq!(Some(42), parse_byte(Some("42")));
+/// assert_eq!(Some(0), parse_byte(Some("forty two")));
+/// assert_eq!(Some(0), parse_byte(None));
+/// ```
+pub fn parse_byte(input: Option<&str>) -> Option<u8> {
+    input
+        .and_then(parse_u32)
+        .and_then(clamp_to_byte)
+        .or(Some(0))
+}
+
+/// Parse the `input` as the byte, or returns the error message.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch06::option_combinators::byte_or_err;
+///
+/// assert_eq!(Ok(42), byte_or_err("42"));
+/// assert_eq!(Err("not a byte"), byte_or_err("256"));
+/// ```
+pub fn byte_or_err(input: &str) -> Result<u8, &'static str> {
+    parse_u32(input).and_then(clamp_to_byte).ok_or("not a byte")
+}
+
+/// Parse the optional `input`, which is `Ok(None)` for the absent input
+/// and `Err` for the malformed one.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch06::option_combinators::parse_optional;
+///
+/// assert_eq!(Ok(Some(42)), parse_optional(Some("42")));
+/// assert_eq!(Ok(None), parse_optional(None));
+/// assert!(parse_optional(Some("x")).is_err());
+/// ```
+pub fn parse_optional(input: Option<&str>) -> Result<Option<u32>, ParseIntError> {
+    input.map(str::parse).transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_byte() {
+        struct Test {
+            name: &'static str,
+            data: Option<&'static str>,
+            want: Option<u8>,
+        }
+        let tests = [
+            Test {
+                name: "byte",
+                data: Some("42"),
+                want: Some(42),
+            },
+            Test {
+                name: "max byte",
+                data: Some("255"),
+                want: Some(255),
+            },
+            Test {
+                name: "out of range",
+                data: Some("256"),
+                want: Some(0),
+            },
+            Test {
+                name: "malformed",
+                data: Some("forty two"),
+                want: Some(0),
+            },
+            Test {
+                name: "absent",
+                data: None,
+                want: Some(0),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, super::parse_byte(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn map() {
+        let (some, none) = (Some("abc"), None::<&str>);
+        assert_eq!(Some(3), some.map(str::len));
+        assert_eq!(None, none.map(str::len));
+    }
+    #[test]
+    fn and_then() {
+        assert_eq!(Some(42), Some("42").and_then(parse_u32));
+        assert_eq!(None, Some("x").and_then(parse_u32));
+        assert_eq!(None, None.and_then(parse_u32));
+    }
+    #[test]
+    fn or_else() {
+        let fallback = || parse_u32("7");
+        assert_eq!(Some(1), Some(1).or_else(fallback));
+        assert_eq!(Some(7), None.or_else(fallback));
+        assert_eq!(None, None.or_else(|| parse_u32("x")));
+    }
+    #[test]
+    fn filter() {
+        let large = |n: &u32| *n > 3;
+        assert_eq!(Some(4), Some(4).filter(large));
+        assert_eq!(None, Some(3).filter(large));
+        assert_eq!(None, None.filter(large));
+    }
+    #[test]
+    fn zip() {
+        assert_eq!(Some((1, 'a')), Some(1).zip(Some('a')));
+        assert_eq!(None, Some(1).zip(None::<char>));
+        assert_eq!(None, None::<u32>.zip(Some('a')));
+    }
+    #[test]
+    fn flatten() {
+        assert_eq!(Some(1), Some(Some(1)).flatten());
+        assert_eq!(None, Some(None::<u32>).flatten());
+        assert_eq!(None, None::<Option<u32>>.flatten());
+    }
+    #[test]
+    #[allow(
+        clippy::bind_instead_of_map,
+        clippy::map_flatten,
+        clippy::redundant_closure
+    )]
+    fn map_flatten_is_and_then() {
+        for x in &[Some(1), None] {
+            assert_eq!(x.map(|x| Some(x)).flatten(), x.and_then(|x| Some(x)));
+            assert_eq!(x.map(clamp_to_byte).flatten(), x.and_then(clamp_to_byte));
+        }
+    }
+    #[test]
+    fn ok_or() {
+        assert_eq!(Ok(42), byte_or_err("42"));
+        assert_eq!(Err("not a byte"), byte_or_err("256"));
+        assert_eq!(Err("not a byte"), byte_or_err(""));
+    }
+    #[test]
+    fn transpose() {
+        assert_eq!(Ok(Some(42)), parse_optional(Some("42")));
+        assert_eq!(Ok(None), parse_optional(None));
+        assert!(parse_optional(Some("-1")).is_err());
+    }
+}
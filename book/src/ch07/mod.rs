@@ -0,0 +1,4 @@
+//! [Managing Growing Projects with Packages, Crates, and Modules]
+//!
+//! [managing growing projects with packages, crates, and modules]: https://doc.rust-lang.org/book/ch07-00-managing-growing-projects-with-packages-crates-and-modules.html
+pub mod restaurant;
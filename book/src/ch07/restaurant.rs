@@ -0,0 +1,206 @@
+//! [Bringing Paths into Scope] and [Re-exporting Names]
+//!
+//! `pub use` re-exports [`Appetizer`] at the `restaurant` level, so the
+//! callers don't need to know about the private `back_of_house` module.
+//!
+//! ```
+//! use the_book::ch07::restaurant::Appetizer;
+//!
+//! let soup = Appetizer::soup();
+//! assert_eq!("soup", soup.salad);
+//! ```
+//!
+//! while `back_of_house` itself is not accessible.
+//!
+//! ```compile_fail
+//! use the_book::ch07::restaurant::back_of_house::Appetizer; // module `back_of_house` is private
+//! ```
+//!
+//! The idiomatic `use` brings the parent module of the function into the
+//! scope, to make it clear the function is not locally defined,
+//!
+//! ```
+//! use std::fs;
+//!
+//! assert!(fs::read_to_string("does/not/exist").is_err());
+//! ```
+//!
+//! and the full path of the struct, the enum and other items.
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! let mut map = HashMap::new();
+//! map.insert(1, 2);
+//! assert_eq!(Some(&2), map.get(&1));
+//! ```
+//!
+//! `as` gives the new local name to the item with the same name.
+//!
+//! ```
+//! use std::fmt::Result as FmtResult;
+//! use std::io::Result as IoResult;
+//!
+//! fn format() -> FmtResult {
+//!     Ok(())
+//! }
+//! fn read() -> IoResult<()> {
+//!     Ok(())
+//! }
+//! assert!(format().is_ok());
+//! assert!(read().is_ok());
+//! ```
+//! [bringing paths into scope]: https://doc.rust-lang.org/book/ch07-04-bringing-paths-into-scope-with-the-use-keyword.html
+//! [re-exporting names]: https://doc.rust-lang.org/book/ch07-04-bringing-paths-into-scope-with-the-use-keyword.html#re-exporting-names-with-pub-use
+use std::fmt::{self, Result as FmtResult};
+
+pub use back_of_house::Appetizer;
+
+/// # Examples
+///
+/// ```
+/// use the_book::ch07::restaurant::front_of_house::{hosting, serving};
+///
+/// hosting::add_to_waitlist();
+/// assert_eq!(3, hosting::seat_at_table(3));
+/// assert_eq!("soup for table 3", serving::serve_order(3, "soup"));
+/// ```
+pub mod front_of_house {
+    pub mod hosting {
+        pub fn add_to_waitlist() {}
+        /// Returns the table number.
+        pub fn seat_at_table(table: u32) -> u32 {
+            table
+        }
+    }
+    pub mod serving {
+        pub fn take_order() {}
+        pub fn serve_order(table: u32, dish: &str) -> String {
+            format!("{} for table {}", dish, table)
+        }
+        /// Private function, which is only visible in `serving`.
+        #[allow(dead_code)]
+        fn take_payment() {}
+    }
+}
+
+mod back_of_house {
+    /// Appetizer, which is only created by the chef.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Appetizer {
+        pub salad: String,
+        // Private field, which prevents the callers from creating it.
+        seasonal: bool,
+    }
+
+    impl Appetizer {
+        pub fn soup() -> Self {
+            Self {
+                salad: String::from("soup"),
+                seasonal: false,
+            }
+        }
+        pub fn seasonal(salad: &str) -> Self {
+            Self {
+                salad: salad.to_string(),
+                seasonal: true,
+            }
+        }
+        pub fn is_seasonal(&self) -> bool {
+            self.seasonal
+        }
+    }
+}
+
+/// Order at the table, which is written with `FmtResult` to tell it
+/// from `std::io::Result`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch07::restaurant::{Appetizer, Order};
+///
+/// let order = Order {
+///     table: 3,
+///     appetizer: Appetizer::seasonal("kale"),
+/// };
+/// assert_eq!("table 3: kale (seasonal)", order.to_string());
+/// ```
+#[derive(Debug)]
+pub struct Order {
+    pub table: u32,
+    pub appetizer: Appetizer,
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> FmtResult {
+        write!(f, "table {}: {}", self.table, self.appetizer.salad)?;
+        if self.appetizer.is_seasonal() {
+            write!(f, " (seasonal)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Seat at the table and serve the order, through the absolute and the
+/// relative paths.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch07::restaurant::{eat_at_restaurant, Appetizer};
+///
+/// assert_eq!("soup for table 1", eat_at_restaurant(Appetizer::soup()));
+/// ```
+pub fn eat_at_restaurant(appetizer: Appetizer) -> String {
+    // Absolute path.
+    let table = crate::ch07::restaurant::front_of_house::hosting::seat_at_table(1);
+    // Relative path.
+    front_of_house::serving::take_order();
+    self::front_of_house::serving::serve_order(table, &appetizer.salad)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appetizer() {
+        struct Test {
+            name: &'static str,
+            data: Appetizer,
+            want: (&'static str, bool),
+        }
+        let tests = [
+            Test {
+                name: "soup",
+                data: Appetizer::soup(),
+                want: ("soup", false),
+            },
+            Test {
+                name: "seasonal",
+                data: Appetizer::seasonal("kale"),
+                want: ("kale", true),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want.0, t.data.salad, "{}", t.name);
+            assert_eq!(t.want.1, t.data.is_seasonal(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn re_export_is_same_type() {
+        // The re-exported name and the original path are the same type
+        // inside the crate.
+        let a: Appetizer = back_of_house::Appetizer::soup();
+        assert_eq!(Appetizer::soup(), a);
+    }
+    #[test]
+    fn order_display() {
+        let order = Order {
+            table: 2,
+            appetizer: Appetizer::soup(),
+        };
+        assert_eq!("table 2: soup", order.to_string());
+    }
+}
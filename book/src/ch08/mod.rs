@@ -0,0 +1,6 @@
+//! Common [Collections]
+//!
+//! [collections]: https://doc.rust-lang.org/book/ch08-00-common-collections.html
+pub mod strings;
+pub mod vec_ops;
+pub mod word_freq;
@@ -0,0 +1,280 @@
+//! Storing UTF-8 Encoded Text with [Strings]
+//!
+//! # Examples
+//!
+//! `len` returns the number of bytes, not the number of characters.
+//!
+//! ```
+//! let hello = "你好";
+//! assert_eq!(6, hello.len());
+//! assert_eq!(2, hello.chars().count());
+//! ```
+//!
+//! `String` can't be indexed by the integer.
+//!
+//! ```compile_fail
+//! let hello = String::from("你好");
+//! let h = hello[0];
+//! ```
+//!
+//! and slicing in the middle of the multi-byte character panics.
+//!
+//! ```should_panic
+//! let hello = "你好";
+//! let _h = &hello[0..1];
+//! ```
+//! [strings]: https://doc.rust-lang.org/book/ch08-02-strings.html
+
+/// Returns the `n`th character of `s`, instead of the `n`th byte.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::nth_char;
+///
+/// assert_eq!(Some('好'), nth_char("你好", 1));
+/// assert_eq!(None, nth_char("你好", 2));
+/// ```
+pub fn nth_char(s: &str, n: usize) -> Option<char> {
+    s.chars().nth(n)
+}
+
+/// Returns the byte slice of `s`, or `None` in case the range is out
+/// of bounds or not on the character boundary.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::slice;
+///
+/// assert_eq!(Some("你"), slice("你好", 0, 3));
+/// assert_eq!(None, slice("你好", 0, 1));
+/// ```
+pub fn slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    s.get(start..end)
+}
+
+/// Returns the byte position of each character with `char_indices`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::char_positions;
+///
+/// assert_eq!(vec![(0, 'a'), (1, '你'), (4, 'b')], char_positions("a你b"));
+/// ```
+pub fn char_positions(s: &str) -> Vec<(usize, char)> {
+    s.char_indices().collect()
+}
+
+/// Returns the first `n` characters of `s` as the slice, with the
+/// byte position from `char_indices`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::first_chars;
+///
+/// assert_eq!("你", first_chars("你好", 1));
+/// assert_eq!("ab", first_chars("abc", 2));
+/// ```
+pub fn first_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((pos, _)) => &s[..pos],
+        None => s,
+    }
+}
+
+/// Returns the non-empty lines of `s` with `lines`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::non_empty_lines;
+///
+/// assert_eq!(vec!["one", "two"], non_empty_lines("one\n\ntwo\r\n"));
+/// ```
+pub fn non_empty_lines(s: &str) -> Vec<&str> {
+    s.lines().filter(|line| !line.is_empty()).collect()
+}
+
+/// Returns the words of `s` with `split_whitespace`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::words;
+///
+/// assert_eq!(vec!["你好", "世界"], words(" 你好\t世界 "));
+/// ```
+pub fn words(s: &str) -> Vec<&str> {
+    s.split_whitespace().collect()
+}
+
+/// Split `s` into the key and the value at the first `sep` with `splitn`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::key_value;
+///
+/// assert_eq!(Some(("key", "a=b")), key_value("key=a=b", '='));
+/// assert_eq!(None, key_value("key", '='));
+/// ```
+pub fn key_value(s: &str, sep: char) -> Option<(&str, &str)> {
+    let mut kv = s.splitn(2, sep);
+    match (kv.next(), kv.next()) {
+        (Some(k), Some(v)) => Some((k, v)),
+        _ => None,
+    }
+}
+
+/// Remove the surrounding quotes with `trim_matches`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::unquote;
+///
+/// assert_eq!("「你好」", unquote("\"「你好」\""));
+/// ```
+pub fn unquote(s: &str) -> &str {
+    s.trim_matches(|c| c == '"' || c == '\'')
+}
+
+/// Returns `true` if `s` contains `word`, ignoring the case.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::strings::contains_ignore_case;
+///
+/// assert!(contains_ignore_case("Hello, World", "world"));
+/// ```
+pub fn contains_ignore_case(s: &str, word: &str) -> bool {
+    s.to_lowercase().contains(&word.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn len_and_chars_count() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            len: usize,
+            count: usize,
+        }
+        let tests = [
+            Test {
+                name: "ASCII",
+                data: "hello",
+                len: 5,
+                count: 5,
+            },
+            Test {
+                name: "Chinese",
+                data: "你好",
+                len: 6,
+                count: 2,
+            },
+            Test {
+                name: "Cyrillic",
+                data: "Здравствуйте",
+                len: 24,
+                count: 12,
+            },
+            Test {
+                name: "empty",
+                data: "",
+                len: 0,
+                count: 0,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.len, t.data.len(), "{}", t.name);
+            assert_eq!(t.count, t.data.chars().count(), "{}", t.name);
+        }
+    }
+    #[test]
+    #[should_panic]
+    fn slice_in_the_middle_of_char() {
+        let hello = String::from("你好");
+        let _ = &hello[0..1];
+    }
+    #[test]
+    fn nth_char_and_slice() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            n: usize,
+            range: (usize, usize),
+            nth: Option<char>,
+            slice: Option<&'static str>,
+        }
+        let tests = [
+            Test {
+                name: "ASCII",
+                data: "hello",
+                n: 1,
+                range: (0, 1),
+                nth: Some('e'),
+                slice: Some("h"),
+            },
+            Test {
+                name: "Chinese on the boundary",
+                data: "你好",
+                n: 0,
+                range: (3, 6),
+                nth: Some('你'),
+                slice: Some("好"),
+            },
+            Test {
+                name: "Chinese not on the boundary",
+                data: "你好",
+                n: 5,
+                range: (1, 3),
+                nth: None,
+                slice: None,
+            },
+            Test {
+                name: "out of bounds",
+                data: "hi",
+                n: 2,
+                range: (0, 3),
+                nth: None,
+                slice: None,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.nth, nth_char(t.data, t.n), "{}", t.name);
+            assert_eq!(t.slice, slice(t.data, t.range.0, t.range.1), "{}", t.name);
+        }
+    }
+    #[test]
+    fn char_indices() {
+        assert_eq!(vec![(0, 'h'), (1, 'i')], char_positions("hi"));
+        assert_eq!(vec![(0, '你'), (3, '好')], char_positions("你好"));
+        assert!(char_positions("").is_empty());
+        assert_eq!("你好", first_chars("你好", 5));
+        assert_eq!("", first_chars("你好", 0));
+    }
+    #[test]
+    fn lines_and_words() {
+        assert_eq!(vec!["a b", "c"], non_empty_lines("a b\nc"));
+        assert_eq!(vec!["你好", "世界"], non_empty_lines("你好\n\n世界\n"));
+        assert_eq!(vec!["a", "b", "c"], words("a b\nc"));
+        assert_eq!(vec!["你好", "世界"], words("你好　世界"));
+        assert!(words("  ").is_empty());
+    }
+    #[test]
+    fn splitn_trim_and_contains() {
+        assert_eq!(Some(("名前", "太郎")), key_value("名前：太郎", '：'));
+        assert_eq!(Some(("k", "")), key_value("k=", '='));
+        assert_eq!("hello", unquote("'hello'"));
+        assert_eq!("it's", unquote("\"it's\""));
+        assert!(contains_ignore_case("你好 World", "你好"));
+        assert!(!contains_ignore_case("hello", "world"));
+    }
+}
@@ -0,0 +1,390 @@
+//! Advanced [Vector] operations
+//!
+//! # Examples
+//!
+//! ```
+//! use the_book::ch08::vec_ops::moving_average;
+//!
+//! let data = [1.0, 2.0, 3.0, 4.0];
+//! assert_eq!(vec![1.5, 2.5, 3.5], moving_average(&data, 2));
+//! ```
+//! [vector]: https://doc.rust-lang.org/book/ch08-01-vectors.html
+use std::ops::Range;
+
+/// Returns the sum of each `n` sized overlapping window with `windows`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::window_sums;
+///
+/// assert_eq!(vec![3, 5, 7], window_sums(&[1, 2, 3, 4], 2));
+/// assert!(window_sums(&[1, 2], 3).is_empty());
+/// ```
+pub fn window_sums(data: &[i32], n: usize) -> Vec<i32> {
+    if n == 0 {
+        return vec![];
+    }
+    data.windows(n).map(|w| w.iter().sum()).collect()
+}
+
+/// Returns the sum of each `n` sized chunk with `chunks`.  The last
+/// chunk may be shorter than `n`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::chunk_sums;
+///
+/// assert_eq!(vec![3, 7, 5], chunk_sums(&[1, 2, 3, 4, 5], 2));
+/// ```
+pub fn chunk_sums(data: &[i32], n: usize) -> Vec<i32> {
+    if n == 0 {
+        return vec![];
+    }
+    data.chunks(n).map(|c| c.iter().sum()).collect()
+}
+
+/// Split `data` into `n` sized chunks with `chunks_exact` and
+/// returns the chunks and the remainder.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::exact_chunks;
+///
+/// let (chunks, rest) = exact_chunks(&[1, 2, 3, 4, 5], 2);
+/// assert_eq!(vec![&[1, 2][..], &[3, 4][..]], chunks);
+/// assert_eq!(&[5], rest);
+/// ```
+pub fn exact_chunks(data: &[i32], n: usize) -> (Vec<&[i32]>, &[i32]) {
+    if n == 0 {
+        return (vec![], data);
+    }
+    let chunks = data.chunks_exact(n);
+    let rest = chunks.remainder();
+    (chunks.collect(), rest)
+}
+
+/// Remove the `range` from `v` with `drain` and returns the removed
+/// elements.  The `range` is clamped to the length of `v`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::drain_range;
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// assert_eq!(vec![2, 3], drain_range(&mut v, 1..3));
+/// assert_eq!(vec![1, 4, 5], v);
+/// ```
+pub fn drain_range(v: &mut Vec<i32>, range: Range<usize>) -> Vec<i32> {
+    let end = range.end.min(v.len());
+    let start = range.start.min(end);
+    v.drain(start..end).collect()
+}
+
+/// Keep only the even numbers with `retain`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::retain_even;
+///
+/// let mut v = vec![1, 2, 3, 4, 5, 6];
+/// retain_even(&mut v);
+/// assert_eq!(vec![2, 4, 6], v);
+/// ```
+pub fn retain_even(v: &mut Vec<i32>) {
+    v.retain(|x| x % 2 == 0);
+}
+
+/// Remove the consecutive case-insensitive duplicates with `dedup_by_key`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::dedup_ignore_case;
+///
+/// let mut v = vec![String::from("a"), String::from("A"), String::from("b"), String::from("a")];
+/// dedup_ignore_case(&mut v);
+/// assert_eq!(vec!["a", "b", "a"], v);
+/// ```
+pub fn dedup_ignore_case(v: &mut Vec<String>) {
+    v.dedup_by_key(|s| s.to_lowercase());
+}
+
+/// Sort the words by the length with `sort_unstable_by_key`.
+///
+/// The order of the same length words is unspecified.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::sort_by_len;
+///
+/// let mut v = vec!["three", "a", "to"];
+/// sort_by_len(&mut v);
+/// assert_eq!(vec!["a", "to", "three"], v);
+/// ```
+pub fn sort_by_len(v: &mut [&str]) {
+    v.sort_unstable_by_key(|s| s.len());
+}
+
+/// Split `data` into the halves with `split_at`.  The second half
+/// gets the extra element for the odd length.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::split_halves;
+///
+/// assert_eq!((&[1][..], &[2, 3][..]), split_halves(&[1, 2, 3]));
+/// ```
+pub fn split_halves(data: &[i32]) -> (&[i32], &[i32]) {
+    data.split_at(data.len() / 2)
+}
+
+/// Returns the moving average of `data` over the `window` sized
+/// windows.
+///
+/// It's empty for the zero `window` or the `window` larger than `data`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::vec_ops::moving_average;
+///
+/// assert_eq!(vec![2.0, 3.0], moving_average(&[1.0, 2.0, 3.0, 4.0], 3));
+/// ```
+pub fn moving_average(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 {
+        return vec![];
+    }
+    data.windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn window_and_chunk_sums() {
+        struct Test {
+            name: &'static str,
+            data: &'static [i32],
+            n: usize,
+            windows: Vec<i32>,
+            chunks: Vec<i32>,
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                n: 2,
+                windows: vec![],
+                chunks: vec![],
+            },
+            Test {
+                name: "zero size",
+                data: &[1, 2, 3],
+                n: 0,
+                windows: vec![],
+                chunks: vec![],
+            },
+            Test {
+                name: "size larger than slice",
+                data: &[1, 2, 3],
+                n: 4,
+                windows: vec![],
+                chunks: vec![6],
+            },
+            Test {
+                name: "size equal to slice",
+                data: &[1, 2, 3],
+                n: 3,
+                windows: vec![6],
+                chunks: vec![6],
+            },
+            Test {
+                name: "size of one",
+                data: &[1, 2, 3],
+                n: 1,
+                windows: vec![1, 2, 3],
+                chunks: vec![1, 2, 3],
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.windows, window_sums(t.data, t.n), "{}", t.name);
+            assert_eq!(t.chunks, chunk_sums(t.data, t.n), "{}", t.name);
+        }
+    }
+    #[test]
+    fn exact_chunks_remainder() {
+        struct Test {
+            name: &'static str,
+            data: &'static [i32],
+            n: usize,
+            want: (Vec<&'static [i32]>, &'static [i32]),
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                n: 2,
+                want: (vec![], &[]),
+            },
+            Test {
+                name: "no remainder",
+                data: &[1, 2, 3, 4],
+                n: 2,
+                want: (vec![&[1, 2], &[3, 4]], &[]),
+            },
+            Test {
+                name: "size larger than slice",
+                data: &[1, 2],
+                n: 3,
+                want: (vec![], &[1, 2]),
+            },
+            Test {
+                name: "zero size",
+                data: &[1, 2],
+                n: 0,
+                want: (vec![], &[1, 2]),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, exact_chunks(t.data, t.n), "{}", t.name);
+        }
+    }
+    #[test]
+    fn drain_range_clamped() {
+        struct Test {
+            name: &'static str,
+            range: Range<usize>,
+            drained: Vec<i32>,
+            rest: Vec<i32>,
+        }
+        let tests = [
+            Test {
+                name: "whole range",
+                range: 0..3,
+                drained: vec![1, 2, 3],
+                rest: vec![],
+            },
+            Test {
+                name: "empty range",
+                range: 1..1,
+                drained: vec![],
+                rest: vec![1, 2, 3],
+            },
+            Test {
+                name: "end out of range",
+                range: 1..10,
+                drained: vec![2, 3],
+                rest: vec![1],
+            },
+            Test {
+                name: "start out of range",
+                range: 5..10,
+                drained: vec![],
+                rest: vec![1, 2, 3],
+            },
+        ];
+        for t in &tests {
+            let mut v = vec![1, 2, 3];
+            assert_eq!(
+                t.drained,
+                drain_range(&mut v, t.range.clone()),
+                "{}",
+                t.name
+            );
+            assert_eq!(t.rest, v, "{}", t.name);
+        }
+    }
+    #[test]
+    fn retain_even_edge_cases() {
+        let mut v = vec![];
+        retain_even(&mut v);
+        assert!(v.is_empty());
+        let mut v = vec![1, 3, 5];
+        retain_even(&mut v);
+        assert!(v.is_empty());
+        let mut v = vec![-2, -1, 0];
+        retain_even(&mut v);
+        assert_eq!(vec![-2, 0], v);
+    }
+    #[test]
+    fn dedup_ignore_case_edge_cases() {
+        let mut v: Vec<String> = vec![];
+        dedup_ignore_case(&mut v);
+        assert!(v.is_empty());
+        let mut v: Vec<String> = ["Rust", "rust", "RUST"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        dedup_ignore_case(&mut v);
+        assert_eq!(vec!["Rust"], v);
+    }
+    #[test]
+    fn sort_by_len_edge_cases() {
+        let mut v: Vec<&str> = vec![];
+        sort_by_len(&mut v);
+        assert!(v.is_empty());
+        let mut v = vec!["ccc", "a", "bb", "dd"];
+        sort_by_len(&mut v);
+        let lens: Vec<_> = v.iter().map(|s| s.len()).collect();
+        assert_eq!(vec![1, 2, 2, 3], lens);
+    }
+    #[test]
+    fn split_halves_edge_cases() {
+        assert_eq!((&[][..], &[][..]), split_halves(&[]));
+        assert_eq!((&[][..], &[1][..]), split_halves(&[1]));
+        assert_eq!((&[1, 2][..], &[3, 4][..]), split_halves(&[1, 2, 3, 4]));
+    }
+    #[test]
+    fn moving_average_edge_cases() {
+        struct Test {
+            name: &'static str,
+            data: &'static [f64],
+            window: usize,
+            want: Vec<f64>,
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                window: 2,
+                want: vec![],
+            },
+            Test {
+                name: "zero window",
+                data: &[1.0, 2.0],
+                window: 0,
+                want: vec![],
+            },
+            Test {
+                name: "window larger than slice",
+                data: &[1.0, 2.0],
+                window: 3,
+                want: vec![],
+            },
+            Test {
+                name: "window of one",
+                data: &[1.0, 2.0],
+                window: 1,
+                want: vec![1.0, 2.0],
+            },
+            Test {
+                name: "window equal to slice",
+                data: &[1.0, 2.0, 6.0],
+                window: 3,
+                want: vec![3.0],
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, moving_average(t.data, t.window), "{}", t.name);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Word frequency with [Hash Maps]
+//!
+//! # Examples
+//!
+//! ```
+//! use the_book::ch08::word_freq::{top_n_words, word_frequency};
+//!
+//! let text = "hello world hello";
+//! let freq = word_frequency(text);
+//! assert_eq!(Some(&2), freq.get("hello"));
+//! assert_eq!(Some(&1), freq.get("world"));
+//! assert_eq!(vec![("hello", 2)], top_n_words(text, 1));
+//! ```
+//! [hash maps]: https://doc.rust-lang.org/book/ch08-03-hash-maps.html
+use std::collections::{BTreeMap, HashMap};
+
+/// Count the whitespace separated words in `text`.
+pub fn word_frequency(text: &str) -> HashMap<&str, usize> {
+    let mut map = HashMap::new();
+    for word in text.split_whitespace() {
+        let count = map.entry(word).or_insert(0);
+        *count += 1;
+    }
+    map
+}
+
+/// Returns the `n` most frequent words in `text`, sorted by the
+/// frequency in descending order.
+///
+/// Words with the same frequency are sorted alphabetically.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::word_freq::top_n_words;
+///
+/// let text = "b a b c a b";
+/// assert_eq!(vec![("b", 3), ("a", 2)], top_n_words(text, 2));
+/// ```
+pub fn top_n_words(text: &str, n: usize) -> Vec<(&str, usize)> {
+    let mut words: Vec<_> = word_frequency(text).into_iter().collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    words.truncate(n);
+    words
+}
+
+/// Count the whitespace separated words in `text`, ordered by the word.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch08::word_freq::word_frequency_sorted;
+///
+/// let freq = word_frequency_sorted("world hello world");
+/// assert_eq!(vec!["hello", "world"], freq.keys().collect::<Vec<_>>());
+/// ```
+pub fn word_frequency_sorted(text: &str) -> BTreeMap<String, usize> {
+    let mut map = BTreeMap::new();
+    for word in text.split_whitespace() {
+        let count = map.entry(word.to_string()).or_insert(0);
+        *count += 1;
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn word_frequency_sample_text() {
+        let mut want = HashMap::new();
+        want.insert("hello", 2);
+        want.insert("world", 1);
+        assert_eq!(want, word_frequency("hello world hello"));
+    }
+    #[test]
+    fn top_n_words_with_different_n() {
+        struct Test {
+            name: &'static str,
+            text: &'static str,
+            n: usize,
+            want: Vec<(&'static str, usize)>,
+        }
+        let tests = [
+            Test {
+                name: "top 1 of the sample text",
+                text: "hello world hello",
+                n: 1,
+                want: vec![("hello", 2)],
+            },
+            Test {
+                name: "n larger than the word count",
+                text: "hello world hello",
+                n: 5,
+                want: vec![("hello", 2), ("world", 1)],
+            },
+            Test {
+                name: "zero n",
+                text: "hello world hello",
+                n: 0,
+                want: vec![],
+            },
+            Test {
+                name: "empty text",
+                text: "",
+                n: 1,
+                want: vec![],
+            },
+            Test {
+                name: "same frequency in alphabetical order",
+                text: "c b a b c a",
+                n: 3,
+                want: vec![("a", 2), ("b", 2), ("c", 2)],
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, top_n_words(t.text, t.n), "{}", t.name);
+        }
+    }
+    #[test]
+    fn word_frequency_sorted_key_order() {
+        let got = word_frequency_sorted("pear apple zucchini apple banana");
+        let keys: Vec<_> = got.keys().map(String::as_str).collect();
+        assert_eq!(vec!["apple", "banana", "pear", "zucchini"], keys);
+        assert_eq!(Some(&2), got.get("apple"));
+    }
+}
@@ -0,0 +1,193 @@
+//! [Generic Data Types]
+//!
+//! # Performance of Code Using Generics
+//!
+//! Rust monomorphizes the generic code at compile time, e.g. the
+//! following code
+//!
+//! ```
+//! use the_book::ch10::generics::Stack;
+//!
+//! let mut integers = Stack::new();
+//! integers.push(5);
+//! let mut strings = Stack::new();
+//! strings.push(String::from("five"));
+//! ```
+//!
+//! is compiled as if there are two concrete types, as shown in the
+//! `cargo expand`-like pseudo output below.  There is no runtime cost
+//! of using the generic types.
+//!
+//! ```text
+//! pub struct Stack_i32(Vec<i32>);
+//!
+//! impl Stack_i32 {
+//!     pub fn push(&mut self, item: i32) {
+//!         self.0.push(item)
+//!     }
+//!     ...
+//! }
+//!
+//! pub struct Stack_String(Vec<String>);
+//!
+//! impl Stack_String {
+//!     pub fn push(&mut self, item: String) {
+//!         self.0.push(item)
+//!     }
+//!     ...
+//! }
+//! ```
+//! [generic data types]: https://doc.rust-lang.org/book/ch10-01-syntax.html
+
+/// LIFO stack of `T`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::generics::Stack;
+///
+/// let mut s = Stack::new();
+/// s.push('a');
+/// s.push('b');
+/// assert_eq!(Some(&'b'), s.peek());
+/// assert_eq!(Some('b'), s.pop());
+/// assert_eq!(1, s.len());
+/// ```
+#[derive(Debug, Default)]
+pub struct Stack<T>(Vec<T>);
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+    pub fn push(&mut self, item: T) {
+        self.0.push(item)
+    }
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+    /// Returns the reference to the top item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.0.last()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Returns the largest item in the `list`, or `None` for the empty
+/// `list`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::generics::largest;
+///
+/// assert_eq!(Some(&100), largest(&[34, 50, 25, 100, 65]));
+/// assert_eq!(Some(&'y'), largest(&['y', 'm', 'a', 'q']));
+/// assert_eq!(None, largest::<i32>(&[]));
+/// ```
+pub fn largest<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    let mut iter = list.iter();
+    let mut largest = iter.next()?;
+    for item in iter {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Debug;
+
+    /// Push `items` and pop all of them in the reverse order.
+    fn push_and_pop<T: Clone + Debug + PartialEq>(items: &[T]) {
+        let mut s = Stack::new();
+        assert!(s.is_empty());
+        assert_eq!(None, s.peek());
+        for (i, item) in items.iter().enumerate() {
+            s.push(item.clone());
+            assert_eq!(i + 1, s.len());
+            assert_eq!(Some(item), s.peek());
+        }
+        for item in items.iter().rev() {
+            assert_eq!(Some(item.clone()), s.pop());
+        }
+        assert!(s.is_empty());
+        assert_eq!(None, s.pop());
+    }
+    #[test]
+    fn stack_of_i32_and_string() {
+        push_and_pop(&[1, 2, 3]);
+        push_and_pop(&[String::from("one"), String::from("two")]);
+        push_and_pop::<i32>(&[]);
+        push_and_pop::<String>(&[]);
+    }
+    #[test]
+    fn largest_i32() {
+        struct Test {
+            name: &'static str,
+            data: &'static [i32],
+            want: Option<i32>,
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                want: None,
+            },
+            Test {
+                name: "single item",
+                data: &[1],
+                want: Some(1),
+            },
+            Test {
+                name: "ascending order",
+                data: &[1, 2, 3],
+                want: Some(3),
+            },
+            Test {
+                name: "negative values",
+                data: &[-3, -1, -2],
+                want: Some(-1),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want.as_ref(), largest(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn largest_char() {
+        struct Test {
+            name: &'static str,
+            data: &'static [char],
+            want: Option<char>,
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                want: None,
+            },
+            Test {
+                name: "ASCII characters",
+                data: &['y', 'm', 'a', 'q'],
+                want: Some('y'),
+            },
+            Test {
+                name: "multi-byte characters",
+                data: &['a', 'あ', 'z'],
+                want: Some('あ'),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want.as_ref(), largest(t.data), "{}", t.name);
+        }
+    }
+}
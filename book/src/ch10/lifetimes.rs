@@ -0,0 +1,263 @@
+//! [Lifetime Elision] Rules
+//!
+//! The compiler assigns the lifetimes to the references in the function
+//! and method signatures with the following three rules.  The explicit
+//! annotations are required only if the output lifetimes are still
+//! ambiguous after applying all of them.
+//!
+//! 1. Each reference parameter gets its own lifetime parameter, e.g.
+//!    `fn foo(x: &str, y: &str)` is `fn foo<'a, 'b>(x: &'a str, y: &'b str)`.
+//! 2. If there is exactly one input lifetime, it's assigned to all the
+//!    output lifetimes, e.g. [`first_word`] is
+//!    `fn first_word<'a>(s: &'a str) -> &'a str`.
+//! 3. If one of the input lifetimes is `&self` or `&mut self`, the lifetime
+//!    of `self` is assigned to all the output lifetimes, e.g.
+//!    [`announce_and_return_part`] is
+//!    `fn announce_and_return_part<'s, 'b>(&'s self, announcement: &'b str) -> &'s str`.
+//!
+//! # Examples
+//!
+//! Rule #1 and #2.
+//!
+//! ```
+//! use the_book::ch10::first_word;
+//!
+//! let sentence = String::from("hello world");
+//! let word = first_word(&sentence);
+//! assert_eq!("hello", word);
+//! assert_eq!(sentence.as_ptr(), word.as_ptr());
+//! ```
+//!
+//! Rule #1 and #3.
+//!
+//! ```
+//! use the_book::ch10::ImportantExcerpt;
+//!
+//! let novel = String::from("Call me Ishmael.  Some years ago...");
+//! let i = ImportantExcerpt::new(novel.split('.').next().unwrap());
+//! let part = i.announce_and_return_part("attention");
+//! assert_eq!("Call me Ishmael", part);
+//! assert_eq!(3, i.level());
+//! ```
+//!
+//! None of the rules figures out the output lifetime of the function with
+//! two reference parameters and without `self`.  It needs the explicit
+//! annotation, as [`longest`] does:
+//!
+//! ```compile_fail
+//! // error[E0106]: missing lifetime specifier
+//! fn longest(x: &str, y: &str) -> &str {
+//!     if x.len() > y.len() {
+//!         x
+//!     } else {
+//!         y
+//!     }
+//! }
+//! ```
+//!
+//! [lifetime elision]: https://doc.rust-lang.org/book/ch10-03-lifetime-syntax.html#lifetime-elision
+//! [`first_word`]: ../fn.first_word.html
+//! [`announce_and_return_part`]: ../struct.ImportantExcerpt.html#method.announce_and_return_part
+//! [`longest`]: ../fn.longest.html
+use super::sec03::ImportantExcerpt;
+
+impl<'a> ImportantExcerpt<'a> {
+    /// No lifetime annotation is required as there is no output
+    /// reference.
+    pub fn level(&self) -> i32 {
+        3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sec03::longest_with_an_announcement;
+    use super::super::sec03::{first_word, longest, longest_by, longest_of};
+    use super::*;
+
+    /// Returns `true` if `part` points into `whole`.
+    fn points_into(whole: &str, part: &str) -> bool {
+        let whole = whole.as_bytes().as_ptr_range();
+        let part = part.as_bytes().as_ptr_range();
+        whole.start <= part.start && part.end <= whole.end
+    }
+    #[test]
+    fn first_word_points_into_input() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "two words",
+                data: "hello world",
+                want: "hello",
+            },
+            Test {
+                name: "single word",
+                data: "hello",
+                want: "hello",
+            },
+            Test {
+                name: "leading space",
+                data: " hello",
+                want: "",
+            },
+            Test {
+                name: "empty string",
+                data: "",
+                want: "",
+            },
+        ];
+        for t in &tests {
+            let data = String::from(t.data);
+            let got = first_word(&data);
+            assert_eq!(t.want, got, "{}", t.name);
+            assert!(points_into(&data, got), "{}", t.name);
+        }
+    }
+    #[test]
+    fn excerpt_part_points_into_novel() {
+        let novel = String::from("Call me Ishmael.  Some years ago...");
+        let i = ImportantExcerpt::new(novel.split('.').next().unwrap());
+        let announcement = String::from("attention");
+        let part = i.announce_and_return_part(&announcement);
+        assert_eq!("Call me Ishmael", part);
+        assert!(points_into(&novel, part));
+        assert!(points_into(&novel, i.part()));
+        assert!(!points_into(&announcement, part));
+        let got = i.announce_and_return_announcement(&announcement);
+        assert!(points_into(&announcement, got));
+        assert_eq!(3, i.level());
+    }
+    #[test]
+    fn longest_points_into_either_input() {
+        let x = String::from("long string is long");
+        let y = String::from("xyz");
+        assert!(points_into(&x, longest(&x, &y)));
+        assert!(points_into(&y, longest(&y[..1], &y)));
+    }
+    #[test]
+    fn longest_with_an_announcement_formats_any_display() {
+        let x = String::from("long string is long");
+        let y = String::from("xyz");
+        let (got, announcement) = longest_with_an_announcement(&x, &y, "today");
+        assert!(points_into(&x, got));
+        assert_eq!("Announcement! today", announcement);
+        let (got, announcement) = longest_with_an_announcement(&y, &x, 3.5);
+        assert!(points_into(&x, got));
+        assert_eq!("Announcement! 3.5", announcement);
+    }
+    #[test]
+    fn longest_of_slice() {
+        struct Test {
+            name: &'static str,
+            data: &'static [&'static str],
+            want: Option<&'static str>,
+        }
+        let tests = [
+            Test {
+                name: "empty slice",
+                data: &[],
+                want: None,
+            },
+            Test {
+                name: "single item",
+                data: &["a"],
+                want: Some("a"),
+            },
+            Test {
+                name: "longest in the middle",
+                data: &["a", "abc", "ab"],
+                want: Some("abc"),
+            },
+            Test {
+                name: "last of the ties",
+                data: &["ab", "cd", "e"],
+                want: Some("cd"),
+            },
+            Test {
+                name: "multibyte by bytes",
+                data: &["abcd", "ééé"],
+                want: Some("ééé"),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, longest_of(t.data), "{}", t.name);
+        }
+        // Outlives the slice.
+        let x = String::from("long string is long");
+        let got = {
+            let items = vec![x.as_str(), "xyz"];
+            longest_of(&items).unwrap()
+        };
+        assert!(points_into(&x, got));
+    }
+    #[test]
+    fn longest_by_chars_and_bytes() {
+        // 6 bytes and 3 chars, against 4 bytes and 4 chars.
+        let x = "ééé";
+        let y = "abcd";
+        assert_eq!(longest(x, y), longest_by(x, y, str::len));
+        assert_eq!(x, longest_by(x, y, str::len));
+        assert_eq!(y, longest_by(x, y, |s| s.chars().count()));
+        assert_eq!(y, longest_by(y, x, |s| s.chars().count()));
+        // Ties go to `y`, as `longest`.
+        assert_eq!("日本", longest_by("ab", "日本", |s| s.chars().count()));
+    }
+    #[test]
+    fn excerpt_sentences() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: Vec<&'static str>,
+        }
+        let tests = [
+            Test {
+                name: "trailing period",
+                data: "Call me Ishmael. Some years ago.",
+                want: vec!["Call me Ishmael", "Some years ago"],
+            },
+            Test {
+                name: "consecutive periods",
+                data: "Call me Ishmael.  Some years ago...never mind",
+                want: vec!["Call me Ishmael", "Some years ago", "never mind"],
+            },
+            Test {
+                name: "no periods",
+                data: " Call me Ishmael ",
+                want: vec!["Call me Ishmael"],
+            },
+            Test {
+                name: "only periods and spaces",
+                data: ". .. ",
+                want: vec![],
+            },
+            Test {
+                name: "empty string",
+                data: "",
+                want: vec![],
+            },
+        ];
+        for t in &tests {
+            let data = String::from(t.data);
+            let got: Vec<_> = ImportantExcerpt::sentences(&data)
+                .map(ImportantExcerpt::into_part)
+                .collect();
+            assert_eq!(t.want, got, "{}", t.name);
+            assert!(
+                got.iter().all(|part| points_into(&data, part)),
+                "{}",
+                t.name
+            );
+            let first = ImportantExcerpt::first_sentence_of(&data).map(ImportantExcerpt::into_part);
+            assert_eq!(t.want.first().copied(), first, "{}", t.name);
+            assert!(
+                first.is_none_or(|part| points_into(&data, part)),
+                "{}",
+                t.name
+            );
+        }
+    }
+}
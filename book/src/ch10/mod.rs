@@ -1,12 +1,19 @@
 //! [Generic Types], Traits, and Lifetimes
 //!
 //! [generic types]: https://doc.rust-lang.org/book/ch10-00-generics.html
+pub mod generics;
+pub mod lifetimes;
 pub mod sec00;
 pub mod sec01;
 pub mod sec02;
 pub mod sec03;
+pub mod traits_advanced;
 
-pub use sec01::{largest, Point};
-pub use sec02::{detailed_notify, detailed_notify2, notify, notify2, summarizable};
-pub use sec02::{Article, Pair, Summary, Tweet};
-pub use sec03::{first_word, longest, ImportantExcerpt};
+pub use sec01::{largest, largest_checked, largest_ref, try_largest, Point};
+pub use sec02::{
+    detailed_notify, detailed_notify2, notify, notify2, notify_all, notify_bound, notify_display,
+    summarizable,
+};
+pub use sec02::{Article, Describe, Pair, Summary, Tweet};
+pub use sec03::ImportantExcerpt;
+pub use sec03::{first_word, longest, longest_by, longest_of, longest_with_an_announcement};
@@ -4,15 +4,31 @@
 //!
 //! # Examples
 //!
+//! `largest` copies the item out, which needs `Copy` on top of
+//! `PartialOrd`, while `largest_ref` returns the reference and works
+//! with `String` too.
+//!
+//! ```
+//! use the_book::ch10::{largest, largest_ref};
+//!
+//! assert_eq!(100, largest(&[34, 50, 25, 100, 65]));
+//! assert_eq!('y', largest(&['y', 'm', 'a', 'q']));
+//! let list = vec![String::from("apple"), String::from("pear")];
+//! assert_eq!("pear", largest_ref(&list));
+//! ```
+//!
+//! Both panic on the empty slice, as there is no largest item.
+//! `largest_checked` returns `None` instead, and `try_largest` the
+//! `Error`.
+//!
 //! ```
-//! use the_book::ch10::sec01::{largest, Error};
-//!
-//! let list = vec![1, 2, 3, 4, 5];
-//! assert_eq!(&5, largest(&list).unwrap());
-//! let list = vec!['a', 'b', 'c'];
-//! assert_eq!(&'c', largest(&list).unwrap());
-//! let list: Vec<String> = vec![];
-//! assert_eq!(Err(Error::RangeError), largest(&list));
+//! use the_book::ch10::sec01::{try_largest, Error};
+//! use the_book::ch10::largest_checked;
+//!
+//! assert_eq!(Some(&5), largest_checked(&[1, 5, 3]));
+//! assert_eq!(None, largest_checked::<String>(&[]));
+//! assert_eq!(Ok(&'c'), try_largest(&['a', 'b', 'c']));
+//! assert_eq!(Err(Error::RangeError), try_largest::<String>(&[]));
 //! ```
 //!
 //! `Point` generic strucutre with the same types.
@@ -42,8 +58,6 @@
 //! assert_eq!(1, Point { x: 1, y: 'a' }.x());
 //! assert_eq!(2.0, Point { x: 1.1, y: 2.0 }.y());
 //! ```
-use core::cmp::PartialOrd;
-
 /// Error type.
 #[derive(Debug, PartialEq)]
 pub enum Error {
@@ -52,21 +66,43 @@ pub enum Error {
 
 type Result<T> = core::result::Result<T, Error>;
 
-/// Returns the largest item out of `list` slice.
-pub fn largest<T>(list: &[T]) -> Result<&T>
-where
-    T: PartialOrd,
-{
-    if list.is_empty() {
-        return Err(Error::RangeError);
-    }
-    let mut largest = &list[0];
-    list.iter().for_each(|item| {
-        if *largest < *item {
-            largest = item
+/// Returns the copy of the largest item in `list`.
+///
+/// # Panics
+///
+/// Panics on the empty `list`.
+pub fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
+    *largest_ref(list)
+}
+
+/// Returns the largest item in `list`, or the first one of the ties.
+///
+/// # Panics
+///
+/// Panics on the empty `list`.
+pub fn largest_ref<T: PartialOrd>(list: &[T]) -> &T {
+    largest_checked(list).expect("largest of the empty list")
+}
+
+/// Returns the largest item in `list`, or the first one of the ties, or
+/// `None` for the empty `list`.
+pub fn largest_checked<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    let mut iter = list.iter();
+    let mut largest = iter.next()?;
+    for item in iter {
+        if item > largest {
+            largest = item;
         }
-    });
-    Ok(largest)
+    }
+    Some(largest)
+}
+
+/// [`largest_checked`] with the `Error`, which was `largest` before it
+/// copied the item out.
+///
+/// [`largest_checked`]: fn.largest_checked.html
+pub fn try_largest<T: PartialOrd>(list: &[T]) -> Result<&T> {
+    largest_checked(list).ok_or(Error::RangeError)
 }
 
 /// Double type point structure.
@@ -91,3 +127,95 @@ where
         self.y
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_i32() {
+        struct Test {
+            name: &'static str,
+            data: &'static [i32],
+            want: i32,
+        }
+        let tests = [
+            Test {
+                name: "single item",
+                data: &[1],
+                want: 1,
+            },
+            Test {
+                name: "unordered",
+                data: &[34, 50, 25, 100, 65],
+                want: 100,
+            },
+            Test {
+                name: "negative values",
+                data: &[-3, -1, -2],
+                want: -1,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, largest(t.data), "{}", t.name);
+            assert_eq!(&t.want, largest_ref(t.data), "{}", t.name);
+            assert_eq!(Some(&t.want), largest_checked(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn largest_char() {
+        struct Test {
+            name: &'static str,
+            data: &'static [char],
+            want: char,
+        }
+        let tests = [
+            Test {
+                name: "ASCII characters",
+                data: &['y', 'm', 'a', 'q'],
+                want: 'y',
+            },
+            Test {
+                name: "multi-byte characters",
+                data: &['a', 'あ', 'z'],
+                want: 'あ',
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, largest(t.data), "{}", t.name);
+            assert_eq!(Some(&t.want), largest_checked(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn largest_string() {
+        let list: Vec<String> = ["apple", "pear", "banana"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!("pear", largest_ref(&list));
+        assert_eq!(Some(&list[1]), largest_checked(&list));
+    }
+    #[test]
+    fn ties() {
+        let list = [String::from("pear"), String::from("pear")];
+        assert!(std::ptr::eq(&list[0], largest_ref(&list)));
+        assert_eq!(7, largest(&[7, 3, 7]));
+    }
+    #[test]
+    fn empty() {
+        assert_eq!(None, largest_checked::<i32>(&[]));
+        assert_eq!(None, largest_checked::<String>(&[]));
+        assert_eq!(Err(Error::RangeError), try_largest::<i32>(&[]));
+        assert_eq!(Ok(&3), try_largest(&[1, 3, 2]));
+    }
+    #[test]
+    #[should_panic(expected = "largest of the empty list")]
+    fn largest_empty() {
+        largest::<i32>(&[]);
+    }
+    #[test]
+    #[should_panic(expected = "largest of the empty list")]
+    fn largest_ref_empty() {
+        largest_ref::<String>(&[]);
+    }
+}
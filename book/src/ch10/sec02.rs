@@ -9,6 +9,7 @@
 //!
 //! let article = Article {
 //!     headline: String::from("Headline!"),
+//!     author: String::from("Iceburgh"),
 //!     content: String::from("Article"),
 //! };
 //! let tweet = Tweet {
@@ -16,21 +17,51 @@
 //!     content: String::from("tweet, tweet, tweet!"),
 //! };
 //!
-//! assert_eq!(String::from("(Read more...)"), article.summarize());
+//! assert_eq!(String::from("(Read more from Iceburgh...)"), article.summarize());
 //! assert_eq!(String::from("tweet, tweet, tweet! @Sam I am"), tweet.summarize());
+//! assert_eq!(String::from("@Sam I am"), tweet.summarize_author());
 //! ```
 //!
 //! Traits as Parameter
 //!
 //! ```
-//! use the_book::ch10::{notify, notify2, Article, Summary};
+//! use the_book::ch10::{notify, notify2, notify_bound, Article, Summary};
 //!
 //! let article = Article {
 //!     headline: String::from("Headline!"),
+//!     author: String::from("Iceburgh"),
 //!     content: String::from("Article"),
 //! };
-//! assert_eq!(String::from("Breaking news!: (Read more...)"), notify(&article));
+//! assert_eq!(
+//!     String::from("Breaking news!: (Read more from Iceburgh...)"),
+//!     notify(&article),
+//! );
 //! assert_eq!(notify(&article), notify2(&article));
+//! assert_eq!(notify(&article), notify_bound(&article));
+//! ```
+//!
+//! The trait objects, which are dispatched at runtime, mix the
+//! implementors in the one slice.
+//!
+//! ```
+//! use the_book::ch10::{notify_all, Article, Summary, Tweet};
+//!
+//! let article = Article {
+//!     headline: String::from("Headline!"),
+//!     author: String::from("Iceburgh"),
+//!     content: String::from("Article"),
+//! };
+//! let tweet = Tweet {
+//!     username: String::from("I"),
+//!     content: String::from("yep"),
+//! };
+//! assert_eq!(
+//!     vec![
+//!         String::from("Breaking news!: (Read more from Iceburgh...)"),
+//!         String::from("Breaking news!: yep @I"),
+//!     ],
+//!     notify_all(&[&article, &tweet]),
+//! );
 //! ```
 //!
 //! Traits as Parameter with the multiple trait bounds.
@@ -63,17 +94,37 @@
 //! Using trait bounds to conditionally implement methods
 //!
 //! ```
-//! use the_book::ch10::{Pair, Tweet};
+//! use the_book::ch10::Pair;
 //!
 //! let p = Pair::new(1, 2);
+//! assert_eq!("The largest member is y = 2", p.cmp_display());
+//! ```
+//!
+//! `cmp_display` is only implemented for the `Display` and `PartialOrd`
+//! types, e.g. not for `Vec`, which doesn't implement `Display`:
+//!
+//! ```compile_fail,E0599
+//! use the_book::ch10::Pair;
+//!
+//! let p = Pair::new(vec![1], vec![2]);
+//! // error[E0599]: the method `cmp_display` exists for struct
+//! // `Pair<Vec<{integer}>>`, but its trait bounds were not satisfied
 //! p.cmp_display();
+//! ```
+//!
+//! nor for [`Tweet`], which doesn't implement `PartialOrd`.
+//!
+//! Blanket implementations
 //!
-//! let p = Pair::new(
-//!     Tweet { username: "A".to_string(), content: "Some tweet".to_string() },
-//!     Tweet { username: "B".to_string(), content: "Another tweet".to_string() },
-//! );
-//! // you can't do this, as [`Tweet`] doesn't implement PartialOrd.
-//! // p.cmp_display();
+//! ```
+//! use the_book::ch10::{Describe, Tweet};
+//!
+//! let tweet = Tweet {
+//!     username: "I".to_string(),
+//!     content: "yep".to_string(),
+//! };
+//! assert_eq!("It's 3.", 3.describe());
+//! assert_eq!("It's @I: yep.", tweet.describe());
 //! ```
 //! [`tweet`]: struct.Tweet.html
 use core::fmt::{Debug, Display};
@@ -92,6 +143,35 @@ pub fn notify2<T: Summary>(item: &T) -> String {
     format!("Breaking news!: {}", item.summarize())
 }
 
+/// [`notify2`] by the name of the [`notify_display`] and [`notify_all`]
+/// family.
+///
+/// [`notify2`]: fn.notify2.html
+/// [`notify_all`]: fn.notify_all.html
+/// [`notify_display`]: fn.notify_display.html
+pub fn notify_bound<T: Summary>(item: &T) -> String {
+    notify2(item)
+}
+
+/// [`notify`] with the multiple trait bounds, which also prints the
+/// item with `Display`.
+///
+/// [`notify`]: fn.notify.html
+pub fn notify_display<T: Summary + Display>(item: &T) -> String {
+    format!("Breaking news!: {}\n{}", item.summarize(), item)
+}
+
+/// Dynamically dispatched [`notify`] over the trait objects, unlike the
+/// above which are monomorphized for each implementor.
+///
+/// [`notify`]: fn.notify.html
+pub fn notify_all(items: &[&dyn Summary]) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| format!("Breaking news!: {}", item.summarize()))
+        .collect()
+}
+
 /// `impl` based `detailed_notify`, which is just a syntax sugar
 /// of [`detailed_notify2`].
 ///
@@ -115,10 +195,11 @@ pub fn summarizable(username: &str, content: &str) -> impl Summary {
     }
 }
 
-/// Default trait implementation.
+/// Default trait implementation, which calls the required method.
 pub trait Summary {
+    fn summarize_author(&self) -> String;
     fn summarize(&self) -> String {
-        String::from("(Read more...)")
+        format!("(Read more from {}...)", self.summarize_author())
     }
 }
 
@@ -127,11 +208,17 @@ pub trait Summary {
 /// [`summary`]: trait.Summary.html
 pub struct Article {
     pub headline: String,
+    pub author: String,
     pub content: String,
 }
 
-/// `Article` uses the default `summarize` method.
-impl Summary for Article {}
+/// `Article` only implements `summarize_author`, and uses the default
+/// `summarize` method on top of it.
+impl Summary for Article {
+    fn summarize_author(&self) -> String {
+        self.author.clone()
+    }
+}
 
 /// [`Summary`] trait implementor.
 ///
@@ -142,12 +229,22 @@ pub struct Tweet {
     pub content: String,
 }
 
+/// `Tweet` overrides the default `summarize` method.
 impl Summary for Tweet {
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.username)
+    }
     fn summarize(&self) -> String {
         format!("{} @{}", self.content, self.username)
     }
 }
 
+impl Display for Tweet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "@{}: {}", self.username, self.content)
+    }
+}
+
 /// Conditional generic implementor with the trait bound.
 pub struct Pair<T> {
     x: T,
@@ -161,11 +258,131 @@ impl<T> Pair<T> {
 }
 
 impl<T: Display + PartialOrd> Pair<T> {
-    pub fn cmp_display(&self) {
-        if self.x >= self.y {
-            println!("The largest member is x = {}", self.x);
+    /// Returns which member is larger, only for the comparable and
+    /// displayable `T`.
+    pub fn cmp_display(&self) -> String {
+        if self.x > self.y {
+            format!("The largest member is x = {}", self.x)
+        } else if self.x < self.y {
+            format!("The largest member is y = {}", self.y)
         } else {
-            println!("The largest member is y = {}", self.y);
+            format!("Both members are {}", self.x)
         }
     }
 }
+
+/// Blanket implementor example, as `ToString` of the standard library.
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+/// Implemented for all the `Display` types.
+impl<T: Display> Describe for T {
+    fn describe(&self) -> String {
+        format!("It's {}.", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_summarize() {
+        let article = Article {
+            headline: String::from("Penguins win the Stanley Cup Championship!"),
+            author: String::from("Iceburgh"),
+            content: String::from("The Pittsburgh Penguins once again..."),
+        };
+        assert_eq!("Iceburgh", article.summarize_author());
+        assert_eq!("(Read more from Iceburgh...)", article.summarize());
+        assert_eq!(
+            "Breaking news!: (Read more from Iceburgh...)",
+            notify(&article)
+        );
+    }
+    #[test]
+    fn overridden_summarize() {
+        let tweet = Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course"),
+        };
+        assert_eq!("@horse_ebooks", tweet.summarize_author());
+        // Not the default "(Read more from @horse_ebooks...)".
+        assert_eq!("of course @horse_ebooks", tweet.summarize());
+    }
+    #[test]
+    fn notify_article_and_tweet() {
+        let article = Article {
+            headline: String::from("Penguins win the Stanley Cup Championship!"),
+            author: String::from("Iceburgh"),
+            content: String::from("The Pittsburgh Penguins once again..."),
+        };
+        let tweet = Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course"),
+        };
+        assert_eq!(
+            "Breaking news!: (Read more from Iceburgh...)",
+            notify_bound(&article)
+        );
+        assert_eq!("Breaking news!: of course @horse_ebooks", notify(&tweet));
+        assert_eq!(notify(&tweet), notify2(&tweet));
+        assert_eq!(notify(&tweet), notify_bound(&tweet));
+        assert_eq!(
+            "Breaking news!: of course @horse_ebooks\n@horse_ebooks: of course",
+            notify_display(&tweet)
+        );
+        assert_eq!(
+            vec![
+                "Breaking news!: of course @horse_ebooks",
+                "Breaking news!: (Read more from Iceburgh...)",
+                "Breaking news!: of course @horse_ebooks",
+            ],
+            notify_all(&[&tweet, &article, &tweet])
+        );
+        assert!(notify_all(&[]).is_empty());
+    }
+    #[test]
+    fn pair_cmp_display() {
+        struct Test {
+            name: &'static str,
+            data: Pair<f64>,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "larger x",
+                data: Pair::new(2.5, 1.0),
+                want: "The largest member is x = 2.5",
+            },
+            Test {
+                name: "larger y",
+                data: Pair::new(-1.0, 0.5),
+                want: "The largest member is y = 0.5",
+            },
+            Test {
+                name: "equal",
+                data: Pair::new(3.0, 3.0),
+                want: "Both members are 3",
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, t.data.cmp_display(), "{}", t.name);
+        }
+        assert_eq!(
+            "The largest member is x = b",
+            Pair::new("b", "a").cmp_display()
+        );
+    }
+    #[test]
+    fn blanket_describe() {
+        let tweet = Tweet {
+            username: String::from("horse_ebooks"),
+            content: String::from("of course"),
+        };
+        assert_eq!("It's @horse_ebooks: of course.", tweet.describe());
+        assert_eq!("It's 1.", 1.describe());
+        assert_eq!("It's str.", "str".describe());
+    }
+}
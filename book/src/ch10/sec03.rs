@@ -30,6 +30,23 @@
 //! }
 //! ```
 //!
+//! Generic type parameters, trait bounds, and lifetimes together
+//!
+//! ```
+//! use the_book::ch10::{longest_by, longest_of, longest_with_an_announcement};
+//!
+//! let (longest, announcement) = longest_with_an_announcement("abc", "de", 42);
+//! assert_eq!("abc", longest);
+//! assert_eq!("Announcement! 42", announcement);
+//!
+//! assert_eq!(Some("abcd"), longest_of(&["a", "abcd", "ab"]));
+//! assert_eq!(None, longest_of(&[]));
+//!
+//! // 6 bytes, but 3 chars.
+//! assert_eq!("ééé", longest_by("ééé", "abcd", str::len));
+//! assert_eq!("abcd", longest_by("ééé", "abcd", |s| s.chars().count()));
+//! ```
+//!
 //! Lifetime annotations in struct.
 //!
 //! ```
@@ -42,6 +59,21 @@
 //! assert_eq!("Call me Ishmael", i.part());                         //  <--+
 //! ```
 //!
+//! The excerpts of each sentence, which borrow from the novel.
+//!
+//! ```
+//! use the_book::ch10::ImportantExcerpt;
+//!
+//! let novel = String::from("Call me Ishmael.  Some years ago...");
+//! let i = ImportantExcerpt::first_sentence_of(&novel).unwrap();
+//! assert_eq!("Call me Ishmael", i.part());
+//! let parts: Vec<_> = ImportantExcerpt::sentences(&novel)
+//!     .map(ImportantExcerpt::into_part)
+//!     .collect();
+//! assert_eq!(vec!["Call me Ishmael", "Some years ago"], parts);
+//! assert!(ImportantExcerpt::first_sentence_of(" ... ").is_none());
+//! ```
+//!
 //! Lifetime elision
 //!
 //! ```
@@ -66,6 +98,8 @@
 //! assert_eq!("Some years ago", i.announce_and_return_announcement(announcement));
 //! ```
 
+use std::fmt::Display;
+
 /// It returns the longest strings.
 pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
     if x.len() > y.len() {
@@ -75,6 +109,44 @@ pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
     }
 }
 
+/// [`longest`] with the announcement of any `Display` type, which
+/// returns the formatted announcement instead of printing it.
+///
+/// [`longest`]: fn.longest.html
+pub fn longest_with_an_announcement<'a, T>(x: &'a str, y: &'a str, ann: T) -> (&'a str, String)
+where
+    T: Display,
+{
+    let announcement = format!("Announcement! {}", ann);
+    (longest(x, y), announcement)
+}
+
+/// [`longest`] of the slice, which returns the last one of the longest
+/// strings as [`longest`] does, or `None` for the empty slice.
+///
+/// The returned string lives as long as the strings in the slice, not
+/// the slice itself.
+///
+/// [`longest`]: fn.longest.html
+pub fn longest_of<'a>(items: &[&'a str]) -> Option<&'a str> {
+    items.iter().copied().reduce(longest)
+}
+
+/// [`longest`] by the length of `key`, e.g. the number of chars
+/// instead of the bytes, which differ for the multibyte strings.
+///
+/// [`longest`]: fn.longest.html
+pub fn longest_by<'a, F>(x: &'a str, y: &'a str, key: F) -> &'a str
+where
+    F: Fn(&str) -> usize,
+{
+    if key(x) > key(y) {
+        x
+    } else {
+        y
+    }
+}
+
 /// Lifetime annotations example in structures.
 pub struct ImportantExcerpt<'a> {
     part: &'a str,
@@ -86,12 +158,35 @@ impl<'a> ImportantExcerpt<'a> {
     pub fn new(sentence: &'a str) -> Self {
         Self { part: sentence }
     }
+    /// Returns the excerpt of the first sentence of `text`, or `None`
+    /// if `text` has no sentence.
+    pub fn first_sentence_of(text: &'a str) -> Option<Self> {
+        Self::sentences(text).next()
+    }
+    /// Returns the excerpts of each sentence of `text`, split on `.`,
+    /// with the whitespace trimmed and the empty ones skipped.
+    ///
+    /// Both the iterator and the excerpts borrow from `text`, not from
+    /// the iterator.
+    pub fn sentences(text: &'a str) -> impl Iterator<Item = ImportantExcerpt<'a>> {
+        text.split('.')
+            .map(str::trim)
+            .filter(|sentence| !sentence.is_empty())
+            .map(Self::new)
+    }
     /// We don't need to provide the lifetime annotation for the
     /// return value, as it will get the &self lifetime annotation
     /// through the lifetime annotation rule #3.
     pub fn part(&self) -> &str {
         self.part
     }
+    /// Unlike [`part`], the explicit `'a` ties the part to the original
+    /// text instead of the excerpt, so it outlives the excerpt.
+    ///
+    /// [`part`]: #method.part
+    pub fn into_part(self) -> &'a str {
+        self.part
+    }
     /// We don't need any lifetime annotations here, as `annoucement`
     /// will get its own lifetime annotation, e.g. `'b` through the
     /// first rule of the lifetime annotation elision rule and the
@@ -0,0 +1,234 @@
+//! [Traits]: Multiple Trait Bounds, `where` Clauses and `impl Trait`
+//!
+//! # Returning Types that Implement Traits
+//!
+//! `impl Trait` in the return position hides the concrete type from the
+//! caller, but it's still a *single* concrete type chosen at the compile
+//! time.  The function can't return the different types conditionally:
+//!
+//! ```compile_fail
+//! use the_book::ch10::{Article, Summary, Tweet};
+//!
+//! fn summarizable(switch: bool) -> impl Summary {
+//!     if switch {
+//!         Article {
+//!             headline: String::from("Penguins win the Stanley Cup!"),
+//!             author: String::from("Iceburgh"),
+//!             content: String::from("The Pittsburgh Penguins once again..."),
+//!         }
+//!     } else {
+//!         // error[E0308]: `if` and `else` have incompatible types
+//!         Tweet {
+//!             username: String::from("horse_ebooks"),
+//!             content: String::from("of course, as you probably already know"),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Use the trait object, e.g. `Box<dyn Summary>`, instead as explained in
+//! [chapter 17].
+//!
+//! [traits]: https://doc.rust-lang.org/book/ch10-02-traits.html
+//! [chapter 17]: https://doc.rust-lang.org/book/ch17-02-trait-objects.html
+use core::fmt::Display;
+
+use super::sec02::{Article, Summary};
+
+/// Multiple trait bounds with the `+` syntax.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::traits_advanced::notify;
+/// use the_book::ch10::Tweet;
+///
+/// let tweet = Tweet {
+///     username: String::from("I"),
+///     content: String::from("yep"),
+/// };
+/// assert_eq!("Breaking news from @I: yep!: yep @I", notify(&tweet));
+/// ```
+pub fn notify<T: Summary + Display>(item: &T) -> String {
+    format!("Breaking news from {}!: {}", item, item.summarize())
+}
+
+/// [`notify`] with the `where` clause, which is easier to read with
+/// many trait bounds.
+///
+/// [`notify`]: fn.notify.html
+pub fn notify_where<T>(item: &T) -> String
+where
+    T: Summary + Display,
+{
+    format!("Breaking news from {}!: {}", item, item.summarize())
+}
+
+/// Returns the [`Article`] hidden behind the [`Summary`] trait.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::traits_advanced::make_summary;
+/// use the_book::ch10::Summary;
+///
+/// assert_eq!("(Read more from Iceburgh...)", make_summary().summarize());
+/// ```
+/// [`article`]: ../struct.Article.html
+/// [`summary`]: ../trait.Summary.html
+pub fn make_summary() -> impl Summary {
+    Article {
+        headline: String::from("Penguins win the Stanley Cup Championship!"),
+        author: String::from("Iceburgh"),
+        content: String::from(
+            "The Pittsburgh Penguins once again are the best hockey team in the NHL.",
+        ),
+    }
+}
+
+/// Returns the largest item in the `list`, which is displayable.
+///
+/// # Panics
+///
+/// Function `largest_displayable` panics on the empty `list`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::traits_advanced::largest_displayable;
+///
+/// assert_eq!("100", largest_displayable(&[34, 50, 25, 100, 65]).to_string());
+/// assert_eq!("y", largest_displayable(&['y', 'm', 'a', 'q']).to_string());
+///
+/// // you can't do this, as `Tweet` doesn't implement `PartialOrd`.
+/// // largest_displayable(&[Tweet { .. }]);
+/// ```
+pub fn largest_displayable<T: PartialOrd + Display>(list: &[T]) -> &T {
+    let mut largest = &list[0];
+    for item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+    largest
+}
+
+/// Blanket implementation example.
+///
+/// The book style `impl<T: Display + Summary> Summary for T {}` conflicts
+/// with the existing `Summary` implementors, e.g. [`Tweet`], with
+/// `error[E0119]`.  Instead, `Headline` is implemented for every type
+/// which implements both `Display` and `Summary`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch10::traits_advanced::Headline;
+/// use the_book::ch10::Tweet;
+///
+/// let tweet = Tweet {
+///     username: String::from("I"),
+///     content: String::from("yep"),
+/// };
+/// assert_eq!("@I: yep (yep @I)", tweet.headline());
+/// ```
+/// [`tweet`]: ../struct.Tweet.html
+pub trait Headline {
+    fn headline(&self) -> String;
+}
+
+impl<T: Display + Summary> Headline for T {
+    fn headline(&self) -> String {
+        format!("{} ({})", self, self.summarize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sec02::Tweet;
+    use super::*;
+
+    fn tweet(username: &str, content: &str) -> Tweet {
+        Tweet {
+            username: username.to_string(),
+            content: content.to_string(),
+        }
+    }
+    #[test]
+    fn notify_with_multiple_bounds() {
+        struct Test {
+            name: &'static str,
+            data: Tweet,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "short tweet",
+                data: tweet("I", "yep"),
+                want: "Breaking news from @I: yep!: yep @I",
+            },
+            Test {
+                name: "empty tweet",
+                data: tweet("", ""),
+                want: "Breaking news from @: !:  @",
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, notify(&t.data), "{}", t.name);
+            assert_eq!(t.want, notify_where(&t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn make_summary_hides_article() {
+        assert_eq!("(Read more from Iceburgh...)", make_summary().summarize());
+    }
+    #[test]
+    fn largest_displayable_i32() {
+        struct Test {
+            name: &'static str,
+            data: &'static [i32],
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "single item",
+                data: &[1],
+                want: "1",
+            },
+            Test {
+                name: "ascending order",
+                data: &[1, 2, 3],
+                want: "3",
+            },
+            Test {
+                name: "negative values",
+                data: &[-3, -1, -2],
+                want: "-1",
+            },
+        ];
+        for t in &tests {
+            assert_eq!(
+                t.want,
+                largest_displayable(t.data).to_string(),
+                "{}",
+                t.name
+            );
+        }
+    }
+    #[test]
+    fn largest_displayable_str() {
+        assert_eq!(&"pear", largest_displayable(&["apple", "pear", "banana"]));
+    }
+    #[test]
+    #[should_panic]
+    fn largest_displayable_empty() {
+        largest_displayable::<i32>(&[]);
+    }
+    #[test]
+    fn blanket_headline() {
+        assert_eq!(
+            "@A: Some tweet (Some tweet @A)",
+            tweet("A", "Some tweet").headline()
+        );
+    }
+}
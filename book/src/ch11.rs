@@ -1,4 +1,6 @@
 //! Writing Automated Tests
+pub mod adder;
+pub mod tdd;
 
 /// Adds two to the number given.
 ///
@@ -0,0 +1,89 @@
+//! [Test Organization] with the unit and the integration tests
+//!
+//! The unit tests are in the `tests` module below, next to the code, and
+//! can call the private `internal_adder`.  The integration tests in
+//! `tests/adder_integration.rs` use the crate from the outside, as the
+//! other crates do, so only `add_two` and `greeting` are reachable there.
+//!
+//! ```text
+//! $ cargo test -- --show-output               # show println! of the passing tests
+//! $ cargo test add_two                        # run the tests matching add_two
+//! $ cargo test --test adder_integration       # run the integration tests only
+//! ```
+//!
+//! [test organization]: https://doc.rust-lang.org/book/ch11-03-test-organization.html
+
+/// Adds two to the number given.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch11::adder::add_two;
+///
+/// assert_eq!(7, add_two(5));
+/// ```
+pub fn add_two(x: i32) -> i32 {
+    internal_adder(x, 2)
+}
+
+/// Returns the greeting to `name`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch11::adder::greeting;
+///
+/// assert_eq!("Hello, Carol!", greeting("Carol"));
+/// ```
+pub fn greeting(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+fn internal_adder(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn internal() {
+        struct Test {
+            name: &'static str,
+            data: (i32, i32),
+            want: i32,
+        }
+        let tests = [
+            Test {
+                name: "two and two",
+                data: (2, 2),
+                want: 4,
+            },
+            Test {
+                name: "negative and positive",
+                data: (-3, 2),
+                want: -1,
+            },
+        ];
+        for t in &tests {
+            let (a, b) = t.data;
+            assert_eq!(t.want, internal_adder(a, b), "{}", t.name);
+        }
+    }
+    #[test]
+    fn add_two_to_three() {
+        // Shown with `cargo test -- --show-output` only.
+        println!("add_two(3) = {}", add_two(3));
+        assert_eq!(5, add_two(3));
+    }
+    #[test]
+    fn greeting_contains_name() {
+        let got = greeting("Carol");
+        assert!(
+            got.contains("Carol"),
+            "greeting doesn't contain name: {}",
+            got
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! [Test-Driven Development] with `is_prime`
+//!
+//! 1. Write the failing test first, e.g. `two_is_prime` below, which
+//!    fails with `is_prime` always returning `false`.
+//! 2. Write just enough code to pass it, e.g. `n == 2`.
+//! 3. Add the next failing test, e.g. `composites_are_not_prime`, and
+//!    repeat until the tests drive out the trial division.
+//! 4. Refactor, e.g. stop the trial division at `sqrt(n)`, while keeping
+//!    the tests passing.
+//!
+//! [test-driven development]: https://doc.rust-lang.org/book/ch12-04-testing-the-librarys-functionality.html
+
+/// Returns `true` if `n` is the prime number, by the trial division up
+/// to `sqrt(n)`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch11::tdd::is_prime;
+///
+/// assert!(is_prime(2));
+/// assert!(is_prime(97));
+/// assert!(!is_prime(1));
+/// assert!(!is_prime(91));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+    if n.is_multiple_of(2) {
+        return false;
+    }
+    // `i <= n / i` instead of `i * i <= n`, which overflows.
+    let mut i = 3;
+    while i <= n / i {
+        if n.is_multiple_of(i) {
+            return false;
+        }
+        i += 2;
+    }
+    true
+}
+
+/// Returns the smallest prime number larger than `n`.
+///
+/// # Panics
+///
+/// Function `next_prime` will panic in case the next prime number
+/// overflows `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch11::tdd::next_prime;
+///
+/// assert_eq!(2, next_prime(0));
+/// assert_eq!(101, next_prime(97));
+/// ```
+pub fn next_prime(n: u64) -> u64 {
+    let mut candidate = n;
+    loop {
+        candidate = candidate.checked_add(1).expect("next prime overflow");
+        if is_prime(candidate) {
+            return candidate;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_is_prime() {
+        assert!(is_prime(2));
+    }
+    #[test]
+    fn small_primes() {
+        for &n in &[2, 3, 5, 7, 11, 13, 97, 7919] {
+            assert!(is_prime(n), "expected {} to be prime", n);
+        }
+    }
+    #[test]
+    fn composites_are_not_prime() {
+        for &n in &[0, 1, 4, 9, 15, 91, 7917, 65_537 * 65_539] {
+            assert!(!is_prime(n), "expected {} not to be prime", n);
+        }
+    }
+    #[test]
+    fn large_prime() {
+        // The largest prime below 2^32, whose square root is the largest
+        // trial divisor below 2^16.
+        assert!(is_prime(4_294_967_291));
+        assert_ne!(is_prime(4_294_967_291), is_prime(4_294_967_293));
+    }
+    #[test]
+    fn next_prime() {
+        struct Test {
+            name: &'static str,
+            data: u64,
+            want: u64,
+        }
+        let tests = [
+            Test {
+                name: "zero",
+                data: 0,
+                want: 2,
+            },
+            Test {
+                name: "prime",
+                data: 7,
+                want: 11,
+            },
+            Test {
+                name: "composite",
+                data: 24,
+                want: 29,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, super::next_prime(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn next_prime_overflow() {
+        super::next_prime(u64::MAX);
+    }
+    #[test]
+    fn test_returns_ok() -> Result<(), String> {
+        is_prime(2)
+            .then_some(())
+            .ok_or_else(|| "2 is not prime".into())
+    }
+    #[test]
+    #[ignore]
+    fn count_primes_below_ten_million() {
+        // Run with `cargo test -- --ignored`.
+        assert_eq!(664_579, (0..10_000_000).filter(|&n| is_prime(n)).count());
+    }
+}
@@ -0,0 +1,455 @@
+// SPDX-License-Identifier: GPL-2.0
+//! An [I/O Project]: Building a Command Line Program
+//!
+//! # Examples
+//!
+//! ```
+//! use the_book::ch12::{search, search_case_insensitive};
+//!
+//! let contents = "\
+//! Rust:
+//! safe, fast, productive.
+//! Pick three.
+//! Trust me.";
+//!
+//! assert_eq!(vec!["safe, fast, productive."], search("duct", contents));
+//! assert_eq!(
+//!     vec!["Rust:", "Trust me."],
+//!     search_case_insensitive("rUsT", contents),
+//! );
+//! ```
+//!
+//! [i/o project]: https://doc.rust-lang.org/book/ch12-00-an-io-project.html
+use std::{env, error::Error, fs};
+
+/// Command line flag of the case insensitive search.
+const IGNORE_CASE_FLAG: &str = "--ignore-case";
+
+/// Config to capture command line arguments for the I/O project.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch12::Config;
+///
+/// let args = vec![
+///     String::from("minigrep"),
+///     String::from("some query"),
+///     String::from("some file"),
+///     String::from("--ignore-case"),
+/// ];
+/// let config = Config::build(&args).unwrap();
+/// assert_eq!("some query", config.query());
+/// assert_eq!("some file", config.file_path());
+/// assert!(config.ignore_case());
+///
+/// assert_eq!(
+///     "not enough arguments",
+///     Config::build(&args[..2]).unwrap_err(),
+/// );
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    query: String,
+    file_path: String,
+    ignore_case: bool,
+}
+
+impl Config {
+    /// Build the config out of the command line arguments, which are
+    /// the program name, the query, the file path, and the optional
+    /// `--ignore-case` flag.
+    ///
+    /// The search is case insensitive without the flag as well, in case
+    /// the `IGNORE_CASE` environment variable is set.
+    pub fn build(args: &[String]) -> Result<Self, &'static str> {
+        Self::build_with_env(args, env::var_os("IGNORE_CASE").is_some())
+    }
+    /// [`build`] out of the iterator of the command line arguments, e.g.
+    /// `env::args()`, which takes the ownership of the arguments instead
+    /// of cloning them, as refactored in [chapter 13].
+    ///
+    /// It also tells which argument is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use the_book::ch12::Config;
+    ///
+    /// let args: Vec<_> = vec!["minigrep", "some query", "some file"]
+    ///     .into_iter()
+    ///     .map(String::from)
+    ///     .collect();
+    /// let config = Config::build_from_iter(args.clone().into_iter()).unwrap();
+    /// assert_eq!(Config::build(&args), Ok(config));
+    ///
+    /// let args = vec![String::from("minigrep"), String::from("some query")];
+    /// assert_eq!(
+    ///     Err("Didn't get a file path"),
+    ///     Config::build_from_iter(args.into_iter()),
+    /// );
+    /// ```
+    ///
+    /// [`build`]: #method.build
+    /// [chapter 13]: https://doc.rust-lang.org/book/ch13-03-improving-our-io-project.html
+    pub fn build_from_iter(args: impl Iterator<Item = String>) -> Result<Self, &'static str> {
+        Self::build_from_iter_with_env(args, env::var_os("IGNORE_CASE").is_some())
+    }
+    /// [`build`] with the `IGNORE_CASE` environment variable as `env`.
+    ///
+    /// [`build`]: #method.build
+    fn build_with_env(args: &[String], env: bool) -> Result<Self, &'static str> {
+        if args.len() < 3 {
+            return Err("not enough arguments");
+        }
+        let query = args[1].clone();
+        let file_path = args[2].clone();
+        let ignore_case = env || args[3..].iter().any(|arg| arg == IGNORE_CASE_FLAG);
+        Ok(Self {
+            query,
+            file_path,
+            ignore_case,
+        })
+    }
+    /// [`build_from_iter`] with the `IGNORE_CASE` environment variable as
+    /// `env`.
+    ///
+    /// [`build_from_iter`]: #method.build_from_iter
+    fn build_from_iter_with_env(
+        mut args: impl Iterator<Item = String>,
+        env: bool,
+    ) -> Result<Self, &'static str> {
+        // The program name.
+        args.next();
+        let query = match args.next() {
+            Some(arg) => arg,
+            None => return Err("Didn't get a query string"),
+        };
+        let file_path = match args.next() {
+            Some(arg) => arg,
+            None => return Err("Didn't get a file path"),
+        };
+        let ignore_case = env || args.any(|arg| arg == IGNORE_CASE_FLAG);
+        Ok(Self {
+            query,
+            file_path,
+            ignore_case,
+        })
+    }
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+    pub fn file_path(&self) -> &str {
+        &self.file_path
+    }
+    pub fn ignore_case(&self) -> bool {
+        self.ignore_case
+    }
+}
+
+/// run reads a file provided by Config.file_path() and returns the
+/// matching lines, instead of printing them, so that the caller decides
+/// where they go.
+pub fn run(config: Config) -> Result<Vec<String>, Box<dyn Error>> {
+    let contents = fs::read_to_string(config.file_path())?;
+    let lines = if config.ignore_case() {
+        search_case_insensitive(config.query(), &contents)
+    } else {
+        search(config.query(), &contents)
+    };
+    Ok(lines.into_iter().map(String::from).collect())
+}
+
+/// search takes `query` as a first parameter and returns the line
+/// in case it's in `line`.
+///
+/// # Examples
+/// ```
+/// use the_book::ch12::search;
+///
+/// let data = "\
+/// something here,
+/// and some there.";
+///
+/// let query = "some";
+/// let want = vec!["something here,", "and some there."];
+/// assert_eq!(want, search(query, data));
+///
+/// let query = "another";
+/// let want: Vec<&str> = vec![];
+/// assert_eq!(want, search(query, data));
+///
+/// let query = "Some";
+/// let want: Vec<&str> = vec![];
+/// assert_eq!(want, search(query, data));
+/// ```
+pub fn search<'a>(query: &str, data: &'a str) -> Vec<&'a str> {
+    let mut result = Vec::<&str>::new();
+    for line in data.lines() {
+        if line.contains(query) {
+            result.push(line);
+        }
+    }
+    result
+}
+
+/// [`search`] in the iterator style of [chapter 13], which compiles
+/// down to the same code as the loop, the zero-cost abstraction, see
+/// `benches/ch13_search.rs`.
+///
+/// # Examples
+/// ```
+/// use the_book::ch12::{search, search_iter};
+///
+/// let data = "\
+/// something here,
+/// and some there.";
+///
+/// assert_eq!(search("some", data), search_iter("some", data));
+/// assert_eq!(vec!["and some there."], search_iter("some ", data));
+/// ```
+///
+/// [`search`]: fn.search.html
+/// [chapter 13]: https://doc.rust-lang.org/book/ch13-03-improving-our-io-project.html
+pub fn search_iter<'a>(query: &str, data: &'a str) -> Vec<&'a str> {
+    data.lines().filter(|line| line.contains(query)).collect()
+}
+
+/// [`search`] ignoring the case of both `query` and the lines.
+///
+/// The returned lines are the original ones, not the lowercase ones.
+///
+/// [`search`]: fn.search.html
+pub fn search_case_insensitive<'a>(query: &str, data: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    let mut result = Vec::<&str>::new();
+    for line in data.lines() {
+        if line.to_lowercase().contains(&query) {
+            result.push(line);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn config_build() {
+        struct Test {
+            name: &'static str,
+            args: Vec<String>,
+            env: bool,
+            want: Result<Config, &'static str>,
+        }
+        let tests = [
+            Test {
+                name: "no arguments",
+                args: vec![],
+                env: false,
+                want: Err("not enough arguments"),
+            },
+            Test {
+                name: "no query",
+                args: vec![String::from("minigrep")],
+                env: false,
+                want: Err("not enough arguments"),
+            },
+            Test {
+                name: "no file path",
+                args: vec![String::from("minigrep"), String::from("query")],
+                env: true,
+                want: Err("not enough arguments"),
+            },
+            Test {
+                name: "query and file path",
+                args: vec![
+                    String::from("minigrep"),
+                    String::from("query"),
+                    String::from("file path"),
+                ],
+                env: false,
+                want: Ok(Config {
+                    query: String::from("query"),
+                    file_path: String::from("file path"),
+                    ignore_case: false,
+                }),
+            },
+            Test {
+                name: "ignore case flag",
+                args: vec![
+                    String::from("minigrep"),
+                    String::from("query"),
+                    String::from("file path"),
+                    String::from("--ignore-case"),
+                ],
+                env: false,
+                want: Ok(Config {
+                    query: String::from("query"),
+                    file_path: String::from("file path"),
+                    ignore_case: true,
+                }),
+            },
+            Test {
+                name: "ignore case environment variable",
+                args: vec![
+                    String::from("minigrep"),
+                    String::from("query"),
+                    String::from("file path"),
+                    String::from("another argument"),
+                ],
+                env: true,
+                want: Ok(Config {
+                    query: String::from("query"),
+                    file_path: String::from("file path"),
+                    ignore_case: true,
+                }),
+            },
+            Test {
+                name: "flag as the query",
+                args: vec![
+                    String::from("minigrep"),
+                    String::from("--ignore-case"),
+                    String::from("file path"),
+                ],
+                env: false,
+                want: Ok(Config {
+                    query: String::from("--ignore-case"),
+                    file_path: String::from("file path"),
+                    ignore_case: false,
+                }),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, Config::build_with_env(&t.args, t.env), "{}", t.name);
+        }
+    }
+    #[test]
+    fn config_build_from_iter() {
+        struct Test {
+            name: &'static str,
+            args: Vec<&'static str>,
+            env: bool,
+            want: Result<(), &'static str>,
+        }
+        let tests = [
+            Test {
+                name: "zero args",
+                args: vec![],
+                env: false,
+                want: Err("Didn't get a query string"),
+            },
+            Test {
+                name: "one arg",
+                args: vec!["minigrep"],
+                env: false,
+                want: Err("Didn't get a query string"),
+            },
+            Test {
+                name: "two args",
+                args: vec!["minigrep", "query"],
+                env: true,
+                want: Err("Didn't get a file path"),
+            },
+            Test {
+                name: "three args",
+                args: vec!["minigrep", "query", "file path"],
+                env: false,
+                want: Ok(()),
+            },
+            Test {
+                name: "ignore case flag",
+                args: vec!["minigrep", "query", "file path", "--ignore-case"],
+                env: false,
+                want: Ok(()),
+            },
+            Test {
+                name: "ignore case environment variable",
+                args: vec!["minigrep", "query", "file path", "another argument"],
+                env: true,
+                want: Ok(()),
+            },
+        ];
+        for t in &tests {
+            let args: Vec<String> = t.args.iter().map(|arg| String::from(*arg)).collect();
+            let got = Config::build_from_iter_with_env(args.clone().into_iter(), t.env);
+            match t.want {
+                // Same as the slice version.
+                Ok(()) => assert_eq!(Config::build_with_env(&args, t.env), got, "{}", t.name),
+                Err(want) => {
+                    assert_eq!(Err(want), got, "{}", t.name);
+                    assert!(Config::build_with_env(&args, t.env).is_err(), "{}", t.name);
+                }
+            }
+        }
+    }
+    #[test]
+    fn case_sensitive() {
+        let query = "duct";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.";
+        assert_eq!(vec!["safe, fast, productive."], search(query, contents));
+    }
+    #[test]
+    fn case_insensitive() {
+        let query = "rUsT";
+        let contents = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Trust me.";
+        assert_eq!(
+            vec!["Rust:", "Trust me."],
+            search_case_insensitive(query, contents)
+        );
+    }
+    #[test]
+    fn search_string() {
+        struct Test {
+            query: &'static str,
+            data: &'static str,
+            want: Vec<&'static str>,
+        }
+        let tests = [
+            Test {
+                query: "",
+                data: "",
+                want: vec![],
+            },
+            Test {
+                query: "line",
+                data: "
+This is a line.
+Another line.
+and another line.
+",
+                want: vec!["This is a line.", "Another line.", "and another line."],
+            },
+            Test {
+                query: "Line",
+                data: "
+This is a line.
+Another Line.
+",
+                want: vec!["Another Line."],
+            },
+        ];
+        for t in &tests {
+            let got = search(t.query, t.data);
+            assert_eq!(t.want, got);
+            assert_eq!(t.want, search_iter(t.query, t.data));
+        }
+    }
+    #[test]
+    fn run_missing_file() {
+        let config = Config {
+            query: String::from("query"),
+            file_path: String::from("no such file"),
+            ignore_case: false,
+        };
+        assert!(run(config).is_err());
+    }
+}
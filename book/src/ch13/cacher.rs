@@ -0,0 +1,109 @@
+//! Generic [`Cacher`] Memoizer
+//!
+//! The [`sec01::Cacher`] only keeps the first value, and ignores the
+//! argument of the later calls, which the chapter leaves as the
+//! exercise.  This [`Cacher`] keeps the value of each argument in the
+//! `HashMap` instead, and is generic over the argument and the value
+//! types:
+//!
+//! ```
+//! use the_book::ch13::cacher::Cacher;
+//!
+//! let mut c = Cacher::new(|x: &u64| x * 2);
+//! assert_eq!(2, *c.value(1));
+//! // Not the first value.
+//! assert_eq!(4, *c.value(2));
+//! assert_eq!(2, c.len());
+//! ```
+//!
+//! [`cacher`]: struct.Cacher.html
+//! [`sec01::cacher`]: ../sec01/struct.Cacher.html
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// `Cacher` caches the value of each argument got through `calc`
+/// [`Fn`] closure.
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub struct Cacher<A, V, F>
+where
+    A: Eq + Hash,
+    F: Fn(&A) -> V,
+{
+    calc: F,
+    values: HashMap<A, V>,
+}
+
+impl<A, V, F> Cacher<A, V, F>
+where
+    A: Eq + Hash,
+    F: Fn(&A) -> V,
+{
+    pub fn new(calc: F) -> Self {
+        Self {
+            calc,
+            values: HashMap::new(),
+        }
+    }
+    /// Returns the value of `arg`, which is calculated only once for
+    /// each distinct `arg`.
+    pub fn value(&mut self, arg: A) -> &V {
+        // Not to borrow `self` in the closure while `values` is borrowed.
+        let calc = &self.calc;
+        self.values.entry(arg).or_insert_with_key(|arg| calc(arg))
+    }
+    /// Returns the number of the cached values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// Returns `true` if the value of `arg` is cached.
+    pub fn contains(&self, arg: &A) -> bool {
+        self.values.contains_key(arg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cacher;
+    use std::cell::Cell;
+    #[test]
+    fn once_per_argument() {
+        let calls = Cell::new(0);
+        let mut c = Cacher::new(|x: &u64| {
+            calls.set(calls.get() + 1);
+            x * x
+        });
+        assert!(c.is_empty());
+        for x in [3, 1, 3, 2, 1, 3].iter() {
+            assert_eq!(x * x, *c.value(*x));
+        }
+        assert_eq!(3, calls.get());
+        assert_eq!(3, c.len());
+        assert!(c.contains(&2));
+        assert!(!c.contains(&4));
+    }
+    #[test]
+    fn str_to_string() {
+        let mut c = Cacher::new(|name: &&str| format!("Hello, {}!", name));
+        assert_eq!("Hello, Ferris!", c.value("Ferris"));
+        assert_eq!("Hello, Corro!", c.value("Corro"));
+        assert_eq!("Hello, Ferris!", c.value("Ferris"));
+        assert_eq!(2, c.len());
+        assert!(c.contains(&"Corro"));
+    }
+    #[test]
+    fn different_types() {
+        let mut doubles = Cacher::new(|x: &usize| x * 2);
+        let mut lens = Cacher::new(|s: &String| s.len());
+        assert_eq!(42, *doubles.value(21));
+        assert_eq!(5, *lens.value(String::from("hello")));
+        assert_eq!(0, *lens.value(String::new()));
+        assert_eq!(1, doubles.len());
+        assert_eq!(2, lens.len());
+        assert!(!doubles.contains(&42));
+        assert!(lens.contains(&String::from("hello")));
+    }
+}
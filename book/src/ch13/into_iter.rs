@@ -0,0 +1,159 @@
+//! `for` Loops and the [`IntoIterator`] Trait
+//!
+//! The `for` loop takes anything implementing [`IntoIterator`], and
+//!
+//! ```
+//! # let v = vec![1, 2, 3];
+//! # let mut sum = 0;
+//! for x in v {
+//!     sum += x;
+//! }
+//! # assert_eq!(6, sum);
+//! ```
+//!
+//! desugars to
+//!
+//! ```
+//! # let v = vec![1, 2, 3];
+//! # let mut sum = 0;
+//! {
+//!     let mut iter = IntoIterator::into_iter(v);
+//!     loop {
+//!         match iter.next() {
+//!             Some(x) => sum += x,
+//!             None => break,
+//!         }
+//!     }
+//! }
+//! # assert_eq!(6, sum);
+//! ```
+//!
+//! hence [`Words`] is iterated by the `for` loop through its
+//! [`IntoIterator`] implementation.
+//!
+//! [`intoiterator`]: https://doc.rust-lang.org/std/iter/trait.IntoIterator.html
+//! [`words`]: struct.Words.html
+
+/// Whitespace separated words in the text.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::into_iter::Words;
+///
+/// let mut got = Vec::new();
+/// for word in Words::new("  hello,\tfor\nloop ") {
+///     got.push(word);
+/// }
+/// assert_eq!(vec!["hello,", "for", "loop"], got);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Words<'a>(&'a str);
+
+impl<'a> Words<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self(text)
+    }
+}
+
+impl<'a> IntoIterator for Words<'a> {
+    type Item = &'a str;
+    type IntoIter = WordIter<'a>;
+
+    fn into_iter(self) -> WordIter<'a> {
+        WordIter { rest: self.0 }
+    }
+}
+
+/// Iterator over the [`Words`], returned by `Words::into_iter`.
+///
+/// [`words`]: struct.Words.html
+#[derive(Debug, Clone)]
+pub struct WordIter<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for WordIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.rest.trim_start();
+        if rest.is_empty() {
+            self.rest = rest;
+            return None;
+        }
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (word, rest) = rest.split_at(end);
+        self.rest = rest;
+        Some(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::while_let_loop)]
+    fn for_loop_and_desugared_loop() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: &'static [&'static str],
+        }
+        let tests = [
+            Test {
+                name: "empty",
+                data: "",
+                want: &[],
+            },
+            Test {
+                name: "whitespace only",
+                data: " \t\n ",
+                want: &[],
+            },
+            Test {
+                name: "single word",
+                data: "rust",
+                want: &["rust"],
+            },
+            Test {
+                name: "mixed whitespace",
+                data: "  the\tquick \n brown  fox ",
+                want: &["the", "quick", "brown", "fox"],
+            },
+            Test {
+                name: "unicode whitespace",
+                data: "caf\u{e9}\u{3000}na\u{ef}ve",
+                want: &["caf\u{e9}", "na\u{ef}ve"],
+            },
+        ];
+        for t in &tests {
+            let mut for_loop = Vec::new();
+            for word in Words::new(t.data) {
+                for_loop.push(word);
+            }
+            let mut desugared = Vec::new();
+            {
+                let mut iter = IntoIterator::into_iter(Words::new(t.data));
+                loop {
+                    match iter.next() {
+                        Some(word) => desugared.push(word),
+                        None => break,
+                    }
+                }
+            }
+            assert_eq!(t.want, &for_loop[..], "{}", t.name);
+            assert_eq!(for_loop, desugared, "{}", t.name);
+            let split: Vec<_> = t.data.split_whitespace().collect();
+            assert_eq!(split, for_loop, "{}: split_whitespace", t.name);
+        }
+    }
+    #[test]
+    fn fused() {
+        let mut iter = Words::new("one").into_iter();
+        assert_eq!(Some("one"), iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
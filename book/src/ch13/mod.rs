@@ -1,6 +1,11 @@
 //! [Functional Language] Features: Iterators and Closures
 //!
 //! [functional language]: https://doc.rust-lang.org/book/ch13-00-functional-features.html
+pub mod cacher;
+pub mod into_iter;
 pub mod sec01;
 pub mod sec02;
 pub mod sec03;
+
+pub use sec01::{consume_and_report, make_adder, make_counter};
+pub use sec02::{shoes_in_size, Shoe};
@@ -29,9 +29,78 @@ impl<T: Copy + Clone, F: Fn(T) -> T> Cacher<T, F> {
     }
 }
 
+/// Returns the [`Fn`] closure, which captures `x` by value with `move`,
+/// as it outlives the function, and only reads it.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::make_adder;
+///
+/// let add_two = make_adder(2);
+/// assert_eq!(3, add_two(1));
+/// assert_eq!(3, add_two(1));
+/// ```
+///
+/// [`Fn`]: https://doc.rust-lang.org/std/ops/trait.Fn.html
+pub fn make_adder(x: i32) -> impl Fn(i32) -> i32 {
+    move |y| x + y
+}
+
+/// Returns the [`FnMut`] closure, which mutates the captured count on
+/// each call.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::make_counter;
+///
+/// let mut counter = make_counter();
+/// assert_eq!(1, counter());
+/// assert_eq!(2, counter());
+/// ```
+///
+/// [`FnMut`]: https://doc.rust-lang.org/std/ops/trait.FnMut.html
+pub fn make_counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+/// Returns the [`FnOnce`] closure, which moves `v` out of itself, so
+/// it's callable only once.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::consume_and_report;
+///
+/// let report = consume_and_report(vec![String::from("a"), String::from("b")]);
+/// assert_eq!(2, report());
+/// ```
+///
+/// ```compile_fail
+/// use the_book::ch13::consume_and_report;
+///
+/// let report = consume_and_report(vec![]);
+/// report();
+/// // error[E0382]: use of moved value: `report`
+/// report();
+/// ```
+///
+/// [`FnOnce`]: https://doc.rust-lang.org/std/ops/trait.FnOnce.html
+pub fn consume_and_report(v: Vec<String>) -> impl FnOnce() -> usize {
+    move || {
+        let consumed = v;
+        consumed.len()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Cacher;
+    use super::{consume_and_report, make_adder, make_counter, Cacher};
     #[test]
     fn value() {
         let mut c = Cacher::new(|v| v);
@@ -43,4 +112,31 @@ mod tests {
         let got = c.get(2);
         assert_eq!(1, got);
     }
+    #[test]
+    fn adder() {
+        let add_five = make_adder(5);
+        let add_minus_one = make_adder(-1);
+        for i in -3..3 {
+            assert_eq!(i + 5, add_five(i));
+            assert_eq!(i - 1, add_minus_one(i));
+        }
+    }
+    #[test]
+    fn counter() {
+        let mut a = make_counter();
+        let mut b = make_counter();
+        assert_eq!(1, a());
+        assert_eq!(2, a());
+        assert_eq!(3, a());
+        // Counts independently.
+        assert_eq!(1, b());
+        assert_eq!(4, a());
+    }
+    #[test]
+    fn consumed_once() {
+        let v: Vec<_> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let report = consume_and_report(v);
+        assert_eq!(3, report());
+        assert_eq!(0, consume_and_report(vec![])());
+    }
 }
@@ -1,25 +1,58 @@
 //! Processing a Series of Items with [Iterators]
 //!
 //! [iterators]: https://doc.rust-lang.org/book/ch13-02-iterators.html
+use std::iter::FusedIterator;
 
 /// `Shoe` type demonstrates the `filter` iterator adaptor.
 #[derive(PartialEq, Debug)]
 pub struct Shoe {
-    pub size: f32,
+    pub size: u32,
     pub style: String,
 }
 
-/// Collect the `shoe_size` [`Shoe`]s.
+/// Collect the `shoe_size` [`Shoe`]s, in the original order, with the
+/// closure capturing `shoe_size`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::{shoes_in_size, Shoe};
+///
+/// let shoes = vec![
+///     Shoe {
+///         size: 10,
+///         style: String::from("sneaker"),
+///     },
+///     Shoe {
+///         size: 13,
+///         style: String::from("sandal"),
+///     },
+/// ];
+/// let got = shoes_in_size(shoes, 10);
+/// assert_eq!(1, got.len());
+/// assert_eq!("sneaker", got[0].style);
+/// ```
 ///
 /// [`shoe`]: struct.Shoe.html
-pub fn shoes_in_my_size(shoes: Vec<Shoe>, shoe_size: f32) -> Vec<Shoe> {
+pub fn shoes_in_size(shoes: Vec<Shoe>, shoe_size: u32) -> Vec<Shoe> {
     shoes
         .into_iter()
-        .filter(|shoe| (shoe.size - shoe_size).abs() < std::f32::EPSILON)
+        .filter(|shoe| shoe.size == shoe_size)
         .collect()
 }
 
-/// `Counter` type demonstrates the [`Iterator`] trait implementation.
+/// `Counter` type demonstrates the [`Iterator`] trait implementation,
+/// which counts from 1 to 5.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::sec02::Counter;
+///
+/// assert_eq!(vec![1, 2, 3, 4, 5], Counter::new().collect::<Vec<_>>());
+/// assert_eq!(vec![3, 2, 1], Counter::with_limit(3).rev().collect::<Vec<_>>());
+/// assert_eq!(10, Counter::with_limit(10).len());
+/// ```
 ///
 /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
 pub struct Counter {
@@ -28,121 +61,209 @@ pub struct Counter {
 }
 
 impl Counter {
-    /// Counter with the maximum `limit`.
-    pub fn new(limit: u32) -> Self {
+    /// Counter from 1 to 5, as the book.
+    pub fn new() -> Self {
+        Self::with_limit(5)
+    }
+    /// Counter from 1 to `limit`.
+    pub fn with_limit(limit: u32) -> Self {
         Self { count: 0, limit }
     }
 }
 
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Iterator for Counter {
     type Item = u32;
-    /// It returns Some(u32) when the counter is less than `limit`,
-    /// otherwise None.
+    /// It returns Some(u32) up to `limit`, otherwise None.
     fn next(&mut self) -> Option<Self::Item> {
-        self.count += 1;
         if self.count < self.limit {
+            self.count += 1;
             Some(self.count)
         } else {
             None
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.limit - self.count) as usize;
+        (len, Some(len))
+    }
+}
+
+/// Counts down from `limit`, and meets [`next`] in the middle.
+///
+/// [`next`]: #method.next
+impl DoubleEndedIterator for Counter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.count < self.limit {
+            self.limit -= 1;
+            Some(self.limit + 1)
+        } else {
+            None
+        }
+    }
+}
+
+/// Exact [`size_hint`], which provides `len`.
+///
+/// [`size_hint`]: #method.size_hint
+impl ExactSizeIterator for Counter {}
+
+/// Keeps returning None once it's exhausted.
+impl FusedIterator for Counter {}
+
+/// The book's [`Counter`] combinator example, which sums up the products
+/// of the pairs of the two counters, the second one skipping the first
+/// value, which are divisible by 3.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch13::sec02::sum_of_zipped_counters;
+///
+/// // (2 * 3) + (3 * 4)
+/// assert_eq!(18, sum_of_zipped_counters());
+/// ```
+///
+/// [`counter`]: struct.Counter.html
+pub fn sum_of_zipped_counters() -> u32 {
+    Counter::new()
+        .zip(Counter::new().skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
     #[test]
-    fn shoes_in_my_size() {
+    fn shoes_in_size() {
         use super::Shoe;
+        fn shoe(size: u32, style: &str) -> Shoe {
+            Shoe {
+                size,
+                style: String::from(style),
+            }
+        }
         struct Test {
-            shoes: Option<Vec<Shoe>>,
-            size: f32,
+            name: &'static str,
+            shoes: Vec<Shoe>,
+            size: u32,
             want: Vec<Shoe>,
-        };
-        let mut tests = vec![
+        }
+        let tests = vec![
+            Test {
+                name: "no shoes",
+                shoes: vec![],
+                size: 10,
+                want: vec![],
+            },
+            Test {
+                name: "book example",
+                shoes: vec![shoe(10, "sneaker"), shoe(13, "sandal"), shoe(10, "boot")],
+                size: 10,
+                want: vec![shoe(10, "sneaker"), shoe(10, "boot")],
+            },
             Test {
-                shoes: Some(vec![
-                    Shoe {
-                        size: 8.0,
-                        style: String::from("best fit"),
-                    },
-                    Shoe {
-                        size: 7.5,
-                        style: String::from("a bit small"),
-                    },
-                    Shoe {
-                        size: 8.5,
-                        style: String::from("a bit big"),
-                    },
-                ]),
-                size: 8.0,
-                want: vec![Shoe {
-                    size: 8.0,
-                    style: String::from("best fit"),
-                }],
+                name: "no match",
+                shoes: vec![shoe(7, "best fit"), shoe(8, "a bit big")],
+                size: 9,
+                want: vec![],
             },
             Test {
-                shoes: Some(vec![
-                    Shoe {
-                        size: 8.0,
-                        style: String::from("a bit small"),
-                    },
-                    Shoe {
-                        size: 7.5,
-                        style: String::from("best fit"),
-                    },
-                    Shoe {
-                        size: 8.5,
-                        style: String::from("even bigger"),
-                    },
-                ]),
-                size: 7.5,
-                want: vec![Shoe {
-                    size: 7.5,
-                    style: String::from("best fit"),
-                }],
+                name: "all match in order",
+                shoes: vec![shoe(8, "c"), shoe(8, "a"), shoe(8, "b")],
+                size: 8,
+                want: vec![shoe(8, "c"), shoe(8, "a"), shoe(8, "b")],
             },
         ];
-        for t in &mut tests {
-            match t.shoes.take() {
-                None => panic!("unexpected test case"),
-                Some(shoes) => {
-                    let got = super::shoes_in_my_size(shoes, t.size);
-                    assert_eq!(&t.want, &got);
-                }
-            }
+        for t in tests {
+            assert_eq!(t.want, super::shoes_in_size(t.shoes, t.size), "{}", t.name);
         }
     }
     #[test]
     fn counter() {
         use super::Counter;
         struct Test {
-            limit: u32,
-            want: Vec<Option<u32>>,
-        };
-        let test = vec![
+            name: &'static str,
+            counter: Counter,
+            want: Vec<u32>,
+        }
+        let tests = vec![
             Test {
-                limit: 0,
-                want: vec![None],
+                name: "new",
+                counter: Counter::new(),
+                want: vec![1, 2, 3, 4, 5],
             },
             Test {
-                limit: 3,
-                want: vec![Some(1), Some(2), None],
+                name: "zero limit",
+                counter: Counter::with_limit(0),
+                want: vec![],
             },
             Test {
-                limit: 5,
-                want: vec![Some(1), Some(2), Some(3), Some(4), None],
+                name: "one limit",
+                counter: Counter::with_limit(1),
+                want: vec![1],
+            },
+            Test {
+                name: "large limit",
+                counter: Counter::with_limit(1_000),
+                want: (1..=1_000).collect(),
             },
         ];
-        for t in &test {
-            let mut counter = Counter::new(t.limit);
-            for want in &t.want {
-                assert_eq!(want, &counter.next());
-            }
-            // You can access the `Counter` iterator after
-            // the actual iteration.  And the counter.count
-            // actually contains the number of `next` method
-            // call.
-            assert_eq!(counter.count, t.want.len() as u32);
+        for t in tests {
+            assert_eq!(t.want.len(), t.counter.len(), "{}", t.name);
+            assert_eq!(t.want, t.counter.collect::<Vec<_>>(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn sum_of_zipped_counters() {
+        assert_eq!(18, super::sum_of_zipped_counters());
+    }
+    #[test]
+    fn counter_rev() {
+        use super::Counter;
+        assert_eq!(
+            vec![5, 4, 3, 2, 1],
+            Counter::new().rev().collect::<Vec<_>>()
+        );
+
+        // Both ends meet in the middle.
+        let mut counter = Counter::new();
+        assert_eq!(Some(1), counter.next());
+        assert_eq!(Some(5), counter.next_back());
+        assert_eq!(3, counter.len());
+        assert_eq!(Some(2), counter.next());
+        assert_eq!(Some(4), counter.next_back());
+        assert_eq!(Some(3), counter.next_back());
+        assert_eq!(0, counter.len());
+        assert_eq!(None, counter.next());
+        assert_eq!(None, counter.next_back());
+    }
+    #[test]
+    fn counter_len() {
+        use super::Counter;
+        let mut counter = Counter::with_limit(3);
+        for want in (0..=3).rev() {
+            assert_eq!(want, counter.len());
+            assert_eq!((want, Some(want)), counter.size_hint());
+            counter.next();
+        }
+    }
+    #[test]
+    fn counter_fused() {
+        use super::Counter;
+        let mut counter = Counter::with_limit(2);
+        assert_eq!(Some(1), counter.next());
+        assert_eq!(Some(2), counter.next());
+        for _ in 0..10 {
+            assert_eq!(None, counter.next());
         }
+        // Not counted up after the exhaustion.
+        assert_eq!(2, counter.count);
     }
 }
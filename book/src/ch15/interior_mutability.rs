@@ -0,0 +1,210 @@
+//! [Cell<T>] and [RefCell<T>] Interior Mutability Comparison
+//!
+//! Both mutate the value through the shared reference.  `Cell<T>` moves
+//! the value in and out without borrowing it and never fails, while
+//! `RefCell<T>` lends the reference and checks the borrowing rules at
+//! runtime instead of the compile time.
+//!
+//! # Examples
+//!
+//! `Cell<u32>` with `get`, `set`, `replace` and `take`.
+//!
+//! ```
+//! use std::cell::Cell;
+//!
+//! let c = Cell::new(5u32);
+//! let r1 = &c;
+//! let r2 = &c;
+//! r1.set(r2.get() + 1);
+//! assert_eq!(6, c.get());
+//! assert_eq!(6, c.replace(10));
+//! assert_eq!(10, c.take());
+//! assert_eq!(0, c.get());
+//! ```
+//!
+//! `RefCell<String>` with `borrow`, `borrow_mut` and `try_borrow`.
+//!
+//! ```
+//! use std::cell::RefCell;
+//!
+//! let s = RefCell::new(String::from("hello"));
+//! s.borrow_mut().push_str(", world");
+//! assert_eq!("hello, world", *s.borrow());
+//! {
+//!     let _w = s.borrow_mut();
+//!     // you can't read it while it's mutably borrowed.
+//!     assert!(s.try_borrow().is_err());
+//! }
+//! assert!(s.try_borrow().is_ok());
+//! ```
+//!
+//! `borrow_mut()` while `borrow()` is active panics at runtime.
+//!
+//! ```should_panic
+//! use std::cell::RefCell;
+//!
+//! let s = RefCell::new(String::from("hello"));
+//! let _r = s.borrow();
+//! s.borrow_mut().push_str(", world");
+//! ```
+//! [cell<t>]: https://doc.rust-lang.org/std/cell/struct.Cell.html
+//! [refcell<t>]: https://doc.rust-lang.org/book/ch15-05-interior-mutability.html
+use std::cell::{BorrowMutError, Cell, RefCell};
+use std::rc::Rc;
+
+/// Hit counter updated through `&self` with `Cell<u32>`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch15::interior_mutability::HitCounter;
+///
+/// let counter = HitCounter::default();
+/// counter.hit();
+/// counter.hit();
+/// assert_eq!(2, counter.get());
+/// assert_eq!(2, counter.reset());
+/// assert_eq!(0, counter.get());
+/// ```
+#[derive(Debug, Default)]
+pub struct HitCounter(Cell<u32>);
+
+impl HitCounter {
+    /// Increment the counter and returns the new value.
+    pub fn hit(&self) -> u32 {
+        let hits = self.0.get() + 1;
+        self.0.set(hits);
+        hits
+    }
+    pub fn get(&self) -> u32 {
+        self.0.get()
+    }
+    /// Set the counter to `hits` and returns the previous value.
+    pub fn replace(&self, hits: u32) -> u32 {
+        self.0.replace(hits)
+    }
+    /// Reset the counter and returns the previous value.
+    pub fn reset(&self) -> u32 {
+        self.0.take()
+    }
+}
+
+/// Append `suffix` to `s`, or returns `Err` instead of panicking in case
+/// `s` is already borrowed.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use the_book::ch15::interior_mutability::try_append;
+///
+/// let s = RefCell::new(String::from("hello"));
+/// assert!(try_append(&s, ", world").is_ok());
+/// let r = s.borrow();
+/// assert!(try_append(&s, "!").is_err());
+/// assert_eq!("hello, world", *r);
+/// ```
+pub fn try_append(s: &RefCell<String>, suffix: &str) -> Result<(), BorrowMutError> {
+    s.try_borrow_mut()?.push_str(suffix);
+    Ok(())
+}
+
+/// Shared mutable list of `i32` with `Rc<RefCell<Vec<i32>>>`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch15::interior_mutability::SharedList;
+///
+/// let a = SharedList::default();
+/// let b = a.clone();
+/// a.push(1);
+/// b.push(2);
+/// assert_eq!(vec![1, 2], a.to_vec());
+/// assert_eq!(2, a.owners());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SharedList(Rc<RefCell<Vec<i32>>>);
+
+impl SharedList {
+    pub fn push(&self, value: i32) {
+        self.0.borrow_mut().push(value);
+    }
+    pub fn to_vec(&self) -> Vec<i32> {
+        self.0.borrow().clone()
+    }
+    /// Returns the number of the `SharedList` sharing the list.
+    pub fn owners(&self) -> usize {
+        Rc::strong_count(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn hit_counter() {
+        let counter = HitCounter::default();
+        assert_eq!(1, counter.hit());
+        assert_eq!(2, counter.hit());
+        assert_eq!(2, counter.replace(10));
+        assert_eq!(11, counter.hit());
+        assert_eq!(11, counter.reset());
+        assert_eq!(0, counter.get());
+    }
+    #[test]
+    fn try_append() {
+        struct Test {
+            name: &'static str,
+            borrowed: bool,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "not borrowed",
+                borrowed: false,
+                want: "hello, world",
+            },
+            Test {
+                name: "borrowed",
+                borrowed: true,
+                want: "hello",
+            },
+        ];
+        for t in &tests {
+            let s = RefCell::new(String::from("hello"));
+            {
+                let _r = if t.borrowed { Some(s.borrow()) } else { None };
+                let got = super::try_append(&s, ", world");
+                assert_eq!(t.borrowed, got.is_err(), "{}", t.name);
+            }
+            assert_eq!(t.want, *s.borrow(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn borrow_mut_while_borrowed_panics() {
+        let s = RefCell::new(String::from("hello"));
+        let got = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _r = s.borrow();
+            s.borrow_mut().push_str(", world");
+        }));
+        assert!(got.is_err());
+        // The borrow is released during the unwinding.
+        assert_eq!("hello", *s.borrow_mut());
+    }
+    #[test]
+    fn shared_list() {
+        let a = SharedList::default();
+        let b = a.clone();
+        let c = b.clone();
+        a.push(1);
+        b.push(2);
+        c.push(3);
+        assert_eq!(3, a.owners());
+        drop(c);
+        assert_eq!(2, b.owners());
+        assert_eq!(vec![1, 2, 3], b.to_vec());
+    }
+}
@@ -1,6 +1,7 @@
 //! [Smart Pointers] examples
 //!
 //! [smart pointers]: https://doc.rust-lang.org/book/ch15-00-smart-pointers.html
+pub mod interior_mutability;
 pub mod sec01;
 pub mod sec02;
 pub mod sec03;
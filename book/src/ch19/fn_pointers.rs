@@ -0,0 +1,135 @@
+//! [Function Pointers]
+//!
+//! # Examples
+//!
+//! The non-capturing closure coerces to the `fn` type, as the named
+//! function does.
+//!
+//! ```
+//! use the_book::ch19::fn_pointers::double;
+//!
+//! let f: fn(i32) -> i32 = |x| x + 1;
+//! let g: fn(i32) -> i32 = double;
+//! assert_eq!(6, g(f(2)));
+//! ```
+//!
+//! The method is the function, too, so the path to it is passed where
+//! the closure is expected.
+//!
+//! ```
+//! let closure: Vec<String> = vec![1, 2, 3].iter().map(|x| x.to_string()).collect();
+//! let method: Vec<String> = vec![1, 2, 3].iter().map(i32::to_string).collect();
+//! assert_eq!(closure, method);
+//! ```
+//!
+//! The closure capturing its environment is not the `fn` pointer, as
+//! there is nowhere in the bare code address to keep the captured `n`.
+//! Take it as `impl Fn(i32) -> i32` or `Box<dyn Fn(i32) -> i32>` instead.
+//!
+//! ```compile_fail
+//! let n = 3;
+//! let f: fn(i32) -> i32 = |x| x + n;
+//! ```
+//! [function pointers]: https://doc.rust-lang.org/book/ch19-05-advanced-functions-and-closures.html#function-pointers
+
+pub fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+pub fn double(x: i32) -> i32 {
+    x * 2
+}
+
+/// Applies `fns` in order, from `x`.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch19::fn_pointers::{add_one, apply_all, double};
+///
+/// assert_eq!(8, apply_all(&[add_one, double], 3));
+/// assert_eq!(7, apply_all(&[double, add_one], 3));
+/// assert_eq!(3, apply_all(&[], 3));
+/// ```
+pub fn apply_all(fns: &[fn(i32) -> i32], x: i32) -> i32 {
+    fns.iter().fold(x, |x, f| f(x))
+}
+
+/// Calls `f` once, which any `fn` pointer is, as it implements `Fn`,
+/// `FnMut` and `FnOnce`.
+pub fn call_once<F: FnOnce(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(x)
+}
+
+pub fn call_mut<F: FnMut(i32) -> i32>(mut f: F, x: i32) -> i32 {
+    f(x)
+}
+
+pub fn call<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: i32) -> i32 {
+        x * x
+    }
+    #[test]
+    fn fn_pointers() {
+        struct Test {
+            name: &'static str,
+            data: fn(i32) -> i32,
+            want: i32,
+        }
+        let tests = [
+            Test {
+                name: "add_one",
+                data: add_one,
+                want: 4,
+            },
+            Test {
+                name: "double",
+                data: double,
+                want: 6,
+            },
+            Test {
+                name: "private square",
+                data: square,
+                want: 9,
+            },
+            Test {
+                name: "non-capturing closure",
+                data: |x| x - 10,
+                want: -7,
+            },
+            Test {
+                name: "associated function",
+                data: i32::abs,
+                want: 3,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, (t.data)(3), "{}", t.name);
+            assert_eq!(t.want, call(t.data, 3), "{}: Fn", t.name);
+            assert_eq!(t.want, call_mut(t.data, 3), "{}: FnMut", t.name);
+            assert_eq!(t.want, call_once(t.data, 3), "{}: FnOnce", t.name);
+            assert_eq!(t.want, apply_all(&[t.data], 3), "{}", t.name);
+        }
+    }
+    #[test]
+    fn apply_all_in_order() {
+        let fns: [fn(i32) -> i32; 5] = [add_one, double, square, |x| x - 10, i32::abs];
+        // ((3 + 1) * 2)^2 - 10 = 54
+        assert_eq!(54, apply_all(&fns, 3));
+        assert_eq!(2, apply_all(&fns[..2], 0));
+    }
+    #[test]
+    fn capturing_closure() {
+        let n = 3;
+        let boxed: Box<dyn Fn(i32) -> i32> = Box::new(move |x| x + n);
+        assert_eq!(5, call(&boxed, 2));
+        assert_eq!(5, call(move |x| x + n, 2));
+    }
+}
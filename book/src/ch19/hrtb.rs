@@ -0,0 +1,204 @@
+//! [Higher-Rank Trait Bounds]
+//!
+//! # Examples
+//!
+//! `for<'a>` bound lets the closure accept the reference with any lifetime,
+//! including the one local to the callee.
+//!
+//! ```
+//! use the_book::ch19::hrtb::{call_with_local, call_with_ref};
+//!
+//! let x = 41;
+//! assert_eq!(42, call_with_ref(|x| *x + 1, &x));
+//! assert_eq!(10, call_with_local(|x| *x * 2));
+//! ```
+//!
+//! The lifetime picked by the caller doesn't work for the reference
+//! created inside the callee, as the local `x` doesn't live long enough
+//! for the caller's `'a`.
+//!
+//! ```compile_fail
+//! fn call_with_local<'a, F>(f: F) -> i32
+//! where
+//!     F: Fn(&'a i32) -> i32,
+//! {
+//!     let x = 5;
+//!     f(&x)
+//! }
+//! ```
+//!
+//! The same goes for the trait with the lifetime parameter.
+//!
+//! ```
+//! use the_book::ch19::hrtb::{parse_trimmed, FirstWord};
+//!
+//! assert_eq!(Some(String::from("hello")), parse_trimmed(&FirstWord, "  hello world  "));
+//! assert_eq!(None, parse_trimmed(&FirstWord, "   "));
+//! ```
+//!
+//! ```compile_fail
+//! use the_book::ch19::hrtb::Parser;
+//!
+//! fn parse_trimmed<'a, P: Parser<'a>>(parser: &P, input: &str) -> Option<String> {
+//!     let local = input.trim().to_string();
+//!     parser.parse(&local).map(String::from)
+//! }
+//! ```
+//! [higher-rank trait bounds]: https://doc.rust-lang.org/nomicon/hrtb.html
+
+/// Calls `f` with the `x` reference.
+///
+/// `F` should accept the reference with any lifetime, which is expressed
+/// by the `for<'a>` higher-rank trait bound.  This is what the compiler
+/// infers for `Fn(&i32) -> i32` through the lifetime elision, too.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch19::hrtb::call_with_ref;
+///
+/// let x = 2;
+/// assert_eq!(4, call_with_ref(|x| x * x, &x));
+/// ```
+pub fn call_with_ref<F>(f: F, x: &i32) -> i32
+where
+    F: for<'a> Fn(&'a i32) -> i32,
+{
+    f(x)
+}
+
+/// Calls `f` with the reference to the local variable.
+///
+/// There is no lifetime the caller can name for the local variable,
+/// hence the `for<'a>` higher-rank trait bound is required here.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch19::hrtb::call_with_local;
+///
+/// assert_eq!(5, call_with_local(|x| *x));
+/// ```
+pub fn call_with_local<F>(f: F) -> i32
+where
+    F: for<'a> Fn(&'a i32) -> i32,
+{
+    let x = 5;
+    f(&x)
+}
+
+/// Parser trait which returns the slice of the `input`.
+pub trait Parser<'a> {
+    fn parse(&self, input: &'a str) -> Option<&'a str>;
+}
+
+/// [`Parser`] implementor, which returns the first word of the input.
+///
+/// [`parser`]: trait.Parser.html
+pub struct FirstWord;
+
+impl<'a> Parser<'a> for FirstWord {
+    fn parse(&self, input: &'a str) -> Option<&'a str> {
+        input.split_whitespace().next()
+    }
+}
+
+/// Parses the trimmed copy of the `input` with `parser`.
+///
+/// The trimmed copy is local to this function, so `P` should
+/// implement [`Parser`] for any lifetime, not just for the one
+/// chosen by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use the_book::ch19::hrtb::{parse_trimmed, FirstWord};
+///
+/// assert_eq!(Some(String::from("a")), parse_trimmed(&FirstWord, " a b "));
+/// ```
+/// [`parser`]: trait.Parser.html
+pub fn parse_trimmed<P>(parser: &P, input: &str) -> Option<String>
+where
+    for<'a> P: Parser<'a>,
+{
+    let local = input.trim().to_string();
+    parser.parse(&local).map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn call_with_ref_closure() {
+        struct Test {
+            name: &'static str,
+            data: i32,
+            want: i32,
+        }
+        let tests = [
+            Test {
+                name: "positive value",
+                data: 2,
+                want: 3,
+            },
+            Test {
+                name: "zero value",
+                data: 0,
+                want: 1,
+            },
+            Test {
+                name: "negative value",
+                data: -2,
+                want: -1,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, call_with_ref(|x| x + 1, &t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn call_with_ref_fn_pointer() {
+        fn double(x: &i32) -> i32 {
+            x * 2
+        }
+        assert_eq!(8, call_with_ref(double, &4));
+    }
+    #[test]
+    fn call_with_local_closure() {
+        let offset = 10;
+        assert_eq!(15, call_with_local(|x| x + offset));
+    }
+    #[test]
+    fn parse_trimmed_first_word() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: Option<String>,
+        }
+        let tests = [
+            Test {
+                name: "single word",
+                data: "word",
+                want: Some(String::from("word")),
+            },
+            Test {
+                name: "multiple words with spaces",
+                data: "  first second  ",
+                want: Some(String::from("first")),
+            },
+            Test {
+                name: "empty string",
+                data: "",
+                want: None,
+            },
+            Test {
+                name: "only spaces",
+                data: "    ",
+                want: None,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, parse_trimmed(&FirstWord, t.data), "{}", t.name);
+        }
+    }
+}
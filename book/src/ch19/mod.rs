@@ -1,4 +1,6 @@
 //! [Advanced Features]
 //!
 //! [advanced features]: https://doc.rust-lang.org/book/ch19-00-advanced-features.html
+pub mod fn_pointers;
+pub mod hrtb;
 pub mod sec02;
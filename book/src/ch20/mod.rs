@@ -1,6 +1,9 @@
 //! Building a [Multithreaded] Web Server
 //!
 //! [multithreaded]: https://doc.rust-lang.org/book/ch20-03-graceful-shutdown-and-cleanup.html
+pub mod thread_pool;
+pub mod web_server;
+
 use std::{
     error::Error,
     fmt::{self, Debug},
@@ -0,0 +1,156 @@
+//! [Graceful Shutdown] with the explicit `Terminate` message
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::mpsc;
+//! use the_book::ch20::thread_pool::ThreadPool;
+//!
+//! let (tx, rx) = mpsc::channel();
+//! let pool = ThreadPool::new(4);
+//! for i in 0..8 {
+//!     let tx = tx.clone();
+//!     pool.execute(move || tx.send(i).unwrap());
+//! }
+//! drop(pool);
+//! drop(tx);
+//! assert_eq!(28, rx.iter().sum::<i32>());
+//! ```
+//! [graceful shutdown]: https://doc.rust-lang.org/book/ch20-03-graceful-shutdown-and-cleanup.html
+use std::{
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Message sent to the workers.
+enum Message {
+    Execute(Job),
+    Terminate,
+}
+
+/// Thread pool, which sends `Terminate` message to all the workers
+/// and joins them on drop.
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: Option<Sender<Message>>,
+}
+
+impl ThreadPool {
+    /// Create a new `ThreadPool` with `size` workers.
+    ///
+    /// # Panics
+    ///
+    /// Function `new` will panic if the `size` argument is zero.
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0);
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|id| Worker::new(id, Arc::clone(&receiver)))
+            .collect();
+        Self {
+            workers,
+            sender: Some(sender),
+        }
+    }
+    /// Execute `job` on one of the workers.
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        if let Some(sender) = &self.sender {
+            sender.send(Message::Execute(Box::new(job))).unwrap();
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Terminate messages are queued after all the pending jobs,
+        // so that the workers drain the queue before exiting.
+        if let Some(sender) = self.sender.take() {
+            for _ in &self.workers {
+                sender.send(Message::Terminate).unwrap();
+            }
+        }
+        for worker in &mut self.workers {
+            if let Some(handle) = worker.handle.take() {
+                handle.join().unwrap();
+            }
+        }
+    }
+}
+
+struct Worker {
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<Receiver<Message>>>) -> Self {
+        let handle = thread::Builder::new()
+            .name(format!("worker{}", id))
+            .spawn(move || loop {
+                // release the lock before running the job.
+                let msg = receiver.lock().unwrap().recv();
+                match msg {
+                    Ok(Message::Execute(job)) => job(),
+                    Ok(Message::Terminate) | Err(_) => break,
+                }
+            })
+            .unwrap();
+        Self {
+            handle: Some(handle),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ThreadPool;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc, Arc,
+        },
+        thread,
+        time::Duration,
+    };
+    #[test]
+    #[should_panic]
+    fn new_with_zero_size() {
+        ThreadPool::new(0);
+    }
+    #[test]
+    fn execute_100_tasks_with_4_workers() {
+        let (tx, rx) = mpsc::channel();
+        let pool = ThreadPool::new(4);
+        for i in 0..100 {
+            let tx = tx.clone();
+            pool.execute(move || tx.send(i).unwrap());
+        }
+        drop(tx);
+        let mut got: Vec<usize> = rx.iter().collect();
+        got.sort_unstable();
+        assert_eq!((0..100).collect::<Vec<_>>(), got);
+    }
+    #[test]
+    fn drop_before_tasks_complete() {
+        let done = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::new(2);
+        for _ in 0..8 {
+            let done = Arc::clone(&done);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(10));
+                done.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        // drop returns once all the queued tasks are completed.
+        drop(pool);
+        assert_eq!(8, done.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,107 @@
+//! [Single-Threaded] and [Multithreaded] HTTP/1.0 web server handler
+//!
+//! # Examples
+//!
+//! ```
+//! use std::io::Cursor;
+//! use the_book::ch20::web_server::handle_connection;
+//!
+//! let mut stream = Cursor::new(b"GET / HTTP/1.0\r\n\r\n".to_vec());
+//! handle_connection(&mut stream).unwrap();
+//! let resp = String::from_utf8(stream.into_inner()).unwrap();
+//! assert!(resp.contains("HTTP/1.0 200 OK\r\n"));
+//! assert!(resp.contains("<p>Hi from Rust</p>"));
+//! ```
+//! [single-threaded]: https://doc.rust-lang.org/book/ch20-01-single-threaded.html
+//! [multithreaded]: https://doc.rust-lang.org/book/ch20-02-multithreaded.html
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Read, Write},
+    thread,
+    time::Duration,
+};
+
+/// Delay of the `GET /sleep` route.
+pub const SLEEP: Duration = Duration::from_secs(5);
+
+const HELLO: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/hello.html");
+const NOT_FOUND: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/404.html");
+
+/// Read the request line from `stream` and write back the HTTP/1.0
+/// response.
+///
+/// - `GET /` responds with `hello.html`.
+/// - `GET /sleep` responds with `hello.html` after [`SLEEP`].
+/// - Anything else responds with `404.html`.
+///
+/// [`sleep`]: constant.SLEEP.html
+pub fn handle_connection<S: Read + Write>(mut stream: S) -> io::Result<()> {
+    let mut line = String::new();
+    BufReader::new(&mut stream).read_line(&mut line)?;
+    let mut req = line.split_whitespace();
+    let (status, file) = match (req.next(), req.next()) {
+        (Some("GET"), Some("/")) => ("200 OK", HELLO),
+        (Some("GET"), Some("/sleep")) => {
+            thread::sleep(SLEEP);
+            ("200 OK", HELLO)
+        }
+        _ => ("404 NOT FOUND", NOT_FOUND),
+    };
+    let body = fs::read_to_string(file)?;
+    write!(
+        stream,
+        "HTTP/1.0 {}\r\nContent-Length: {}\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    #[test]
+    fn handle_connection_routes() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "GET / with HTTP/1.0",
+                data: "GET / HTTP/1.0\r\n\r\n",
+                want: "HTTP/1.0 200 OK\r\n",
+            },
+            Test {
+                name: "GET / with HTTP/1.1",
+                data: "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+                want: "HTTP/1.0 200 OK\r\n",
+            },
+            Test {
+                name: "unknown path",
+                data: "GET /unknown HTTP/1.0\r\n\r\n",
+                want: "HTTP/1.0 404 NOT FOUND\r\n",
+            },
+            Test {
+                name: "unknown method",
+                data: "POST / HTTP/1.0\r\n\r\n",
+                want: "HTTP/1.0 404 NOT FOUND\r\n",
+            },
+            Test {
+                name: "empty request",
+                data: "",
+                want: "HTTP/1.0 404 NOT FOUND\r\n",
+            },
+        ];
+        for t in &tests {
+            let mut stream = Cursor::new(t.data.as_bytes().to_vec());
+            handle_connection(&mut stream).unwrap();
+            let resp = String::from_utf8(stream.into_inner()).unwrap();
+            let resp = &resp[t.data.len()..];
+            assert!(resp.starts_with(t.want), "{}: {}", t.name, resp);
+        }
+    }
+}
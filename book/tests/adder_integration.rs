@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Integration tests of `ch11::adder`, which only see its public API, e.g.
+//! `internal_adder` is not reachable from here.
+use std::fs;
+use std::path::Path;
+
+use the_book::ch11::adder::{add_two, greeting};
+
+mod common;
+
+#[test]
+fn add_two_from_fixture() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/add_two.txt");
+    let fixture = fs::read_to_string(&path).unwrap();
+    common::setup();
+    let mut n = 0;
+    for line in fixture.lines().filter(|line| !line.starts_with('#')) {
+        let mut fields = line.split_whitespace().map(|v| v.parse::<i32>().unwrap());
+        let (data, want) = (fields.next().unwrap(), fields.next().unwrap());
+        assert_eq!(want, add_two(data), "{}", line);
+        n += 1;
+    }
+    assert_eq!(4, n, "{}", path.display());
+}
+
+#[test]
+fn greeting_from_outside() {
+    assert_eq!("Hello, Ferris!", greeting("Ferris"));
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: GPL-2.0
+//! `Pair::cmp_display` is only implemented for the `Display` and
+//! `PartialOrd` types, and the snapshots pin the unsatisfied bound as the
+//! cause of the errors.
+#[test]
+fn pair_cmp_display_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/pair_cmp_display_*.rs");
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Integration tests of `ch12`, which search the poem of the book.
+use std::path::Path;
+
+use the_book::ch12::{run, Config};
+
+fn config(query: &str, flags: &[&str]) -> Config {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/poem.txt");
+    let mut args = vec![
+        String::from("minigrep"),
+        String::from(query),
+        path.to_string_lossy().into_owned(),
+    ];
+    args.extend(flags.iter().map(|flag| String::from(*flag)));
+    Config::build(&args).unwrap()
+}
+
+#[test]
+fn run_on_fixture() {
+    struct Test {
+        name: &'static str,
+        query: &'static str,
+        flags: &'static [&'static str],
+        want: Vec<&'static str>,
+    }
+    let tests = [
+        Test {
+            name: "case sensitive",
+            query: "to",
+            flags: &[],
+            want: vec!["Are you nobody, too?", "How dreary to be somebody!"],
+        },
+        Test {
+            name: "case insensitive",
+            query: "to",
+            flags: &["--ignore-case"],
+            want: vec![
+                "Are you nobody, too?",
+                "How dreary to be somebody!",
+                "To tell your name the livelong day",
+                "To an admiring bog!",
+            ],
+        },
+        Test {
+            name: "no match",
+            query: "monomorphization",
+            flags: &["--ignore-case"],
+            want: vec![],
+        },
+    ];
+    for t in &tests {
+        let got = run(config(t.query, t.flags)).unwrap();
+        assert_eq!(t.want, got, "{}", t.name);
+    }
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-2.0
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    thread::{self, JoinHandle},
+    time::Instant,
+};
+
+use the_book::ch20::{
+    thread_pool::ThreadPool,
+    web_server::{handle_connection, SLEEP},
+};
+
+/// Serve `count` connections with the 4 workers thread pool.
+fn server(count: usize) -> (SocketAddr, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = thread::spawn(move || {
+        let pool = ThreadPool::new(4);
+        for stream in listener.incoming().take(count) {
+            let stream = stream.unwrap();
+            pool.execute(|| handle_connection(stream).unwrap());
+        }
+    });
+    (addr, handle)
+}
+
+fn get(addr: SocketAddr, path: &str) -> String {
+    let mut s = TcpStream::connect(addr).unwrap();
+    write!(s, "GET {} HTTP/1.0\r\n\r\n", path).unwrap();
+    let mut resp = String::new();
+    s.read_to_string(&mut resp).unwrap();
+    resp
+}
+
+#[test]
+fn get_routes() {
+    struct Test {
+        name: &'static str,
+        path: &'static str,
+        status: &'static str,
+        body: &'static str,
+    }
+    let tests = [
+        Test {
+            name: "hello",
+            path: "/",
+            status: "HTTP/1.0 200 OK\r\n",
+            body: "<p>Hi from Rust</p>",
+        },
+        Test {
+            name: "not found",
+            path: "/not-found",
+            status: "HTTP/1.0 404 NOT FOUND\r\n",
+            body: "<h1>Oops!</h1>",
+        },
+    ];
+    let (addr, server) = server(tests.len());
+    for t in &tests {
+        let resp = get(addr, t.path);
+        assert!(resp.starts_with(t.status), "{}: {}", t.name, resp);
+        assert!(resp.contains(t.body), "{}: {}", t.name, resp);
+    }
+    server.join().unwrap();
+}
+
+#[test]
+fn get_while_sleeping() {
+    let (addr, server) = server(2);
+    let start = Instant::now();
+    let sleep = thread::spawn(move || get(addr, "/sleep"));
+    // slow request doesn't block the other workers.
+    let resp = get(addr, "/");
+    assert!(resp.starts_with("HTTP/1.0 200 OK\r\n"));
+    assert!(start.elapsed() < SLEEP);
+    let resp = sleep.join().unwrap();
+    assert!(resp.starts_with("HTTP/1.0 200 OK\r\n"));
+    assert!(start.elapsed() >= SLEEP);
+    server.join().unwrap();
+}
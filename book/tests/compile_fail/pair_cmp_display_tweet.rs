@@ -0,0 +1,10 @@
+use the_book::ch10::{Pair, Tweet};
+
+fn main() {
+    let tweet = |username: &str| Tweet {
+        username: username.to_string(),
+        content: "yep".to_string(),
+    };
+    // Tweet doesn't implement PartialOrd.
+    Pair::new(tweet("A"), tweet("B")).cmp_display();
+}
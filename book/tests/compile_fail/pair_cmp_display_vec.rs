@@ -0,0 +1,6 @@
+use the_book::ch10::Pair;
+
+fn main() {
+    // Vec doesn't implement Display.
+    Pair::new(vec![1], vec![2]).cmp_display();
+}
@@ -0,0 +1,67 @@
+//! `Monster` table copy benchmark
+//!
+//! `copy_into` copies the borrowed fields straight into the destination
+//! builder, while `unpack_pack` goes through the owned `MonsterData`.
+//!
+//! # Examples
+//!
+//! ```sh
+//! $ c bench --bench copy
+//! running 2 tests
+//! test copy_into   ... bench:         395.16 ns/iter (+/- 32.21)
+//! test unpack_pack ... bench:         757.71 ns/iter (+/- 100.32)
+//!
+//! test result: ok. 0 passed; 0 failed; 0 ignored; 2 measured; 0 filtered out; finished in 5.11s
+//! ```
+#![feature(test)]
+extern crate test;
+
+use test::Bencher;
+
+use flatbuf_tutorial::model::my_game::sample::{Color, Vec3};
+use flatbuf_tutorial::monster::{self, MonsterData, WeaponData};
+use flatbuf_tutorial::{Monster, MonsterBuf};
+use flatbuffers::FlatBufferBuilder;
+
+const BUFFER_CAPACITY: usize = 1_024;
+
+fn orc() -> MonsterBuf {
+    Monster::build_pooled(&MonsterData {
+        name: String::from("orc"),
+        pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+        mana: 20,
+        hp: 80,
+        inventory: (0..32).collect(),
+        color: Color::Red,
+        weapons: (0..4)
+            .map(|i| WeaponData {
+                name: format!("weapon-{}", i),
+                damage: i,
+            })
+            .collect(),
+        equipped: Some(1),
+        path: (0..16).map(|i| Vec3::new(i as f32, 0.0, 0.0)).collect(),
+    })
+}
+
+#[bench]
+fn copy_into(b: &mut Bencher) {
+    let orc = orc();
+    let mut builder = FlatBufferBuilder::with_capacity(BUFFER_CAPACITY);
+    b.iter(|| {
+        builder.reset();
+        let monster = monster::copy_into(orc.view(), &mut builder);
+        builder.finish(monster, None);
+    });
+}
+
+#[bench]
+fn unpack_pack(b: &mut Bencher) {
+    let orc = orc();
+    let mut builder = FlatBufferBuilder::with_capacity(BUFFER_CAPACITY);
+    b.iter(|| {
+        builder.reset();
+        let monster = MonsterData::from(orc.view()).build(&mut builder);
+        builder.finish(monster, None);
+    });
+}
@@ -0,0 +1,119 @@
+//! flatbuffers and protobuf interop benchmark
+//!
+//! Serialize, deserialize to the owned data and read one field of the
+//! same generated monster with flatbuffers and prost, for each size
+//! class.  The prost benches start from the `proto::Monster`, as the
+//! flatbuffers ones start from the `MonsterData`, so neither includes
+//! the conversion.
+//!
+//! # Examples
+//!
+//! ```sh
+//! $ cargo +nightly bench --bench interop --features protobuf,test-util
+//! running 21 tests
+//! test large::flatbuffers_access_one_field  ... bench:       2,260.93 ns/iter (+/- 253.07)
+//! test large::flatbuffers_deserialize       ... bench:       7,745.15 ns/iter (+/- 2,546.24)
+//! test large::flatbuffers_serialize_fresh   ... bench:       4,937.32 ns/iter (+/- 407.45)
+//! test large::flatbuffers_serialize_pooled  ... bench:       2,766.37 ns/iter (+/- 282.25)
+//! test large::prost_access_one_field        ... bench:      25,781.09 ns/iter (+/- 12,298.95)
+//! test large::prost_deserialize             ... bench:      25,614.72 ns/iter (+/- 5,093.54)
+//! test large::prost_serialize               ... bench:      20,796.68 ns/iter (+/- 5,742.21)
+//! test medium::flatbuffers_access_one_field ... bench:         625.12 ns/iter (+/- 69.30)
+//! test medium::flatbuffers_deserialize      ... bench:       1,081.48 ns/iter (+/- 107.45)
+//! test medium::flatbuffers_serialize_fresh  ... bench:         879.44 ns/iter (+/- 137.01)
+//! test medium::flatbuffers_serialize_pooled ... bench:         556.91 ns/iter (+/- 92.08)
+//! test medium::prost_access_one_field       ... bench:         961.58 ns/iter (+/- 128.57)
+//! test medium::prost_deserialize            ... bench:         987.94 ns/iter (+/- 660.37)
+//! test medium::prost_serialize              ... bench:         405.30 ns/iter (+/- 99.20)
+//! test small::flatbuffers_access_one_field  ... bench:         341.46 ns/iter (+/- 257.44)
+//! test small::flatbuffers_deserialize       ... bench:         695.66 ns/iter (+/- 846.66)
+//! test small::flatbuffers_serialize_fresh   ... bench:         808.56 ns/iter (+/- 452.62)
+//! test small::flatbuffers_serialize_pooled  ... bench:         509.99 ns/iter (+/- 47.45)
+//! test small::prost_access_one_field        ... bench:         559.64 ns/iter (+/- 35.31)
+//! test small::prost_deserialize             ... bench:         556.31 ns/iter (+/- 68.68)
+//! test small::prost_serialize               ... bench:         160.76 ns/iter (+/- 57.92)
+//!
+//! test result: ok. 0 passed; 0 failed; 0 ignored; 21 measured; 0 filtered out
+//! ```
+#![feature(test)]
+extern crate test;
+
+use test::{black_box, Bencher};
+
+use flatbuf_tutorial::monster::testing::{MonsterGenerator, SizeClass};
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::{proto, Monster, MonsterView};
+use flatbuffers::FlatBufferBuilder;
+use prost::Message;
+
+const SEED: u64 = 42;
+
+fn data(class: SizeClass) -> MonsterData {
+    MonsterGenerator::new(SEED).size_class(class).monster()
+}
+
+macro_rules! interop_benches {
+    ($name:ident, $class:expr) => {
+        mod $name {
+            use super::*;
+
+            #[bench]
+            fn flatbuffers_serialize_pooled(b: &mut Bencher) {
+                let data = data($class);
+                b.iter(|| Monster::build_pooled(black_box(&data)));
+            }
+
+            #[bench]
+            fn flatbuffers_serialize_fresh(b: &mut Bencher) {
+                let data = data($class);
+                b.iter(|| {
+                    let mut b = FlatBufferBuilder::new();
+                    let monster = black_box(&data).build(&mut b);
+                    b.finish(monster, None);
+                    b.finished_data().to_vec()
+                });
+            }
+
+            #[bench]
+            fn prost_serialize(b: &mut Bencher) {
+                let monster = proto::Monster::from(data($class));
+                b.iter(|| black_box(&monster).encode_to_vec());
+            }
+
+            #[bench]
+            fn flatbuffers_deserialize(b: &mut Bencher) {
+                let buf = Monster::build_pooled(&data($class));
+                b.iter(|| {
+                    let view = MonsterView::from_bytes(black_box(buf.as_ref())).unwrap();
+                    MonsterData::from(view)
+                });
+            }
+
+            #[bench]
+            fn prost_deserialize(b: &mut Bencher) {
+                let buf = proto::Monster::from(data($class)).encode_to_vec();
+                b.iter(|| proto::Monster::decode(black_box(&buf[..])).unwrap());
+            }
+
+            #[bench]
+            fn flatbuffers_access_one_field(b: &mut Bencher) {
+                let buf = Monster::build_pooled(&data($class));
+                b.iter(|| {
+                    MonsterView::from_bytes(black_box(buf.as_ref()))
+                        .unwrap()
+                        .hp()
+                });
+            }
+
+            #[bench]
+            fn prost_access_one_field(b: &mut Bencher) {
+                let buf = proto::Monster::from(data($class)).encode_to_vec();
+                b.iter(|| proto::Monster::decode(black_box(&buf[..])).unwrap().hp);
+            }
+        }
+    };
+}
+
+interop_benches!(small, SizeClass::Small);
+interop_benches!(medium, SizeClass::Medium);
+interop_benches!(large, SizeClass::Large);
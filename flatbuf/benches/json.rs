@@ -0,0 +1,69 @@
+//! JSON export benchmark
+//!
+//! Serialize the same generated monster buffer to JSON straight from the
+//! `MonsterView`, and through the owned `MonsterData`, for each size
+//! class.  The writer is reused, so the difference is the `MonsterData`
+//! allocations and copies.
+//!
+//! # Examples
+//!
+//! ```sh
+//! $ cargo +nightly bench --bench json --features serde,test-util
+//! running 6 tests
+//! test large::json_from_data  ... bench:     147,151.08 ns/iter (+/- 27,807.03)
+//! test large::json_from_view  ... bench:     156,221.97 ns/iter (+/- 67,173.87)
+//! test medium::json_from_data ... bench:       2,074.01 ns/iter (+/- 319.04)
+//! test medium::json_from_view ... bench:       1,901.71 ns/iter (+/- 246.83)
+//! test small::json_from_data  ... bench:       1,155.61 ns/iter (+/- 691.47)
+//! test small::json_from_view  ... bench:         443.05 ns/iter (+/- 136.47)
+//!
+//! test result: ok. 0 passed; 0 failed; 0 ignored; 6 measured; 0 filtered out
+//! ```
+//!
+//! The large monsters are dominated by the float formatting of the long
+//! paths, hence within the noise.
+#![feature(test)]
+extern crate test;
+
+use test::Bencher;
+
+use flatbuf_tutorial::monster::testing::{MonsterGenerator, SizeClass};
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::{Monster, MonsterBuf};
+
+const SEED: u64 = 42;
+
+fn buf(class: SizeClass) -> MonsterBuf {
+    Monster::build_pooled(&MonsterGenerator::new(SEED).size_class(class).monster())
+}
+
+macro_rules! json_benches {
+    ($name:ident, $class:expr) => {
+        mod $name {
+            use super::*;
+
+            #[bench]
+            fn json_from_view(b: &mut Bencher) {
+                let buf = buf($class);
+                let mut out = Vec::new();
+                b.iter(|| {
+                    out.clear();
+                    serde_json::to_writer(&mut out, &buf.view()).unwrap();
+                });
+            }
+            #[bench]
+            fn json_from_data(b: &mut Bencher) {
+                let buf = buf($class);
+                let mut out = Vec::new();
+                b.iter(|| {
+                    out.clear();
+                    serde_json::to_writer(&mut out, &MonsterData::from(buf.view())).unwrap();
+                });
+            }
+        }
+    };
+}
+
+json_benches!(small, SizeClass::Small);
+json_benches!(medium, SizeClass::Medium);
+json_benches!(large, SizeClass::Large);
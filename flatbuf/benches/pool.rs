@@ -23,18 +23,23 @@ extern crate test;
 
 use test::Bencher;
 
-use flatbuf_tutorial::pool::{v1, v2, v3};
+use std::sync::atomic::AtomicUsize;
+use std::sync::Once;
+
+use flatbuf_tutorial::pool::{v1, v2, v3, v4, ShardedPoolBuilder, ShardingStrategy};
 use flatbuffers::FlatBufferBuilder;
 use parking_lot::Mutex;
 
 const INIT_POOL_SIZE: usize = 4_096;
 const MAX_POOL_SIZE: usize = 8_192;
 const BUFFER_CAPACITY: usize = 64;
+const THREADS: usize = 8;
+const ITERS_PER_THREAD: usize = 1_000;
 
 #[bench]
 fn pool_stack(b: &mut Bencher) {
     b.iter(|| {
-        let mut b = FlatBufferBuilder::new_with_capacity(BUFFER_CAPACITY);
+        let mut b = FlatBufferBuilder::with_capacity(BUFFER_CAPACITY);
         let data = b.create_string("a");
         b.finish(data, None);
     });
@@ -42,7 +47,7 @@ fn pool_stack(b: &mut Bencher) {
 
 #[bench]
 fn pool_mutex(b: &mut Bencher) {
-    let builder = Mutex::new(FlatBufferBuilder::new_with_capacity(BUFFER_CAPACITY));
+    let builder = Mutex::new(FlatBufferBuilder::with_capacity(BUFFER_CAPACITY));
     b.iter(|| {
         let b = &mut *builder.lock();
         let data = b.create_string("a");
@@ -56,7 +61,7 @@ fn pool_global_v1(b: &mut Bencher) {
     v1::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     v1::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
     b.iter(|| {
-        let mut b = v1::FlatBufferBuilderPool::get();
+        let mut b = v1::FlatBufferBuilderPool::get().unwrap();
         let data = b.create_string("a");
         b.finish(data, None);
     });
@@ -68,19 +73,28 @@ fn pool_global_v2(b: &mut Bencher) {
     v2::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     v2::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
     b.iter(|| {
-        let mut b = v2::FlatBufferBuilderPool::get();
+        let mut b = v2::FlatBufferBuilderPool::get().unwrap();
         let data = b.create_string("a");
         b.finish(data, None);
     });
 }
 
+/// Configure the v3 global pool once for all the v3 global benches, as
+/// the sizes can't be changed after the first `get`.
+fn configure_global_v3() {
+    static CONFIGURE: Once = Once::new();
+    CONFIGURE.call_once(|| {
+        v3::FlatBufferBuilderPool::init_global_pool_size(INIT_POOL_SIZE);
+        v3::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
+        v3::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
+    });
+}
+
 #[bench]
 fn pool_global_v3(b: &mut Bencher) {
-    v3::FlatBufferBuilderPool::init_global_pool_size(INIT_POOL_SIZE);
-    v3::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
-    v3::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
+    configure_global_v3();
     b.iter(|| {
-        let mut b = v3::FlatBufferBuilderPool::get();
+        let mut b = v3::FlatBufferBuilderPool::get().unwrap();
         let data = b.create_string("a");
         b.finish(data, None);
     });
@@ -127,3 +141,124 @@ fn pool_local_v3(b: &mut Bencher) {
         b.finish(data, None);
     });
 }
+
+#[bench]
+fn pool_local_v3_get_8(b: &mut Bencher) {
+    let pool = v3::FlatBufferBuilderPool::new()
+        .init_pool_size(INIT_POOL_SIZE)
+        .max_pool_size(MAX_POOL_SIZE)
+        .buffer_capacity(BUFFER_CAPACITY)
+        .build();
+    b.iter(|| {
+        let builders: [_; 8] = [(); 8].map(|_| pool.get());
+        test::black_box(builders);
+    });
+}
+
+#[bench]
+fn pool_local_v3_get_array_8(b: &mut Bencher) {
+    let pool = v3::FlatBufferBuilderPool::new()
+        .init_pool_size(INIT_POOL_SIZE)
+        .max_pool_size(MAX_POOL_SIZE)
+        .buffer_capacity(BUFFER_CAPACITY)
+        .build();
+    b.iter(|| {
+        let builders = pool.get_array::<8>();
+        test::black_box(builders);
+    });
+}
+
+/// Run `f` `ITERS_PER_THREAD` times on each of the `THREADS` threads,
+/// which start together.
+fn contended<F: Fn() + Sync>(f: F) {
+    let barrier = std::sync::Barrier::new(THREADS);
+    std::thread::scope(|s| {
+        for _ in 0..THREADS {
+            let (barrier, f) = (&barrier, &f);
+            s.spawn(move || {
+                barrier.wait();
+                for _ in 0..ITERS_PER_THREAD {
+                    f();
+                }
+            });
+        }
+    });
+}
+
+/// 8-thread contention on the shared queue, to compare with
+/// [`pool_global_v4_8_threads`].  Both include the thread spawns.
+///
+/// The cache misses are counted by `perf`, e.g. with
+///
+/// ```sh
+/// $ RUSTC_BOOTSTRAP=1 cargo bench --bench pool --no-run
+/// $ perf stat -e cache-references,cache-misses \
+///     target/release/deps/pool-* --bench pool_global_v3_8_threads
+/// ```
+///
+/// where all the threads hit the cache lines of the `ArrayQueue` head
+/// and tail, while the v4 threads mostly stay in their own thread local
+/// pools.
+///
+/// [`pool_global_v4_8_threads`]: fn.pool_global_v4_8_threads.html
+#[bench]
+fn pool_global_v3_8_threads(b: &mut Bencher) {
+    configure_global_v3();
+    b.iter(|| {
+        contended(|| {
+            let mut b = v3::FlatBufferBuilderPool::get().unwrap();
+            let data = b.create_string("a");
+            b.finish(data, None);
+        })
+    });
+}
+
+#[bench]
+fn pool_global_v4_8_threads(b: &mut Bencher) {
+    v4::FlatBufferBuilderPool::init_global_pool_size(INIT_POOL_SIZE);
+    v4::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
+    v4::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
+    b.iter(|| {
+        contended(|| {
+            let mut b = v4::FlatBufferBuilderPool::get();
+            let data = b.create_string("a");
+            b.finish(data, None);
+        })
+    });
+}
+
+/// 8-thread get and return on the 8 shards, to compare the
+/// [`ShardingStrategy`] with each other, and with
+/// [`pool_global_v3_8_threads`].
+///
+/// [`pool_global_v3_8_threads`]: fn.pool_global_v3_8_threads.html
+fn sharded_8_threads(b: &mut Bencher, strategy: ShardingStrategy) {
+    let pool = ShardedPoolBuilder::new()
+        .shards(THREADS)
+        .max_pool_size(MAX_POOL_SIZE / THREADS)
+        .buffer_capacity(BUFFER_CAPACITY)
+        .sharding_strategy(strategy)
+        .build();
+    b.iter(|| {
+        contended(|| {
+            let mut b = pool.get();
+            let data = b.create_string("a");
+            b.finish(data, None);
+        })
+    });
+}
+
+#[bench]
+fn pool_sharded_thread_id_8_threads(b: &mut Bencher) {
+    sharded_8_threads(b, ShardingStrategy::ThreadId);
+}
+
+#[bench]
+fn pool_sharded_sequential_8_threads(b: &mut Bencher) {
+    sharded_8_threads(b, ShardingStrategy::Sequential(AtomicUsize::new(0)));
+}
+
+#[bench]
+fn pool_sharded_random_8_threads(b: &mut Bencher) {
+    sharded_8_threads(b, ShardingStrategy::Random);
+}
@@ -35,7 +35,7 @@ const BUFFER_CAPACITY: usize = 1_024;
 #[bench]
 fn pool_monster_stack(b: &mut Bencher) {
     b.iter(|| {
-        let mut b = FlatBufferBuilder::new_with_capacity(BUFFER_CAPACITY);
+        let mut b = FlatBufferBuilder::with_capacity(BUFFER_CAPACITY);
         let monster = Monster::create(&mut b, "monster");
         b.finish(monster, None);
     });
@@ -43,7 +43,7 @@ fn pool_monster_stack(b: &mut Bencher) {
 
 #[bench]
 fn pool_monster_mutex(b: &mut Bencher) {
-    let builder = Mutex::new(FlatBufferBuilder::new_with_capacity(BUFFER_CAPACITY));
+    let builder = Mutex::new(FlatBufferBuilder::with_capacity(BUFFER_CAPACITY));
     b.iter(|| {
         let mut b = &mut *builder.lock();
         let monster = Monster::create(&mut b, "monster");
@@ -57,7 +57,7 @@ fn pool_monster_global_v1(b: &mut Bencher) {
     v1::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     v1::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
     b.iter(|| {
-        let mut b = v1::FlatBufferBuilderPool::get();
+        let mut b = v1::FlatBufferBuilderPool::get().unwrap();
         let monster = Monster::create(&mut b, "monster");
         b.finish(monster, None);
     });
@@ -69,7 +69,7 @@ fn pool_monster_global_v2(b: &mut Bencher) {
     v2::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     v2::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
     b.iter(|| {
-        let mut b = v2::FlatBufferBuilderPool::get();
+        let mut b = v2::FlatBufferBuilderPool::get().unwrap();
         let monster = Monster::create(&mut b, "monster");
         b.finish(monster, None);
     });
@@ -81,7 +81,7 @@ fn pool_monster_global_v3(b: &mut Bencher) {
     v3::FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     v3::FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
     b.iter(|| {
-        let mut b = v3::FlatBufferBuilderPool::get();
+        let mut b = v3::FlatBufferBuilderPool::get().unwrap();
         let monster = Monster::create(&mut b, "monster");
         b.finish(monster, None);
     });
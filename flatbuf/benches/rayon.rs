@@ -0,0 +1,57 @@
+//! rayon parallel serialization benchmark
+//!
+//! Serializes 10,000 small monsters on the rayon threads with
+//! `par_serialize`, and on the current thread with the same global pool.
+//! The numbers below are from the single CPU machine, where rayon only
+//! adds its overhead.
+//!
+//! # Examples
+//!
+//! ```sh
+//! $ c bench --bench rayon --features rayon,test-util
+//! running 2 tests
+//! test serialize_rayon  ... bench:   3,757,511.65 ns/iter (+/- 356,584.46)
+//! test serialize_serial ... bench:   3,402,110.65 ns/iter (+/- 242,068.87)
+//!
+//! test result: ok. 0 passed; 0 failed; 0 ignored; 2 measured; 0 filtered out; finished in 6.51s
+//! ```
+#![feature(test)]
+extern crate test;
+
+use test::Bencher;
+
+use flatbuf_tutorial::monster::testing::{MonsterGenerator, SizeClass};
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::pool::rayon_ext::par_serialize;
+use flatbuf_tutorial::FlatBufferBuilderPool;
+
+const MONSTERS: usize = 10_000;
+
+fn monsters() -> Vec<MonsterData> {
+    MonsterGenerator::new(42)
+        .size_class(SizeClass::Small)
+        .take(MONSTERS)
+        .collect()
+}
+
+#[bench]
+fn serialize_rayon(b: &mut Bencher) {
+    let monsters = monsters();
+    b.iter(|| par_serialize(&monsters, |data, b| data.build(b).as_union_value()));
+}
+
+#[bench]
+fn serialize_serial(b: &mut Bencher) {
+    let monsters = monsters();
+    b.iter(|| {
+        monsters
+            .iter()
+            .map(|data| {
+                let mut b = FlatBufferBuilderPool::get_or_alloc();
+                let monster = data.build(&mut b);
+                b.finish(monster, None);
+                b.finished_data().to_vec()
+            })
+            .collect::<Vec<_>>()
+    });
+}
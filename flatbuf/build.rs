@@ -1,9 +1,83 @@
-use std::process::Command;
-
-fn main() -> std::io::Result<()> {
-    Command::new("flatc")
-        .args(&["-o", "./src", "-r"])
-        .arg("./schema/monster.fbs")
-        .output()?;
-    Ok(())
+//! Regenerate the `model` module from `schema/monster.fbs` with
+//! `flatc` when the `regenerate-model` feature is enabled.
+//!
+//! Without the feature, or without `flatc`, the crate falls back
+//! to the checked-in `src/monster_generated.rs`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const SCHEMA: &str = "schema/monster.fbs";
+const MODEL: &str = "src/model.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SCHEMA);
+    println!("cargo:rerun-if-changed={}", MODEL);
+    println!("cargo:rerun-if-env-changed=FLATC_PATH");
+    println!("cargo:rustc-check-cfg=cfg(flatc_model)");
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let schema = fs::read(SCHEMA).unwrap();
+    let hash = fnv1a(&schema);
+    fs::write(
+        out_dir.join("schema_hash.rs"),
+        format!("pub const SCHEMA_HASH: u64 = {:#018x};\n", hash),
+    )
+    .unwrap();
+    if checked_in_hash() != Some(hash) {
+        println!(
+            "cargo:warning=checked-in model is out of date with {} ({:#018x}), \
+             regenerate it with the `regenerate-model` feature",
+            SCHEMA, hash,
+        );
+    }
+
+    if env::var_os("CARGO_FEATURE_REGENERATE_MODEL").is_none() {
+        return;
+    }
+    match flatc() {
+        None => println!("cargo:warning=flatc not found, using the checked-in model"),
+        Some(flatc) => {
+            let status = Command::new(&flatc)
+                .arg("--rust")
+                .arg("-o")
+                .arg(&out_dir)
+                .arg(SCHEMA)
+                .status();
+            match status {
+                Ok(status) if status.success() => println!("cargo:rustc-cfg=flatc_model"),
+                Ok(status) => println!("cargo:warning={} failed: {}", flatc.display(), status),
+                Err(err) => println!("cargo:warning={} failed: {}", flatc.display(), err),
+            }
+        }
+    }
+}
+
+/// Find `flatc` through `FLATC_PATH`, then `PATH`.
+fn flatc() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("FLATC_PATH") {
+        return Some(PathBuf::from(path)).filter(|path| path.is_file());
+    }
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join("flatc"))
+        .find(|path| Path::is_file(path))
+}
+
+/// Schema hash the checked-in model was generated from.
+fn checked_in_hash() -> Option<u64> {
+    let model = fs::read_to_string(MODEL).ok()?;
+    let line = model
+        .lines()
+        .find(|line| line.contains("const CHECKED_IN_SCHEMA_HASH"))?;
+    let hash = line.split('=').nth(1)?.trim().trim_end_matches(';');
+    u64::from_str_radix(hash.trim_start_matches("0x").replace('_', "").as_str(), 16).ok()
+}
+
+/// 64 bit FNV-1a hash.
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
 }
@@ -0,0 +1,77 @@
+//! actix-web server building the monster buffers with the builder pools.
+//!
+//! ```sh
+//! $ cargo run --example actix_monster --features actix
+//! $ curl -s -H 'content-type: application/json' -d '{"name":"orc","hp":300}' \
+//!     localhost:8080/monsters | xxd | head -1
+//! 00000000: 1000 0000 0c00 0c00 0000 0000 0600 0800  ................
+//! ```
+use std::convert::TryFrom;
+
+use actix_web::{post, web, App, HttpResponse, HttpServer};
+use flatbuf_tutorial::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, Monster};
+use flatbuffers::FlatBufferBuilder;
+use serde::Deserialize;
+
+const ADDR: &str = "127.0.0.1:8080";
+
+#[derive(Debug, Deserialize)]
+struct MonsterRequest {
+    name: String,
+    hp: u16,
+}
+
+/// Build the monster of `req` in `b`, and returns the finished buffer.
+fn build<'a>(b: &'a mut FlatBufferBuilder<'_>, req: &MonsterRequest) -> Option<&'a [u8]> {
+    let hp = i16::try_from(req.hp).ok()?;
+    let monster = Monster::builder().name(&req.name).hp(hp).build(b);
+    b.finish(monster, None);
+    Some(b.finished_data())
+}
+
+fn response(buf: Option<&[u8]>) -> HttpResponse {
+    match buf {
+        Some(buf) => HttpResponse::Ok()
+            .content_type("application/octet-stream")
+            .body(buf.to_vec()),
+        None => HttpResponse::BadRequest().body("hp out of range"),
+    }
+}
+
+/// Build the monster with the builder from the global pool, which is
+/// shared by all the handlers and the workers without any setup.
+#[post("/monsters")]
+async fn create_monster(req: web::Json<MonsterRequest>) -> HttpResponse {
+    let mut b = FlatBufferBuilderPool::get_or_alloc();
+    response(build(&mut b, &req))
+}
+
+/// Build the monster with the builder from the local pool in the app
+/// data, see `main`.
+#[post("/local/monsters")]
+async fn create_local_monster(
+    pool: web::Data<FlatBufferBuilderLocalPool<'static>>,
+    req: web::Json<MonsterRequest>,
+) -> HttpResponse {
+    let mut b = pool.get();
+    response(build(&mut b, &req))
+}
+
+/// The app factory runs once for each worker thread, so the local pool
+/// is built once outside of it, and shared with `web::Data`, which is
+/// the `Arc` of the pool.  Building the pool in the factory would give
+/// each worker its own pool instead.
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    let pool = web::Data::new(FlatBufferBuilderPool::new().build());
+    println!("listening on {}", ADDR);
+    HttpServer::new(move || {
+        App::new()
+            .app_data(pool.clone())
+            .service(create_monster)
+            .service(create_local_monster)
+    })
+    .bind(ADDR)?
+    .run()
+    .await
+}
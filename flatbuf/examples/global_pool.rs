@@ -1,4 +1,5 @@
 //! Global pool example
+use flatbuf_tutorial::monster::MonsterData;
 use flatbuf_tutorial::{FlatBufferBuilderPool, Monster};
 
 const INIT_POOL_SIZE: usize = 4;
@@ -10,7 +11,13 @@ fn main() {
     FlatBufferBuilderPool::max_global_pool_size(MAX_POOL_SIZE);
     FlatBufferBuilderPool::global_buffer_capacity(BUFFER_CAPACITY);
 
-    let mut b = FlatBufferBuilderPool::get();
-    let monster = Monster::create(&mut b, "monster");
-    b.finish(monster, None);
+    let data = MonsterData {
+        name: String::from("monster"),
+        ..Default::default()
+    };
+    for _ in 0..2 {
+        let monster = Monster::build_pooled(&data);
+        println!("{:?}: {} bytes", monster.name(), monster.as_ref().len());
+    }
+    println!("{:?}", FlatBufferBuilderPool::global_stats());
 }
@@ -1,4 +1,5 @@
 //! Global pool example
+use flatbuf_tutorial::monster::MonsterData;
 use flatbuf_tutorial::{FlatBufferBuilderPool, Monster};
 
 const INIT_POOL_SIZE: usize = 4;
@@ -11,7 +12,13 @@ fn main() {
         .max_pool_size(MAX_POOL_SIZE)
         .buffer_capacity(BUFFER_CAPACITY)
         .build();
-    let mut b = pool.get();
-    let monster = Monster::create(&mut b, "monster");
-    b.finish(monster, None);
+    let data = MonsterData {
+        name: String::from("monster"),
+        ..Default::default()
+    };
+    for _ in 0..2 {
+        let monster = Monster::build_with(&pool, &data);
+        println!("{:?}: {} bytes", monster.name(), monster.as_ref().len());
+    }
+    println!("{:?}", pool.stats());
 }
@@ -0,0 +1,47 @@
+//! Monster TCP client, which sends the orc to [`monster_tcp_server`] and
+//! checks the doubled `hp` in the response.
+//!
+//! ```sh
+//! $ cargo run --example monster_tcp_client --features async
+//! Some("Orc"): hp 600
+//! ```
+//!
+//! [`monster_tcp_server`]: ../monster_tcp_server/index.html
+use std::io;
+
+use flatbuf_tutorial::monster::aio::{append_size_prefixed, FrameReader};
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::{AioError, Monster, MonsterBuf};
+use tokio::net::{TcpStream, ToSocketAddrs};
+
+const ADDR: &str = "127.0.0.1:7878";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), AioError> {
+    let orc = request(ADDR, &build_orc()).await?;
+    assert_eq!(600, orc.hp());
+    println!("{:?}: hp {}", orc.name(), orc.hp());
+    Ok(())
+}
+
+pub fn build_orc() -> MonsterBuf {
+    Monster::build_pooled(&MonsterData {
+        name: String::from("Orc"),
+        hp: 300,
+        mana: 150,
+        ..Default::default()
+    })
+}
+
+/// Send `monster` to the server at `addr`, and returns the response.
+pub async fn request<A: ToSocketAddrs>(
+    addr: A,
+    monster: &MonsterBuf,
+) -> Result<MonsterBuf, AioError> {
+    let mut stream = TcpStream::connect(addr).await?;
+    let (r, mut w) = stream.split();
+    append_size_prefixed(&mut w, monster).await?;
+    FrameReader::new(r).next_frame().await.unwrap_or_else(|| {
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connection closed").into())
+    })
+}
@@ -0,0 +1,64 @@
+//! Monster TCP echo server, which doubles the `hp` of each size-prefixed
+//! monster and sends it back, e.g. to [`monster_tcp_client`].
+//!
+//! ```sh
+//! $ cargo run --example monster_tcp_server --features async
+//! listening on 127.0.0.1:7878
+//! ```
+//!
+//! [`monster_tcp_client`]: ../monster_tcp_client/index.html
+use flatbuf_tutorial::monster::aio::{append_size_prefixed, FrameReader};
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::{AioError, FlatBufferBuilderPool, MonsterBuf};
+use tokio::net::{TcpListener, TcpStream};
+
+const ADDR: &str = "127.0.0.1:7878";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> std::io::Result<()> {
+    let listener = TcpListener::bind(ADDR).await?;
+    println!("listening on {}", listener.local_addr()?);
+    serve(listener).await
+}
+
+/// Serve each connection of `listener` on its own task.
+pub async fn serve(listener: TcpListener) -> std::io::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(err) = echo(stream).await {
+                eprintln!("{}: {}", peer, err);
+            }
+        });
+    }
+}
+
+/// Echo the monsters back with the doubled `hp` until the peer closes
+/// the connection.
+async fn echo(mut stream: TcpStream) -> Result<(), AioError> {
+    let (r, mut w) = stream.split();
+    let mut frames = FrameReader::new(r);
+    while let Some(monster) = frames.next_frame().await {
+        let monster = double_hp(monster?);
+        append_size_prefixed(&mut w, &monster).await?;
+    }
+    Ok(())
+}
+
+/// Double the `hp` in place, or re-serialize the monster with the
+/// builder from the global pool in case `hp` is not in the buffer, e.g.
+/// omitted as the default value.
+fn double_hp(mut monster: MonsterBuf) -> MonsterBuf {
+    let hp = monster.hp().saturating_mul(2);
+    if monster.mutate_hp(hp) {
+        return monster;
+    }
+    let data = MonsterData {
+        hp,
+        ..MonsterData::from(monster.view())
+    };
+    let mut b = FlatBufferBuilderPool::get_or_alloc();
+    let doubled = data.builder().force_defaults(true).build(&mut b);
+    b.finish(doubled, None);
+    MonsterBuf::from_builder(b).expect("monster built from the verified one")
+}
@@ -0,0 +1,48 @@
+/* SPDX-License-Identifier: GPL-2.0 */
+/*
+ * C API of the flatbuf-tutorial crate, built with the `ffi` feature:
+ *
+ *   cargo rustc --lib --release --features ffi --crate-type cdylib
+ *
+ * The buffers are passed as the pointer and the length, and are never
+ * assumed to be null-terminated.  Every function returns MONSTER_OK or
+ * one of the negative MONSTER_ERR_* codes.
+ */
+#ifndef FLATBUF_TUTORIAL_H
+#define FLATBUF_TUTORIAL_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define MONSTER_OK 0
+#define MONSTER_ERR_NULL -1
+#define MONSTER_ERR_JSON -2
+#define MONSTER_ERR_INVALID -3
+#define MONSTER_ERR_PANIC -4
+
+/*
+ * Build the Monster buffer from the MonsterData JSON.  On success, the
+ * buffer is stored in *out_buf and *out_len, and must be released with
+ * monster_free.
+ */
+int32_t monster_build_json(const uint8_t *json, size_t json_len,
+                           uint8_t **out_buf, size_t *out_len);
+
+/* Verify the Monster buffer. */
+int32_t monster_verify(const uint8_t *buf, size_t len);
+
+/* Verify the Monster buffer and store its hp in *out_hp. */
+int32_t monster_get_hp(const uint8_t *buf, size_t len, int16_t *out_hp);
+
+/* Release the buffer returned by monster_build_json.  NULL is ignored. */
+void monster_free(uint8_t *buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* FLATBUF_TUTORIAL_H */
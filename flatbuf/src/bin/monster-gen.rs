@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Create and inspect the `Monster` buffers.
+use std::{env, io, process};
+
+use flatbuf_tutorial::cli::{Command, USAGE};
+use flatbuf_tutorial::CliError;
+
+fn main() {
+    let result = Command::parse(env::args().skip(1)).and_then(|command| {
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+        command.run(&mut out)
+    });
+    match result {
+        Ok(()) => {}
+        Err(CliError::Usage(message)) => {
+            eprintln!("monster-gen: {}\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(err) => {
+            eprintln!("monster-gen: {}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Owned, verified `Monster` buffer.
+use std::ops::Deref;
+
+use flatbuffers::FlatBufferBuilder;
+
+use crate::error::MonsterError;
+use crate::model::my_game::sample;
+use crate::view::{MonsterView, VerifyOpts};
+
+/// Owned `Monster` buffer, verified once at the construction.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::{Monster, MonsterBuf};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().name("orc").hp(80).build(&mut b);
+/// b.finish(orc, None);
+/// let orc = MonsterBuf::new(b.finished_data().to_vec()).unwrap();
+/// assert_eq!(Some("orc"), orc.name());
+/// assert_eq!(80, orc.view().hp());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonsterBuf(Vec<u8>);
+
+impl MonsterBuf {
+    /// Verify `buf` and take the ownership of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{MonsterBuf, MonsterError};
+    ///
+    /// match MonsterBuf::new(vec![0xff; 3]) {
+    ///     Err(MonsterError::Invalid(_)) => {}
+    ///     _ => panic!("unexpected result"),
+    /// }
+    /// ```
+    pub fn new(buf: Vec<u8>) -> Result<Self, MonsterError> {
+        Self::new_with_opts(buf, VerifyOpts::strict())
+    }
+    /// Verify `buf` under the `opts` limits and take the ownership of it.
+    pub fn new_with_opts(buf: Vec<u8>, opts: VerifyOpts) -> Result<Self, MonsterError> {
+        MonsterView::from_bytes_with_opts(&buf, opts)?;
+        Ok(Self(buf))
+    }
+    /// Take the buffer built by this crate, which is valid by
+    /// construction, without verifying it.
+    pub(crate) fn from_built(buf: Vec<u8>) -> Self {
+        debug_assert!(MonsterView::from_bytes_with_opts(&buf, VerifyOpts::relaxed()).is_ok());
+        Self(buf)
+    }
+    /// Copy out the finished data of the pooled `builder` and release
+    /// the builder back to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Function `from_builder` will panic if the `builder` is not finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{FlatBufferBuilderPool, Monster, MonsterBuf};
+    ///
+    /// let mut b = FlatBufferBuilderPool::get_or_alloc();
+    /// let orc = Monster::builder().name("orc").build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterBuf::from_builder(b).unwrap();
+    /// assert_eq!(Some("orc"), orc.name());
+    /// ```
+    pub fn from_builder<'b, B>(builder: B) -> Result<Self, MonsterError>
+    where
+        B: Deref<Target = FlatBufferBuilder<'b>>,
+    {
+        Self::new(builder.finished_data().to_vec())
+    }
+    /// Returns the view of the verified buffer without re-verifying it.
+    #[inline]
+    pub fn view(&self) -> MonsterView<'_> {
+        // Safety: verified in `new`.
+        MonsterView::from(unsafe { sample::root_as_monster_unchecked(&self.0) })
+    }
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.view().name()
+    }
+    #[inline]
+    pub fn hp(&self) -> i16 {
+        self.view().hp()
+    }
+    /// Update `hp` in place.
+    ///
+    /// It returns `false` without updating the buffer in case `hp` is
+    /// not physically present, e.g. omitted as the default value, or
+    /// the update breaks the buffer, e.g. the crafted `hp` slot overlaps
+    /// with other fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterBuf};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").force_defaults(true).build(&mut b);
+    /// b.finish(orc, None);
+    /// let mut orc = MonsterBuf::new(b.finished_data().to_vec()).unwrap();
+    /// assert!(orc.mutate_hp(50));
+    /// assert_eq!(50, orc.hp());
+    /// ```
+    pub fn mutate_hp(&mut self, hp: i16) -> bool {
+        match self.view().field_pos(sample::Monster::VT_HP) {
+            None => false,
+            Some(pos) => {
+                let old = [self.0[pos], self.0[pos + 1]];
+                self.0[pos..pos + 2].copy_from_slice(&hp.to_le_bytes());
+                // The verifier doesn't reject the overlapping fields.
+                // Re-verify to keep `view` safe.
+                if MonsterView::from_bytes(&self.0).is_err() {
+                    self.0[pos..pos + 2].copy_from_slice(&old);
+                    return false;
+                }
+                true
+            }
+        }
+    }
+    /// Returns the underlying bytes.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for MonsterBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MonsterBuf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FlatBufferBuilderPool, Monster};
+    use std::thread;
+
+    fn orc() -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let orc = Monster::builder()
+            .name("orc")
+            .hp(80)
+            .weapon("axe", 5)
+            .build(&mut b);
+        b.finish(orc, None);
+        b.finished_data().to_vec()
+    }
+    #[test]
+    fn new() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: Option<(&'static str, i16)>,
+        }
+        let tests = [
+            Test {
+                name: "valid monster",
+                data: orc(),
+                want: Some(("orc", 80)),
+            },
+            Test {
+                name: "empty buffer",
+                data: vec![],
+                want: None,
+            },
+            Test {
+                name: "truncated monster",
+                data: orc()[..8].to_vec(),
+                want: None,
+            },
+        ];
+        for t in &tests {
+            match (MonsterBuf::new(t.data.clone()), t.want) {
+                (Ok(got), Some((name, hp))) => {
+                    assert_eq!(Some(name), got.name(), "{}", t.name);
+                    assert_eq!(hp, got.hp(), "{}", t.name);
+                    assert_eq!(&t.data[..], got.as_ref(), "{}", t.name);
+                }
+                (Err(MonsterError::Invalid(_)), None) => {}
+                (got, _) => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[test]
+    fn from_pooled_builder() {
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let orc = Monster::builder().name("orc").hp(80).build(&mut b);
+        b.finish(orc, None);
+        let got = MonsterBuf::from_builder(b).unwrap();
+        assert_eq!(Some("orc"), got.name());
+        assert_eq!(80, got.hp());
+    }
+    #[test]
+    fn clone() {
+        let want = MonsterBuf::new(orc()).unwrap();
+        let got = want.clone();
+        drop(want);
+        assert_eq!(Some("orc"), got.name());
+        assert_eq!(1, got.view().weapons().count());
+    }
+    #[test]
+    fn send_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<MonsterBuf>();
+        let buf = MonsterBuf::new(orc()).unwrap();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let buf = buf.clone();
+                thread::spawn(move || buf.name().map(String::from))
+            })
+            .collect();
+        for h in handles {
+            assert_eq!(Some(String::from("orc")), h.join().unwrap());
+        }
+    }
+}
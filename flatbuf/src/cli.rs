@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Commands of the `monster-gen` binary.
+//!
+//! ```text
+//! monster-gen gen --name Orc [--hp 80] [--mana 150] [--color red]
+//!                 [--weapons Sword:3,Axe:5] [--equipped 1] -o orc.bin
+//! monster-gen dump orc.bin
+//! monster-gen verify orc.bin...
+//! monster-gen rand [--seed 42] [-n 10] -o dir/
+//! ```
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::CliError;
+use crate::monster::testing::MonsterGenerator;
+use crate::monster::{MonsterData, WeaponData};
+use crate::{Monster, MonsterBuf};
+
+pub const USAGE: &str = "\
+usage: monster-gen gen --name NAME [--hp HP] [--mana MANA] [--color COLOR]
+                       [--weapons NAME:DAMAGE,...] [--equipped INDEX] -o FILE
+       monster-gen dump FILE
+       monster-gen verify FILE...
+       monster-gen rand [--seed SEED] [-n COUNT] -o DIR";
+
+/// Parsed `monster-gen` command line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Build `data` and write it to `output`.
+    Gen { data: MonsterData, output: PathBuf },
+    /// Print the monster in the file as JSON.
+    Dump(PathBuf),
+    /// Verify the files.
+    Verify(Vec<PathBuf>),
+    /// Write `n` random monsters generated from `seed` under `output`.
+    Rand {
+        seed: u64,
+        n: usize,
+        output: PathBuf,
+    },
+}
+
+impl Command {
+    /// Parse the command line arguments, without the program name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::cli::Command;
+    /// use std::path::PathBuf;
+    ///
+    /// let got = Command::parse(vec!["dump", "orc.bin"]).unwrap();
+    /// assert_eq!(Command::Dump(PathBuf::from("orc.bin")), got);
+    /// assert!(Command::parse(vec!["dump"]).is_err());
+    /// ```
+    pub fn parse<I, S>(args: I) -> Result<Self, CliError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+        let command = args.next().ok_or_else(|| usage("missing command"))?;
+        let mut flags = Vec::new();
+        let mut operands = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg.starts_with('-') && arg.len() > 1 {
+                let value = args
+                    .next()
+                    .ok_or_else(|| usage(format!("missing value of {}", arg)))?;
+                flags.push((arg, value));
+            } else {
+                operands.push(PathBuf::from(arg));
+            }
+        }
+        let mut flags = Flags(flags);
+        let command = match command.as_str() {
+            "gen" => {
+                let mut data = MonsterData {
+                    name: flags.required("--name")?,
+                    ..Default::default()
+                };
+                data.hp = flags.parse("--hp")?.unwrap_or(data.hp);
+                data.mana = flags.parse("--mana")?.unwrap_or(data.mana);
+                data.color = flags.parse("--color")?.unwrap_or(data.color);
+                if let Some(weapons) = flags.take("--weapons") {
+                    data.weapons = parse_weapons(&weapons)?;
+                }
+                data.equipped = flags.parse("--equipped")?;
+                let output = flags.output()?;
+                no_operands(&operands)?;
+                Command::Gen { data, output }
+            }
+            "dump" => match operands.len() {
+                1 => Command::Dump(operands.remove(0)),
+                _ => return Err(usage("dump takes one file")),
+            },
+            "verify" if operands.is_empty() => return Err(usage("verify takes files")),
+            "verify" => Command::Verify(operands),
+            "rand" => {
+                let seed = flags.parse("--seed")?.unwrap_or(0);
+                let n = flags.parse("-n")?.unwrap_or(1);
+                let output = flags.output()?;
+                no_operands(&operands)?;
+                Command::Rand { seed, n, output }
+            }
+            _ => return Err(usage(format!("unknown command {:?}", command))),
+        };
+        flags.finish()?;
+        Ok(command)
+    }
+    /// Run the command, printing the report to `out`.
+    ///
+    /// `Verify` stops at the first invalid file.
+    pub fn run<W: Write>(&self, out: &mut W) -> Result<(), CliError> {
+        match self {
+            Command::Gen { data, output } => {
+                let buf = Monster::build_pooled(data);
+                write(output, buf.as_ref())?;
+                report(out, output, &buf)
+            }
+            Command::Dump(path) => {
+                let buf = read(path)?;
+                let data = MonsterData::from(buf.view());
+                serde_json::to_writer_pretty(&mut *out, &data)
+                    .map_err(|err| CliError::Io(path.clone(), err.into()))?;
+                writeln!(out).map_err(|err| CliError::Io(path.clone(), err))
+            }
+            Command::Verify(paths) => {
+                for path in paths {
+                    let buf = read(path)?;
+                    if let Err(errs) = buf.view().validate() {
+                        return Err(CliError::Invalid(path.clone(), errs[0].clone()));
+                    }
+                    writeln!(out, "{}: ok", path.display())
+                        .map_err(|err| CliError::Io(path.clone(), err))?;
+                }
+                Ok(())
+            }
+            Command::Rand { seed, n, output } => {
+                fs::create_dir_all(output).map_err(|err| CliError::Io(output.clone(), err))?;
+                let monsters = MonsterGenerator::new(*seed).take(*n);
+                for (i, data) in monsters.enumerate() {
+                    let buf = Monster::build_pooled(&data);
+                    let path = output.join(format!("monster-{:03}.bin", i));
+                    write(&path, buf.as_ref())?;
+                    report(out, &path, &buf)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Flags with values, which are taken out as they are parsed.
+struct Flags(Vec<(String, String)>);
+
+impl Flags {
+    fn take(&mut self, name: &str) -> Option<String> {
+        let i = self.0.iter().position(|(flag, _)| flag == name)?;
+        Some(self.0.remove(i).1)
+    }
+    fn required(&mut self, name: &str) -> Result<String, CliError> {
+        self.take(name)
+            .ok_or_else(|| usage(format!("missing {}", name)))
+    }
+    fn parse<T: FromStr>(&mut self, name: &str) -> Result<Option<T>, CliError> {
+        match self.take(name) {
+            None => Ok(None),
+            Some(value) => match value.parse() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) => Err(usage(format!("invalid {} {:?}", name, value))),
+            },
+        }
+    }
+    fn output(&mut self) -> Result<PathBuf, CliError> {
+        match self.take("-o") {
+            Some(output) => Ok(PathBuf::from(output)),
+            None => self.required("--output").map(PathBuf::from),
+        }
+    }
+    fn finish(self) -> Result<(), CliError> {
+        match self.0.first() {
+            None => Ok(()),
+            Some((flag, _)) => Err(usage(format!("unknown flag {}", flag))),
+        }
+    }
+}
+
+fn usage<S: Into<String>>(message: S) -> CliError {
+    CliError::Usage(message.into())
+}
+
+fn no_operands(operands: &[PathBuf]) -> Result<(), CliError> {
+    match operands.first() {
+        None => Ok(()),
+        Some(operand) => Err(usage(format!("unexpected {}", operand.display()))),
+    }
+}
+
+/// Parse `Sword:3,Axe:5` as the weapons.
+fn parse_weapons(s: &str) -> Result<Vec<WeaponData>, CliError> {
+    s.split(',')
+        .map(|weapon| {
+            let mut fields = weapon.splitn(2, ':');
+            match (fields.next(), fields.next().map(str::parse)) {
+                (Some(name), Some(Ok(damage))) if !name.is_empty() => Ok(WeaponData {
+                    name: name.to_string(),
+                    damage,
+                }),
+                _ => Err(usage(format!("invalid weapon {:?}", weapon))),
+            }
+        })
+        .collect()
+}
+
+fn read(path: &Path) -> Result<MonsterBuf, CliError> {
+    let buf = fs::read(path).map_err(|err| CliError::Io(path.to_path_buf(), err))?;
+    MonsterBuf::new(buf).map_err(|err| CliError::Invalid(path.to_path_buf(), err))
+}
+
+fn write(path: &Path, buf: &[u8]) -> Result<(), CliError> {
+    fs::write(path, buf).map_err(|err| CliError::Io(path.to_path_buf(), err))
+}
+
+fn report<W: Write>(out: &mut W, path: &Path, buf: &MonsterBuf) -> Result<(), CliError> {
+    writeln!(
+        out,
+        "{}: {:?} {} bytes",
+        path.display(),
+        buf.name().unwrap_or_default(),
+        buf.as_ref().len()
+    )
+    .map_err(|err| CliError::Io(path.to_path_buf(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        struct Test {
+            name: &'static str,
+            data: &'static [&'static str],
+            want: Option<Command>,
+        }
+        let tests = [
+            Test {
+                name: "gen",
+                data: &[
+                    "gen",
+                    "--name",
+                    "Orc",
+                    "--hp",
+                    "80",
+                    "--weapons",
+                    "Sword:3,Axe:5",
+                    "-o",
+                    "orc.bin",
+                ],
+                want: Some(Command::Gen {
+                    data: MonsterData {
+                        name: String::from("Orc"),
+                        hp: 80,
+                        weapons: vec![
+                            WeaponData {
+                                name: String::from("Sword"),
+                                damage: 3,
+                            },
+                            WeaponData {
+                                name: String::from("Axe"),
+                                damage: 5,
+                            },
+                        ],
+                        ..Default::default()
+                    },
+                    output: PathBuf::from("orc.bin"),
+                }),
+            },
+            Test {
+                name: "gen without name",
+                data: &["gen", "-o", "orc.bin"],
+                want: None,
+            },
+            Test {
+                name: "gen with malformed weapon",
+                data: &["gen", "--name", "Orc", "--weapons", "Sword", "-o", "o"],
+                want: None,
+            },
+            Test {
+                name: "gen with unknown flag",
+                data: &["gen", "--name", "Orc", "--level", "3", "-o", "o"],
+                want: None,
+            },
+            Test {
+                name: "verify",
+                data: &["verify", "a.bin", "b.bin"],
+                want: Some(Command::Verify(vec![
+                    PathBuf::from("a.bin"),
+                    PathBuf::from("b.bin"),
+                ])),
+            },
+            Test {
+                name: "verify without files",
+                data: &["verify"],
+                want: None,
+            },
+            Test {
+                name: "rand",
+                data: &["rand", "--seed", "42", "-n", "10", "-o", "dir/"],
+                want: Some(Command::Rand {
+                    seed: 42,
+                    n: 10,
+                    output: PathBuf::from("dir/"),
+                }),
+            },
+            Test {
+                name: "rand with negative count",
+                data: &["rand", "-n", "-1", "-o", "dir/"],
+                want: None,
+            },
+            Test {
+                name: "unknown command",
+                data: &["show", "orc.bin"],
+                want: None,
+            },
+            Test {
+                name: "no command",
+                data: &[],
+                want: None,
+            },
+        ];
+        for t in &tests {
+            match (Command::parse(t.data.iter().copied()), &t.want) {
+                (Ok(got), Some(want)) => assert_eq!(want, &got, "{}", t.name),
+                (Err(CliError::Usage(_)), None) => {}
+                (got, _) => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+}
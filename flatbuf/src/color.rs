@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Conversions of the generated `Color`.
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ColorError;
+use crate::model::my_game::sample::Color;
+
+impl Color {
+    /// All the known variants, in the schema order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::Color;
+    ///
+    /// let names: Vec<_> = Color::ALL.iter().map(Color::to_string).collect();
+    /// assert_eq!(vec!["Red", "Green", "Blue"], names);
+    /// ```
+    pub const ALL: [Color; 3] = [Color::Red, Color::Green, Color::Blue];
+}
+
+impl TryFrom<i8> for Color {
+    type Error = ColorError;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::Color;
+    /// use flatbuf_tutorial::ColorError;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Ok(Color::Green), Color::try_from(1));
+    /// assert_eq!(Err(ColorError::OutOfRange(3)), Color::try_from(3));
+    /// ```
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match Color(value) {
+            color if color.variant_name().is_some() => Ok(color),
+            _ => Err(ColorError::OutOfRange(value)),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    /// Parse the case-insensitive variant name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::Color;
+    ///
+    /// assert_eq!(Ok(Color::Red), "red".parse());
+    /// assert_eq!(Ok(Color::Blue), "BLUE".parse());
+    /// assert!("purple".parse::<Color>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::ALL
+            .iter()
+            .find(|color| {
+                color
+                    .variant_name()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(s))
+            })
+            .copied()
+            .ok_or_else(|| ColorError::UnknownName(s.to_string()))
+    }
+}
+
+/// Prints the canonical variant name, or the raw value for the unknown
+/// variant.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant_name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// Serialized as the canonical variant name.  The unknown variant fails
+/// to serialize.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self.variant_name() {
+            Some(name) => s.serialize_str(name),
+            None => Err(serde::ser::Error::custom(ColorError::OutOfRange(self.0))),
+        }
+    }
+}
+
+/// Deserialized from the case-insensitive variant name.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        // Report the error from the visitor to keep the error location
+        // in the parser.
+        struct ColorVisitor;
+
+        impl serde::de::Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("color name")
+            }
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Color, E> {
+                s.parse()
+                    .map_err(|_| E::unknown_variant(s, &["Red", "Green", "Blue"]))
+            }
+        }
+
+        d.deserialize_str(ColorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for &color in &Color::ALL {
+            assert_eq!(Ok(color), Color::try_from(color.0), "{:?}", color);
+            let name = color.to_string();
+            assert_eq!(Ok(color), name.parse(), "{}", name);
+            assert_eq!(Ok(color), name.to_lowercase().parse(), "{}", name);
+            assert_eq!(Ok(color), name.to_uppercase().parse(), "{}", name);
+        }
+    }
+    #[test]
+    fn try_from_out_of_range() {
+        for &value in &[-128, -1, 3, 127] {
+            assert_eq!(
+                Err(ColorError::OutOfRange(value)),
+                Color::try_from(value),
+                "{}",
+                value
+            );
+        }
+    }
+    #[test]
+    fn from_unknown_str() {
+        for &name in &["", "purple", "Re", "Red ", "0"] {
+            assert_eq!(
+                Err(ColorError::UnknownName(name.to_string())),
+                name.parse::<Color>(),
+                "{:?}",
+                name
+            );
+        }
+    }
+    #[test]
+    fn display_unknown() {
+        assert_eq!("7", Color(7).to_string());
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_case_insensitive() {
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::de::{Deserialize, IntoDeserializer};
+
+        for &color in &Color::ALL {
+            let name = color.to_string().to_lowercase();
+            let d: StrDeserializer<'_, Error> = name.as_str().into_deserializer();
+            assert_eq!(Ok(color), Color::deserialize(d), "{}", name);
+        }
+        let d: StrDeserializer<'_, Error> = "purple".into_deserializer();
+        assert!(Color::deserialize(d).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Base64 and hex encodings of the `Monster` buffer, to carry it in the
+//! JSON envelopes and the log lines.
+use std::fmt;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::error::EncodingError;
+use crate::MonsterBuf;
+
+impl MonsterBuf {
+    /// Returns the buffer in the standard, padded base64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::{Monster, MonsterBuf};
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_pooled(&data);
+    /// let got = MonsterBuf::from_base64(&orc.to_base64()).unwrap();
+    /// assert_eq!(orc, got);
+    /// ```
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self)
+    }
+    /// Decode the standard, padded base64 and verify the buffer.
+    pub fn from_base64(s: &str) -> Result<Self, EncodingError> {
+        Ok(Self::new(STANDARD.decode(s)?)?)
+    }
+    /// Returns the buffer in the lowercase hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::{Monster, MonsterBuf};
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_pooled(&data);
+    /// let got = MonsterBuf::from_hex(&orc.to_hex().to_uppercase()).unwrap();
+    /// assert_eq!(orc, got);
+    /// ```
+    pub fn to_hex(&self) -> String {
+        hex::encode(self)
+    }
+    /// Decode the case-insensitive hex and verify the buffer.
+    pub fn from_hex(s: &str) -> Result<Self, EncodingError> {
+        Ok(Self::new(hex::decode(s)?)?)
+    }
+    /// Returns the hex preview of the first and the last `n` bytes, with
+    /// the buffer length, for the logs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::Monster;
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_pooled(&data);
+    /// assert_eq!(
+    ///     "10000000..6f726300 (32 bytes)",
+    ///     orc.fmt_hex_preview(4).to_string(),
+    /// );
+    /// ```
+    pub fn fmt_hex_preview(&self, n: usize) -> HexPreview<'_> {
+        HexPreview {
+            buf: self.as_ref(),
+            n,
+        }
+    }
+}
+
+/// Hex preview returned by [`MonsterBuf::fmt_hex_preview`].
+///
+/// [`monsterbuf::fmt_hex_preview`]: ../buf/struct.MonsterBuf.html#method.fmt_hex_preview
+#[derive(Debug, Clone, Copy)]
+pub struct HexPreview<'a> {
+    buf: &'a [u8],
+    n: usize,
+}
+
+impl fmt::Display for HexPreview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_hex = |f: &mut fmt::Formatter<'_>, bytes: &[u8]| {
+            bytes.iter().try_for_each(|b| write!(f, "{:02x}", b))
+        };
+        let len = self.buf.len();
+        if len <= self.n.saturating_mul(2) {
+            write_hex(f, self.buf)?;
+        } else {
+            write_hex(f, &self.buf[..self.n])?;
+            f.write_str("..")?;
+            write_hex(f, &self.buf[len - self.n..])?;
+        }
+        write!(f, " ({} bytes)", len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::{MonsterData, WeaponData};
+    use crate::{Monster, MonsterError};
+
+    fn orc() -> MonsterBuf {
+        Monster::build_pooled(&MonsterData {
+            name: String::from("orc"),
+            hp: 80,
+            weapons: vec![WeaponData {
+                name: String::from("axe"),
+                damage: 5,
+            }],
+            equipped: Some(0),
+            ..Default::default()
+        })
+    }
+    #[test]
+    fn round_trip() {
+        let orc = orc();
+        assert_eq!(orc, MonsterBuf::from_base64(&orc.to_base64()).unwrap());
+        assert_eq!(orc, MonsterBuf::from_hex(&orc.to_hex()).unwrap());
+        assert_eq!(orc.as_ref().len() * 2, orc.to_hex().len());
+        assert_eq!(0, orc.to_base64().len() % 4);
+    }
+    #[test]
+    fn from_base64_errors() {
+        use base64::DecodeError;
+
+        let orc = orc().to_base64();
+        // Flip the table offset at the head of the buffer.
+        let mut corrupted = STANDARD.decode(&orc).unwrap();
+        corrupted[0] = 0xff;
+        let corrupted = STANDARD.encode(corrupted);
+        struct Test {
+            name: &'static str,
+            data: String,
+            want: fn(&EncodingError) -> bool,
+        }
+        let tests = [
+            Test {
+                name: "missing padding",
+                data: String::from("AAA"),
+                want: |err| matches!(err, EncodingError::Base64(DecodeError::InvalidPadding)),
+            },
+            Test {
+                name: "misplaced padding",
+                data: String::from("AA==AAAA"),
+                want: |err| {
+                    matches!(
+                        err,
+                        EncodingError::Base64(DecodeError::InvalidByte(2, b'='))
+                    )
+                },
+            },
+            Test {
+                name: "bad character",
+                data: format!("*{}", &orc[1..]),
+                want: |err| {
+                    matches!(
+                        err,
+                        EncodingError::Base64(DecodeError::InvalidByte(0, b'*'))
+                    )
+                },
+            },
+            Test {
+                name: "corrupted payload",
+                data: corrupted,
+                want: |err| matches!(err, EncodingError::Invalid(MonsterError::Invalid(_))),
+            },
+            Test {
+                name: "empty",
+                data: String::new(),
+                want: |err| matches!(err, EncodingError::Invalid(MonsterError::Invalid(_))),
+            },
+        ];
+        for t in &tests {
+            match MonsterBuf::from_base64(&t.data) {
+                Err(err) => assert!((t.want)(&err), "{}: {:?}", t.name, err),
+                Ok(got) => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[test]
+    fn from_hex_errors() {
+        use hex::FromHexError;
+
+        let orc = orc().to_hex();
+        struct Test {
+            name: &'static str,
+            data: String,
+            want: fn(&EncodingError) -> bool,
+        }
+        let tests = [
+            Test {
+                name: "odd length",
+                data: orc[1..].to_string(),
+                want: |err| matches!(err, EncodingError::Hex(FromHexError::OddLength)),
+            },
+            Test {
+                name: "bad character",
+                data: format!("g{}", &orc[1..]),
+                want: |err| {
+                    matches!(
+                        err,
+                        EncodingError::Hex(FromHexError::InvalidHexCharacter { c: 'g', index: 0 })
+                    )
+                },
+            },
+            Test {
+                name: "corrupted payload",
+                data: format!("ff{}", &orc[2..]),
+                want: |err| matches!(err, EncodingError::Invalid(MonsterError::Invalid(_))),
+            },
+        ];
+        for t in &tests {
+            match MonsterBuf::from_hex(&t.data) {
+                Err(err) => assert!((t.want)(&err), "{}: {:?}", t.name, err),
+                Ok(got) => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[test]
+    fn fmt_hex_preview() {
+        let orc = orc();
+        let hex = orc.to_hex();
+        let len = orc.as_ref().len();
+        struct Test {
+            name: &'static str,
+            data: usize,
+            want: String,
+        }
+        let tests = [
+            Test {
+                name: "head and tail",
+                data: 2,
+                want: format!("{}..{} ({} bytes)", &hex[..4], &hex[hex.len() - 4..], len),
+            },
+            Test {
+                name: "whole buffer",
+                data: len / 2,
+                want: format!("{} ({} bytes)", hex, len),
+            },
+            Test {
+                name: "larger than buffer",
+                data: usize::MAX,
+                want: format!("{} ({} bytes)", hex, len),
+            },
+            Test {
+                name: "length only",
+                data: 0,
+                want: format!(".. ({} bytes)", len),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(
+                t.want,
+                orc.fmt_hex_preview(t.data).to_string(),
+                "{}",
+                t.name
+            );
+        }
+    }
+}
@@ -0,0 +1,752 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Error type of the flatbuf-tutorial crate.
+#[cfg(any(
+    feature = "yaml",
+    feature = "toml",
+    feature = "cli",
+    feature = "mmap",
+    feature = "async",
+    feature = "stream"
+))]
+use std::io;
+#[cfg(any(feature = "yaml", feature = "toml", feature = "cli"))]
+use std::path::PathBuf;
+use std::{error, fmt};
+
+use flatbuffers::InvalidFlatbuffer;
+
+use crate::monster::validate::RuleViolation;
+
+/// Error returned by [`MonsterView`] for the malformed `Monster` buffer.
+///
+/// [`monsterview`]: ../view/struct.MonsterView.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum MonsterError {
+    /// Buffer failed the flatbuffers verifier.
+    Invalid(InvalidFlatbuffer),
+    /// Required `name` field is absent.
+    MissingName,
+    /// `equipped_type` is not the known `Equipment` variant.
+    UnknownEquipment(u8),
+    /// Equipped weapon is not one of the `weapons`.
+    EquippedNotInWeapons,
+    /// `hp` is negative.
+    NegativeHp(i16),
+    /// `path` point at the index has the NaN or infinite coordinate.
+    NonFinitePath(usize),
+}
+
+impl fmt::Display for MonsterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonsterError::Invalid(err) => write!(f, "invalid monster buffer: {}", err),
+            MonsterError::MissingName => write!(f, "missing monster name"),
+            MonsterError::UnknownEquipment(kind) => write!(f, "unknown equipment type {}", kind),
+            MonsterError::EquippedNotInWeapons => write!(f, "equipped weapon not in weapons"),
+            MonsterError::NegativeHp(hp) => write!(f, "negative hp {}", hp),
+            MonsterError::NonFinitePath(i) => write!(f, "non-finite path[{}]", i),
+        }
+    }
+}
+
+impl error::Error for MonsterError {}
+
+impl From<InvalidFlatbuffer> for MonsterError {
+    fn from(err: InvalidFlatbuffer) -> Self {
+        MonsterError::Invalid(err)
+    }
+}
+
+/// Error returned by [`Monster::from_bytes_validated`].
+///
+/// [`monster::from_bytes_validated`]: ../monster/struct.Monster.html#method.from_bytes_validated
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// Buffer failed the flatbuffers verifier.
+    Invalid(MonsterError),
+    /// Monster violates the business rules.
+    Rules(Vec<RuleViolation>),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Invalid(err) => write!(f, "{}", err),
+            ValidationError::Rules(violations) => {
+                write!(f, "monster rule violations: ")?;
+                for (i, violation) in violations.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", violation)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ValidationError::Invalid(err) => Some(err),
+            ValidationError::Rules(_) => None,
+        }
+    }
+}
+
+impl From<MonsterError> for ValidationError {
+    fn from(err: MonsterError) -> Self {
+        ValidationError::Invalid(err)
+    }
+}
+
+/// Error returned by the `Color` conversions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    /// Value is not the known `Color` variant.
+    OutOfRange(i8),
+    /// Name is not the known `Color` variant.
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::OutOfRange(value) => write!(f, "color value {} out of range", value),
+            ColorError::UnknownName(name) => write!(f, "unknown color name {:?}", name),
+        }
+    }
+}
+
+impl error::Error for ColorError {}
+
+/// Error returned by [`FlatBufferBuilderPool::try_get`] when the
+/// global pool is empty, and the hard limit of the outstanding builders
+/// is reached.
+///
+/// [`flatbufferbuilderpool::try_get`]: ../pool/v3/struct.FlatBufferBuilderPool.html#method.try_get
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolExhausted {
+    /// Hard limit of the outstanding global builders.
+    pub hard_limit: usize,
+}
+
+impl fmt::Display for PoolExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "global pool exhausted at {} outstanding builders",
+            self.hard_limit
+        )
+    }
+}
+
+impl error::Error for PoolExhausted {}
+
+/// Error returned by the `Item` conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemError {
+    /// Id is not in the registered item table.
+    Unregistered(u8),
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemError::Unregistered(id) => write!(f, "unregistered item id {}", id),
+        }
+    }
+}
+
+impl error::Error for ItemError {}
+
+/// Error returned by the [`MonsterEditor`] edits.
+///
+/// [`monstereditor`]: ../monster/struct.MonsterEditor.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditError {
+    /// No weapon has the name.
+    UnknownWeapon(String),
+    /// Weapon index is out of the `len` weapons.
+    WeaponOutOfRange { index: usize, len: usize },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EditError::UnknownWeapon(name) => write!(f, "unknown weapon {:?}", name),
+            EditError::WeaponOutOfRange { index, len } => {
+                write!(f, "weapon index {} out of {} weapons", index, len)
+            }
+        }
+    }
+}
+
+impl error::Error for EditError {}
+
+/// Error returned by [`monster::parse_size_prefixed`].
+///
+/// [`monster::parse_size_prefixed`]: ../monster/fn.parse_size_prefixed.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum SizePrefixedError {
+    /// Buffer is shorter than the size prefix, or than the `want` bytes
+    /// of the prefixed frame.
+    TooShort { len: usize, want: usize },
+    /// Frame failed the flatbuffers verifier.
+    Invalid(MonsterError),
+}
+
+impl fmt::Display for SizePrefixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SizePrefixedError::TooShort { len, want } => {
+                write!(
+                    f,
+                    "size prefixed buffer too short: {} < {} bytes",
+                    len, want
+                )
+            }
+            SizePrefixedError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for SizePrefixedError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            SizePrefixedError::TooShort { .. } => None,
+            SizePrefixedError::Invalid(err) => Some(err),
+        }
+    }
+}
+
+impl From<MonsterError> for SizePrefixedError {
+    fn from(err: MonsterError) -> Self {
+        SizePrefixedError::Invalid(err)
+    }
+}
+
+/// Error returned by the [`monster::collection`] chunking and reassembly.
+///
+/// [`monster::collection`]: ../monster/collection/index.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChunkError {
+    /// Monster at `index` takes up to `size` bytes, which doesn't fit the
+    /// `max` bytes chunk on its own.
+    TooLarge {
+        index: usize,
+        size: usize,
+        max: usize,
+    },
+    /// Chunk failed the flatbuffers verifier.
+    Invalid(MonsterError),
+    /// Chunk of `index` is absent.
+    Missing { index: u32 },
+    /// More than one chunk of `index`.
+    Duplicate { index: u32 },
+    /// Chunk of `index` is out of the `total` chunks.
+    OutOfRange { index: u32, total: u32 },
+    /// Chunk claims `total` chunks, while the first one claims `want`.
+    TotalMismatch { total: u32, want: u32 },
+}
+
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkError::TooLarge { index, size, max } => write!(
+                f,
+                "monster {} too large for the chunk: {} > {} bytes",
+                index, size, max
+            ),
+            ChunkError::Invalid(err) => write!(f, "{}", err),
+            ChunkError::Missing { index } => write!(f, "missing chunk {}", index),
+            ChunkError::Duplicate { index } => write!(f, "duplicate chunk {}", index),
+            ChunkError::OutOfRange { index, total } => {
+                write!(f, "chunk {} out of {} chunks", index, total)
+            }
+            ChunkError::TotalMismatch { total, want } => {
+                write!(f, "total chunks mismatch: {} != {}", total, want)
+            }
+        }
+    }
+}
+
+impl error::Error for ChunkError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ChunkError::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<MonsterError> for ChunkError {
+    fn from(err: MonsterError) -> Self {
+        ChunkError::Invalid(err)
+    }
+}
+
+/// Error returned by the [`monster::fixture`] loaders.
+///
+/// [`monster::fixture`]: ../monster/fixture/index.html
+#[cfg(any(feature = "yaml", feature = "toml"))]
+#[derive(Debug)]
+pub enum FixtureError {
+    Io(io::Error),
+    /// Fixture is not the valid `MonsterData`, with the one-based
+    /// location reported by the parser, if any.
+    Parse {
+        location: Option<Location>,
+        message: String,
+    },
+    /// Fixture is parsed but the built buffer is not the valid monster.
+    Invalid(MonsterError),
+    /// Error found in the fixture file.
+    File(PathBuf, Box<FixtureError>),
+}
+
+/// One-based line and column in the fixture.
+#[cfg(any(feature = "yaml", feature = "toml"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl FixtureError {
+    /// Returns the location of the parse error, if any.
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            FixtureError::Parse { location, .. } => *location,
+            FixtureError::File(_, err) => err.location(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::Io(err) => write!(f, "fixture I/O error: {}", err),
+            FixtureError::Parse {
+                location: Some(loc),
+                message,
+            } => write!(f, "{}:{}: {}", loc.line, loc.column, message),
+            FixtureError::Parse {
+                location: None,
+                message,
+            } => write!(f, "{}", message),
+            FixtureError::Invalid(err) => write!(f, "{}", err),
+            FixtureError::File(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FixtureError::Io(err) => Some(err),
+            FixtureError::Invalid(err) => Some(err),
+            FixtureError::File(_, err) => Some(err.as_ref()),
+            FixtureError::Parse { .. } => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl From<io::Error> for FixtureError {
+    fn from(err: io::Error) -> Self {
+        FixtureError::Io(err)
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml"))]
+impl From<MonsterError> for FixtureError {
+    fn from(err: MonsterError) -> Self {
+        FixtureError::Invalid(err)
+    }
+}
+
+/// Error returned by the [`cli`] commands.
+///
+/// [`cli`]: ../cli/index.html
+#[cfg(feature = "cli")]
+#[derive(Debug)]
+pub enum CliError {
+    /// Command line is malformed.
+    Usage(String),
+    /// I/O error on the file.
+    Io(PathBuf, io::Error),
+    /// File is not the valid monster.
+    Invalid(PathBuf, MonsterError),
+}
+
+#[cfg(feature = "cli")]
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            CliError::Invalid(path, err) => write!(f, "{}: {}", path.display(), err),
+        }
+    }
+}
+
+#[cfg(feature = "cli")]
+impl error::Error for CliError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CliError::Usage(_) => None,
+            CliError::Io(_, err) => Some(err),
+            CliError::Invalid(_, err) => Some(err),
+        }
+    }
+}
+
+/// Error returned by the [`mmap`] readers.
+///
+/// [`mmap`]: ../mmap/index.html
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub enum MmapError {
+    Io(io::Error),
+    /// File is shorter than the smallest root table.
+    TooShort(u64),
+    /// Mapped bytes failed the flatbuffers verifier.
+    Invalid(MonsterError),
+}
+
+#[cfg(feature = "mmap")]
+impl fmt::Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MmapError::Io(err) => write!(f, "mmap I/O error: {}", err),
+            MmapError::TooShort(len) => {
+                write!(f, "file too short for the root table: {} bytes", len)
+            }
+            MmapError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl error::Error for MmapError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MmapError::Io(err) => Some(err),
+            MmapError::Invalid(err) => Some(err),
+            MmapError::TooShort(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl From<io::Error> for MmapError {
+    fn from(err: io::Error) -> Self {
+        MmapError::Io(err)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl From<MonsterError> for MmapError {
+    fn from(err: MonsterError) -> Self {
+        MmapError::Invalid(err)
+    }
+}
+
+/// Error returned by the [`reflection`] queries.
+///
+/// [`reflection`]: ../reflection/index.html
+#[cfg(feature = "reflection")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReflectError {
+    /// Embedded schema failed the flatbuffers verifier.
+    InvalidSchema(InvalidFlatbuffer),
+    /// Embedded schema is out of date with the generated code.
+    SchemaMismatch(String),
+    /// Path is not the `.` separated `name` or `name[index]`.
+    InvalidPath(String),
+    /// Table at the path has no such field.
+    UnknownField(String),
+    /// Field at the path is not the table, hence has no fields.
+    NotATable(String),
+    /// Field at the path is not the vector, hence has no index.
+    NotAVector(String),
+    /// Field at the path is absent, hence has no fields.
+    Absent(String),
+    IndexOutOfRange {
+        path: String,
+        index: usize,
+        len: usize,
+    },
+}
+
+#[cfg(feature = "reflection")]
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectError::InvalidSchema(err) => write!(f, "invalid embedded schema: {}", err),
+            ReflectError::SchemaMismatch(what) => {
+                write!(f, "embedded schema mismatch with generated code: {}", what)
+            }
+            ReflectError::InvalidPath(path) => write!(f, "invalid field path: {:?}", path),
+            ReflectError::UnknownField(path) => write!(f, "unknown field: {}", path),
+            ReflectError::NotATable(path) => write!(f, "not a table: {}", path),
+            ReflectError::NotAVector(path) => write!(f, "not a vector: {}", path),
+            ReflectError::Absent(path) => write!(f, "absent field: {}", path),
+            ReflectError::IndexOutOfRange { path, index, len } => {
+                write!(f, "index out of range: {}: {} >= {}", path, index, len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "reflection")]
+impl error::Error for ReflectError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ReflectError::InvalidSchema(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned by the [`aio`] readers.
+///
+/// [`aio`]: ../monster/aio/index.html
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub enum AioError {
+    Io(io::Error),
+    /// Stream ended in the middle of the frame.
+    Truncated {
+        want: usize,
+        got: usize,
+    },
+    /// Frame length prefix is over the reader's limit.
+    FrameTooLarge(usize),
+    /// Frame failed the flatbuffers verifier.
+    Invalid(MonsterError),
+}
+
+#[cfg(feature = "async")]
+impl fmt::Display for AioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AioError::Io(err) => write!(f, "async I/O error: {}", err),
+            AioError::Truncated { want, got } => {
+                write!(f, "truncated frame: {} of {} bytes", got, want)
+            }
+            AioError::FrameTooLarge(len) => write!(f, "frame too large: {} bytes", len),
+            AioError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl error::Error for AioError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            AioError::Io(err) => Some(err),
+            AioError::Invalid(err) => Some(err),
+            AioError::Truncated { .. } | AioError::FrameTooLarge(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<io::Error> for AioError {
+    fn from(err: io::Error) -> Self {
+        AioError::Io(err)
+    }
+}
+
+#[cfg(feature = "async")]
+impl From<MonsterError> for AioError {
+    fn from(err: MonsterError) -> Self {
+        AioError::Invalid(err)
+    }
+}
+
+/// Error returned by [`monster::integrity::unseal`].
+///
+/// [`monster::integrity::unseal`]: ../monster/integrity/fn.unseal.html
+#[cfg(feature = "integrity")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntegrityError {
+    /// Buffer is shorter than the footer.
+    TooShort(usize),
+    /// Footer doesn't end with the magic, e.g. the buffer is not sealed.
+    BadMagic([u8; 4]),
+    /// Footer CRC32C doesn't match the buffer.
+    BadCrc { want: u32, got: u32 },
+    /// Buffer failed the flatbuffers verifier.
+    Invalid(MonsterError),
+}
+
+#[cfg(feature = "integrity")]
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::TooShort(len) => write!(f, "sealed buffer too short: {} bytes", len),
+            IntegrityError::BadMagic(magic) => write!(f, "bad seal magic: {:02x?}", magic),
+            IntegrityError::BadCrc { want, got } => {
+                write!(f, "seal checksum mismatch: {:#010x} != {:#010x}", got, want)
+            }
+            IntegrityError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "integrity")]
+impl error::Error for IntegrityError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            IntegrityError::Invalid(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "integrity")]
+impl From<MonsterError> for IntegrityError {
+    fn from(err: MonsterError) -> Self {
+        IntegrityError::Invalid(err)
+    }
+}
+
+/// Error returned by the [`stream`] readers.
+///
+/// [`stream`]: ../monster/stream/index.html
+#[cfg(feature = "stream")]
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// Log ended in the middle of the record at `offset`, e.g. the torn
+    /// write of the crash.
+    Truncated {
+        offset: u64,
+    },
+    /// Record length prefix is over the reader's limit.
+    RecordTooLarge {
+        offset: u64,
+        len: usize,
+    },
+    /// Record checksum doesn't match its payload.
+    Crc {
+        offset: u64,
+        want: u32,
+        got: u32,
+    },
+    /// Record failed the flatbuffers verifier.
+    Invalid {
+        offset: u64,
+        err: MonsterError,
+    },
+    /// Record is not sealed, in the log written with the integrity
+    /// footers.
+    Unsealed {
+        offset: u64,
+        err: IntegrityError,
+    },
+}
+
+#[cfg(feature = "stream")]
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "stream I/O error: {}", err),
+            StreamError::Truncated { offset } => write!(f, "truncated record at {}", offset),
+            StreamError::RecordTooLarge { offset, len } => {
+                write!(f, "record too large at {}: {} bytes", offset, len)
+            }
+            StreamError::Crc { offset, want, got } => write!(
+                f,
+                "record checksum mismatch at {}: {:#010x} != {:#010x}",
+                offset, got, want
+            ),
+            StreamError::Invalid { offset, err } => write!(f, "record at {}: {}", offset, err),
+            StreamError::Unsealed { offset, err } => write!(f, "record at {}: {}", offset, err),
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Invalid { err, .. } => Some(err),
+            StreamError::Unsealed { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "stream")]
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Error returned by the [`encoding`] decoders.
+///
+/// [`encoding`]: ../encoding/index.html
+#[cfg(feature = "encoding")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EncodingError {
+    /// Input is not the valid base64.
+    Base64(base64::DecodeError),
+    /// Input is not the valid hex.
+    Hex(hex::FromHexError),
+    /// Decoded buffer is not the valid monster.
+    Invalid(MonsterError),
+}
+
+#[cfg(feature = "encoding")]
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingError::Base64(err) => write!(f, "invalid base64: {}", err),
+            EncodingError::Hex(err) => write!(f, "invalid hex: {}", err),
+            EncodingError::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl error::Error for EncodingError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            EncodingError::Base64(err) => Some(err),
+            EncodingError::Hex(err) => Some(err),
+            EncodingError::Invalid(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl From<base64::DecodeError> for EncodingError {
+    fn from(err: base64::DecodeError) -> Self {
+        EncodingError::Base64(err)
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl From<hex::FromHexError> for EncodingError {
+    fn from(err: hex::FromHexError) -> Self {
+        EncodingError::Hex(err)
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl From<MonsterError> for EncodingError {
+    fn from(err: MonsterError) -> Self {
+        EncodingError::Invalid(err)
+    }
+}
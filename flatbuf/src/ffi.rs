@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: GPL-2.0
+//! C API to build and read the `Monster` buffers, declared in
+//! `include/flatbuf_tutorial.h`.
+//!
+//! Cargo can't select the crate type by the feature, so build the shared
+//! library with:
+//!
+//! ```sh
+//! $ cargo rustc --lib --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! Every function catches the panic, and returns [`MONSTER_OK`] or one of
+//! the negative error codes.
+//!
+//! [`monster_ok`]: constant.MONSTER_OK.html
+use std::panic::{self, UnwindSafe};
+use std::{ptr, slice};
+
+use crate::monster::MonsterData;
+use crate::{Monster, MonsterView};
+
+pub const MONSTER_OK: i32 = 0;
+/// Null pointer argument.
+pub const MONSTER_ERR_NULL: i32 = -1;
+/// Malformed `MonsterData` JSON.
+pub const MONSTER_ERR_JSON: i32 = -2;
+/// Invalid `Monster` buffer.
+pub const MONSTER_ERR_INVALID: i32 = -3;
+/// Panic caught at the boundary.
+pub const MONSTER_ERR_PANIC: i32 = -4;
+
+/// Run `f`, turning the panic into [`MONSTER_ERR_PANIC`].
+///
+/// [`monster_err_panic`]: constant.MONSTER_ERR_PANIC.html
+fn catch<F: FnOnce() -> i32 + UnwindSafe>(f: F) -> i32 {
+    panic::catch_unwind(f).unwrap_or(MONSTER_ERR_PANIC)
+}
+
+/// Returns the `len` bytes at `ptr`, or `None` for the null `ptr`.
+///
+/// # Safety
+///
+/// The non-null `ptr` must be valid for the `len` bytes reads.
+unsafe fn bytes<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(slice::from_raw_parts(ptr, len))
+    }
+}
+
+/// Build the `Monster` buffer from the [`MonsterData`] JSON, and store it
+/// in `*out_buf` and `*out_len`.  The buffer must be released with
+/// [`monster_free`].
+///
+/// # Safety
+///
+/// `json` must be valid for the `json_len` bytes reads, and `out_buf` and
+/// `out_len` for the writes.
+///
+/// [`monsterdata`]: ../monster/struct.MonsterData.html
+/// [`monster_free`]: fn.monster_free.html
+#[no_mangle]
+pub unsafe extern "C" fn monster_build_json(
+    json: *const u8,
+    json_len: usize,
+    out_buf: *mut *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    catch(|| {
+        let json = match bytes(json, json_len) {
+            Some(json) if !out_buf.is_null() && !out_len.is_null() => json,
+            _ => return MONSTER_ERR_NULL,
+        };
+        let data: MonsterData = match serde_json::from_slice(json) {
+            Ok(data) => data,
+            Err(_) => return MONSTER_ERR_JSON,
+        };
+        let buf = Monster::build_pooled(&data).into_inner().into_boxed_slice();
+        *out_len = buf.len();
+        *out_buf = Box::into_raw(buf).cast::<u8>();
+        MONSTER_OK
+    })
+}
+
+/// Verify the `Monster` buffer.
+///
+/// # Safety
+///
+/// `buf` must be valid for the `len` bytes reads.
+#[no_mangle]
+pub unsafe extern "C" fn monster_verify(buf: *const u8, len: usize) -> i32 {
+    catch(|| match bytes(buf, len).map(MonsterView::from_bytes) {
+        None => MONSTER_ERR_NULL,
+        Some(Ok(_)) => MONSTER_OK,
+        Some(Err(_)) => MONSTER_ERR_INVALID,
+    })
+}
+
+/// Verify the `Monster` buffer and store its `hp` in `*out_hp`.
+///
+/// # Safety
+///
+/// `buf` must be valid for the `len` bytes reads, and `out_hp` for the
+/// write.
+#[no_mangle]
+pub unsafe extern "C" fn monster_get_hp(buf: *const u8, len: usize, out_hp: *mut i16) -> i32 {
+    catch(|| {
+        let buf = match bytes(buf, len) {
+            Some(buf) if !out_hp.is_null() => buf,
+            _ => return MONSTER_ERR_NULL,
+        };
+        match MonsterView::from_bytes(buf) {
+            Ok(monster) => {
+                *out_hp = monster.hp();
+                MONSTER_OK
+            }
+            Err(_) => MONSTER_ERR_INVALID,
+        }
+    })
+}
+
+/// Release the buffer returned by [`monster_build_json`].  The null `buf`
+/// is ignored.
+///
+/// # Safety
+///
+/// `buf` and `len` must be the ones returned by `monster_build_json`,
+/// and not released yet.
+///
+/// [`monster_build_json`]: fn.monster_build_json.html
+#[no_mangle]
+pub unsafe extern "C" fn monster_free(buf: *mut u8, len: usize) {
+    if !buf.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buf, len)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Called through the C ABI signatures of the header.
+    const BUILD_JSON: unsafe extern "C" fn(*const u8, usize, *mut *mut u8, *mut usize) -> i32 =
+        monster_build_json;
+    const VERIFY: unsafe extern "C" fn(*const u8, usize) -> i32 = monster_verify;
+    const GET_HP: unsafe extern "C" fn(*const u8, usize, *mut i16) -> i32 = monster_get_hp;
+    const FREE: unsafe extern "C" fn(*mut u8, usize) = monster_free;
+
+    /// Build the buffer from `json`, and returns the error code with the
+    /// copy of the buffer.
+    fn build(json: &[u8]) -> (i32, Vec<u8>) {
+        let (mut buf, mut len) = (ptr::null_mut(), 0);
+        let code = unsafe { BUILD_JSON(json.as_ptr(), json.len(), &mut buf, &mut len) };
+        let copy = unsafe { bytes(buf, len) }.unwrap_or_default().to_vec();
+        unsafe { FREE(buf, len) };
+        (code, copy)
+    }
+    #[test]
+    fn build_json() {
+        struct Test {
+            name: &'static str,
+            data: &'static [u8],
+            want: i32,
+        }
+        let tests = [
+            Test {
+                name: "orc",
+                data: br#"{"name": "orc", "hp": 80}"#,
+                want: MONSTER_OK,
+            },
+            Test {
+                name: "defaults",
+                data: b"{}",
+                want: MONSTER_OK,
+            },
+            Test {
+                // Not null-terminated, and cut in the middle.
+                name: "truncated",
+                data: &br#"{"name": "orc", "hp": 80}"#[..12],
+                want: MONSTER_ERR_JSON,
+            },
+            Test {
+                name: "unknown field",
+                data: br#"{"mp": 80}"#,
+                want: MONSTER_ERR_JSON,
+            },
+            Test {
+                name: "empty",
+                data: b"",
+                want: MONSTER_ERR_JSON,
+            },
+        ];
+        for t in &tests {
+            let (code, buf) = build(t.data);
+            assert_eq!(t.want, code, "{}", t.name);
+            assert_eq!(t.want == MONSTER_OK, !buf.is_empty(), "{}", t.name);
+            if code == MONSTER_OK {
+                assert_eq!(
+                    MONSTER_OK,
+                    unsafe { VERIFY(buf.as_ptr(), buf.len()) },
+                    "{}",
+                    t.name
+                );
+            }
+        }
+    }
+    #[test]
+    fn get_hp() {
+        let (_, buf) = build(br#"{"name": "orc", "hp": 80}"#);
+        struct Test {
+            name: &'static str,
+            data: &'static [u8],
+            want: (i32, i16),
+        }
+        let buf: &'static [u8] = Box::leak(buf.into_boxed_slice());
+        let tests = [
+            Test {
+                name: "orc",
+                data: buf,
+                want: (MONSTER_OK, 80),
+            },
+            Test {
+                name: "short length",
+                data: &buf[..buf.len() / 2],
+                want: (MONSTER_ERR_INVALID, -1),
+            },
+            Test {
+                name: "empty",
+                data: &[],
+                want: (MONSTER_ERR_INVALID, -1),
+            },
+        ];
+        for t in &tests {
+            let mut hp = -1;
+            let code = unsafe { GET_HP(t.data.as_ptr(), t.data.len(), &mut hp) };
+            assert_eq!(t.want, (code, hp), "{}", t.name);
+            let want = t.want.0;
+            assert_eq!(
+                want,
+                unsafe { VERIFY(t.data.as_ptr(), t.data.len()) },
+                "{}",
+                t.name
+            );
+        }
+    }
+    #[test]
+    fn null() {
+        let json = b"{}";
+        let (mut buf, mut len, mut hp) = (ptr::null_mut(), 0, 0);
+        unsafe {
+            assert_eq!(
+                MONSTER_ERR_NULL,
+                BUILD_JSON(ptr::null(), 0, &mut buf, &mut len)
+            );
+            assert_eq!(
+                MONSTER_ERR_NULL,
+                BUILD_JSON(json.as_ptr(), json.len(), ptr::null_mut(), &mut len)
+            );
+            assert_eq!(
+                MONSTER_ERR_NULL,
+                BUILD_JSON(json.as_ptr(), json.len(), &mut buf, ptr::null_mut())
+            );
+            assert_eq!(MONSTER_ERR_NULL, VERIFY(ptr::null(), 8));
+            assert_eq!(MONSTER_ERR_NULL, GET_HP(ptr::null(), 8, &mut hp));
+            assert_eq!(
+                MONSTER_ERR_NULL,
+                GET_HP(json.as_ptr(), json.len(), ptr::null_mut())
+            );
+            FREE(ptr::null_mut(), 0);
+        }
+        assert!(buf.is_null());
+    }
+    #[test]
+    fn header() {
+        let header = include_str!("../include/flatbuf_tutorial.h");
+        for (name, value) in &[
+            ("MONSTER_OK", MONSTER_OK),
+            ("MONSTER_ERR_NULL", MONSTER_ERR_NULL),
+            ("MONSTER_ERR_JSON", MONSTER_ERR_JSON),
+            ("MONSTER_ERR_INVALID", MONSTER_ERR_INVALID),
+            ("MONSTER_ERR_PANIC", MONSTER_ERR_PANIC),
+        ] {
+            let define = format!("#define {} {}\n", name, value);
+            assert!(header.contains(&define), "{}", define);
+        }
+        for name in &[
+            "int32_t monster_build_json(const uint8_t *json, size_t json_len,",
+            "int32_t monster_verify(const uint8_t *buf, size_t len);",
+            "int32_t monster_get_hp(const uint8_t *buf, size_t len, int16_t *out_hp);",
+            "void monster_free(uint8_t *buf, size_t len);",
+        ] {
+            assert!(header.contains(name), "{}", name);
+        }
+    }
+}
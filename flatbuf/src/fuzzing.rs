@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Entry points shared by the `fuzz/` targets and the regression test,
+//! which must not panic on any input.
+use std::convert::TryInto;
+
+use crate::model::my_game::sample::size_prefixed_root_as_monster;
+use crate::monster::MonsterData;
+use crate::{MonsterBuf, MonsterView};
+
+/// Parse `data` as the `Monster` buffer and walk all the accessors.
+pub fn monster(data: &[u8]) {
+    if let Ok(view) = MonsterView::from_bytes(data) {
+        walk(view);
+        let mut buf = MonsterBuf::new(data.to_vec()).expect("verified buffer");
+        if buf.mutate_hp(buf.hp().wrapping_add(1)) {
+            walk(buf.view());
+        }
+    }
+}
+
+/// Parse `data` as the sequence of the size-prefixed `Monster` frames
+/// and walk all the accessors of each frame.
+pub fn size_prefixed_frames(mut data: &[u8]) {
+    while data.len() >= 4 {
+        let len = u32::from_le_bytes(data[..4].try_into().unwrap()) as usize;
+        let end = match len.checked_add(4) {
+            Some(end) if end <= data.len() => end,
+            _ => return,
+        };
+        if let Ok(monster) = size_prefixed_root_as_monster(&data[..end]) {
+            walk(monster.into());
+        }
+        data = &data[end..];
+    }
+}
+
+fn walk(view: MonsterView<'_>) {
+    let _ = view.name_or_err();
+    let _ = (view.has_mana(), view.has_hp(), view.has_color());
+    let _ = view.color().to_string();
+    for weapon in view.weapons() {
+        let _ = weapon.to_string();
+    }
+    let _ = view.equipped().map(|weapon| weapon.to_string());
+    let _ = view.validate();
+    let _ = MonsterData::from(view);
+}
@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Typed item ids in the `inventory: [ubyte]` field.
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::slice;
+
+use crate::error::ItemError;
+use crate::view::MonsterView;
+
+/// Registered item, with the id as the discriminant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Item {
+    Gold = 0,
+    Potion = 1,
+    Elixir = 2,
+    Key = 3,
+    Map = 4,
+    Torch = 5,
+    Rope = 6,
+    Bread = 7,
+    Gem = 8,
+    Scroll = 9,
+}
+
+impl Item {
+    /// Registered item table, in the id order.
+    pub const ALL: [Item; 10] = [
+        Item::Gold,
+        Item::Potion,
+        Item::Elixir,
+        Item::Key,
+        Item::Map,
+        Item::Torch,
+        Item::Rope,
+        Item::Bread,
+        Item::Gem,
+        Item::Scroll,
+    ];
+
+    pub fn id(self) -> u8 {
+        self as u8
+    }
+}
+
+impl TryFrom<u8> for Item {
+    type Error = ItemError;
+
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::inventory::Item;
+    /// use flatbuf_tutorial::ItemError;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Ok(Item::Potion), Item::try_from(1));
+    /// assert_eq!(Err(ItemError::Unregistered(10)), Item::try_from(10));
+    /// ```
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Item::ALL
+            .get(usize::from(id))
+            .copied()
+            .ok_or(ItemError::Unregistered(id))
+    }
+}
+
+/// Item id read from or written to the `inventory` field.
+///
+/// The unregistered id is kept as `Unknown`, instead of being dropped, so
+/// that it round-trips.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::inventory::{Item, ItemId};
+///
+/// assert_eq!(ItemId::Known(Item::Key), ItemId::from(3));
+/// assert_eq!(ItemId::Unknown(200), ItemId::from(200));
+/// assert_eq!(200, ItemId::Unknown(200).raw());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemId {
+    Known(Item),
+    Unknown(u8),
+}
+
+impl ItemId {
+    /// Returns the id in the `inventory` field.
+    pub fn raw(self) -> u8 {
+        match self {
+            ItemId::Known(item) => item.id(),
+            ItemId::Unknown(id) => id,
+        }
+    }
+}
+
+impl From<u8> for ItemId {
+    fn from(id: u8) -> Self {
+        Item::try_from(id).map_or(ItemId::Unknown(id), ItemId::Known)
+    }
+}
+
+impl From<Item> for ItemId {
+    fn from(item: Item) -> Self {
+        ItemId::Known(item)
+    }
+}
+
+impl fmt::Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ItemId::Known(item) => write!(f, "{:?}", item),
+            ItemId::Unknown(id) => write!(f, "unknown item {}", id),
+        }
+    }
+}
+
+/// Iterator over the item ids in the `inventory` field, returned by
+/// [`MonsterView::inventory_items`].
+///
+/// [`monsterview::inventory_items`]: ../view/struct.MonsterView.html#method.inventory_items
+#[derive(Debug, Clone)]
+pub struct Inventory<'a>(slice::Iter<'a, u8>);
+
+impl<'a> Inventory<'a> {
+    pub fn new(inventory: &'a [u8]) -> Self {
+        Self(inventory.iter())
+    }
+    /// Returns the number of each item in the rest of the inventory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::inventory::{Inventory, Item, ItemId};
+    ///
+    /// let counts = Inventory::new(&[1, 1, 200]).counts();
+    /// assert_eq!(Some(&2), counts.get(&ItemId::Known(Item::Potion)));
+    /// assert_eq!(Some(&1), counts.get(&ItemId::Unknown(200)));
+    /// ```
+    pub fn counts(&self) -> HashMap<ItemId, usize> {
+        let mut counts = HashMap::new();
+        for item in self.clone() {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+        counts
+    }
+    /// Returns `true` if the rest of the inventory has `item`.
+    pub fn contains(&self, item: ItemId) -> bool {
+        self.0.as_slice().contains(&item.raw())
+    }
+}
+
+impl Iterator for Inventory<'_> {
+    type Item = ItemId;
+
+    fn next(&mut self) -> Option<ItemId> {
+        self.0.next().map(|&id| ItemId::from(id))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Inventory<'_> {}
+
+impl FusedIterator for Inventory<'_> {}
+
+impl<'a> MonsterView<'a> {
+    /// Returns the item ids in the `inventory`, which is empty for the
+    /// absent field.
+    pub fn inventory_items(&self) -> Inventory<'a> {
+        Inventory::new(self.inventory().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Monster;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn round_trip() {
+        let items = [
+            ItemId::Known(Item::Gold),
+            ItemId::Unknown(200),
+            ItemId::Known(Item::Scroll),
+            ItemId::Known(Item::Gold),
+            ItemId::Unknown(10),
+        ];
+        let mut b = FlatBufferBuilder::new();
+        let orc = Monster::builder()
+            .name("orc")
+            .inventory_items(&items)
+            .build(&mut b);
+        b.finish(orc, None);
+        let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert_eq!(Some(&[0, 200, 9, 0, 10][..]), orc.inventory());
+        assert_eq!(items.to_vec(), orc.inventory_items().collect::<Vec<_>>());
+        assert_eq!(items.len(), orc.inventory_items().len());
+    }
+    #[test]
+    fn counts() {
+        struct Test {
+            name: &'static str,
+            data: &'static [u8],
+            want: &'static [(ItemId, usize)],
+        }
+        let tests = [
+            Test {
+                name: "empty",
+                data: &[],
+                want: &[],
+            },
+            Test {
+                name: "known",
+                data: &[1, 2, 1],
+                want: &[
+                    (ItemId::Known(Item::Potion), 2),
+                    (ItemId::Known(Item::Elixir), 1),
+                ],
+            },
+            Test {
+                name: "mixed",
+                data: &[255, 0, 255, 255],
+                want: &[(ItemId::Unknown(255), 3), (ItemId::Known(Item::Gold), 1)],
+            },
+        ];
+        for t in &tests {
+            let want: HashMap<_, _> = t.want.iter().copied().collect();
+            assert_eq!(want, Inventory::new(t.data).counts(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn contains() {
+        let inventory = Inventory::new(&[3, 42]);
+        assert!(inventory.contains(ItemId::Known(Item::Key)));
+        assert!(inventory.contains(ItemId::Unknown(42)));
+        assert!(!inventory.contains(ItemId::Known(Item::Gold)));
+        assert!(!inventory.contains(ItemId::Unknown(43)));
+    }
+    #[test]
+    fn absent_inventory() {
+        let mut b = FlatBufferBuilder::new();
+        let orc = Monster::builder().name("orc").build(&mut b);
+        b.finish(orc, None);
+        let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert_eq!(0, orc.inventory_items().count());
+        assert!(orc.inventory_items().counts().is_empty());
+    }
+    #[test]
+    fn item_ids() {
+        for id in 0..=u8::MAX {
+            let item = ItemId::from(id);
+            assert_eq!(id, item.raw(), "{}", id);
+            match Item::try_from(id) {
+                Ok(known) => assert_eq!(ItemId::Known(known), item, "{}", id),
+                Err(ItemError::Unregistered(unknown)) => {
+                    assert_eq!(ItemId::Unknown(unknown), item, "{}", id)
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,52 @@
 //! [Flatbuffers tutorial](https://google.github.io/flatbuffers/flatbuffers_guide_tutorial.html).
+pub mod buf;
+#[cfg(feature = "cli")]
+pub mod cli;
+pub mod color;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[doc(hidden)]
+pub mod fuzzing;
+pub mod inventory;
+mod math;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod model;
 pub mod monster;
 pub mod pool;
+#[cfg(feature = "protobuf")]
+pub mod proto;
+#[cfg(feature = "reflection")]
+pub mod reflection;
+pub mod view;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod weapon;
+pub use buf::MonsterBuf;
+#[cfg(feature = "async")]
+pub use error::AioError;
+#[cfg(feature = "cli")]
+pub use error::CliError;
+#[cfg(feature = "encoding")]
+pub use error::EncodingError;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub use error::FixtureError;
+#[cfg(feature = "integrity")]
+pub use error::IntegrityError;
+#[cfg(feature = "mmap")]
+pub use error::MmapError;
+#[cfg(feature = "reflection")]
+pub use error::ReflectError;
+#[cfg(feature = "stream")]
+pub use error::StreamError;
+pub use error::{
+    ChunkError, ColorError, EditError, ItemError, MonsterError, PoolExhausted, SizePrefixedError,
+    ValidationError,
+};
 pub use monster::Monster;
-pub use pool::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool};
+pub use pool::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, GlobalBuilder};
+pub use view::{MonsterView, VerifyOpts};
+pub use weapon::{Weapon, WeaponView};
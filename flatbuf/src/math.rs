@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Conversions between the `Vec3` struct and the math library vectors.
+//!
+//! The plain `[f32; 3]` arrays are always available, and the `glam` and
+//! the `nalgebra` vectors are behind the features of the same name.
+use crate::model::my_game::sample::Vec3;
+
+impl From<[f32; 3]> for Vec3 {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<Vec3> for [f32; 3] {
+    fn from(v: Vec3) -> Self {
+        [v.x(), v.y(), v.z()]
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3> for Vec3 {
+    fn from(v: glam::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::Vec3 {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Vec3A> for Vec3 {
+    fn from(v: glam::Vec3A) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Vec3> for glam::Vec3A {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector3<f32>> for Vec3 {
+    fn from(v: nalgebra::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Vec3> for nalgebra::Vector3<f32> {
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample;
+    use crate::monster::Monster;
+    use crate::view::MonsterView;
+    use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+    const PATH: [[f32; 3]; 3] = [[0.5, -1.0, 2.0], [-3.0, 4.0, 0.0], [1.0, 2.5, -6.0]];
+
+    fn monster(
+        build: impl FnOnce(&mut FlatBufferBuilder<'static>) -> WIPOffset<sample::Monster<'static>>,
+    ) -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let monster = build(&mut b);
+        b.finish(monster, None);
+        b.finished_data().to_vec()
+    }
+    #[test]
+    fn bounding_box() {
+        let buf = monster(|b| Monster::builder().name("orc").path_points(PATH).build(b));
+        let orc = MonsterView::from_bytes(&buf).unwrap();
+        let want = (Vec3::new(-3.0, -1.0, -6.0), Vec3::new(1.0, 4.0, 2.0));
+        assert_eq!(Some(want), orc.path_bounding_box());
+
+        let buf = monster(|b| Monster::builder().name("orc").build(b));
+        let orc = MonsterView::from_bytes(&buf).unwrap();
+        assert_eq!(None, orc.path_bounding_box());
+    }
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam() {
+        let path: Vec<_> = PATH.iter().map(|&p| glam::Vec3::from(p)).collect();
+        let pos = glam::Vec3A::new(1.0, 2.0, 3.0);
+        let buf = monster(|b| {
+            Monster::builder()
+                .name("orc")
+                .pos(pos)
+                .path_points(path.iter().copied())
+                .build(b)
+        });
+        let orc = MonsterView::from_bytes(&buf).unwrap();
+        assert_eq!(Some(pos), orc.pos().map(glam::Vec3A::from));
+        assert_eq!(path, orc.path_glam().collect::<Vec<_>>());
+    }
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra() {
+        let path: Vec<_> = PATH.iter().map(|&p| nalgebra::Vector3::from(p)).collect();
+        let pos = nalgebra::Vector3::new(1.0, 2.0, 3.0);
+        let buf = monster(|b| {
+            Monster::builder()
+                .name("orc")
+                .pos(pos)
+                .path_points(path.iter().copied())
+                .build(b)
+        });
+        let orc = MonsterView::from_bytes(&buf).unwrap();
+        assert_eq!(Some(pos), orc.pos().map(nalgebra::Vector3::from));
+        let got: Vec<nalgebra::Vector3<f32>> = orc.path().map(Into::into).collect();
+        assert_eq!(path, got);
+    }
+}
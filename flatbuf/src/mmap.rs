@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Zero-copy `Monster` and `MonsterList` readers over the memory-mapped
+//! files.
+//!
+//! The mapped bytes are verified once at the open, and the views borrow
+//! the reader, which owns the map, so they can't outlive it.
+//!
+//! The file must not be modified while it's mapped, or the verified
+//! bytes may change under the views.
+use std::fs::File;
+use std::path::Path;
+
+use flatbuffers::{ForwardsUOffset, Vector, VerifierOptions};
+use memmap2::Mmap;
+
+use crate::error::{MmapError, MonsterError};
+use crate::model::my_game::sample::{self, MonsterList};
+use crate::{MonsterBuf, MonsterView};
+
+/// Smallest root table: the root offset and the table's vtable offset.
+const MIN_LEN: u64 = (flatbuffers::SIZE_UOFFSET + flatbuffers::SIZE_SOFFSET) as u64;
+
+impl MonsterBuf {
+    /// Map the `Monster` buffer in `path` and verify it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterBuf};
+    /// use flatbuffers::FlatBufferBuilder;
+    /// # let path = std::env::temp_dir().join(format!("open_mmap-{}.bin", std::process::id()));
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").build(&mut b);
+    /// b.finish(orc, None);
+    /// std::fs::write(&path, b.finished_data())?;
+    ///
+    /// let orc = MonsterBuf::open_mmap(&path)?;
+    /// assert_eq!(Some("orc"), orc.name());
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> Result<MmapMonsterBuf, MmapError> {
+        let map = map(path.as_ref())?;
+        MonsterView::from_bytes(&map)?;
+        Ok(MmapMonsterBuf(map))
+    }
+    /// Map the `MonsterList` buffer in `path` and verify it.
+    ///
+    /// The verifier limits are raised with the file size, to accept the
+    /// list of millions of monsters.
+    pub fn open_mmap_list<P: AsRef<Path>>(path: P) -> Result<MmapMonsterList, MmapError> {
+        let map = map(path.as_ref())?;
+        let defaults = VerifierOptions::default();
+        let opts = VerifierOptions {
+            // Every table takes at least its vtable offset.
+            max_tables: defaults
+                .max_tables
+                .max(map.len() / flatbuffers::SIZE_SOFFSET),
+            max_apparent_size: defaults.max_apparent_size.max(map.len()),
+            ..defaults
+        };
+        let list =
+            flatbuffers::root_with_opts::<MonsterList>(&opts, &map).map_err(MonsterError::from)?;
+        let len = list.monsters().map_or(0, |monsters| monsters.len());
+        Ok(MmapMonsterList { map, len })
+    }
+}
+
+fn map(path: &Path) -> Result<Mmap, MmapError> {
+    let file = File::open(path)?;
+    let len = file.metadata()?.len();
+    if len < MIN_LEN {
+        return Err(MmapError::TooShort(len));
+    }
+    // Safety: the map is read-only, and the file is not expected to be
+    // modified while it's mapped, as documented in the module.
+    Ok(unsafe { Mmap::map(&file)? })
+}
+
+/// Memory-mapped `Monster` buffer, verified once at the open.
+#[derive(Debug)]
+pub struct MmapMonsterBuf(Mmap);
+
+impl MmapMonsterBuf {
+    /// Returns the view of the verified buffer without re-verifying it.
+    #[inline]
+    pub fn view(&self) -> MonsterView<'_> {
+        // Safety: verified in `open_mmap`.
+        MonsterView::from(unsafe { sample::root_as_monster_unchecked(&self.0) })
+    }
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.view().name()
+    }
+    #[inline]
+    pub fn hp(&self) -> i16 {
+        self.view().hp()
+    }
+}
+
+impl AsRef<[u8]> for MmapMonsterBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Memory-mapped `MonsterList` buffer, verified once at the open, with
+/// the indexed access to the monsters.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::MonsterData;
+/// use flatbuf_tutorial::MonsterBuf;
+/// use flatbuffers::FlatBufferBuilder;
+/// # let path = std::env::temp_dir().join(format!("open_mmap_list-{}.bin", std::process::id()));
+///
+/// let monsters: Vec<_> = (0..3)
+///     .map(|hp| MonsterData {
+///         name: format!("orc{}", hp),
+///         hp,
+///         ..Default::default()
+///     })
+///     .collect();
+/// let mut b = FlatBufferBuilder::new();
+/// let list = MonsterData::build_list(&monsters, &mut b);
+/// b.finish(list, None);
+/// std::fs::write(&path, b.finished_data())?;
+///
+/// let list = MonsterBuf::open_mmap_list(&path)?;
+/// assert_eq!(3, list.len());
+/// assert_eq!(Some("orc2"), list.get(2).and_then(|orc| orc.name()));
+/// assert!(list.get(3).is_none());
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct MmapMonsterList {
+    map: Mmap,
+    len: usize,
+}
+
+impl MmapMonsterList {
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns the view of the monster at `index`, or `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<MonsterView<'_>> {
+        if index < self.len {
+            self.monsters().map(|monsters| monsters.get(index).into())
+        } else {
+            None
+        }
+    }
+    pub fn iter(&self) -> impl Iterator<Item = MonsterView<'_>> {
+        self.monsters()
+            .into_iter()
+            .flat_map(|monsters| monsters.iter().map(MonsterView::from))
+    }
+    fn monsters(&self) -> Option<Vector<'_, ForwardsUOffset<sample::Monster<'_>>>> {
+        // Safety: verified in `open_mmap_list`.
+        unsafe { flatbuffers::root_unchecked::<MonsterList>(&self.map) }.monsters()
+    }
+}
+
+impl AsRef<[u8]> for MmapMonsterList {
+    fn as_ref(&self) -> &[u8] {
+        &self.map
+    }
+}
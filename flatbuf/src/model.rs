@@ -1,10 +1,36 @@
 // SPDX-License-Identifier: GPL-2.0
 //! Flatbuffer auto-generated sample module explained in the [tutorial](https://google.github.io/flatbuffers/flatbuffers_guide_tutorial.html).
+//!
+//! The module is generated from `schema/monster.fbs` by `flatc` with the
+//! `regenerate-model` feature, or falls back to the checked-in
+//! `monster_generated.rs` otherwise.
 #![allow(
     unused_imports,
+    mismatched_lifetime_syntaxes,
+    clippy::derivable_impls,
     clippy::extra_unused_lifetimes,
+    clippy::missing_safety_doc,
     clippy::needless_lifetimes,
     clippy::redundant_closure,
     clippy::redundant_static_lifetimes
 )]
+#[cfg(flatc_model)]
+include!(concat!(env!("OUT_DIR"), "/monster_generated.rs"));
+#[cfg(not(flatc_model))]
 include!("monster_generated.rs");
+
+// Hash of the schema the current build is based on.
+include!(concat!(env!("OUT_DIR"), "/schema_hash.rs"));
+
+/// Hash of the schema the checked-in `monster_generated.rs` is generated
+/// from.  Update it together with the checked-in code.
+pub const CHECKED_IN_SCHEMA_HASH: u64 = 0xc230_0dfe_ce9d_b513;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn checked_in_model_is_up_to_date() {
+        assert_eq!(SCHEMA_HASH, CHECKED_IN_SCHEMA_HASH);
+    }
+}
@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Non-blocking `Monster` buffer persistence on the tokio runtime.
+//!
+//! The size-prefixed stream is the sequence of the frames, each of which
+//! is the little endian `u32` length followed by the `Monster` buffer, as
+//! written by [`append_size_prefixed`].
+//!
+//! [`append_size_prefixed`]: fn.append_size_prefixed.html
+use std::convert::TryFrom;
+use std::io;
+use std::path::Path;
+
+use tokio::fs;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::error::AioError;
+use crate::MonsterBuf;
+
+/// Default [`FrameReader`] limit of the frame length.
+///
+/// [`framereader`]: struct.FrameReader.html
+pub const DEFAULT_MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+const PREFIX_LEN: usize = flatbuffers::SIZE_UOFFSET;
+
+/// Write `buf` to `path`, replacing the file.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{aio, MonsterData};
+/// use flatbuf_tutorial::Monster;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let path = std::env::temp_dir().join(format!("write_monster-{}.bin", std::process::id()));
+/// let data = MonsterData {
+///     name: String::from("orc"),
+///     ..Default::default()
+/// };
+/// aio::write_monster(&path, &Monster::build_pooled(&data)).await?;
+/// let orc = aio::read_monster(&path).await?;
+/// assert_eq!(Some("orc"), orc.name());
+/// # tokio::fs::remove_file(&path).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn write_monster<P: AsRef<Path>>(path: P, buf: &MonsterBuf) -> io::Result<()> {
+    fs::write(path, buf).await
+}
+
+/// Read the `Monster` buffer in `path` and verify it.
+pub async fn read_monster<P: AsRef<Path>>(path: P) -> Result<MonsterBuf, AioError> {
+    Ok(MonsterBuf::new(fs::read(path).await?)?)
+}
+
+/// Append `buf` to `w` as the size-prefixed frame.
+///
+/// `w` is not flushed, e.g. call `sync_data` on the file to persist the
+/// frames.
+pub async fn append_size_prefixed<W>(w: &mut W, buf: &MonsterBuf) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let len = u32::try_from(buf.as_ref().len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "monster too large"))?;
+    w.write_all(&len.to_le_bytes()).await?;
+    w.write_all(buf.as_ref()).await
+}
+
+/// Reader of the verified monsters in the size-prefixed stream.
+///
+/// The corrupted frame is returned as [`AioError::Invalid`] and the
+/// reader moves on to the next frame, as the frame boundary is still
+/// known.  The other errors, e.g. the truncated frame, end the stream.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::aio::{append_size_prefixed, FrameReader};
+/// use flatbuf_tutorial::monster::MonsterData;
+/// use flatbuf_tutorial::Monster;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = Vec::new();
+/// for name in &["orc", "goblin"] {
+///     let data = MonsterData {
+///         name: name.to_string(),
+///         ..Default::default()
+///     };
+///     append_size_prefixed(&mut stream, &Monster::build_pooled(&data)).await?;
+/// }
+/// let mut frames = FrameReader::new(&stream[..]);
+/// assert_eq!(Some("orc"), frames.next_frame().await.unwrap()?.name());
+/// assert_eq!(Some("goblin"), frames.next_frame().await.unwrap()?.name());
+/// assert!(frames.next_frame().await.is_none());
+/// # Ok(())
+/// # }
+/// ```
+/// [`aioerror::invalid`]: ../../error/enum.AioError.html#variant.Invalid
+#[derive(Debug)]
+pub struct FrameReader<R> {
+    r: R,
+    max_frame_len: usize,
+    done: bool,
+}
+
+impl<R: AsyncRead + Unpin> FrameReader<R> {
+    pub fn new(r: R) -> Self {
+        Self {
+            r,
+            max_frame_len: DEFAULT_MAX_FRAME_LEN,
+            done: false,
+        }
+    }
+    /// Set the limit of the frame length, which guards the allocation
+    /// against the corrupted length prefix.
+    pub fn max_frame_len(mut self, max_frame_len: usize) -> Self {
+        self.max_frame_len = max_frame_len;
+        self
+    }
+    /// Returns the next monster, or `None` at the end of the stream.
+    pub async fn next_frame(&mut self) -> Option<Result<MonsterBuf, AioError>> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_frame().await;
+        match &result {
+            Some(Err(AioError::Invalid(_))) => {}
+            None | Some(Err(_)) => self.done = true,
+            Some(Ok(_)) => {}
+        }
+        result
+    }
+    pub fn into_inner(self) -> R {
+        self.r
+    }
+    async fn read_frame(&mut self) -> Option<Result<MonsterBuf, AioError>> {
+        let mut prefix = [0; PREFIX_LEN];
+        match self.read_full(&mut prefix).await {
+            Err(err) => return Some(Err(err.into())),
+            Ok(0) => return None,
+            Ok(n) if n < PREFIX_LEN => {
+                return Some(Err(AioError::Truncated {
+                    want: PREFIX_LEN,
+                    got: n,
+                }))
+            }
+            Ok(_) => {}
+        }
+        let len = u32::from_le_bytes(prefix) as usize;
+        if len > self.max_frame_len {
+            return Some(Err(AioError::FrameTooLarge(len)));
+        }
+        let mut buf = vec![0; len];
+        Some(match self.read_full(&mut buf).await {
+            Err(err) => Err(err.into()),
+            Ok(n) if n < len => Err(AioError::Truncated { want: len, got: n }),
+            Ok(_) => MonsterBuf::new(buf).map_err(AioError::from),
+        })
+    }
+    /// Fill `buf` across the partial reads, and returns the number of
+    /// bytes read, which is short of `buf` only at the end of the stream.
+    async fn read_full(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            match self.r.read(&mut buf[n..]).await {
+                Ok(0) => break,
+                Ok(m) => n += m,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(n)
+    }
+}
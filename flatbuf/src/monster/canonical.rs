@@ -0,0 +1,350 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Canonical form of the `Monster`, which is independent from the buffer
+//! layout.
+//!
+//! The fields are in the schema order, the absent `name`, `inventory`
+//! and `path` are the same as the empty ones, `weapons` are sorted by the
+//! name and then the damage, `equipped` is the weapon itself rather than
+//! its index, and the floats are compared by the bit pattern.
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use super::{MonsterData, WeaponData};
+use crate::model::my_game::sample::Vec3;
+use crate::view::MonsterView;
+
+/// Canonical `Monster` fields in the schema order.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Canonical<'a> {
+    pos: Option<[u32; 3]>,
+    mana: i16,
+    hp: i16,
+    name: &'a str,
+    inventory: &'a [u8],
+    color: i8,
+    weapons: Vec<(&'a str, i16)>,
+    equipped: Option<(&'a str, i16)>,
+    path: Vec<[u32; 3]>,
+}
+
+impl<'a> Canonical<'a> {
+    fn from_data(data: &'a MonsterData) -> Self {
+        let weapon = |weapon: &'a WeaponData| (weapon.name.as_str(), weapon.damage);
+        let mut weapons: Vec<_> = data.weapons.iter().map(weapon).collect();
+        weapons.sort_unstable();
+        Self {
+            pos: data.pos.as_ref().map(bits),
+            mana: data.mana,
+            hp: data.hp,
+            name: &data.name,
+            inventory: &data.inventory,
+            color: data.color.0,
+            weapons,
+            equipped: data
+                .equipped
+                .and_then(|index| data.weapons.get(index))
+                .map(weapon),
+            path: data.path.iter().map(bits).collect(),
+        }
+    }
+    /// Write the fields in the little endian, with the `u32` length
+    /// prefixes and the `0` or `1` presence bytes.
+    fn write(&self, out: &mut Vec<u8>) {
+        let len = |out: &mut Vec<u8>, len: usize| out.extend(&(len as u32).to_le_bytes());
+        let point = |out: &mut Vec<u8>, point: &[u32; 3]| {
+            point.iter().for_each(|v| out.extend(&v.to_le_bytes()))
+        };
+        let weapon = |out: &mut Vec<u8>, (name, damage): (&str, i16)| {
+            len(out, name.len());
+            out.extend(name.as_bytes());
+            out.extend(&damage.to_le_bytes());
+        };
+        match &self.pos {
+            None => out.push(0),
+            Some(pos) => {
+                out.push(1);
+                point(out, pos);
+            }
+        }
+        out.extend(&self.mana.to_le_bytes());
+        out.extend(&self.hp.to_le_bytes());
+        len(out, self.name.len());
+        out.extend(self.name.as_bytes());
+        len(out, self.inventory.len());
+        out.extend(self.inventory);
+        out.extend(&self.color.to_le_bytes());
+        len(out, self.weapons.len());
+        self.weapons.iter().for_each(|&w| weapon(out, w));
+        match self.equipped {
+            None => out.push(0),
+            Some(equipped) => {
+                out.push(1);
+                weapon(out, equipped);
+            }
+        }
+        len(out, self.path.len());
+        self.path.iter().for_each(|p| point(out, p));
+    }
+}
+
+fn bits(v: &Vec3) -> [u32; 3] {
+    [v.x().to_bits(), v.y().to_bits(), v.z().to_bits()]
+}
+
+impl MonsterData {
+    /// Returns the deterministic serialization of the canonical form,
+    /// e.g. as the input of the content hash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+    ///
+    /// let weapon = |name: &str| WeaponData {
+    ///     name: name.to_string(),
+    ///     damage: 3,
+    /// };
+    /// let a = MonsterData {
+    ///     weapons: vec![weapon("axe"), weapon("sword")],
+    ///     equipped: Some(1),
+    ///     ..Default::default()
+    /// };
+    /// let b = MonsterData {
+    ///     weapons: vec![weapon("sword"), weapon("axe")],
+    ///     equipped: Some(0),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(a.canonical_bytes(), b.canonical_bytes());
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.serialized_size_hint());
+        Canonical::from_data(self).write(&mut out);
+        out
+    }
+}
+
+impl MonsterView<'_> {
+    /// Returns the same canonical bytes as the [`MonsterData`] of the view.
+    ///
+    /// [`monsterdata`]: ../monster/struct.MonsterData.html
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        MonsterData::from(*self).canonical_bytes()
+    }
+}
+
+impl PartialEq for MonsterData {
+    fn eq(&self, other: &Self) -> bool {
+        Canonical::from_data(self) == Canonical::from_data(other)
+    }
+}
+
+impl Eq for MonsterData {}
+
+impl PartialOrd for MonsterData {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MonsterData {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Canonical::from_data(self).cmp(&Canonical::from_data(other))
+    }
+}
+
+impl Hash for MonsterData {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Canonical::from_data(self).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::{self, Color};
+    use crate::Monster;
+    use flatbuffers::FlatBufferBuilder;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::BTreeSet;
+
+    fn weapon(name: &str, damage: i16) -> WeaponData {
+        WeaponData {
+            name: name.to_string(),
+            damage,
+        }
+    }
+    fn orc() -> MonsterData {
+        MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            hp: 80,
+            inventory: vec![1, 2],
+            color: Color::Red,
+            weapons: vec![weapon("sword", 3), weapon("axe", 5)],
+            equipped: Some(1),
+            path: vec![Vec3::new(0.5, 0.0, -0.5)],
+            ..Default::default()
+        }
+    }
+    fn hash(data: &MonsterData) -> u64 {
+        let mut h = DefaultHasher::new();
+        data.hash(&mut h);
+        h.finish()
+    }
+    /// Build `orc` table by table in the reverse order of the builder.
+    fn orc_reversed() -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let path = b.create_vector(&[Vec3::new(0.5, 0.0, -0.5)]);
+        let mut weapons = Vec::new();
+        for &(name, damage) in &[("axe", 5), ("sword", 3)] {
+            let name = b.create_string(name);
+            weapons.push(sample::Weapon::create(
+                &mut b,
+                &sample::WeaponArgs {
+                    name: Some(name),
+                    damage,
+                },
+            ));
+        }
+        let equipped = weapons[0];
+        let weapons = b.create_vector(&weapons);
+        let inventory = b.create_vector(&[1u8, 2]);
+        let name = b.create_string("orc");
+        let orc = sample::Monster::create(
+            &mut b,
+            &sample::MonsterArgs {
+                path: Some(path),
+                equipped: Some(equipped.as_union_value()),
+                equipped_type: sample::Equipment::Weapon,
+                weapons: Some(weapons),
+                color: Color::Red,
+                inventory: Some(inventory),
+                name: Some(name),
+                hp: 80,
+                pos: Some(&Vec3::new(1.0, 2.0, 3.0)),
+                ..Default::default()
+            },
+        );
+        b.finish(orc, None);
+        b.finished_data().to_vec()
+    }
+    #[test]
+    fn canonical_bytes_independent_from_layout() {
+        let want = orc().canonical_bytes();
+        let built = Monster::build_pooled(&orc());
+        let reversed = orc_reversed();
+        assert_ne!(built.as_ref(), &reversed[..]);
+        let reversed = MonsterView::from_bytes(&reversed).unwrap();
+        assert_eq!(want, built.view().canonical_bytes());
+        assert_eq!(want, reversed.canonical_bytes());
+        assert_eq!(orc(), MonsterData::from(reversed));
+        assert_eq!(hash(&orc()), hash(&MonsterData::from(reversed)));
+    }
+    #[test]
+    fn canonical_bytes_are_stable() {
+        let want: &[u8] = &[
+            1, // pos
+            0, 0, 0x80, 0x3f, 0, 0, 0, 0x40, 0, 0, 0x40, 0x40, // 1.0, 2.0, 3.0
+            150, 0, // mana
+            80, 0, // hp
+            3, 0, 0, 0, b'o', b'r', b'c', // name
+            2, 0, 0, 0, 1, 2, // inventory
+            0, // color
+            2, 0, 0, 0, // weapons
+            3, 0, 0, 0, b'a', b'x', b'e', 5, 0, // axe
+            5, 0, 0, 0, b's', b'w', b'o', b'r', b'd', 3, 0, // sword
+            1, 3, 0, 0, 0, b'a', b'x', b'e', 5, 0, // equipped axe
+            1, 0, 0, 0, // path
+            0, 0, 0, 0x3f, 0, 0, 0, 0, 0, 0, 0, 0xbf, // 0.5, 0.0, -0.5
+        ];
+        for _ in 0..3 {
+            let orc = Monster::build_pooled(&orc());
+            assert_eq!(want, &orc.view().canonical_bytes()[..]);
+        }
+    }
+    #[test]
+    fn eq() {
+        struct Test {
+            name: &'static str,
+            data: MonsterData,
+            want: bool,
+        }
+        let tests = [
+            Test {
+                name: "weapons in different order",
+                data: MonsterData {
+                    weapons: vec![weapon("axe", 5), weapon("sword", 3)],
+                    equipped: Some(0),
+                    ..orc()
+                },
+                want: true,
+            },
+            Test {
+                name: "different equipped weapon",
+                data: MonsterData {
+                    equipped: Some(0),
+                    ..orc()
+                },
+                want: false,
+            },
+            Test {
+                name: "negative zero",
+                data: MonsterData {
+                    path: vec![Vec3::new(0.5, -0.0, -0.5)],
+                    ..orc()
+                },
+                want: false,
+            },
+            Test {
+                name: "out of range equipped",
+                data: MonsterData {
+                    equipped: Some(2),
+                    ..orc()
+                },
+                want: false,
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, t.data == orc(), "{}", t.name);
+            assert_eq!(t.want, hash(&t.data) == hash(&orc()), "{}", t.name);
+            assert_eq!(
+                t.want,
+                t.data.canonical_bytes() == orc().canonical_bytes(),
+                "{}",
+                t.name
+            );
+        }
+    }
+    #[test]
+    fn nan_is_equal_to_itself() {
+        let nan = MonsterData {
+            pos: Some(Vec3::new(f32::NAN, 0.0, 0.0)),
+            ..orc()
+        };
+        assert_eq!(nan, nan.clone());
+        assert_eq!(Ordering::Equal, nan.cmp(&nan.clone()));
+    }
+    #[test]
+    fn btree_set_dedupe() {
+        let goblin = MonsterData {
+            name: String::from("goblin"),
+            ..Default::default()
+        };
+        let monsters = vec![
+            orc(),
+            goblin.clone(),
+            MonsterData::from(MonsterView::from_bytes(&orc_reversed()).unwrap()),
+            MonsterData {
+                weapons: vec![weapon("axe", 5), weapon("sword", 3)],
+                equipped: Some(0),
+                ..orc()
+            },
+            goblin.clone(),
+        ];
+        let set: BTreeSet<_> = monsters.into_iter().collect();
+        let names: Vec<_> = set.iter().map(|monster| monster.name.as_str()).collect();
+        // `goblin` has no `pos`, which is ordered first.
+        assert_eq!(vec!["goblin", "orc"], names);
+    }
+}
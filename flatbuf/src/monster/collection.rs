@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Large monster collections split into the size limited `MonsterList`
+//! chunks, and reassembled from them.
+//!
+//! Each chunk carries its `chunk_index` and the `total_chunks` of the
+//! collection, so that the chunks can be sent in any order, and the
+//! receiver tells the incomplete collection from the complete one.
+//!
+//! # Examples
+//!
+//! ```
+//! use flatbuf_tutorial::monster::{collection, MonsterData};
+//! use flatbuf_tutorial::FlatBufferBuilderPool;
+//!
+//! let monsters: Vec<_> = (0..100)
+//!     .map(|hp| MonsterData {
+//!         name: format!("orc{}", hp),
+//!         hp,
+//!         ..Default::default()
+//!     })
+//!     .collect();
+//! let pool = FlatBufferBuilderPool::new().build();
+//! let mut chunks = collection::chunk(&monsters, 1_024, &pool)?;
+//! assert!(chunks.len() > 1);
+//! assert!(chunks.iter().all(|chunk| chunk.as_ref().len() <= 1_024));
+//!
+//! chunks.reverse();
+//! assert_eq!(monsters, collection::reassemble(&chunks)?);
+//! # Ok::<(), flatbuf_tutorial::ChunkError>(())
+//! ```
+use std::ops::Range;
+
+use flatbuffers::{FlatBufferBuilder, VerifierOptions};
+
+use super::{layout, MonsterData};
+use crate::error::{ChunkError, MonsterError};
+use crate::model::my_game::sample::{self, MonsterList, MonsterListArgs};
+use crate::view::MonsterView;
+use crate::FlatBufferBuilderLocalPool;
+
+/// Owned `MonsterList` chunk of the collection, verified once at the
+/// construction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkBuf(Vec<u8>);
+
+impl ChunkBuf {
+    /// Verify the received chunk `buf` and take the ownership of it.
+    pub fn new(buf: Vec<u8>) -> Result<Self, ChunkError> {
+        flatbuffers::root_with_opts::<MonsterList>(&VerifierOptions::default(), &buf)
+            .map_err(MonsterError::from)?;
+        Ok(Self(buf))
+    }
+    /// Returns the position of the chunk in the collection.
+    pub fn chunk_index(&self) -> u32 {
+        self.list().chunk_index()
+    }
+    /// Returns the number of the chunks in the collection.
+    pub fn total_chunks(&self) -> u32 {
+        self.list().total_chunks()
+    }
+    /// Returns the number of the monsters in the chunk.
+    pub fn len(&self) -> usize {
+        self.list().monsters().map_or(0, |monsters| monsters.len())
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn iter(&self) -> impl Iterator<Item = MonsterView<'_>> {
+        self.list()
+            .monsters()
+            .into_iter()
+            .flat_map(|monsters| monsters.iter().map(MonsterView::from))
+    }
+    /// Returns the buffer, e.g. to send it.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+    fn list(&self) -> MonsterList<'_> {
+        // Safety: verified in `new`, or built by `chunk`.
+        unsafe { flatbuffers::root_unchecked::<MonsterList>(&self.0) }
+    }
+}
+
+impl AsRef<[u8]> for ChunkBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Pack `monsters` greedily into the chunks of up to `max_chunk_bytes`
+/// each, in order, with the builders from `pool`.
+///
+/// The monster sizes are the conservative upper bounds by
+/// [`MonsterData::serialized_size_hint`], so the chunks may be a little
+/// smaller than they could be, but never over the limit.  The empty
+/// `monsters` give the single empty chunk, so that the receiver still
+/// sees the complete collection.
+///
+/// Returns [`ChunkError::TooLarge`] for the monster which doesn't fit the
+/// chunk on its own.
+///
+/// [`monsterdata::serialized_size_hint`]: ../struct.MonsterData.html#method.serialized_size_hint
+/// [`chunkerror::toolarge`]: ../../error/enum.ChunkError.html#variant.TooLarge
+pub fn chunk(
+    monsters: &[MonsterData],
+    max_chunk_bytes: usize,
+    pool: &FlatBufferBuilderLocalPool,
+) -> Result<Vec<ChunkBuf>, ChunkError> {
+    let ranges = split(monsters, max_chunk_bytes)?;
+    let total_chunks = ranges.len() as u32;
+    let chunks = ranges
+        .into_iter()
+        .enumerate()
+        .map(|(chunk_index, range)| {
+            let mut b = pool.get();
+            let list = build(&mut b, &monsters[range], chunk_index as u32, total_chunks);
+            b.finish(list, None);
+            ChunkBuf(b.finished_data().to_vec())
+        })
+        .collect();
+    Ok(chunks)
+}
+
+/// Reassemble the collection from all of its `chunks`, in any order.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{collection, MonsterData};
+/// use flatbuf_tutorial::{ChunkError, FlatBufferBuilderPool};
+///
+/// let monsters = vec![MonsterData::default(); 10];
+/// let pool = FlatBufferBuilderPool::new().build();
+/// let mut chunks = collection::chunk(&monsters, 256, &pool).unwrap();
+/// chunks.remove(1);
+/// assert_eq!(
+///     Err(ChunkError::Missing { index: 1 }),
+///     collection::reassemble(&chunks)
+/// );
+/// ```
+pub fn reassemble(chunks: &[ChunkBuf]) -> Result<Vec<MonsterData>, ChunkError> {
+    let want = match chunks.first() {
+        Some(chunk) => chunk.total_chunks(),
+        None => return Err(ChunkError::Missing { index: 0 }),
+    };
+    let mut ordered: Vec<Option<&ChunkBuf>> = vec![None; want as usize];
+    for chunk in chunks {
+        let (index, total) = (chunk.chunk_index(), chunk.total_chunks());
+        if total != want {
+            return Err(ChunkError::TotalMismatch { total, want });
+        }
+        match ordered.get_mut(index as usize) {
+            None => return Err(ChunkError::OutOfRange { index, total }),
+            Some(Some(_)) => return Err(ChunkError::Duplicate { index }),
+            Some(slot) => *slot = Some(chunk),
+        }
+    }
+    let mut monsters = Vec::new();
+    for (index, chunk) in ordered.into_iter().enumerate() {
+        let chunk = chunk.ok_or(ChunkError::Missing {
+            index: index as u32,
+        })?;
+        monsters.extend(chunk.iter().map(MonsterData::from));
+    }
+    Ok(monsters)
+}
+
+/// Returns the ranges of `monsters` for each chunk.
+fn split(monsters: &[MonsterData], max: usize) -> Result<Vec<Range<usize>>, ChunkError> {
+    // The list table and the vector length prefix, and the root offset,
+    // which is included in each monster hint too.
+    let empty = layout::ROOT + layout::MONSTER_LIST + layout::VECTOR;
+    let mut ranges = Vec::new();
+    let (mut start, mut size) = (0, empty);
+    for (index, monster) in monsters.iter().enumerate() {
+        let monster_size = monster.serialized_size_hint() - layout::ROOT + layout::TABLE_OFFSET;
+        if empty + monster_size > max {
+            return Err(ChunkError::TooLarge {
+                index,
+                size: empty + monster_size,
+                max,
+            });
+        }
+        if size + monster_size > max {
+            ranges.push(start..index);
+            (start, size) = (index, empty);
+        }
+        size += monster_size;
+    }
+    ranges.push(start..monsters.len());
+    Ok(ranges)
+}
+
+fn build<'b>(
+    b: &mut FlatBufferBuilder<'b>,
+    monsters: &[MonsterData],
+    chunk_index: u32,
+    total_chunks: u32,
+) -> flatbuffers::WIPOffset<sample::MonsterList<'b>> {
+    let monsters: Vec<_> = monsters.iter().map(|monster| monster.build(b)).collect();
+    let monsters = b.create_vector(&monsters);
+    MonsterList::create(
+        b,
+        &MonsterListArgs {
+            monsters: Some(monsters),
+            chunk_index,
+            total_chunks,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::WeaponData;
+    use crate::FlatBufferBuilderPool;
+
+    const MAX_CHUNK_BYTES: usize = 2_048;
+
+    fn monsters() -> Vec<MonsterData> {
+        (0..500)
+            .map(|i| MonsterData {
+                name: format!("orc-{}", i),
+                hp: i as i16,
+                inventory: (0..i % 50).map(|item| item as u8).collect(),
+                weapons: (0..i % 3)
+                    .map(|j| WeaponData {
+                        name: format!("axe-{}", j),
+                        damage: j as i16,
+                    })
+                    .collect(),
+                ..Default::default()
+            })
+            .collect()
+    }
+    /// Deterministic shuffle, by the multiplicative step coprime with
+    /// `len`.
+    fn shuffle<T: Clone>(items: &[T]) -> Vec<T> {
+        let step = (7..).find(|step| gcd(*step, items.len()) == 1).unwrap();
+        (0..items.len())
+            .map(|i| items[i * step % items.len()].clone())
+            .collect()
+    }
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+    #[test]
+    fn round_trip() {
+        let pool = FlatBufferBuilderPool::new().build();
+        let monsters = monsters();
+        let chunks = chunk(&monsters, MAX_CHUNK_BYTES, &pool).unwrap();
+        assert!(chunks.len() > 10, "{}", chunks.len());
+        for (index, chunk) in chunks.iter().enumerate() {
+            assert!(chunk.as_ref().len() <= MAX_CHUNK_BYTES);
+            assert!(!chunk.is_empty());
+            assert_eq!(index as u32, chunk.chunk_index());
+            assert_eq!(chunks.len() as u32, chunk.total_chunks());
+            // Received over the wire.
+            assert_eq!(*chunk, ChunkBuf::new(chunk.as_ref().to_vec()).unwrap());
+        }
+        let shuffled = shuffle(&chunks);
+        assert_ne!(chunks, shuffled);
+        assert_eq!(monsters, reassemble(&shuffled).unwrap());
+    }
+    #[test]
+    fn empty() {
+        let pool = FlatBufferBuilderPool::new().build();
+        let chunks = chunk(&[], MAX_CHUNK_BYTES, &pool).unwrap();
+        assert_eq!(1, chunks.len());
+        assert!(chunks[0].is_empty());
+        assert_eq!(Ok(vec![]), reassemble(&chunks));
+    }
+    #[test]
+    fn errors() {
+        struct Test {
+            name: &'static str,
+            data: fn(Vec<ChunkBuf>) -> Vec<ChunkBuf>,
+            want: ChunkError,
+        }
+        let tests = [
+            Test {
+                name: "no chunks",
+                data: |_| vec![],
+                want: ChunkError::Missing { index: 0 },
+            },
+            Test {
+                name: "missing",
+                data: |mut chunks| {
+                    chunks.remove(1);
+                    chunks
+                },
+                want: ChunkError::Missing { index: 1 },
+            },
+            Test {
+                name: "missing last",
+                data: |mut chunks| {
+                    chunks.pop();
+                    chunks
+                },
+                want: ChunkError::Missing { index: 2 },
+            },
+            Test {
+                name: "duplicate",
+                data: |mut chunks| {
+                    chunks[0] = chunks[1].clone();
+                    chunks
+                },
+                want: ChunkError::Duplicate { index: 1 },
+            },
+            Test {
+                name: "other collection",
+                data: |mut chunks| {
+                    let pool = FlatBufferBuilderPool::new().build();
+                    let other = chunk(&[MonsterData::default()], MAX_CHUNK_BYTES, &pool);
+                    chunks.extend(other.unwrap());
+                    chunks
+                },
+                want: ChunkError::TotalMismatch { total: 1, want: 3 },
+            },
+        ];
+        // 10 monsters for each of the 3 chunks.
+        let pool = FlatBufferBuilderPool::new().build();
+        let monsters = vec![MonsterData::default(); 30];
+        let size = monsters[0].serialized_size_hint() - layout::ROOT + layout::TABLE_OFFSET;
+        let max = layout::ROOT + layout::MONSTER_LIST + layout::VECTOR + 10 * size;
+        for t in &tests {
+            let chunks = chunk(&monsters, max, &pool).unwrap();
+            assert_eq!(3, chunks.len(), "{}", t.name);
+            assert_eq!(
+                Err(t.want.clone()),
+                reassemble(&(t.data)(chunks)),
+                "{}",
+                t.name
+            );
+        }
+    }
+    #[test]
+    fn too_large() {
+        let pool = FlatBufferBuilderPool::new().build();
+        let mut monsters = monsters();
+        monsters[42].inventory = vec![0; MAX_CHUNK_BYTES];
+        match chunk(&monsters, MAX_CHUNK_BYTES, &pool) {
+            Err(ChunkError::TooLarge { index, size, max }) => {
+                assert_eq!(42, index);
+                assert!(size > max);
+                assert_eq!(MAX_CHUNK_BYTES, max);
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+        let err = ChunkBuf::new(vec![0xff; 3]).unwrap_err();
+        assert!(matches!(err, ChunkError::Invalid(_)), "{:?}", err);
+    }
+}
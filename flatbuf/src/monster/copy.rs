@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Copy of the `Monster` table from one buffer into another.
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use super::create_path;
+use crate::model::my_game::sample::{self, Color, Equipment, Vec3, Weapon, WeaponArgs};
+use crate::view::MonsterView;
+use crate::FlatBufferBuilderPool;
+
+/// Deep-copy the `src` table into `b`, straight from the borrowed fields
+/// without the `MonsterData` round trip.
+///
+/// The copied field keeps its presence, the same as [`merge`].  The
+/// `equipped` union points to the copy of the same weapon table, or to
+/// the first equal weapon, and is dropped otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::{monster, Monster, MonsterView};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().name("orc").hp(80).weapon("axe", 5).build(&mut b);
+/// b.finish(orc, None);
+/// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+///
+/// let mut dst = FlatBufferBuilder::new();
+/// let copy = monster::copy_into(orc, &mut dst);
+/// dst.finish(copy, None);
+/// let copy = MonsterView::from_bytes(dst.finished_data()).unwrap();
+/// assert_eq!(orc.name(), copy.name());
+/// assert_eq!(80, copy.hp());
+/// ```
+/// [`merge`]: fn.merge.html
+pub fn copy_into<'b>(
+    src: MonsterView<'_>,
+    b: &mut FlatBufferBuilder<'b>,
+) -> WIPOffset<sample::Monster<'b>> {
+    let has = |voffset| src.field_pos(voffset).is_some();
+
+    let equipped = src.equipped().and_then(|equipped| {
+        src.weapons()
+            .position(|weapon| weapon.is_same(&equipped))
+            .or_else(|| src.weapons().position(|weapon| weapon == equipped))
+    });
+    let weapons = if has(sample::Monster::VT_WEAPONS) {
+        let weapons: Vec<_> = src
+            .weapons()
+            .map(|weapon| {
+                let name = weapon.name().map(|name| b.create_string(name));
+                Weapon::create(
+                    b,
+                    &WeaponArgs {
+                        name,
+                        damage: weapon.damage(),
+                    },
+                )
+            })
+            .collect();
+        Some(weapons)
+    } else {
+        None
+    };
+    let equipped = equipped.and_then(|i| weapons.as_ref().map(|weapons| weapons[i]));
+    let weapons = weapons.map(|weapons| b.create_vector(&weapons));
+    let name = src.name().map(|name| b.create_string(name));
+    let inventory = src.inventory().map(|inventory| b.create_vector(inventory));
+    let path = src.path_slice().map(|path| create_path(b, path));
+
+    // Same field order as `MonsterBuilder::build`.
+    let start = b.start_table();
+    if let Some(path) = path {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_PATH, path);
+    }
+    if let Some(equipped) = equipped {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_EQUIPPED, equipped.as_union_value());
+    }
+    if let Some(weapons) = weapons {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_WEAPONS, weapons);
+    }
+    if let Some(inventory) = inventory {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_INVENTORY, inventory);
+    }
+    if let Some(name) = name {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_NAME, name);
+    }
+    if let Some(pos) = &src.pos() {
+        b.push_slot_always::<&Vec3>(sample::Monster::VT_POS, pos);
+    }
+    if src.has_hp() {
+        b.push_slot_always::<i16>(sample::Monster::VT_HP, src.hp());
+    }
+    if src.has_mana() {
+        b.push_slot_always::<i16>(sample::Monster::VT_MANA, src.mana());
+    }
+    if equipped.is_some() {
+        b.push_slot_always::<Equipment>(sample::Monster::VT_EQUIPPED_TYPE, Equipment::Weapon);
+    }
+    if src.has_color() {
+        b.push_slot_always::<Color>(sample::Monster::VT_COLOR, src.color());
+    }
+    let monster = b.end_table(start);
+    WIPOffset::new(monster.value())
+}
+
+impl<'a> sample::MonsterList<'a> {
+    /// Copy the monsters matching `pred` into the new `MonsterList`
+    /// buffer, with the builder from the global pool.
+    ///
+    /// It returns the raw buffer, as [`MonsterBuf`] holds the single
+    /// `Monster` root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::MonsterList;
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let monsters: Vec<_> = (0..4)
+    ///     .map(|hp| MonsterData {
+    ///         name: String::from("orc"),
+    ///         hp,
+    ///         ..Default::default()
+    ///     })
+    ///     .collect();
+    /// let mut b = FlatBufferBuilder::new();
+    /// let list = MonsterData::build_list(&monsters, &mut b);
+    /// b.finish(list, None);
+    /// let list = flatbuffers::root::<MonsterList>(b.finished_data()).unwrap();
+    ///
+    /// let alive = list.filter(|monster| monster.hp() > 1);
+    /// let alive = flatbuffers::root::<MonsterList>(&alive).unwrap();
+    /// assert_eq!(2, alive.monsters().unwrap().len());
+    /// ```
+    /// [`monsterbuf`]: ../../../buf/struct.MonsterBuf.html
+    pub fn filter<P>(&self, mut pred: P) -> Vec<u8>
+    where
+        P: FnMut(&MonsterView<'a>) -> bool,
+    {
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let monsters: Vec<_> = self
+            .monsters()
+            .into_iter()
+            .flatten()
+            .map(MonsterView::from)
+            .filter(|monster| pred(monster))
+            .map(|monster| copy_into(monster, &mut b))
+            .collect();
+        let monsters = b.create_vector(&monsters);
+        let list = sample::MonsterList::create(
+            &mut b,
+            &sample::MonsterListArgs {
+                monsters: Some(monsters),
+                ..Default::default()
+            },
+        );
+        b.finish(list, None);
+        b.finished_data().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::MonsterList;
+    use crate::monster::{MonsterData, WeaponData};
+    use crate::{Monster, MonsterBuf};
+
+    fn orc() -> MonsterData {
+        MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            mana: 20,
+            hp: 80,
+            inventory: vec![1, 2],
+            color: Color::Red,
+            weapons: vec![
+                WeaponData {
+                    name: String::from("sword"),
+                    damage: 3,
+                },
+                WeaponData {
+                    name: String::from("axe"),
+                    damage: 5,
+                },
+            ],
+            equipped: Some(1),
+            path: vec![Vec3::new(0.5, 0.0, -0.5), Vec3::new(1.5, 1.0, 0.5)],
+        }
+    }
+    fn copied(src: &[u8]) -> MonsterBuf {
+        let mut b = FlatBufferBuilder::new();
+        let copy = copy_into(MonsterView::from_bytes(src).unwrap(), &mut b);
+        b.finish(copy, None);
+        MonsterBuf::new(b.finished_data().to_vec()).unwrap()
+    }
+    #[test]
+    fn copy() {
+        struct Test {
+            name: &'static str,
+            data: MonsterBuf,
+        }
+        let explicit = {
+            let mut b = FlatBufferBuilder::new();
+            let orc = Monster::builder()
+                .name("orc")
+                .hp_explicit(100)
+                .mana_explicit(150)
+                .color_explicit(Color::Blue)
+                .build(&mut b);
+            b.finish(orc, None);
+            MonsterBuf::new(b.finished_data().to_vec()).unwrap()
+        };
+        let tests = [
+            Test {
+                name: "all fields",
+                data: Monster::build_pooled(&orc()),
+            },
+            Test {
+                name: "defaults",
+                data: Monster::build_pooled(&MonsterData::default()),
+            },
+            Test {
+                name: "explicit defaults",
+                data: explicit,
+            },
+        ];
+        for t in &tests {
+            let copy = copied(t.data.as_ref());
+            assert_eq!(Ok(()), copy.view().validate(), "{}", t.name);
+            assert_eq!(
+                MonsterData::from(t.data.view()),
+                MonsterData::from(copy.view()),
+                "{}",
+                t.name
+            );
+            // Structurally equal, down to the field presence.
+            let (src, copy) = (t.data.view(), copy.view());
+            assert_eq!(src.has_hp(), copy.has_hp(), "{}", t.name);
+            assert_eq!(src.has_mana(), copy.has_mana(), "{}", t.name);
+            assert_eq!(src.has_color(), copy.has_color(), "{}", t.name);
+            for voffset in &[
+                sample::Monster::VT_WEAPONS,
+                sample::Monster::VT_PATH,
+                sample::Monster::VT_INVENTORY,
+            ] {
+                assert_eq!(
+                    src.field_pos(*voffset).is_some(),
+                    copy.field_pos(*voffset).is_some(),
+                    "{}: {}",
+                    t.name,
+                    voffset
+                );
+            }
+        }
+    }
+    #[test]
+    fn filter() {
+        let monsters: Vec<_> = (0..10)
+            .map(|i| MonsterData {
+                name: format!("orc-{}", i),
+                hp: i * 10,
+                ..orc()
+            })
+            .collect();
+        let mut b = FlatBufferBuilder::new();
+        let list = MonsterData::build_list(&monsters, &mut b);
+        b.finish(list, None);
+        let list = flatbuffers::root::<MonsterList>(b.finished_data()).unwrap();
+
+        let filtered = list.filter(|monster| monster.hp() % 20 == 0);
+        let filtered = flatbuffers::root::<MonsterList>(&filtered).unwrap();
+        let got: Vec<_> = filtered
+            .monsters()
+            .unwrap()
+            .iter()
+            .map(|monster| MonsterData::from(MonsterView::from(monster)))
+            .collect();
+        let want: Vec<_> = monsters.into_iter().step_by(2).collect();
+        assert_eq!(want, got);
+    }
+}
@@ -0,0 +1,439 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Owned `Monster` data.
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use super::{size_hint, HasName, Monster, MonsterBuilder, DEFAULT_NAME};
+use crate::model::my_game::sample::{self, Color, MonsterArgs, Vec3};
+use crate::view::MonsterView;
+
+/// Owned `Monster` data, which is independent from the buffer layout.
+///
+/// The empty `inventory` and `path` are not serialized.
+///
+/// With the `serde` feature, `pos` and `path` points are (de)serialized
+/// as `{ x, y, z }` maps, `color` as the case-insensitive variant name,
+/// e.g. `"Red"` or `"red"`, and the absent fields fall back to the schema
+/// defaults.
+///
+/// The equality, the ordering and the hash are on the canonical form,
+/// e.g. regardless of the order of the `weapons`, see
+/// [`canonical_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+/// use flatbuf_tutorial::MonsterView;
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let data = MonsterData {
+///     name: String::from("orc"),
+///     weapons: vec![WeaponData {
+///         name: String::from("axe"),
+///         damage: 5,
+///     }],
+///     equipped: Some(0),
+///     ..Default::default()
+/// };
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = data.build(&mut b);
+/// b.finish(orc, None);
+/// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+/// assert_eq!(data, orc.into());
+/// ```
+/// [`canonical_bytes`]: struct.MonsterData.html#method.canonical_bytes
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct MonsterData {
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(with = "serde_impl::pos"))]
+    pub pos: Option<Vec3>,
+    pub mana: i16,
+    pub hp: i16,
+    pub inventory: Vec<u8>,
+    pub color: Color,
+    pub weapons: Vec<WeaponData>,
+    /// Index of the equipped weapon in `weapons`.
+    pub equipped: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_impl::path"))]
+    pub path: Vec<Vec3>,
+}
+
+/// Owned `Weapon` data.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct WeaponData {
+    pub name: String,
+    pub damage: i16,
+}
+
+/// Same as [`Monster::default_buf`], the `name` is `"unknown"` and the
+/// rest are the schema defaults.
+///
+/// [`monster::default_buf`]: struct.Monster.html#method.default_buf
+impl Default for MonsterData {
+    fn default() -> Self {
+        let args = MonsterArgs::default();
+        Self {
+            name: String::from(DEFAULT_NAME),
+            pos: None,
+            mana: args.mana,
+            hp: args.hp,
+            inventory: Vec::new(),
+            color: args.color,
+            weapons: Vec::new(),
+            equipped: None,
+            path: Vec::new(),
+        }
+    }
+}
+
+impl MonsterData {
+    /// Returns the [`MonsterBuilder`] filled with the data.
+    ///
+    /// [`monsterbuilder`]: struct.MonsterBuilder.html
+    pub fn builder(&self) -> MonsterBuilder<'_, HasName> {
+        let mut builder = Monster::builder()
+            .mana(self.mana)
+            .hp(self.hp)
+            .color(self.color);
+        if let Some(pos) = self.pos {
+            builder = builder.pos(pos);
+        }
+        if !self.inventory.is_empty() {
+            builder = builder.inventory(&self.inventory);
+        }
+        for weapon in &self.weapons {
+            builder = builder.weapon(&weapon.name, weapon.damage);
+        }
+        if let Some(index) = self.equipped {
+            builder = builder.equipped(index);
+        }
+        if !self.path.is_empty() {
+            builder = builder.path(&self.path);
+        }
+        builder.name(&self.name)
+    }
+    /// Build the `Monster` table with the provided builder.
+    pub fn build<'b>(&self, b: &mut FlatBufferBuilder<'b>) -> WIPOffset<sample::Monster<'b>> {
+        self.builder().build(b)
+    }
+    /// Build the `MonsterList` table of `monsters` with the provided
+    /// builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::MonsterList;
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let monsters = vec![MonsterData::default(); 3];
+    /// let mut b = FlatBufferBuilder::new();
+    /// let list = MonsterData::build_list(&monsters, &mut b);
+    /// b.finish(list, None);
+    /// let list = flatbuffers::root::<MonsterList>(b.finished_data()).unwrap();
+    /// assert_eq!(3, list.monsters().unwrap().len());
+    /// ```
+    pub fn build_list<'b>(
+        monsters: &[MonsterData],
+        b: &mut FlatBufferBuilder<'b>,
+    ) -> WIPOffset<sample::MonsterList<'b>> {
+        let monsters: Vec<_> = monsters.iter().map(|monster| monster.build(b)).collect();
+        let monsters = b.create_vector(&monsters);
+        sample::MonsterList::create(
+            b,
+            &sample::MonsterListArgs {
+                monsters: Some(monsters),
+                ..Default::default()
+            },
+        )
+    }
+    /// Returns the conservative upper bound of the finished buffer size,
+    /// to pre-size the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     inventory: vec![1, 2, 3],
+    ///     ..Default::default()
+    /// };
+    /// let mut b = FlatBufferBuilder::with_capacity(data.serialized_size_hint());
+    /// let orc = data.build(&mut b);
+    /// b.finish(orc, None);
+    /// assert!(b.finished_data().len() <= data.serialized_size_hint());
+    /// ```
+    pub fn serialized_size_hint(&self) -> usize {
+        let non_empty = |n: usize| if n == 0 { None } else { Some(n) };
+        size_hint(
+            Some(&self.name),
+            self.weapons.iter().map(|weapon| weapon.name.as_str()),
+            non_empty(self.inventory.len()),
+            non_empty(self.path.len()),
+        )
+    }
+}
+
+impl<'a> From<MonsterView<'a>> for MonsterData {
+    fn from(view: MonsterView<'a>) -> Self {
+        let weapons: Vec<_> = view.weapons().collect();
+        let equipped = view
+            .equipped()
+            .and_then(|equipped| weapons.iter().position(|weapon| weapon.is_same(&equipped)));
+        Self {
+            name: view.name().unwrap_or_default().to_string(),
+            pos: view.pos(),
+            mana: view.mana(),
+            hp: view.hp(),
+            inventory: view.inventory().unwrap_or_default().to_vec(),
+            color: view.color(),
+            weapons: weapons
+                .iter()
+                .map(|weapon| WeaponData {
+                    name: weapon.name().unwrap_or_default().to_string(),
+                    damage: weapon.damage(),
+                })
+                .collect(),
+            equipped,
+            path: view.path().collect(),
+        }
+    }
+}
+
+/// `serde(with)` adapters for the generated types.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::my_game::sample::Vec3;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Point {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    impl From<&Vec3> for Point {
+        fn from(v: &Vec3) -> Self {
+            Self {
+                x: v.x(),
+                y: v.y(),
+                z: v.z(),
+            }
+        }
+    }
+
+    impl From<Point> for Vec3 {
+        fn from(p: Point) -> Self {
+            Vec3::new(p.x, p.y, p.z)
+        }
+    }
+
+    pub mod pos {
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        use super::Point;
+        use crate::model::my_game::sample::Vec3;
+
+        pub fn serialize<S: Serializer>(pos: &Option<Vec3>, s: S) -> Result<S::Ok, S::Error> {
+            pos.as_ref().map(Point::from).serialize(s)
+        }
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec3>, D::Error> {
+            Ok(Option::<Point>::deserialize(d)?.map(Vec3::from))
+        }
+    }
+
+    pub mod path {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::Point;
+        use crate::model::my_game::sample::Vec3;
+
+        pub fn serialize<S: Serializer>(path: &[Vec3], s: S) -> Result<S::Ok, S::Error> {
+            s.collect_seq(path.iter().map(Point::from))
+        }
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Vec3>, D::Error> {
+            let path = Vec::<Point>::deserialize(d)?;
+            Ok(path.into_iter().map(Vec3::from).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlatBufferBuilderPool;
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn vec3()(x in any::<f32>(), y in any::<f32>(), z in any::<f32>()) -> Vec3 {
+            Vec3::new(x, y, z)
+        }
+    }
+    prop_compose! {
+        fn weapon()(name in ".{0,32}", damage in any::<i16>()) -> WeaponData {
+            WeaponData { name, damage }
+        }
+    }
+    prop_compose! {
+        /// `MonsterData` generator.
+        pub(crate) fn monster_data()(
+            name in ".{0,64}",
+            pos in proptest::option::of(vec3()),
+            mana in any::<i16>(),
+            hp in any::<i16>(),
+            inventory in proptest::collection::vec(any::<u8>(), 0..128),
+            color in 0i8..=2,
+            weapons in proptest::collection::vec(weapon(), 0..8),
+            equipped in proptest::option::of(0usize..8),
+            path in proptest::collection::vec(vec3(), 0..16),
+        ) -> MonsterData {
+            MonsterData {
+                name,
+                pos,
+                mana,
+                hp,
+                inventory,
+                color: Color(color),
+                weapons,
+                equipped,
+                path,
+            }
+        }
+    }
+
+    fn finished_len(data: &MonsterData, force_defaults: bool) -> usize {
+        let mut b = FlatBufferBuilder::new();
+        let monster = data.builder().force_defaults(force_defaults).build(&mut b);
+        b.finish(monster, None);
+        b.finished_data().len()
+    }
+
+    proptest! {
+        #[test]
+        fn size_hint_upper_bound(data in monster_data(), force_defaults in any::<bool>()) {
+            let hint = data.serialized_size_hint();
+            prop_assert!(finished_len(&data, force_defaults) <= hint);
+            prop_assert_eq!(hint, data.builder().serialized_size_hint());
+        }
+        #[test]
+        fn serialize_pooled_round_trip(data in monster_data()) {
+            let pool = FlatBufferBuilderPool::new().build();
+            let buf = super::super::serialize_pooled(&data, &pool);
+            let view = MonsterView::from_bytes(&buf).unwrap();
+            prop_assert_eq!(Some(data.name.as_str()), view.name());
+            prop_assert_eq!(data.weapons.len(), view.weapons().count());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    proptest! {
+        #[test]
+        fn serialize_view_as_data(data in monster_data()) {
+            let buf = Monster::build_pooled(&data);
+            let want = serde_json::to_vec(&MonsterData::from(buf.view())).unwrap();
+            prop_assert_eq!(want, serde_json::to_vec(&buf.view()).unwrap());
+        }
+    }
+
+    #[test]
+    fn size_hint_typical() {
+        let weapon = |name: &str, damage| WeaponData {
+            name: name.to_string(),
+            damage,
+        };
+        struct Test {
+            name: &'static str,
+            data: MonsterData,
+        }
+        let tests = [
+            Test {
+                name: "tutorial orc",
+                data: MonsterData {
+                    name: String::from("orc"),
+                    pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+                    hp: 300,
+                    inventory: (0..10).collect(),
+                    color: Color::Red,
+                    weapons: vec![weapon("Sword", 3), weapon("Axe", 5)],
+                    equipped: Some(1),
+                    path: vec![Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)],
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "large inventory",
+                data: MonsterData {
+                    name: String::from("godzilla"),
+                    inventory: vec![0; 1024],
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "many weapons",
+                data: MonsterData {
+                    name: String::from("minilla"),
+                    weapons: (0..32).map(|i| weapon("weapon", i)).collect(),
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "long path",
+                data: MonsterData {
+                    name: String::from("wanderer"),
+                    path: vec![Vec3::new(0.0, 0.0, 0.0); 100],
+                    ..Default::default()
+                },
+            },
+        ];
+        for t in &tests {
+            let got = finished_len(&t.data, false);
+            let hint = t.data.serialized_size_hint();
+            assert!(got <= hint, "{}: {} > {}", t.name, got, hint);
+            assert!(hint <= got * 2, "{}: {} > 2 * {}", t.name, hint, got);
+        }
+    }
+    #[test]
+    fn from_view() {
+        let data = MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            mana: 10,
+            hp: 20,
+            inventory: vec![1, 2],
+            color: Color::Green,
+            weapons: vec![
+                WeaponData {
+                    name: String::from("axe"),
+                    damage: 5,
+                },
+                WeaponData {
+                    name: String::from("sword"),
+                    damage: 3,
+                },
+            ],
+            equipped: Some(1),
+            path: vec![Vec3::new(4.0, 5.0, 6.0)],
+        };
+        let mut b = FlatBufferBuilder::new();
+        let monster = data.build(&mut b);
+        b.finish(monster, None);
+        let view = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert_eq!(data, MonsterData::from(view));
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Read-modify-write edits of the `Monster` buffer.
+use super::{Monster, MonsterData, WeaponData};
+use crate::error::EditError;
+use crate::{FlatBufferBuilderLocalPool, MonsterBuf};
+
+/// Unpack `buf` for the edits.  `buf` itself is untouched.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{self, MonsterData};
+/// use flatbuf_tutorial::{FlatBufferBuilderPool, Monster};
+///
+/// let data = MonsterData {
+///     name: String::from("orc"),
+///     ..Default::default()
+/// };
+/// let orc = Monster::build_pooled(&data);
+/// let mut editor = monster::edit(&orc);
+/// editor.add_weapon("axe", 5);
+/// editor.equip_weapon_by_name("axe").unwrap();
+/// let pool = FlatBufferBuilderPool::new().build();
+/// let armed = editor.commit(&pool);
+/// assert_eq!(Some("axe"), armed.view().equipped().and_then(|axe| axe.name()));
+/// assert!(orc.view().equipped().is_none());
+/// ```
+pub fn edit(buf: &MonsterBuf) -> MonsterEditor {
+    MonsterEditor(MonsterData::from(buf.view()))
+}
+
+/// Owned `Monster` under the edits, returned by [`edit`].
+///
+/// It holds the [`MonsterData`], which is the owned counterpart of the
+/// generated `Monster` table in this crate.
+///
+/// [`edit`]: fn.edit.html
+/// [`monsterdata`]: struct.MonsterData.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonsterEditor(MonsterData);
+
+impl MonsterEditor {
+    /// Returns the data under the edits.
+    pub fn data(&self) -> &MonsterData {
+        &self.0
+    }
+    /// Equip the first weapon named `name`.
+    pub fn equip_weapon_by_name(&mut self, name: &str) -> Result<(), EditError> {
+        match self.0.weapons.iter().position(|weapon| weapon.name == name) {
+            Some(index) => {
+                self.0.equipped = Some(index);
+                Ok(())
+            }
+            None => Err(EditError::UnknownWeapon(name.to_string())),
+        }
+    }
+    /// Append the weapon.  It doesn't change the equipped weapon.
+    pub fn add_weapon(&mut self, name: &str, damage: i16) {
+        self.0.weapons.push(WeaponData {
+            name: name.to_string(),
+            damage,
+        });
+    }
+    /// Remove the weapon at `index` and returns it.  The equipped weapon
+    /// is unequipped if it's removed.
+    pub fn remove_weapon(&mut self, index: usize) -> Result<WeaponData, EditError> {
+        let len = self.0.weapons.len();
+        if index >= len {
+            return Err(EditError::WeaponOutOfRange { index, len });
+        }
+        self.0.equipped = match self.0.equipped {
+            Some(equipped) if equipped == index => None,
+            Some(equipped) if equipped > index => Some(equipped - 1),
+            equipped => equipped,
+        };
+        Ok(self.0.weapons.remove(index))
+    }
+    pub fn set_hp(&mut self, hp: i16) {
+        self.0.hp = hp;
+    }
+    /// Repack the edited monster with the builder from the local `pool`.
+    pub fn commit(&self, pool: &FlatBufferBuilderLocalPool) -> MonsterBuf {
+        Monster::build_with(pool, &self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::Color;
+    use crate::FlatBufferBuilderPool;
+
+    fn orc() -> MonsterData {
+        MonsterData {
+            name: String::from("orc"),
+            hp: 80,
+            color: Color::Red,
+            inventory: vec![1, 2, 3],
+            weapons: vec![
+                WeaponData {
+                    name: String::from("sword"),
+                    damage: 3,
+                },
+                WeaponData {
+                    name: String::from("axe"),
+                    damage: 5,
+                },
+            ],
+            equipped: Some(1),
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn edits() {
+        let pool = FlatBufferBuilderPool::new().build();
+        let buf = Monster::build_with(&pool, &orc());
+        let original = buf.clone();
+
+        let mut editor = edit(&buf);
+        editor.add_weapon("bow", 2);
+        editor.equip_weapon_by_name("bow").unwrap();
+        assert_eq!(
+            "sword",
+            editor.remove_weapon(0).unwrap().name,
+            "remove unequipped"
+        );
+        editor.set_hp(50);
+        let got = editor.commit(&pool);
+
+        let want = MonsterData {
+            hp: 50,
+            weapons: vec![
+                WeaponData {
+                    name: String::from("axe"),
+                    damage: 5,
+                },
+                WeaponData {
+                    name: String::from("bow"),
+                    damage: 2,
+                },
+            ],
+            equipped: Some(1),
+            ..orc()
+        };
+        assert_eq!(want, MonsterData::from(got.view()));
+        assert_eq!(Ok(()), got.view().validate());
+        assert_eq!(original, buf);
+        assert_eq!(orc(), MonsterData::from(buf.view()));
+    }
+    #[test]
+    fn remove_weapon() {
+        struct Test {
+            name: &'static str,
+            data: usize,
+            want: Result<Option<usize>, EditError>,
+        }
+        let tests = [
+            Test {
+                name: "before equipped",
+                data: 0,
+                want: Ok(Some(0)),
+            },
+            Test {
+                name: "equipped",
+                data: 1,
+                want: Ok(None),
+            },
+            Test {
+                name: "out of range",
+                data: 2,
+                want: Err(EditError::WeaponOutOfRange { index: 2, len: 2 }),
+            },
+        ];
+        let buf = Monster::build_pooled(&orc());
+        for t in &tests {
+            let mut editor = edit(&buf);
+            let got = editor.remove_weapon(t.data).map(|_| editor.data().equipped);
+            assert_eq!(t.want, got, "{}", t.name);
+        }
+    }
+    #[test]
+    fn equip_unknown_weapon() {
+        let mut editor = edit(&Monster::build_pooled(&orc()));
+        assert_eq!(
+            Err(EditError::UnknownWeapon(String::from("bow"))),
+            editor.equip_weapon_by_name("bow")
+        );
+        assert_eq!(Some(1), editor.data().equipped);
+    }
+}
@@ -0,0 +1,280 @@
+// SPDX-License-Identifier: GPL-2.0
+//! `Monster` fixtures in YAML or TOML.
+//!
+//! The fixture is the [`MonsterData`] in the text format, e.g.
+//!
+//! ```yaml
+//! name: orc
+//! hp: 300
+//! pos: { x: 1.0, y: 2.0, z: 3.0 }
+//! color: Red
+//! weapons:
+//!   - { name: Sword, damage: 3 }
+//!   - { name: Axe, damage: 5 }
+//! equipped: 1
+//! ```
+//! [`monsterdata`]: ../struct.MonsterData.html
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use flatbuffers::FlatBufferBuilder;
+
+use super::MonsterData;
+use crate::error::{FixtureError, Location};
+use crate::MonsterBuf;
+
+/// Fixture format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl Format {
+    /// Returns the format of the `path` by the extension, or `None` for
+    /// the unsupported one.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Some(Format::Yaml),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Build the `Monster` buffer from the YAML fixture.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::from_yaml_str;
+/// use flatbuf_tutorial::MonsterView;
+///
+/// let buf = from_yaml_str("name: orc\nhp: 80\n").unwrap();
+/// let orc = MonsterView::from_bytes(&buf).unwrap();
+/// assert_eq!(Some("orc"), orc.name());
+/// assert_eq!(80, orc.hp());
+///
+/// let err = from_yaml_str("name: orc\nhp: lots\n").unwrap_err();
+/// assert_eq!(2, err.location().unwrap().line);
+/// ```
+#[cfg(feature = "yaml")]
+pub fn from_yaml_str(s: &str) -> Result<Vec<u8>, FixtureError> {
+    let data: MonsterData = serde_yaml::from_str(s).map_err(|err| {
+        let location = err.location().map(|loc| Location {
+            line: loc.line(),
+            column: loc.column(),
+        });
+        // Drop the location suffix, which is kept in `location`.
+        let mut message = err.to_string();
+        if let Some(loc) = location {
+            let suffix = format!(" at line {} column {}", loc.line, loc.column);
+            if message.ends_with(&suffix) {
+                message.truncate(message.len() - suffix.len());
+            }
+        }
+        FixtureError::Parse { location, message }
+    })?;
+    Ok(serialize(&data))
+}
+
+/// Build the `Monster` buffer from the TOML fixture.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::from_toml_str;
+/// use flatbuf_tutorial::MonsterView;
+///
+/// let buf = from_toml_str("name = \"orc\"\nhp = 80\n").unwrap();
+/// let orc = MonsterView::from_bytes(&buf).unwrap();
+/// assert_eq!(Some("orc"), orc.name());
+/// assert_eq!(80, orc.hp());
+///
+/// let err = from_toml_str("name = \"orc\"\nhp = \"lots\"\n").unwrap_err();
+/// assert_eq!(2, err.location().unwrap().line);
+/// ```
+#[cfg(feature = "toml")]
+pub fn from_toml_str(s: &str) -> Result<Vec<u8>, FixtureError> {
+    let data: MonsterData = toml::from_str(s).map_err(|err| FixtureError::Parse {
+        location: err.span().map(|span| location(s, span.start)),
+        message: err.message().to_string(),
+    })?;
+    Ok(serialize(&data))
+}
+
+/// Build the `Monster` buffer from the `format` fixture read from
+/// the `reader`.
+pub fn from_reader<R: Read>(format: Format, mut reader: R) -> Result<Vec<u8>, FixtureError> {
+    let mut s = String::new();
+    reader.read_to_string(&mut s)?;
+    match format {
+        #[cfg(feature = "yaml")]
+        Format::Yaml => from_yaml_str(&s),
+        #[cfg(feature = "toml")]
+        Format::Toml => from_toml_str(&s),
+    }
+}
+
+/// Load all the `.yaml`, `.yml` and `.toml` fixtures in the directory,
+/// in the file name order.  Other files and sub-directories are ignored.
+///
+/// The error found in the fixture is wrapped in
+/// [`FixtureError::File`] with the path.
+///
+/// [`fixtureerror::file`]: ../../error/enum.FixtureError.html#variant.File
+pub fn load_dir<P: AsRef<Path>>(path: P) -> Result<Vec<MonsterBuf>, FixtureError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_file() {
+            if let Some(format) = Format::from_path(&path) {
+                paths.push((path, format));
+            }
+        }
+    }
+    paths.sort_by(|a, b| a.0.cmp(&b.0));
+    paths
+        .into_iter()
+        .map(|(path, format)| {
+            File::open(&path)
+                .map_err(FixtureError::from)
+                .and_then(|file| from_reader(format, file))
+                .and_then(|buf| Ok(MonsterBuf::new(buf)?))
+                .map_err(|err| FixtureError::File(path, Box::new(err)))
+        })
+        .collect()
+}
+
+fn serialize(data: &MonsterData) -> Vec<u8> {
+    let mut b = FlatBufferBuilder::with_capacity(data.serialized_size_hint());
+    let monster = data.build(&mut b);
+    b.finish(monster, None);
+    b.finished_data().to_vec()
+}
+
+/// Returns the one-based location of the byte `offset` in `s`.
+#[cfg(feature = "toml")]
+fn location(s: &str, offset: usize) -> Location {
+    let before = &s[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Location {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_errors() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: Option<(usize, usize)>,
+        }
+        let tests = [
+            Test {
+                name: "wrong type",
+                data: "name: orc\nhp: lots\n",
+                want: Some((2, 5)),
+            },
+            Test {
+                name: "unknown field",
+                data: "name: orc\n  \nhpp: 1\n",
+                want: Some((3, 1)),
+            },
+            Test {
+                name: "unknown color",
+                data: "name: orc\ncolor: Purple\n",
+                want: Some((2, 8)),
+            },
+            Test {
+                name: "sequence name",
+                data: "name: [orc]\n",
+                want: Some((1, 7)),
+            },
+            Test {
+                name: "malformed yaml",
+                data: "name: orc\nweapons: [\n",
+                want: Some((3, 1)),
+            },
+        ];
+        for t in &tests {
+            match from_yaml_str(t.data) {
+                Err(FixtureError::Parse { location, message }) => {
+                    let got = location.map(|loc| (loc.line, loc.column));
+                    assert_eq!(t.want, got, "{}: {}", t.name, message);
+                }
+                got => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_errors() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: Option<(usize, usize)>,
+        }
+        let tests = [
+            Test {
+                name: "wrong type",
+                data: "name = \"orc\"\nhp = \"lots\"\n",
+                want: Some((2, 6)),
+            },
+            Test {
+                name: "unknown field",
+                data: "name = \"orc\"\nhpp = 1\n",
+                want: Some((2, 1)),
+            },
+            Test {
+                name: "unknown color",
+                data: "name = \"orc\"\ncolor = \"Purple\"\n",
+                want: Some((2, 9)),
+            },
+            Test {
+                name: "malformed toml",
+                data: "name = \"orc\nhp = 1\n",
+                want: Some((1, 12)),
+            },
+        ];
+        for t in &tests {
+            match from_toml_str(t.data) {
+                Err(FixtureError::Parse { location, message }) => {
+                    let got = location.map(|loc| (loc.line, loc.column));
+                    assert_eq!(t.want, got, "{}: {}", t.name, message);
+                }
+                got => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[cfg(all(feature = "yaml", feature = "toml"))]
+    #[test]
+    fn yaml_and_toml_agree() {
+        use crate::MonsterView;
+
+        let yaml = from_yaml_str(
+            "name: orc\npos: { x: 1.0, y: 2.0, z: 3.0 }\ncolor: Green\n\
+             weapons:\n  - { name: Axe, damage: 5 }\nequipped: 0\n",
+        )
+        .unwrap();
+        let toml = from_toml_str(
+            "name = \"orc\"\npos = { x = 1.0, y = 2.0, z = 3.0 }\ncolor = \"Green\"\n\
+             equipped = 0\nweapons = [{ name = \"Axe\", damage = 5 }]\n",
+        )
+        .unwrap();
+        let yaml = MonsterData::from(MonsterView::from_bytes(&yaml).unwrap());
+        let toml = MonsterData::from(MonsterView::from_bytes(&toml).unwrap());
+        assert_eq!(yaml, toml);
+    }
+}
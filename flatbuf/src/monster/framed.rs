@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Size prefixed `Monster` buffers, for the stream framing.
+use flatbuffers::{FlatBufferBuilder, SIZE_UOFFSET};
+
+use super::Monster;
+use crate::error::{MonsterError, SizePrefixedError};
+use crate::model::my_game::sample;
+use crate::view::MonsterView;
+
+/// Build the monster of `name`, `hp` and `mana`, and finish it with the
+/// little endian `u32` size prefix.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster;
+///
+/// let orc = monster::build_size_prefixed("orc", 300, 150);
+/// assert_eq!(orc.len() - 4, u32::from_le_bytes([orc[0], orc[1], orc[2], orc[3]]) as usize);
+/// let orc = monster::parse_size_prefixed(&orc).unwrap();
+/// assert_eq!(Some("orc"), orc.name());
+/// assert_eq!(300, orc.hp());
+/// ```
+pub fn build_size_prefixed(name: &str, hp: i16, mana: i16) -> Vec<u8> {
+    let mut b = FlatBufferBuilder::new();
+    let monster = Monster::builder()
+        .name(name)
+        .hp(hp)
+        .mana(mana)
+        .build(&mut b);
+    b.finish_size_prefixed(monster, None);
+    b.finished_data().to_vec()
+}
+
+/// Parse the size prefixed monster at the start of `buf`, ignoring the
+/// bytes after the frame.
+pub fn parse_size_prefixed(buf: &[u8]) -> Result<MonsterView<'_>, SizePrefixedError> {
+    split_size_prefixed(buf).map(|(monster, _)| monster)
+}
+
+/// Parse the size prefixed monster at the start of `buf`, and returns it
+/// along with the bytes after the frame, e.g. the next frames read from
+/// the stream.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster;
+///
+/// let stream = [
+///     monster::build_size_prefixed("orc", 300, 150),
+///     monster::build_size_prefixed("goblin", 30, 10),
+/// ]
+/// .concat();
+/// let (orc, rest) = monster::split_size_prefixed(&stream).unwrap();
+/// let (goblin, rest) = monster::split_size_prefixed(rest).unwrap();
+/// assert_eq!(Some("orc"), orc.name());
+/// assert_eq!(Some("goblin"), goblin.name());
+/// assert!(rest.is_empty());
+/// ```
+pub fn split_size_prefixed(buf: &[u8]) -> Result<(MonsterView<'_>, &[u8]), SizePrefixedError> {
+    let too_short = |want| SizePrefixedError::TooShort {
+        len: buf.len(),
+        want,
+    };
+    let prefix = buf
+        .get(..SIZE_UOFFSET)
+        .ok_or_else(|| too_short(SIZE_UOFFSET))?;
+    let size = u32::from_le_bytes([prefix[0], prefix[1], prefix[2], prefix[3]]) as usize;
+    let end = SIZE_UOFFSET
+        .checked_add(size)
+        .ok_or_else(|| too_short(usize::MAX))?;
+    if buf.len() < end {
+        return Err(too_short(end));
+    }
+    let (frame, rest) = buf.split_at(end);
+    let monster = sample::size_prefixed_root_as_monster(frame).map_err(MonsterError::from)?;
+    Ok((MonsterView::from(monster), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        struct Test {
+            name: &'static str,
+            data: (&'static str, i16, i16),
+        }
+        let tests = [
+            Test {
+                name: "orc",
+                data: ("orc", 300, 150),
+            },
+            Test {
+                name: "defaults",
+                data: ("", 100, 150),
+            },
+            Test {
+                name: "negative",
+                data: ("zombie", -1, i16::MIN),
+            },
+        ];
+        for t in &tests {
+            let (name, hp, mana) = t.data;
+            let buf = build_size_prefixed(name, hp, mana);
+            let monster = parse_size_prefixed(&buf).unwrap();
+            let got = (monster.name().unwrap(), monster.hp(), monster.mana());
+            assert_eq!(t.data, got, "{}", t.name);
+        }
+    }
+    #[test]
+    fn parse_error() {
+        let orc = build_size_prefixed("orc", 300, 150);
+        let mut corrupted = orc.clone();
+        // Root offset out of the frame.
+        corrupted[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+        }
+        let tests = [
+            Test {
+                name: "empty",
+                data: vec![],
+            },
+            Test {
+                name: "short prefix",
+                data: orc[..3].to_vec(),
+            },
+            Test {
+                name: "short frame",
+                data: orc[..orc.len() - 1].to_vec(),
+            },
+            Test {
+                name: "huge prefix",
+                data: u32::MAX.to_le_bytes().to_vec(),
+            },
+        ];
+        for t in &tests {
+            match parse_size_prefixed(&t.data) {
+                Err(SizePrefixedError::TooShort { len, want }) => {
+                    assert_eq!(t.data.len(), len, "{}", t.name);
+                    assert!(len < want, "{}", t.name);
+                }
+                got => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+        match parse_size_prefixed(&corrupted) {
+            Err(SizePrefixedError::Invalid(MonsterError::Invalid(_))) => {}
+            got => panic!("unexpected {:?}", got),
+        }
+    }
+    #[test]
+    fn tcp_stream() {
+        // Two back-to-back frames, read in the arbitrary chunks.
+        let stream = [
+            build_size_prefixed("orc", 300, 150),
+            build_size_prefixed("goblin", 30, 10),
+        ]
+        .concat();
+        for chunk in 1..=stream.len() {
+            let mut received = Vec::new();
+            let mut names = Vec::new();
+            for bytes in stream.chunks(chunk) {
+                received.extend_from_slice(bytes);
+                loop {
+                    let consumed = match split_size_prefixed(&received) {
+                        Ok((monster, rest)) => {
+                            names.push(monster.name().unwrap().to_string());
+                            received.len() - rest.len()
+                        }
+                        Err(SizePrefixedError::TooShort { .. }) => break,
+                        Err(err) => panic!("{}: {}", chunk, err),
+                    };
+                    received.drain(..consumed);
+                }
+            }
+            assert_eq!(vec!["orc", "goblin"], names, "{}", chunk);
+            assert!(received.is_empty(), "{}", chunk);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Sealed `Monster` buffers for the storage.
+//!
+//! The flatbuffers verifier only checks the buffer structure, so the
+//! flipped bit in the scalar field, e.g. `hp`, passes it with the wrong
+//! value.  The sealed buffer is the `Monster` buffer followed by the
+//! footer, which is the little endian CRC32C (Castagnoli) of the buffer
+//! and the [`MAGIC`], and [`unseal`] checks both before the verifier.
+//!
+//! [`magic`]: constant.MAGIC.html
+//! [`unseal`]: fn.unseal.html
+use std::convert::TryInto;
+
+use crate::error::IntegrityError;
+use crate::MonsterBuf;
+
+/// Magic at the end of the sealed buffer.
+pub const MAGIC: [u8; 4] = *b"MCRC";
+
+/// CRC32C and the magic.
+pub const FOOTER_LEN: usize = 8;
+
+/// Returns `buf` followed by the footer.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{integrity, MonsterData};
+/// use flatbuf_tutorial::Monster;
+///
+/// let orc = Monster::build_pooled(&MonsterData {
+///     name: String::from("orc"),
+///     hp: 300,
+///     ..Default::default()
+/// });
+/// let sealed = integrity::seal(&orc);
+/// assert_eq!(orc.as_ref().len() + integrity::FOOTER_LEN, sealed.len());
+/// assert_eq!(orc, integrity::unseal(&sealed).unwrap());
+/// ```
+pub fn seal(buf: &MonsterBuf) -> Vec<u8> {
+    seal_bytes(buf.as_ref())
+}
+
+/// Check the footer of `bytes`, and verify the buffer in it.
+pub fn unseal(bytes: &[u8]) -> Result<MonsterBuf, IntegrityError> {
+    let len = check(bytes)?;
+    Ok(MonsterBuf::new(bytes[..len].to_vec())?)
+}
+
+/// Same as [`unseal`], without copying the buffer.
+///
+/// [`unseal`]: fn.unseal.html
+#[cfg_attr(not(feature = "stream"), allow(dead_code))]
+pub(crate) fn unseal_vec(mut bytes: Vec<u8>) -> Result<MonsterBuf, IntegrityError> {
+    let len = check(&bytes)?;
+    bytes.truncate(len);
+    Ok(MonsterBuf::new(bytes)?)
+}
+
+fn seal_bytes(data: &[u8]) -> Vec<u8> {
+    let mut sealed = Vec::with_capacity(data.len() + FOOTER_LEN);
+    sealed.extend_from_slice(data);
+    sealed.extend_from_slice(&crc32c::crc32c(data).to_le_bytes());
+    sealed.extend_from_slice(&MAGIC);
+    sealed
+}
+
+/// Check the footer and returns the length of the buffer before it.
+fn check(bytes: &[u8]) -> Result<usize, IntegrityError> {
+    let len = bytes
+        .len()
+        .checked_sub(FOOTER_LEN)
+        .ok_or(IntegrityError::TooShort(bytes.len()))?;
+    let (data, footer) = bytes.split_at(len);
+    let magic: [u8; 4] = footer[4..].try_into().unwrap();
+    if magic != MAGIC {
+        return Err(IntegrityError::BadMagic(magic));
+    }
+    let want = u32::from_le_bytes(footer[..4].try_into().unwrap());
+    let got = crc32c::crc32c(data);
+    if got != want {
+        return Err(IntegrityError::BadCrc { want, got });
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::MonsterData;
+    use crate::Monster;
+
+    fn orc() -> MonsterBuf {
+        Monster::build_pooled(&MonsterData {
+            name: String::from("orc"),
+            hp: 300,
+            inventory: vec![1, 2, 3],
+            ..Default::default()
+        })
+    }
+    #[test]
+    fn round_trip() {
+        let orc = orc();
+        let sealed = seal(&orc);
+        assert_eq!(orc.as_ref(), &sealed[..sealed.len() - FOOTER_LEN]);
+        assert_eq!(Ok(orc.clone()), unseal(&sealed));
+        assert_eq!(Ok(orc), unseal_vec(sealed));
+    }
+    #[test]
+    fn corrupted() {
+        let orc = orc();
+        let sealed = seal(&orc);
+        let len = orc.as_ref().len();
+        for i in 0..sealed.len() {
+            let mut corrupted = sealed.clone();
+            corrupted[i] ^= 0x01;
+            match (i, unseal(&corrupted)) {
+                (i, Err(IntegrityError::BadCrc { .. })) if i < len + 4 => {}
+                (i, Err(IntegrityError::BadMagic(_))) if i >= len + 4 => {}
+                (i, res) => panic!("{} of {}: {:?}", i, sealed.len(), res),
+            }
+        }
+    }
+    #[test]
+    fn flipped_hp() {
+        // Passes the verifier with the wrong `hp`, but not the seal.
+        let mut sealed = seal(&orc());
+        let pos = sealed
+            .windows(2)
+            .position(|w| w == 300i16.to_le_bytes())
+            .unwrap();
+        sealed[pos] ^= 0x04;
+        let len = sealed.len() - FOOTER_LEN;
+        let flipped = MonsterBuf::new(sealed[..len].to_vec()).unwrap();
+        assert_ne!(300, flipped.hp());
+        assert!(matches!(
+            unseal(&sealed),
+            Err(IntegrityError::BadCrc { .. })
+        ));
+    }
+    #[test]
+    fn errors() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "empty",
+                data: vec![],
+                want: "too short",
+            },
+            Test {
+                name: "unsealed",
+                data: orc().into_inner(),
+                want: "bad seal magic",
+            },
+            Test {
+                name: "invalid",
+                data: seal_bytes(&[0xff; 3]),
+                want: "invalid monster buffer",
+            },
+        ];
+        for t in &tests {
+            let err = unseal(&t.data).unwrap_err();
+            assert!(err.to_string().contains(t.want), "{}: {}", t.name, err);
+        }
+    }
+}
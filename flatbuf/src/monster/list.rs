@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: GPL-2.0
+//! `MonsterList` builder sharing the repeated strings and weapons.
+use std::collections::HashMap;
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use super::{HasName, Interner, MonsterBuilder, MonsterData};
+use crate::model::my_game::sample::{self, Weapon, WeaponArgs};
+
+/// Interning statistics of [`ListBuilder`].
+///
+/// [`listbuilder`]: struct.ListBuilder.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternStats {
+    /// Number of the distinct strings written.
+    pub strings: usize,
+    /// Number of the strings referenced instead of written again.
+    pub shared_strings: usize,
+    /// Number of the distinct weapon tables written.
+    pub weapons: usize,
+    /// Number of the weapon tables referenced instead of written again.
+    pub shared_weapons: usize,
+    /// Bytes not written for the shared strings and weapon tables.  It's
+    /// approximate, as the alignment paddings depend on the position.
+    pub bytes_saved: usize,
+}
+
+/// Builder of the `MonsterList` table, which writes each distinct string
+/// once, and optionally each distinct weapon table once, for all the
+/// monsters in the list.
+///
+/// The shared offsets are only valid in the same `FlatBufferBuilder`
+/// until it's reset, so use the new `ListBuilder` for each buffer.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::model::my_game::sample::MonsterList;
+/// use flatbuf_tutorial::monster::{ListBuilder, MonsterData, WeaponData};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let orc = MonsterData {
+///     name: String::from("orc"),
+///     weapons: vec![WeaponData {
+///         name: String::from("axe"),
+///         damage: 5,
+///     }],
+///     ..Default::default()
+/// };
+/// let mut b = FlatBufferBuilder::new();
+/// let mut list = ListBuilder::new().share_weapons(true);
+/// for _ in 0..3 {
+///     list.push(&mut b, &orc);
+/// }
+/// let monsters = list.finish(&mut b);
+/// b.finish(monsters, None);
+/// assert_eq!(2, list.stats().shared_weapons);
+/// let monsters = flatbuffers::root::<MonsterList>(b.finished_data()).unwrap();
+/// assert_eq!(3, monsters.monsters().unwrap().len());
+/// ```
+#[derive(Debug, Default)]
+pub struct ListBuilder<'b> {
+    strings: HashMap<String, (WIPOffset<&'b str>, usize)>,
+    weapons: HashMap<(String, i16), (WIPOffset<Weapon<'b>>, usize)>,
+    share_weapons: bool,
+    monsters: Vec<WIPOffset<sample::Monster<'b>>>,
+    stats: InternStats,
+}
+
+impl<'b> ListBuilder<'b> {
+    /// Create the builder, which shares the strings only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Share the identical weapon tables too.  The monster with the
+    /// identical weapons has them all in the same table then.
+    pub fn share_weapons(self, share_weapons: bool) -> Self {
+        Self {
+            share_weapons,
+            ..self
+        }
+    }
+    /// Build the monster of `data` and add it to the list.
+    pub fn push(&mut self, b: &mut FlatBufferBuilder<'b>, data: &MonsterData) {
+        self.push_builder(b, &data.builder());
+    }
+    /// Build the monster of `builder` and add it to the list.
+    pub fn push_builder(
+        &mut self,
+        b: &mut FlatBufferBuilder<'b>,
+        builder: &MonsterBuilder<'_, HasName>,
+    ) {
+        let monster = builder.build_interned(b, self);
+        self.monsters.push(monster);
+    }
+    /// Returns the number of the monsters in the list.
+    pub fn len(&self) -> usize {
+        self.monsters.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.monsters.is_empty()
+    }
+    /// Build the `MonsterList` table of the monsters added so far, and
+    /// start the new list.
+    pub fn finish(&mut self, b: &mut FlatBufferBuilder<'b>) -> WIPOffset<sample::MonsterList<'b>> {
+        let monsters = b.create_vector(&self.monsters);
+        self.monsters.clear();
+        sample::MonsterList::create(
+            b,
+            &sample::MonsterListArgs {
+                monsters: Some(monsters),
+                ..Default::default()
+            },
+        )
+    }
+    pub fn stats(&self) -> InternStats {
+        self.stats
+    }
+}
+
+impl<'b> Interner<'b> for ListBuilder<'b> {
+    fn string(&mut self, b: &mut FlatBufferBuilder<'b>, s: &str) -> WIPOffset<&'b str> {
+        if let Some(&(offset, size)) = self.strings.get(s) {
+            self.stats.shared_strings += 1;
+            self.stats.bytes_saved += size;
+            return offset;
+        }
+        let start = b.unfinished_data().len();
+        let offset = b.create_string(s);
+        let size = b.unfinished_data().len() - start;
+        self.strings.insert(s.to_string(), (offset, size));
+        self.stats.strings += 1;
+        offset
+    }
+    fn weapon(
+        &mut self,
+        b: &mut FlatBufferBuilder<'b>,
+        name: &str,
+        damage: i16,
+    ) -> WIPOffset<Weapon<'b>> {
+        if !self.share_weapons {
+            let name = self.string(b, name);
+            return Weapon::create(
+                b,
+                &WeaponArgs {
+                    name: Some(name),
+                    damage,
+                },
+            );
+        }
+        let key = (name.to_string(), damage);
+        if let Some(&(offset, size)) = self.weapons.get(&key) {
+            self.stats.shared_weapons += 1;
+            self.stats.bytes_saved += size;
+            // The name is shared along with the table.
+            if let Some(&(_, name_size)) = self.strings.get(name) {
+                self.stats.shared_strings += 1;
+                self.stats.bytes_saved += name_size;
+            }
+            return offset;
+        }
+        let name = self.string(b, &key.0);
+        let start = b.unfinished_data().len();
+        let offset = Weapon::create(
+            b,
+            &WeaponArgs {
+                name: Some(name),
+                damage,
+            },
+        );
+        let size = b.unfinished_data().len() - start;
+        self.weapons.insert(key, (offset, size));
+        self.stats.weapons += 1;
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::MonsterList;
+    use crate::monster::WeaponData;
+    use crate::MonsterView;
+
+    const MONSTERS: usize = 1_000;
+    const WEAPONS: [&str; 5] = ["axe", "bow", "club", "dagger", "sword"];
+
+    fn monsters() -> Vec<MonsterData> {
+        (0..MONSTERS)
+            .map(|i| MonsterData {
+                name: format!("orc-{}", i % 10),
+                weapons: (0..3)
+                    .map(|j| WeaponData {
+                        name: WEAPONS[(i + j) % WEAPONS.len()].to_string(),
+                        damage: 1 + (j as i16),
+                    })
+                    .collect(),
+                equipped: Some(i % 3),
+                ..Default::default()
+            })
+            .collect()
+    }
+    fn parse(buf: &[u8]) -> Vec<MonsterData> {
+        let list = flatbuffers::root::<MonsterList>(buf).unwrap();
+        list.monsters()
+            .unwrap()
+            .iter()
+            .map(|monster| MonsterData::from(MonsterView::from(monster)))
+            .collect()
+    }
+    fn build(share_weapons: Option<bool>) -> (Vec<u8>, InternStats) {
+        let mut b = FlatBufferBuilder::new();
+        let (list, stats) = match share_weapons {
+            None => (
+                MonsterData::build_list(&monsters(), &mut b),
+                InternStats::default(),
+            ),
+            Some(share_weapons) => {
+                let mut list = ListBuilder::new().share_weapons(share_weapons);
+                for monster in &monsters() {
+                    list.push(&mut b, monster);
+                }
+                assert_eq!(MONSTERS, list.len());
+                (list.finish(&mut b), list.stats())
+            }
+        };
+        b.finish(list, None);
+        (b.finished_data().to_vec(), stats)
+    }
+    #[test]
+    fn interned_list() {
+        let (plain, _) = build(None);
+        // Upper bound of the interned list size in percent.
+        for &(share_weapons, percent) in &[(false, 70), (true, 40)] {
+            let (interned, stats) = build(Some(share_weapons));
+            assert!(
+                interned.len() * 100 < plain.len() * percent,
+                "{}: {} vs {} bytes",
+                share_weapons,
+                interned.len(),
+                plain.len()
+            );
+            assert_eq!(parse(&plain), parse(&interned), "{}", share_weapons);
+            assert_eq!(monsters(), parse(&interned), "{}", share_weapons);
+            // Within the paddings and the vtables, which are shared by
+            // the builder anyway.
+            let saved = plain.len() - interned.len();
+            assert!(
+                stats.bytes_saved.abs_diff(saved) * 20 <= saved,
+                "{}: {:?} vs {} bytes",
+                share_weapons,
+                stats,
+                saved
+            );
+        }
+    }
+    #[test]
+    fn stats() {
+        let (_, stats) = build(Some(false));
+        let want = InternStats {
+            strings: 10 + WEAPONS.len(),
+            shared_strings: 4 * MONSTERS - 10 - WEAPONS.len(),
+            weapons: 0,
+            shared_weapons: 0,
+            bytes_saved: stats.bytes_saved,
+        };
+        assert_eq!(want, stats);
+
+        let (_, stats) = build(Some(true));
+        assert_eq!(10 + WEAPONS.len(), stats.strings);
+        // 3 damages for each weapon name.
+        assert_eq!(3 * WEAPONS.len(), stats.weapons);
+        assert_eq!(3 * MONSTERS - 3 * WEAPONS.len(), stats.shared_weapons);
+    }
+}
@@ -0,0 +1,438 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Overlay of one `Monster` buffer onto another.
+//!
+//! The patch field overrides the base one only if it's physically present
+//! in the patch buffer, so the patch built with
+//! [`MonsterBuilder::force_defaults`] can reset the base field to its
+//! default value, while the absent field falls through to the base.
+//!
+//! [`monsterbuilder::force_defaults`]: struct.MonsterBuilder.html#method.force_defaults
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use super::create_path;
+use crate::model::my_game::sample::{self, Color, Equipment, Vec3, Weapon, WeaponArgs};
+use crate::view::MonsterView;
+use crate::weapon::WeaponView;
+use crate::{FlatBufferBuilderPool, MonsterBuf};
+
+/// Options of [`merge_with_opts`].  The default replaces the vectors
+/// wholesale.
+///
+/// [`merge_with_opts`]: fn.merge_with_opts.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MergeOpts {
+    /// Append the patch `weapons` to the base ones.
+    pub append_weapons: bool,
+    /// Append the patch `inventory` to the base one.
+    pub merge_inventory: bool,
+}
+
+/// Merge `patch` onto `base` with the default [`MergeOpts`].
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::{monster, Monster, MonsterView};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().name("orc").hp(80).mana(20).build(&mut b);
+/// b.finish(orc, None);
+/// let orc = b.finished_data().to_vec();
+/// let orc = MonsterView::from_bytes(&orc).unwrap();
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let patch = Monster::builder().name("orc").hp_explicit(100).build(&mut b);
+/// b.finish(patch, None);
+/// let patch = b.finished_data().to_vec();
+/// let patch = MonsterView::from_bytes(&patch).unwrap();
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let merged = monster::merge(&orc, &patch, &mut b);
+/// b.finish(merged, None);
+/// let merged = MonsterView::from_bytes(b.finished_data()).unwrap();
+/// assert_eq!(100, merged.hp());
+/// assert_eq!(20, merged.mana());
+/// ```
+/// [`mergeopts`]: struct.MergeOpts.html
+pub fn merge<'a, 'b>(
+    base: &MonsterView<'a>,
+    patch: &MonsterView<'a>,
+    b: &mut FlatBufferBuilder<'b>,
+) -> WIPOffset<sample::Monster<'b>> {
+    merge_with_opts(base, patch, MergeOpts::default(), b)
+}
+
+/// Merge `patch` onto `base` with the builder from the global pool.
+pub fn merge_pooled(base: &MonsterView<'_>, patch: &MonsterView<'_>) -> MonsterBuf {
+    let mut b = FlatBufferBuilderPool::get_or_alloc();
+    let monster = merge(base, patch, &mut b);
+    b.finish(monster, None);
+    MonsterBuf::from_built(b.finished_data().to_vec())
+}
+
+/// Merge `patch` onto `base` and build the merged `Monster` table.
+///
+/// The merged field keeps its presence, e.g. the forced default of the
+/// patch is still present in the merged buffer, so that it can be the
+/// patch of the next merge.
+///
+/// The `equipped` union is taken from the patch if present, and from the
+/// base otherwise.  It points into the merged `weapons`: to the same
+/// table if it's kept, or to the first equal weapon otherwise.  It's
+/// dropped if there is no such weapon, e.g. the base one is replaced by
+/// the patch `weapons`, as well as the unknown equipment type.
+pub fn merge_with_opts<'a, 'b>(
+    base: &MonsterView<'a>,
+    patch: &MonsterView<'a>,
+    opts: MergeOpts,
+    b: &mut FlatBufferBuilder<'b>,
+) -> WIPOffset<sample::Monster<'b>> {
+    let has = |view: &MonsterView<'a>, voffset| view.field_pos(voffset).is_some();
+
+    let weapons: Option<Vec<WeaponView<'a>>> = match (
+        has(base, sample::Monster::VT_WEAPONS),
+        has(patch, sample::Monster::VT_WEAPONS),
+    ) {
+        (true, true) if opts.append_weapons => {
+            Some(base.weapons().chain(patch.weapons()).collect())
+        }
+        (_, true) => Some(patch.weapons().collect()),
+        (true, false) => Some(base.weapons().collect()),
+        (false, false) => None,
+    };
+    let equipped = if has(patch, sample::Monster::VT_EQUIPPED) {
+        patch.equipped()
+    } else {
+        base.equipped()
+    };
+    let equipped = equipped.and_then(|equipped| {
+        let weapons = weapons.as_deref().unwrap_or_default();
+        weapons
+            .iter()
+            .position(|weapon| weapon.is_same(&equipped))
+            .or_else(|| weapons.iter().position(|weapon| *weapon == equipped))
+    });
+    let weapons = weapons.map(|weapons| {
+        weapons
+            .iter()
+            .map(|weapon| {
+                let name = weapon.name().map(|name| b.create_string(name));
+                Weapon::create(
+                    b,
+                    &WeaponArgs {
+                        name,
+                        damage: weapon.damage(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>()
+    });
+    let equipped = equipped.and_then(|i| weapons.as_ref().map(|weapons| weapons[i]));
+    let weapons = weapons.map(|weapons| b.create_vector(&weapons));
+
+    let name = patch
+        .name()
+        .or_else(|| base.name())
+        .map(|name| b.create_string(name));
+    let inventory = match (base.inventory(), patch.inventory()) {
+        (Some(base), Some(patch)) if opts.merge_inventory => {
+            Some(b.create_vector(&[base, patch].concat()))
+        }
+        (base, patch) => patch.or(base).map(|inventory| b.create_vector(inventory)),
+    };
+    let path = if has(patch, sample::Monster::VT_PATH) {
+        Some(patch.path().collect::<Vec<_>>())
+    } else if has(base, sample::Monster::VT_PATH) {
+        Some(base.path().collect())
+    } else {
+        None
+    };
+    let path = path.map(|path| create_path(b, &path));
+    let pos = patch.pos().or_else(|| base.pos());
+    let scalar = |has: fn(&MonsterView<'a>) -> bool| {
+        if has(patch) {
+            Some(patch)
+        } else if has(base) {
+            Some(base)
+        } else {
+            None
+        }
+    };
+    let hp = scalar(MonsterView::has_hp).map(MonsterView::hp);
+    let mana = scalar(MonsterView::has_mana).map(MonsterView::mana);
+    let color = scalar(MonsterView::has_color).map(MonsterView::color);
+
+    // Same field order as `MonsterBuilder::build`.
+    let start = b.start_table();
+    if let Some(path) = path {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_PATH, path);
+    }
+    if let Some(equipped) = equipped {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_EQUIPPED, equipped.as_union_value());
+    }
+    if let Some(weapons) = weapons {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_WEAPONS, weapons);
+    }
+    if let Some(inventory) = inventory {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_INVENTORY, inventory);
+    }
+    if let Some(name) = name {
+        b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_NAME, name);
+    }
+    if let Some(pos) = &pos {
+        b.push_slot_always::<&Vec3>(sample::Monster::VT_POS, pos);
+    }
+    if let Some(hp) = hp {
+        b.push_slot_always::<i16>(sample::Monster::VT_HP, hp);
+    }
+    if let Some(mana) = mana {
+        b.push_slot_always::<i16>(sample::Monster::VT_MANA, mana);
+    }
+    if equipped.is_some() {
+        b.push_slot_always::<Equipment>(sample::Monster::VT_EQUIPPED_TYPE, Equipment::Weapon);
+    }
+    if let Some(color) = color {
+        b.push_slot_always::<Color>(sample::Monster::VT_COLOR, color);
+    }
+    let monster = b.end_table(start);
+    WIPOffset::new(monster.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::{MonsterData, WeaponData};
+    use crate::Monster;
+
+    fn weapon(name: &str, damage: i16) -> WeaponData {
+        WeaponData {
+            name: name.to_string(),
+            damage,
+        }
+    }
+    fn orc() -> MonsterBuf {
+        Monster::build_pooled(&MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            mana: 20,
+            hp: 80,
+            inventory: vec![1, 2],
+            color: Color::Red,
+            weapons: vec![weapon("sword", 3), weapon("axe", 5)],
+            equipped: Some(1),
+            path: vec![Vec3::new(0.5, 0.0, -0.5)],
+        })
+    }
+    /// Build the patch without the name, which `MonsterBuilder` requires.
+    fn patch(args: &sample::MonsterArgs) -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let patch = sample::Monster::create(&mut b, args);
+        b.finish(patch, None);
+        b.finished_data().to_vec()
+    }
+    fn merged(patch: &[u8], opts: MergeOpts) -> MonsterData {
+        let base = orc();
+        let patch = MonsterView::from_bytes(patch).unwrap();
+        let mut b = FlatBufferBuilder::new();
+        let merged = merge_with_opts(&base.view(), &patch, opts, &mut b);
+        b.finish(merged, None);
+        let merged = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert_eq!(Ok(()), merged.validate());
+        MonsterData::from(merged)
+    }
+    #[test]
+    fn scalars() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: MonsterData,
+        }
+        let tests = [
+            Test {
+                name: "absent fields fall through",
+                data: patch(&Default::default()),
+                want: MonsterData::from(orc().view()),
+            },
+            Test {
+                name: "override",
+                data: patch(&sample::MonsterArgs {
+                    hp: 90,
+                    color: Color::Green,
+                    pos: Some(&Vec3::new(4.0, 5.0, 6.0)),
+                    ..Default::default()
+                }),
+                want: MonsterData {
+                    hp: 90,
+                    color: Color::Green,
+                    pos: Some(Vec3::new(4.0, 5.0, 6.0)),
+                    ..MonsterData::from(orc().view())
+                },
+            },
+            Test {
+                name: "forced defaults",
+                data: {
+                    let mut b = FlatBufferBuilder::new();
+                    let patch = Monster::builder()
+                        .name("goblin")
+                        .force_defaults(true)
+                        .build(&mut b);
+                    b.finish(patch, None);
+                    b.finished_data().to_vec()
+                },
+                want: MonsterData {
+                    name: String::from("goblin"),
+                    mana: 150,
+                    hp: 100,
+                    color: Color::Blue,
+                    ..MonsterData::from(orc().view())
+                },
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, merged(&t.data, MergeOpts::default()), "{}", t.name);
+        }
+    }
+    #[test]
+    fn keeps_presence() {
+        let patch = patch(&Default::default());
+        let patch = MonsterView::from_bytes(&patch).unwrap();
+        let mut b = FlatBufferBuilder::new();
+        let empty = merge(&patch, &patch, &mut b);
+        b.finish(empty, None);
+        let empty = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert!(!empty.has_hp() && !empty.has_mana() && !empty.has_color());
+        assert!(empty.name().is_none() && empty.inventory().is_none());
+
+        let mut b = FlatBufferBuilder::new();
+        let forced = Monster::builder()
+            .name("orc")
+            .hp_explicit(100)
+            .build(&mut b);
+        b.finish(forced, None);
+        let forced = b.finished_data().to_vec();
+        let forced = MonsterView::from_bytes(&forced).unwrap();
+        let merged = merge_pooled(&patch, &forced);
+        assert!(merged.view().has_hp());
+        assert!(!merged.view().has_mana());
+    }
+    #[test]
+    fn vectors() {
+        struct Test {
+            name: &'static str,
+            opts: MergeOpts,
+            want: (Vec<WeaponData>, Vec<u8>),
+        }
+        let tests = [
+            Test {
+                name: "replace",
+                opts: MergeOpts::default(),
+                want: (vec![weapon("bow", 2)], vec![3]),
+            },
+            Test {
+                name: "append",
+                opts: MergeOpts {
+                    append_weapons: true,
+                    merge_inventory: true,
+                },
+                want: (
+                    vec![weapon("sword", 3), weapon("axe", 5), weapon("bow", 2)],
+                    vec![1, 2, 3],
+                ),
+            },
+        ];
+        let data = {
+            let mut b = FlatBufferBuilder::new();
+            let patch = Monster::builder()
+                .name("orc")
+                .weapon("bow", 2)
+                .inventory(&[3])
+                .build(&mut b);
+            b.finish(patch, None);
+            b.finished_data().to_vec()
+        };
+        for t in &tests {
+            let got = merged(&data, t.opts);
+            assert_eq!(t.want.0, got.weapons, "{}", t.name);
+            assert_eq!(t.want.1, got.inventory, "{}", t.name);
+        }
+    }
+    #[test]
+    fn union() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            opts: MergeOpts,
+            want: Option<WeaponData>,
+        }
+        let bow = |equipped: bool| {
+            let mut b = FlatBufferBuilder::new();
+            let mut patch = Monster::builder().name("orc").weapon("bow", 2);
+            if equipped {
+                patch = patch.equipped(0);
+            }
+            let patch = patch.build(&mut b);
+            b.finish(patch, None);
+            b.finished_data().to_vec()
+        };
+        let axe = {
+            let mut b = FlatBufferBuilder::new();
+            let patch = Monster::builder()
+                .name("orc")
+                .weapon("axe", 5)
+                .weapon("sword", 3)
+                .build(&mut b);
+            b.finish(patch, None);
+            b.finished_data().to_vec()
+        };
+        let tests = [
+            Test {
+                name: "from base",
+                data: patch(&Default::default()),
+                opts: MergeOpts::default(),
+                want: Some(weapon("axe", 5)),
+            },
+            Test {
+                name: "from patch",
+                data: bow(true),
+                opts: MergeOpts::default(),
+                want: Some(weapon("bow", 2)),
+            },
+            Test {
+                name: "from patch appended",
+                data: bow(true),
+                opts: MergeOpts {
+                    append_weapons: true,
+                    ..Default::default()
+                },
+                want: Some(weapon("bow", 2)),
+            },
+            Test {
+                name: "from base appended",
+                data: bow(false),
+                opts: MergeOpts {
+                    append_weapons: true,
+                    ..Default::default()
+                },
+                want: Some(weapon("axe", 5)),
+            },
+            Test {
+                name: "base weapon replaced",
+                data: bow(false),
+                opts: MergeOpts::default(),
+                want: None,
+            },
+            Test {
+                name: "equal weapon in patch",
+                data: axe,
+                opts: MergeOpts::default(),
+                want: Some(weapon("axe", 5)),
+            },
+        ];
+        for t in &tests {
+            let got = merged(&t.data, t.opts);
+            let equipped = got.equipped.map(|i| got.weapons[i].clone());
+            assert_eq!(t.want, equipped, "{}", t.name);
+        }
+    }
+}
@@ -0,0 +1,1126 @@
+// SPDX-License-Identifier: GPL-2.0
+#[cfg(feature = "async")]
+pub mod aio;
+mod canonical;
+pub mod collection;
+mod copy;
+mod data;
+mod edit;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub mod fixture;
+mod framed;
+#[cfg(feature = "integrity")]
+pub mod integrity;
+mod list;
+mod merge;
+mod session;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "test-util")]
+pub mod testing;
+pub mod tuning;
+pub mod validate;
+
+pub use copy::copy_into;
+pub use data::{MonsterData, WeaponData};
+pub use edit::{edit, MonsterEditor};
+#[cfg(feature = "toml")]
+pub use fixture::from_toml_str;
+#[cfg(feature = "yaml")]
+pub use fixture::from_yaml_str;
+#[cfg(any(feature = "yaml", feature = "toml"))]
+pub use fixture::{from_reader, load_dir, Format};
+pub use framed::{build_size_prefixed, parse_size_prefixed, split_size_prefixed};
+pub use list::{InternStats, ListBuilder};
+pub use merge::{merge, merge_pooled, merge_with_opts, MergeOpts};
+pub use session::{BuildSession, Building, Empty};
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::LazyLock;
+
+use flatbuffers::{FlatBufferBuilder, Push, Vector, WIPOffset};
+
+use crate::inventory::ItemId;
+use crate::model::my_game::sample;
+use crate::model::my_game::sample::{Color, Equipment, MonsterArgs, Vec3, Weapon, WeaponArgs};
+use crate::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, MonsterBuf};
+
+pub struct Monster;
+
+/// `name` of the default monster.
+const DEFAULT_NAME: &str = "unknown";
+
+static DEFAULT_BUF: LazyLock<MonsterBuf> =
+    LazyLock::new(|| Monster::build_pooled(&MonsterData::default()));
+
+impl Monster {
+    /// Create a [`MonsterBuilder`] without the required `name` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::Monster;
+    /// use flatbuf_tutorial::model::my_game::sample::root_as_monster;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().hp(80).name("orc").weapon("axe", 5).build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = root_as_monster(b.finished_data()).unwrap();
+    /// assert_eq!(Some("orc"), orc.name());
+    /// assert_eq!(80, orc.hp());
+    /// ```
+    /// [`monsterbuilder`]: struct.MonsterBuilder.html
+    pub fn builder<'a>() -> MonsterBuilder<'a, NoName> {
+        MonsterBuilder::default()
+    }
+    /// Build the `Monster` buffer with the builder from the global pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::Monster;
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     hp: 80,
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_pooled(&data);
+    /// assert_eq!(Some("orc"), orc.name());
+    /// assert_eq!(80, orc.hp());
+    /// ```
+    pub fn build_pooled(data: &MonsterData) -> MonsterBuf {
+        // Not `get_with_capacity`, which bypasses the pool for most of
+        // the monsters with the default buffer capacity.  The pooled
+        // builders keep their grown buffers across the `reset` anyway.
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let monster = data.build(&mut b);
+        b.finish(monster, None);
+        tuning::record_build_pooled(b.finished_data().len());
+        MonsterBuf::from_built(b.finished_data().to_vec())
+    }
+    /// Returns the canonical placeholder monster, which is built once
+    /// from [`MonsterData::default`], e.g. the `"unknown"` name and the
+    /// schema defaults elsewhere.
+    ///
+    /// Every call returns the same buffer, which passes the
+    /// verification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::Monster;
+    ///
+    /// let unknown = Monster::default_buf();
+    /// assert_eq!(Some("unknown"), unknown.name());
+    /// assert!(std::ptr::eq(unknown, Monster::default_buf()));
+    /// assert_eq!(MonsterData::default(), unknown.view().into());
+    /// ```
+    /// [`monsterdata::default`]: struct.MonsterData.html#impl-Default-for-MonsterData
+    pub fn default_buf() -> &'static MonsterBuf {
+        &DEFAULT_BUF
+    }
+    /// Build the `Monster` buffer with the builder from the local `pool`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::{FlatBufferBuilderPool, Monster};
+    ///
+    /// let pool = FlatBufferBuilderPool::new().build();
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_with(&pool, &data);
+    /// assert_eq!(Some("orc"), orc.name());
+    /// ```
+    pub fn build_with(pool: &FlatBufferBuilderLocalPool, data: &MonsterData) -> MonsterBuf {
+        // Same as `build_pooled`, not `get_with_capacity`.
+        let mut b = pool.get();
+        let monster = data.build(&mut b);
+        b.finish(monster, None);
+        MonsterBuf::from_built(b.finished_data().to_vec())
+    }
+    #[allow(dead_code)]
+    pub fn create<'b>(b: &mut FlatBufferBuilder<'b>, name: &str) -> WIPOffset<sample::Monster<'b>> {
+        let name1 = b.create_string("Axe");
+        let name2 = b.create_string("Sword");
+        println!("axe name: {:?}", name1);
+        let axe = Weapon::create(
+            b,
+            &WeaponArgs {
+                name: Some(name1),
+                damage: 5,
+            },
+        );
+        println!("axe: {:?}", axe);
+        println!("sword name: {:?}", name2);
+        let sword = Weapon::create(
+            b,
+            &WeaponArgs {
+                name: Some(name2),
+                damage: 3,
+            },
+        );
+        println!("sword: {:?}", sword);
+        let weapons = b.create_vector(&[axe, sword]);
+        println!("weapons: {:?}", weapons);
+        let name = b.create_string(name);
+        println!("name: {:?}", name);
+        let inventory = b.create_vector(&[0u8, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        println!("inventory: {:?}", inventory);
+        let x = Vec3::new(1.0, 2.0, 3.0);
+        println!("x: {:?}", x);
+        let y = Vec3::new(4.0, 5.0, 6.0);
+        println!("x: {:?}", y);
+        let path = b.create_vector(&[x, y]);
+        println!("path: {:?}", path);
+        let orc = sample::Monster::create(
+            b,
+            &MonsterArgs {
+                pos: Some(&Vec3::new(1.0f32, 2.0f32, 3.0f32)),
+                //mana: 150, // It's a default value which is filled in below
+                hp: 80,
+                name: Some(name),
+                inventory: Some(inventory),
+                color: Color::Red,
+                weapons: Some(weapons),
+                equipped_type: Equipment::Weapon,
+                equipped: Some(axe.as_union_value()),
+                path: Some(path),
+                ..Default::default()
+            },
+        );
+        println!("monster: {:?}", orc);
+        orc
+    }
+}
+
+/// [`MonsterBuilder`] state without the required `name` field.
+///
+/// [`monsterbuilder`]: struct.MonsterBuilder.html
+pub struct NoName;
+
+/// [`MonsterBuilder`] state with the required `name` field.
+///
+/// [`monsterbuilder`]: struct.MonsterBuilder.html
+pub struct HasName;
+
+/// Typestate `Monster` builder.
+///
+/// `build` is only available after the required `name` field is
+/// provided, e.g. the following code doesn't compile:
+///
+/// ```compile_fail
+/// use flatbuf_tutorial::Monster;
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().hp(80).build(&mut b);
+/// ```
+pub struct MonsterBuilder<'a, S> {
+    pos: Option<Vec3>,
+    mana: i16,
+    hp: i16,
+    name: Option<&'a str>,
+    inventory: Option<Cow<'a, [u8]>>,
+    color: Color,
+    weapons: Vec<(&'a str, i16)>,
+    equipped: Option<usize>,
+    path: Option<Cow<'a, [Vec3]>>,
+    force_defaults: bool,
+    explicit: Explicit,
+    state: PhantomData<S>,
+}
+
+/// Scalar fields written regardless of the default value.
+#[derive(Clone, Copy, Default)]
+struct Explicit {
+    mana: bool,
+    hp: bool,
+    color: bool,
+}
+
+impl<'a> Default for MonsterBuilder<'a, NoName> {
+    fn default() -> Self {
+        let args = MonsterArgs::default();
+        Self {
+            pos: None,
+            mana: args.mana,
+            hp: args.hp,
+            name: None,
+            inventory: None,
+            color: args.color,
+            weapons: Vec::new(),
+            equipped: None,
+            path: None,
+            force_defaults: false,
+            explicit: Explicit::default(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<'a, S> MonsterBuilder<'a, S> {
+    /// Set the required `name` field.
+    pub fn name(self, name: &'a str) -> MonsterBuilder<'a, HasName> {
+        MonsterBuilder {
+            pos: self.pos,
+            mana: self.mana,
+            hp: self.hp,
+            name: Some(name),
+            inventory: self.inventory,
+            color: self.color,
+            weapons: self.weapons,
+            equipped: self.equipped,
+            path: self.path,
+            force_defaults: self.force_defaults,
+            explicit: self.explicit,
+            state: PhantomData,
+        }
+    }
+    /// Set the `pos` field to the `Vec3`, or to the math library vector
+    /// with the `glam` or `nalgebra` feature.
+    pub fn pos(mut self, pos: impl Into<Vec3>) -> Self {
+        self.pos = Some(pos.into());
+        self
+    }
+    pub fn mana(mut self, mana: i16) -> Self {
+        self.mana = mana;
+        self
+    }
+    /// Write `mana` even if it's the default value.
+    pub fn mana_explicit(mut self, mana: i16) -> Self {
+        self.mana = mana;
+        self.explicit.mana = true;
+        self
+    }
+    pub fn hp(mut self, hp: i16) -> Self {
+        self.hp = hp;
+        self
+    }
+    /// Write `hp` even if it's the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterView};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").hp_explicit(100).build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// assert!(orc.has_hp());
+    /// assert!(!orc.has_mana());
+    /// ```
+    pub fn hp_explicit(mut self, hp: i16) -> Self {
+        self.hp = hp;
+        self.explicit.hp = true;
+        self
+    }
+    pub fn inventory(mut self, inventory: &'a [u8]) -> Self {
+        self.inventory = Some(Cow::Borrowed(inventory));
+        self
+    }
+    /// Set the `inventory` field to the item ids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::inventory::{Item, ItemId};
+    /// use flatbuf_tutorial::{Monster, MonsterView};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let items = [ItemId::from(Item::Gold), ItemId::from(Item::Potion)];
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").inventory_items(&items).build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// assert!(orc.inventory_items().eq(items.iter().copied()));
+    /// ```
+    pub fn inventory_items(mut self, items: &[ItemId]) -> Self {
+        self.inventory = Some(Cow::Owned(items.iter().map(|item| item.raw()).collect()));
+        self
+    }
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+    /// Write `color` even if it's the default value.
+    pub fn color_explicit(mut self, color: Color) -> Self {
+        self.color = color;
+        self.explicit.color = true;
+        self
+    }
+    /// Append the weapon to the `weapons` field.
+    pub fn weapon(mut self, name: &'a str, damage: i16) -> Self {
+        self.weapons.push((name, damage));
+        self
+    }
+    /// Equip the `index`th weapon appended by `weapon`.
+    ///
+    /// The out of range index leaves the monster unequipped.
+    pub fn equipped(mut self, index: usize) -> Self {
+        self.equipped = Some(index);
+        self
+    }
+    pub fn path(mut self, path: &'a [Vec3]) -> Self {
+        self.path = Some(Cow::Borrowed(path));
+        self
+    }
+    /// Set the `path` field to the points, e.g. the math library vectors
+    /// with the `glam` or `nalgebra` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterView};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder()
+    ///     .name("orc")
+    ///     .path_points([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]])
+    ///     .build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// assert_eq!(2, orc.path().count());
+    /// ```
+    pub fn path_points<P: Into<Vec3>>(mut self, points: impl IntoIterator<Item = P>) -> Self {
+        self.path = Some(Cow::Owned(points.into_iter().map(Into::into).collect()));
+        self
+    }
+    /// Returns the conservative upper bound of the finished buffer size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::Monster;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let orc = Monster::builder().name("orc").weapon("axe", 5);
+    /// let mut b = FlatBufferBuilder::new();
+    /// let monster = orc.build(&mut b);
+    /// b.finish(monster, None);
+    /// assert!(b.finished_data().len() <= orc.serialized_size_hint());
+    /// ```
+    pub fn serialized_size_hint(&self) -> usize {
+        size_hint(
+            self.name,
+            self.weapons.iter().map(|(name, _)| *name),
+            self.inventory.as_deref().map(<[u8]>::len),
+            self.path.as_deref().map(<[Vec3]>::len),
+        )
+    }
+    /// Write all the scalar fields even if those are the default values,
+    /// so that those can be mutated in place later.
+    ///
+    /// It turns on the `FlatBufferBuilder::force_defaults` during the
+    /// `build` and turns it off afterward.
+    pub fn force_defaults(mut self, force_defaults: bool) -> Self {
+        self.force_defaults = force_defaults;
+        self
+    }
+}
+
+impl<'a> MonsterBuilder<'a, HasName> {
+    /// Build the `Monster` table with the provided builder.
+    pub fn build<'b>(&self, b: &mut FlatBufferBuilder<'b>) -> WIPOffset<sample::Monster<'b>> {
+        self.build_interned(b, &mut NoInterner)
+    }
+    /// Build the `Monster` table with the strings and the weapons from
+    /// `interner`.
+    fn build_interned<'b, I: Interner<'b>>(
+        &self,
+        b: &mut FlatBufferBuilder<'b>,
+        interner: &mut I,
+    ) -> WIPOffset<sample::Monster<'b>> {
+        if self.force_defaults {
+            b.force_defaults(true);
+        }
+        let weapons: Vec<_> = self
+            .weapons
+            .iter()
+            .map(|&(name, damage)| interner.weapon(b, name, damage))
+            .collect();
+        let equipped = self.equipped.and_then(|i| weapons.get(i)).copied();
+        let weapons = if self.weapons.is_empty() {
+            None
+        } else {
+            Some(b.create_vector(&weapons))
+        };
+        let name = self.name.map(|name| interner.string(b, name));
+        let inventory = self
+            .inventory
+            .as_deref()
+            .map(|inventory| b.create_vector(inventory));
+        let path = self.path.as_deref().map(|path| create_path(b, path));
+        // Same field order as `sample::Monster::create`.
+        let defaults = MonsterArgs::default();
+        let start = b.start_table();
+        if let Some(path) = path {
+            b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_PATH, path);
+        }
+        if let Some(equipped) = equipped {
+            b.push_slot_always::<WIPOffset<_>>(
+                sample::Monster::VT_EQUIPPED,
+                equipped.as_union_value(),
+            );
+        }
+        if let Some(weapons) = weapons {
+            b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_WEAPONS, weapons);
+        }
+        if let Some(inventory) = inventory {
+            b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_INVENTORY, inventory);
+        }
+        if let Some(name) = name {
+            b.push_slot_always::<WIPOffset<_>>(sample::Monster::VT_NAME, name);
+        }
+        if let Some(pos) = &self.pos {
+            b.push_slot_always::<&Vec3>(sample::Monster::VT_POS, pos);
+        }
+        if self.explicit.hp {
+            b.push_slot_always::<i16>(sample::Monster::VT_HP, self.hp);
+        } else {
+            b.push_slot::<i16>(sample::Monster::VT_HP, self.hp, defaults.hp);
+        }
+        if self.explicit.mana {
+            b.push_slot_always::<i16>(sample::Monster::VT_MANA, self.mana);
+        } else {
+            b.push_slot::<i16>(sample::Monster::VT_MANA, self.mana, defaults.mana);
+        }
+        // NONE `equipped_type` without `equipped` fails the verifier,
+        // hence it's not forced.
+        if equipped.is_some() {
+            b.push_slot_always::<Equipment>(sample::Monster::VT_EQUIPPED_TYPE, Equipment::Weapon);
+        }
+        if self.explicit.color {
+            b.push_slot_always::<Color>(sample::Monster::VT_COLOR, self.color);
+        } else {
+            b.push_slot::<Color>(sample::Monster::VT_COLOR, self.color, defaults.color);
+        }
+        let monster = b.end_table(start);
+        if self.force_defaults {
+            b.force_defaults(false);
+        }
+        WIPOffset::new(monster.value())
+    }
+}
+
+/// Source of the strings and the weapon tables of
+/// `MonsterBuilder::build_interned`.
+trait Interner<'b> {
+    fn string(&mut self, b: &mut FlatBufferBuilder<'b>, s: &str) -> WIPOffset<&'b str>;
+    fn weapon(
+        &mut self,
+        b: &mut FlatBufferBuilder<'b>,
+        name: &str,
+        damage: i16,
+    ) -> WIPOffset<Weapon<'b>> {
+        let name = self.string(b, name);
+        Weapon::create(
+            b,
+            &WeaponArgs {
+                name: Some(name),
+                damage,
+            },
+        )
+    }
+}
+
+/// `Interner` which writes every string and weapon.
+struct NoInterner;
+
+impl<'b> Interner<'b> for NoInterner {
+    fn string(&mut self, b: &mut FlatBufferBuilder<'b>, s: &str) -> WIPOffset<&'b str> {
+        b.create_string(s)
+    }
+}
+
+/// Serialize `data` with the builder from the local `pool`, which is
+/// pre-sized by the [`serialized_size_hint`].
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::{serialize_pooled, MonsterData};
+/// use flatbuf_tutorial::{FlatBufferBuilderPool, MonsterView};
+///
+/// let pool = FlatBufferBuilderPool::new().build();
+/// let data = MonsterData {
+///     name: String::from("orc"),
+///     ..Default::default()
+/// };
+/// let buf = serialize_pooled(&data, &pool);
+/// assert_eq!(Some("orc"), MonsterView::from_bytes(&buf).unwrap().name());
+/// ```
+/// [`serialized_size_hint`]: struct.MonsterData.html#method.serialized_size_hint
+pub fn serialize_pooled(data: &MonsterData, pool: &FlatBufferBuilderLocalPool) -> Vec<u8> {
+    let mut b = pool.get_with_capacity(data.serialized_size_hint());
+    let monster = data.build(&mut b);
+    b.finish(monster, None);
+    b.finished_data().to_vec()
+}
+
+/// Upper bounds of the flatbuffers layout, including the alignment
+/// paddings.
+mod layout {
+    /// Root offset and the minimum alignment padding.
+    pub const ROOT: usize = 4 + 3;
+    /// Length prefix, the null terminator and the alignment padding.
+    pub const STRING: usize = 4 + 1 + 3;
+    /// Length prefix and the alignment padding.
+    pub const VECTOR: usize = 4 + 3;
+    /// Table offset in the vector of tables.
+    pub const TABLE_OFFSET: usize = 4;
+    /// `Weapon` table with the vtable of 2 fields.
+    pub const WEAPON: usize = (4 + 4 + 2 + 3) + (4 + 2 * 2 + 1);
+    /// `MonsterList` table with the vtable of 3 fields.
+    pub const MONSTER_LIST: usize = (4 + 3 * 4 + 3) + (4 + 2 * 3 + 1);
+    /// `Monster` table with the vtable of 11 fields.
+    pub const MONSTER: usize = (4 + 12 + 6 * 4 + 2 + 2 + 1 + 1 + 6 * 3) + (4 + 2 * 11 + 1);
+    /// `Vec3` struct.
+    pub const VEC3: usize = 12;
+    /// `Vec3` alignment in the buffer, which is the `float` alignment, as
+    /// the schema has no `force_align` on it.
+    pub const VEC3_ALIGN: usize = 4;
+}
+
+// The generated `Vec3` is the byte array, which is read with the
+// unaligned loads, hence its Rust alignment may be lower than the buffer
+// alignment, but never higher.
+const _: () = assert!(mem::size_of::<Vec3>() == layout::VEC3);
+const _: () = assert!(mem::align_of::<Vec3>() <= layout::VEC3_ALIGN);
+const _: () = assert!(layout::VEC3 % layout::VEC3_ALIGN == 0);
+
+/// Create the `path` vector, with the data aligned to the `Vec3`
+/// alignment in the buffer.
+fn create_path<'b>(b: &mut FlatBufferBuilder<'b>, path: &[Vec3]) -> WIPOffset<Vector<'b, Vec3>> {
+    // `create_vector` aligns the data to `Push::alignment`, which has to
+    // be in sync with the schema, e.g. after adding `force_align`.
+    debug_assert_eq!(layout::VEC3_ALIGN, <Vec3 as Push>::alignment().value());
+    let path = b.create_vector(path);
+    // The data follows the length prefix, and the finished buffer is
+    // aligned to the largest alignment from its end.
+    debug_assert_eq!(0, path.value() as usize % layout::VEC3_ALIGN);
+    path
+}
+
+fn size_hint<'s>(
+    name: Option<&str>,
+    weapons: impl Iterator<Item = &'s str>,
+    inventory: Option<usize>,
+    path: Option<usize>,
+) -> usize {
+    let string = |s: &str| layout::STRING + s.len();
+    let vector = |n: usize, size: usize| layout::VECTOR + n * size;
+    let mut hint = layout::ROOT + layout::MONSTER;
+    hint += name.map_or(0, string);
+    let mut count = 0;
+    for name in weapons {
+        hint += layout::WEAPON + string(name);
+        count += 1;
+    }
+    if count > 0 {
+        hint += vector(count, layout::TABLE_OFFSET);
+    }
+    hint += inventory.map_or(0, |n| vector(n, 1));
+    hint += path.map_or(0, |n| vector(n, layout::VEC3));
+    hint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "pool-v3")]
+    use crate::pool::PoolStats;
+    use std::ptr;
+    #[test]
+    fn builder_with_different_capacities() {
+        let capacities = [1usize, 16, 32, 64, 128, 256, 1024, 2048, 4096];
+        for &t in &capacities {
+            let _builder = FlatBufferBuilder::with_capacity(t);
+        }
+    }
+    #[test]
+    fn serialize_sword_and_axe() {
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let name = b.create_string("Sword");
+        let _sword = Weapon::create(
+            &mut b,
+            &WeaponArgs {
+                name: Some(name),
+                damage: 3,
+            },
+        );
+        let name = b.create_string("Axe");
+        let _axe = Weapon::create(
+            &mut b,
+            &WeaponArgs {
+                name: Some(name),
+                damage: 5,
+            },
+        );
+    }
+    #[test]
+    fn serialize_weapons() {
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let name = b.create_string("Sword");
+        let sword = Weapon::create(
+            &mut b,
+            &WeaponArgs {
+                name: Some(name),
+                damage: 3,
+            },
+        );
+        let name = b.create_string("Axe");
+        let axe = Weapon::create(
+            &mut b,
+            &WeaponArgs {
+                name: Some(name),
+                damage: 5,
+            },
+        );
+        let _weapons = b.create_vector(&[sword, axe]);
+    }
+    #[test]
+    fn serialize_monster() {
+        let mut builder = FlatBufferBuilderPool::get_or_alloc();
+        let orc = super::Monster::create(&mut builder, "ore");
+        builder.finish(orc, None);
+    }
+    #[test]
+    fn serialize_and_deserialize_monster() {
+        use super::sample::root_as_monster;
+        let mut builder = FlatBufferBuilderPool::get_or_alloc();
+        let godzilla = super::Monster::create(&mut builder, "godzilla");
+        builder.finish(godzilla, None);
+        let buf = builder.finished_data(); // Of type `&[u8]`
+        let monster = root_as_monster(buf).unwrap();
+        match monster.name() {
+            Some(got) => assert_eq!("godzilla", got),
+            _ => panic!("unexpected None"),
+        };
+    }
+    #[test]
+    fn multiple_monsters() {
+        use super::sample::root_as_monster;
+        let monsters = ["godzilla", "minilla", "ore"];
+        for name in &monsters {
+            let mut builder = FlatBufferBuilderPool::get_or_alloc();
+            let monster = super::Monster::create(&mut builder, name);
+            builder.finish(monster, None);
+            let buf = builder.finished_data();
+            let monster = root_as_monster(buf).unwrap();
+            match monster.name() {
+                Some(got) => assert_eq!(name, &got),
+                _ => panic!("unexpected None"),
+            }
+        }
+    }
+    #[test]
+    fn builder_with_optional_fields() {
+        use super::sample::root_as_monster;
+        let inventory = [0u8, 1, 2];
+        let path = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+        let mut b = FlatBufferBuilder::new();
+        let orc = super::Monster::builder()
+            .pos(Vec3::new(1.0, 2.0, 3.0))
+            .mana(10)
+            .hp(80)
+            .inventory(&inventory)
+            .color(Color::Red)
+            .weapon("axe", 5)
+            .weapon("sword", 3)
+            .equipped(1)
+            .path(&path)
+            .name("orc")
+            .build(&mut b);
+        b.finish(orc, None);
+        let orc = root_as_monster(b.finished_data()).unwrap();
+        assert_eq!(Some("orc"), orc.name());
+        assert_eq!(Some(&Vec3::new(1.0, 2.0, 3.0)), orc.pos());
+        assert_eq!(10, orc.mana());
+        assert_eq!(80, orc.hp());
+        assert_eq!(Some(&inventory[..]), orc.inventory().map(|v| v.bytes()));
+        assert_eq!(Color::Red, orc.color());
+        assert_eq!(2, orc.weapons().unwrap().len());
+        assert_eq!(Some("sword"), orc.equipped_as_weapon().unwrap().name());
+        assert_eq!(
+            path.to_vec(),
+            orc.path().unwrap().iter().copied().collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn builder_out_of_range_equipped() {
+        use super::sample::root_as_monster;
+        let mut b = FlatBufferBuilder::new();
+        let orc = super::Monster::builder()
+            .name("orc")
+            .weapon("axe", 5)
+            .equipped(1)
+            .build(&mut b);
+        b.finish(orc, None);
+        let orc = root_as_monster(b.finished_data()).unwrap();
+        assert_eq!(Equipment::NONE, orc.equipped_type());
+        assert!(orc.equipped().is_none());
+    }
+    #[test]
+    fn builder_byte_identical_to_plain_builder() {
+        struct Test {
+            name: &'static str,
+            hp: i16,
+            weapons: Vec<(&'static str, i16)>,
+            equipped: Option<usize>,
+            inventory: Option<&'static [u8]>,
+        }
+        let tests = [
+            Test {
+                name: "name only",
+                hp: 100,
+                weapons: vec![],
+                equipped: None,
+                inventory: None,
+            },
+            Test {
+                name: "orc with weapons",
+                hp: 80,
+                weapons: vec![("axe", 5), ("sword", 3)],
+                equipped: Some(0),
+                inventory: Some(&[0, 1, 2, 3]),
+            },
+            Test {
+                name: "zero hp",
+                hp: 0,
+                weapons: vec![("bow", 1)],
+                equipped: None,
+                inventory: Some(&[]),
+            },
+        ];
+        for t in &tests {
+            let mut want = FlatBufferBuilder::new();
+            let weapons: Vec<_> = t
+                .weapons
+                .iter()
+                .map(|(name, damage)| {
+                    let name = want.create_string(name);
+                    Weapon::create(
+                        &mut want,
+                        &WeaponArgs {
+                            name: Some(name),
+                            damage: *damage,
+                        },
+                    )
+                })
+                .collect();
+            let equipped = t.equipped.map(|i| weapons[i]);
+            let weapons = if weapons.is_empty() {
+                None
+            } else {
+                Some(want.create_vector(&weapons))
+            };
+            let name = want.create_string(t.name);
+            let inventory = t.inventory.map(|inventory| want.create_vector(inventory));
+            let monster = sample::Monster::create(
+                &mut want,
+                &MonsterArgs {
+                    hp: t.hp,
+                    name: Some(name),
+                    inventory,
+                    weapons,
+                    equipped_type: match equipped {
+                        Some(_) => Equipment::Weapon,
+                        None => Equipment::NONE,
+                    },
+                    equipped: equipped.map(|weapon| weapon.as_union_value()),
+                    ..Default::default()
+                },
+            );
+            want.finish(monster, None);
+
+            let mut builder = super::Monster::builder().hp(t.hp);
+            for (name, damage) in &t.weapons {
+                builder = builder.weapon(name, *damage);
+            }
+            if let Some(index) = t.equipped {
+                builder = builder.equipped(index);
+            }
+            if let Some(inventory) = t.inventory {
+                builder = builder.inventory(inventory);
+            }
+            let mut got = FlatBufferBuilder::new();
+            let monster = builder.name(t.name).build(&mut got);
+            got.finish(monster, None);
+            assert_eq!(want.finished_data(), got.finished_data(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn default_buf() {
+        let unknown = Monster::default_buf();
+        assert!(ptr::eq(unknown, Monster::default_buf()));
+        let verified = MonsterBuf::new(unknown.as_ref().to_vec()).unwrap();
+        assert_eq!(Ok(()), verified.view().validate());
+        assert_eq!(Some(DEFAULT_NAME), unknown.name());
+
+        // Byte identical to the monster built from the default data.
+        let mut b = FlatBufferBuilder::new();
+        let monster = MonsterData::default().build(&mut b);
+        b.finish(monster, None);
+        assert_eq!(b.finished_data(), unknown.as_ref());
+        assert_eq!(MonsterData::default(), MonsterData::from(unknown.view()));
+    }
+    #[test]
+    fn builder_force_defaults() {
+        use crate::MonsterBuf;
+        struct Test {
+            name: &'static str,
+            builder: MonsterBuilder<'static, HasName>,
+            want: (bool, bool, bool),
+        }
+        let tests = [
+            Test {
+                name: "default values",
+                builder: super::Monster::builder().name("orc"),
+                want: (false, false, false),
+            },
+            Test {
+                name: "non-default values",
+                builder: super::Monster::builder()
+                    .name("orc")
+                    .mana(1)
+                    .hp(2)
+                    .color(Color::Red),
+                want: (true, true, true),
+            },
+            Test {
+                name: "force defaults",
+                builder: super::Monster::builder().name("orc").force_defaults(true),
+                want: (true, true, true),
+            },
+            Test {
+                name: "explicit hp",
+                builder: super::Monster::builder().name("orc").hp_explicit(100),
+                want: (false, true, false),
+            },
+            Test {
+                name: "explicit mana and color",
+                builder: super::Monster::builder()
+                    .name("orc")
+                    .mana_explicit(150)
+                    .color_explicit(Color::Blue),
+                want: (true, false, true),
+            },
+        ];
+        for t in &tests {
+            let mut b = FlatBufferBuilder::new();
+            let monster = t.builder.build(&mut b);
+            b.finish(monster, None);
+            let mut monster = MonsterBuf::new(b.finished_data().to_vec()).unwrap();
+            let view = monster.view();
+            let got = (view.has_mana(), view.has_hp(), view.has_color());
+            assert_eq!(t.want, got, "{}", t.name);
+            assert_eq!(t.want.1, monster.mutate_hp(-1), "{}", t.name);
+            let want = if t.want.1 { -1 } else { 100 };
+            assert_eq!(want, monster.hp(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn builder_force_defaults_turned_off_after_build() {
+        use crate::MonsterView;
+        let mut b = FlatBufferBuilder::new();
+        let orc = super::Monster::builder()
+            .name("orc")
+            .force_defaults(true)
+            .build(&mut b);
+        b.finish(orc, None);
+        b.reset();
+        let orc = super::Monster::builder().name("orc").build(&mut b);
+        b.finish(orc, None);
+        let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert!(!orc.has_hp());
+    }
+    #[test]
+    fn build_pooled() {
+        let weapon = |name: &str, damage| WeaponData {
+            name: name.to_string(),
+            damage,
+        };
+        struct Test {
+            name: &'static str,
+            data: MonsterData,
+        }
+        let tests = [
+            Test {
+                name: "name only",
+                data: MonsterData {
+                    name: String::from("orc"),
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "tutorial orc",
+                data: MonsterData {
+                    name: String::from("orc"),
+                    pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+                    hp: 300,
+                    inventory: (0..10).collect(),
+                    color: Color::Red,
+                    weapons: vec![weapon("Sword", 3), weapon("Axe", 5)],
+                    equipped: Some(1),
+                    path: vec![Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)],
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "larger than the pooled buffer",
+                data: MonsterData {
+                    name: String::from("godzilla"),
+                    inventory: vec![0; 4_096],
+                    ..Default::default()
+                },
+            },
+        ];
+        let pool = FlatBufferBuilderPool::new().build();
+        for t in &tests {
+            let mut b = FlatBufferBuilder::new();
+            let monster = t.data.build(&mut b);
+            b.finish(monster, None);
+            let want = b.finished_data();
+            assert_eq!(want, Monster::build_pooled(&t.data).as_ref(), "{}", t.name);
+            assert_eq!(
+                want,
+                Monster::build_with(&pool, &t.data).as_ref(),
+                "{}",
+                t.name
+            );
+        }
+    }
+    /// Build the monster with `path` and the preceding fields, and
+    /// assert the `path` data is aligned in the finished buffer.
+    fn assert_path_aligned(path: &[Vec3], name: &str, inventory: &[u8], size_prefixed: bool) {
+        let what = format!(
+            "path {} name {:?} inventory {} size prefixed {}",
+            path.len(),
+            name,
+            inventory.len(),
+            size_prefixed
+        );
+        let mut orc = Monster::builder()
+            .name(name)
+            .inventory(inventory)
+            .path(path)
+            .weapon(name, 1);
+        if name.len() % 2 == 1 {
+            orc = orc.pos(Vec3::new(1.0, 2.0, 3.0));
+        }
+        let mut b = FlatBufferBuilder::new();
+        let monster = orc.build(&mut b);
+        let prefix = if size_prefixed {
+            b.finish_size_prefixed(monster, None);
+            flatbuffers::SIZE_SIZEPREFIX
+        } else {
+            b.finish(monster, None);
+            0
+        };
+        let buf = b.finished_data();
+        // The verifier checks the alignment of every field.
+        let view = crate::MonsterView::from_bytes(&buf[prefix..]).expect(&what);
+        if size_prefixed {
+            sample::size_prefixed_root_as_monster(buf).expect(&what);
+        }
+        // Follow the `path` offset by hand.
+        let read_u32 = |at: usize| {
+            let mut word = [0; 4];
+            word.copy_from_slice(&buf[at..at + 4]);
+            u32::from_le_bytes(word) as usize
+        };
+        let field = prefix + view.field_pos(sample::Monster::VT_PATH).unwrap();
+        let vector = field + read_u32(field);
+        let data = vector + flatbuffers::SIZE_UOFFSET;
+        assert_eq!(path.len(), read_u32(vector), "{}", what);
+        assert_eq!(0, data % layout::VEC3_ALIGN, "{}", what);
+        let got: Vec<_> = view.path().collect();
+        assert_eq!(path, &got[..], "{}", what);
+    }
+    #[test]
+    fn path_alignment() {
+        let points: Vec<_> = (0..5).map(|i| Vec3::new(i as f32, 0.5, -0.5)).collect();
+        for path_len in 0..=points.len() {
+            for name in &["", "a", "ab", "abc", "abcd"] {
+                for inventory_len in 0..=3 {
+                    let inventory = vec![7; inventory_len];
+                    for &size_prefixed in &[false, true] {
+                        let path = &points[..path_len];
+                        assert_path_aligned(path, name, &inventory, size_prefixed);
+                    }
+                }
+            }
+        }
+    }
+    fn build_monster(name: &str, hp: i16, mana: i16) -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let monster = Monster::builder()
+            .name(name)
+            .hp(hp)
+            .mana(mana)
+            .build(&mut b);
+        b.finish(monster, None);
+        b.finished_data().to_vec()
+    }
+    /// The accessors read the fields in place, e.g. `name` is the `&str`
+    /// in the serialized buffer, which is what flatbuffers is for: no
+    /// parse step, and no allocation or copy on the read side.
+    #[test]
+    fn name_zero_copy() {
+        let buf: Vec<u8> = build_monster("Orc", 300, 150);
+        let monster = flatbuffers::root::<sample::Monster>(&buf).unwrap();
+        let name: &str = monster.name().unwrap();
+        assert_eq!("Orc", name);
+        let end = buf.as_ptr_range().end;
+        assert!(name.as_ptr() >= buf.as_ptr() && name.as_ptr() < end);
+        assert!(name.as_bytes().as_ptr_range().end <= end);
+    }
+    #[test]
+    #[cfg(feature = "pool-v3")]
+    fn build_pooled_hits_the_pool() {
+        let data = MonsterData {
+            name: String::from("orc"),
+            ..Default::default()
+        };
+        let before = FlatBufferBuilderPool::global_stats();
+        Monster::build_pooled(&data);
+        Monster::build_pooled(&data);
+        let after = FlatBufferBuilderPool::global_stats();
+        assert!(before.hits < after.hits, "{:?} {:?}", before, after);
+        assert!(
+            before.returns + 2 <= after.returns,
+            "{:?} {:?}",
+            before,
+            after
+        );
+
+        let pool = FlatBufferBuilderPool::new().init_pool_size(0).build();
+        Monster::build_with(&pool, &data);
+        let want = PoolStats {
+            hits: 0,
+            misses: 1,
+            returns: 1,
+        };
+        assert_eq!(want, pool.stats());
+        Monster::build_with(&pool, &data);
+        let want = PoolStats {
+            hits: 1,
+            misses: 1,
+            returns: 2,
+        };
+        assert_eq!(want, pool.stats());
+    }
+}
@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Typestate build session over the pooled builder.
+use std::marker::PhantomData;
+
+use flatbuffers::{Push, Vector, WIPOffset};
+
+use super::{HasName, MonsterBuilder};
+use crate::model::my_game::sample;
+use crate::{FlatBufferBuilderPool, GlobalBuilder};
+
+/// [`BuildSession`] state with the reset builder.
+///
+/// [`buildsession`]: struct.BuildSession.html
+pub struct Empty;
+
+/// [`BuildSession`] state writing the strings and the tables.
+///
+/// [`buildsession`]: struct.BuildSession.html
+pub struct Building;
+
+/// Typestate build session over the builder from the global pool.
+///
+/// Only the `Building` session writes to the buffer, and `finish` hands
+/// out the copy of the finished data right away, so the data is never
+/// read before the `finish`.  There is no `finished_data`, e.g. the
+/// following code doesn't compile:
+///
+/// ```compile_fail
+/// use flatbuf_tutorial::monster::BuildSession;
+///
+/// let mut session = BuildSession::new();
+/// let name = session.create_string("orc");
+/// let buf = session.finished_data().to_vec();
+/// ```
+///
+/// The builder goes back to the pool when the session is dropped, in
+/// either state.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::BuildSession;
+/// use flatbuf_tutorial::{Monster, MonsterView};
+///
+/// let mut session = BuildSession::new();
+/// let orc = session.build(&Monster::builder().name("orc").hp(80));
+/// let (orc, session) = session.finish(orc);
+/// assert_eq!(Some("orc"), MonsterView::from_bytes(&orc).unwrap().name());
+///
+/// // Reuse the same builder for the next buffer.
+/// let mut session = session.start();
+/// let goblin = session.build(&Monster::builder().name("goblin"));
+/// let (goblin, _) = session.finish(goblin);
+/// assert_eq!(Some("goblin"), MonsterView::from_bytes(&goblin).unwrap().name());
+/// ```
+pub struct BuildSession<S> {
+    builder: GlobalBuilder,
+    state: PhantomData<S>,
+}
+
+impl BuildSession<Building> {
+    /// Start the session with the builder from the global pool.
+    pub fn new() -> Self {
+        Self::with_builder(FlatBufferBuilderPool::get_or_alloc())
+    }
+    fn with_builder(builder: GlobalBuilder) -> Self {
+        Self {
+            builder,
+            state: PhantomData,
+        }
+    }
+    pub fn create_string(&mut self, s: &str) -> WIPOffset<&'static str> {
+        self.builder.create_string(s)
+    }
+    pub fn create_vector<T: Push + Copy>(
+        &mut self,
+        items: &[T],
+    ) -> WIPOffset<Vector<'static, T::Output>> {
+        self.builder.create_vector(items)
+    }
+    /// Build the `Monster` table of `builder`.
+    pub fn build(
+        &mut self,
+        builder: &MonsterBuilder<'_, HasName>,
+    ) -> WIPOffset<sample::Monster<'static>> {
+        builder.build(&mut self.builder)
+    }
+    /// Finish the buffer with `root`, and returns the copy of the
+    /// finished data along with the session over the reset builder.
+    pub fn finish<T>(mut self, root: WIPOffset<T>) -> (Vec<u8>, BuildSession<Empty>) {
+        self.builder.finish(root, None);
+        let data = self.builder.finished_data().to_vec();
+        self.builder.reset();
+        let session = BuildSession {
+            builder: self.builder,
+            state: PhantomData,
+        };
+        (data, session)
+    }
+}
+
+impl Default for BuildSession<Building> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildSession<Empty> {
+    /// Start writing the next buffer with the same builder.
+    pub fn start(self) -> BuildSession<Building> {
+        BuildSession::with_builder(self.builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::MonsterData;
+    use crate::view::MonsterView;
+
+    #[test]
+    fn session() {
+        let mut session = BuildSession::new();
+        let name = session.create_string("axe");
+        let inventory = session.create_vector(&[1u8, 2, 3]);
+        let axe = sample::Weapon::create(
+            &mut session.builder,
+            &sample::WeaponArgs {
+                name: Some(name),
+                damage: 5,
+            },
+        );
+        let weapons = session.create_vector(&[axe]);
+        let name = session.create_string("orc");
+        let orc = sample::Monster::create(
+            &mut session.builder,
+            &sample::MonsterArgs {
+                name: Some(name),
+                inventory: Some(inventory),
+                weapons: Some(weapons),
+                ..Default::default()
+            },
+        );
+        let (orc, session) = session.finish(orc);
+        let orc = MonsterView::from_bytes(&orc).unwrap();
+        assert_eq!(Some(&[1, 2, 3][..]), orc.inventory());
+        assert_eq!(Some("axe"), orc.weapons().next().unwrap().name());
+
+        // The next buffer doesn't carry the previous one over.
+        let data = MonsterData {
+            name: String::from("goblin"),
+            ..Default::default()
+        };
+        let mut session = session.start();
+        let goblin = session.build(&data.builder());
+        let (goblin, _) = session.finish(goblin);
+        let mut b = flatbuffers::FlatBufferBuilder::new();
+        let want = data.build(&mut b);
+        b.finish(want, None);
+        assert_eq!(b.finished_data(), &goblin[..]);
+    }
+}
@@ -0,0 +1,555 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Append-only `Monster` log files.
+//!
+//! The log is the sequence of the records, each of which is the little
+//! endian `u32` length and the `u32` CRC32 of the `Monster` buffer,
+//! followed by the buffer itself.  The record is identified by its offset
+//! in the file, as returned by [`Writer::write`].
+//!
+//! The crash in the middle of the write leaves the torn record at the end
+//! of the log.  [`Reader::position`] after the last record is the length
+//! to truncate the log to before appending to it again.
+//!
+//! The log written `with_integrity(true)` has the [`integrity`] sealed
+//! buffer, with the CRC32C footer, after the `u32` length instead, and
+//! is read by the reader `with_integrity(true)` only.
+//!
+//! [`writer::write`]: struct.Writer.html#method.write
+//! [`reader::position`]: struct.Reader.html#method.position
+//! [`integrity`]: ../integrity/index.html
+use std::convert::TryFrom;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::error::{IntegrityError, StreamError};
+use crate::monster::integrity;
+use crate::MonsterBuf;
+
+/// Default [`Reader`] limit of the record length.
+///
+/// [`reader`]: struct.Reader.html
+pub const DEFAULT_MAX_RECORD_LEN: usize = 16 * 1024 * 1024;
+
+const HEADER_LEN: usize = 8;
+
+/// Header of the sealed records, which is the length only.
+const SEALED_HEADER_LEN: usize = 4;
+
+fn header_len(integrity: bool) -> usize {
+    if integrity {
+        SEALED_HEADER_LEN
+    } else {
+        HEADER_LEN
+    }
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    let mut h = crc32fast::Hasher::new();
+    h.update(data);
+    h.finalize()
+}
+
+/// Writer of the records at the end of the log.
+///
+/// The records are buffered, and [`flush`] and [`sync`] push them to the
+/// file and to the disk.  Dropping the writer flushes it and ignores the
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::stream::{Reader, Writer};
+/// use flatbuf_tutorial::monster::MonsterData;
+/// use flatbuf_tutorial::Monster;
+///
+/// # let path = std::env::temp_dir().join(format!("stream-writer-{}.log", std::process::id()));
+/// let mut w = Writer::create(&path)?;
+/// for name in &["orc", "goblin"] {
+///     let data = MonsterData {
+///         name: name.to_string(),
+///         ..Default::default()
+///     };
+///     w.write(&Monster::build_pooled(&data))?;
+/// }
+/// w.sync()?;
+///
+/// let names = Reader::open(&path)?
+///     .map(|record| record.map(|(_, buf)| buf.name().unwrap().to_string()))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(vec!["orc", "goblin"], names);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+/// [`flush`]: #method.flush
+/// [`sync`]: #method.sync
+#[derive(Debug)]
+pub struct Writer {
+    w: BufWriter<File>,
+    pos: u64,
+    integrity: bool,
+}
+
+impl Writer {
+    /// Create the empty log at `path`, replacing the file.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            w: BufWriter::new(File::create(path)?),
+            pos: 0,
+            integrity: false,
+        })
+    }
+    /// Open the log at `path` to append the records, creating the file
+    /// if it doesn't exist.
+    pub fn open_append<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let pos = file.metadata()?.len();
+        Ok(Self {
+            w: BufWriter::new(file),
+            pos,
+            integrity: false,
+        })
+    }
+    /// Write the sealed records, which should match the records already
+    /// in the log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::stream::{Reader, Writer};
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::Monster;
+    ///
+    /// # let path = std::env::temp_dir().join(format!("stream-integrity-{}.log", std::process::id()));
+    /// let mut w = Writer::create(&path)?.with_integrity(true);
+    /// w.write(&Monster::build_pooled(&MonsterData::default()))?;
+    /// w.sync()?;
+    ///
+    /// let mut r = Reader::open(&path)?.with_integrity(true);
+    /// assert_eq!(Some("unknown"), r.next().unwrap()?.1.name());
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_integrity(self, integrity: bool) -> Self {
+        Self { integrity, ..self }
+    }
+    /// Append `buf` as the record, and returns its offset.
+    pub fn write(&mut self, buf: &MonsterBuf) -> io::Result<u64> {
+        let sealed;
+        let data = if self.integrity {
+            sealed = integrity::seal(buf);
+            &sealed[..]
+        } else {
+            buf.as_ref()
+        };
+        let len = u32::try_from(data.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "monster too large"))?;
+        self.w.write_all(&len.to_le_bytes())?;
+        if !self.integrity {
+            self.w.write_all(&checksum(data).to_le_bytes())?;
+        }
+        self.w.write_all(data)?;
+        let offset = self.pos;
+        self.pos += (header_len(self.integrity) + data.len()) as u64;
+        Ok(offset)
+    }
+    /// Returns the offset of the next record.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.w.flush()
+    }
+    /// Flush the records and persist them with `sync_data`.
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.w.flush()?;
+        self.w.get_ref().sync_data()
+    }
+}
+
+/// How [`Reader`] handles the broken records.
+///
+/// [`reader`]: struct.Reader.html
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadMode {
+    /// Returns the error for the broken record and ends the log there.
+    #[default]
+    Strict,
+    /// Skips the record with the checksum mismatch, the invalid `Monster`
+    /// or the missing seal, whose boundary is still known, and ends the
+    /// log at the torn record silently.
+    Tolerant,
+}
+
+/// Reader of the `(offset, monster)` records in the log.
+///
+/// The oversized record length, which is the corrupted prefix rather than
+/// the torn write, is the error in both modes.
+#[derive(Debug)]
+pub struct Reader {
+    r: BufReader<File>,
+    pos: u64,
+    mode: ReadMode,
+    max_record_len: usize,
+    integrity: bool,
+    done: bool,
+}
+
+impl Reader {
+    /// Open the log at `path` in the [`ReadMode::Strict`] mode.
+    ///
+    /// [`readmode::strict`]: enum.ReadMode.html#variant.Strict
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            r: BufReader::new(File::open(path)?),
+            pos: 0,
+            mode: ReadMode::default(),
+            max_record_len: DEFAULT_MAX_RECORD_LEN,
+            integrity: false,
+            done: false,
+        })
+    }
+    pub fn mode(self, mode: ReadMode) -> Self {
+        Self { mode, ..self }
+    }
+    /// Set the limit of the record length, which guards the allocation
+    /// against the corrupted length prefix.
+    pub fn max_record_len(self, max_record_len: usize) -> Self {
+        Self {
+            max_record_len,
+            ..self
+        }
+    }
+    /// Read the sealed records written by the writer
+    /// `with_integrity(true)`.
+    pub fn with_integrity(self, integrity: bool) -> Self {
+        Self { integrity, ..self }
+    }
+    /// Move to the record at `offset`, e.g. the one returned by
+    /// [`Writer::write`], and resume the reader ended by the error.
+    ///
+    /// [`writer::write`]: struct.Writer.html#method.write
+    pub fn seek_to(&mut self, offset: u64) -> io::Result<()> {
+        self.pos = self.r.seek(SeekFrom::Start(offset))?;
+        self.done = false;
+        Ok(())
+    }
+    /// Returns the offset of the next record, which is the end of the
+    /// last complete record at the end of the log.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+    fn read_record(&mut self) -> Option<Result<(u64, MonsterBuf), StreamError>> {
+        let offset = self.pos;
+        let header_len = header_len(self.integrity);
+        let mut header = [0; HEADER_LEN];
+        match self.read_full(&mut header[..header_len]) {
+            Err(err) => return Some(Err(err.into())),
+            Ok(0) => return None,
+            Ok(n) if n < header_len => return Some(Err(StreamError::Truncated { offset })),
+            Ok(_) => {}
+        }
+        let mut word = [0; 4];
+        word.copy_from_slice(&header[..4]);
+        let len = u32::from_le_bytes(word) as usize;
+        word.copy_from_slice(&header[4..]);
+        let want = u32::from_le_bytes(word);
+        if len > self.max_record_len {
+            return Some(Err(StreamError::RecordTooLarge { offset, len }));
+        }
+        let mut buf = vec![0; len];
+        match self.read_full(&mut buf) {
+            Err(err) => return Some(Err(err.into())),
+            Ok(n) if n < len => return Some(Err(StreamError::Truncated { offset })),
+            Ok(_) => {}
+        }
+        self.pos += (header_len + len) as u64;
+        if self.integrity {
+            return Some(match integrity::unseal_vec(buf) {
+                Ok(buf) => Ok((offset, buf)),
+                Err(IntegrityError::BadCrc { want, got }) => {
+                    Err(StreamError::Crc { offset, want, got })
+                }
+                Err(IntegrityError::Invalid(err)) => Err(StreamError::Invalid { offset, err }),
+                Err(err) => Err(StreamError::Unsealed { offset, err }),
+            });
+        }
+        let got = checksum(&buf);
+        if got != want {
+            return Some(Err(StreamError::Crc { offset, want, got }));
+        }
+        Some(match MonsterBuf::new(buf) {
+            Ok(buf) => Ok((offset, buf)),
+            Err(err) => Err(StreamError::Invalid { offset, err }),
+        })
+    }
+    /// Fill `buf` across the partial reads, and returns the number of
+    /// bytes read, which is short of `buf` only at the end of the log.
+    fn read_full(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            match self.r.read(&mut buf[n..]) {
+                Ok(0) => break,
+                Ok(m) => n += m,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(n)
+    }
+}
+
+impl Iterator for Reader {
+    type Item = Result<(u64, MonsterBuf), StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let result = self.read_record();
+            match (&result, self.mode) {
+                (None, _) => self.done = true,
+                (Some(Ok(_)), _) => return result,
+                (Some(Err(StreamError::Crc { .. })), ReadMode::Tolerant)
+                | (Some(Err(StreamError::Invalid { .. })), ReadMode::Tolerant)
+                | (Some(Err(StreamError::Unsealed { .. })), ReadMode::Tolerant) => continue,
+                (Some(Err(StreamError::Truncated { .. })), ReadMode::Tolerant) => self.done = true,
+                (Some(Err(_)), _) => {
+                    self.done = true;
+                    return result;
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::MonsterData;
+    use crate::Monster;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn orc(hp: i16) -> MonsterBuf {
+        Monster::build_pooled(&MonsterData {
+            name: String::from("orc"),
+            hp,
+            ..Default::default()
+        })
+    }
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("stream-{}-{}.log", name, std::process::id()))
+    }
+    /// Write the orcs with `hp` 1 to 4, in two writer sessions, and
+    /// returns their offsets.
+    fn write_log(path: &Path) -> Vec<u64> {
+        write_log_with(path, false)
+    }
+    fn write_log_with(path: &Path, integrity: bool) -> Vec<u64> {
+        let mut offsets = Vec::new();
+        let mut w = Writer::create(path).unwrap().with_integrity(integrity);
+        offsets.push(w.write(&orc(1)).unwrap());
+        offsets.push(w.write(&orc(2)).unwrap());
+        w.sync().unwrap();
+        drop(w);
+        let mut w = Writer::open_append(path).unwrap().with_integrity(integrity);
+        offsets.push(w.write(&orc(3)).unwrap());
+        offsets.push(w.write(&orc(4)).unwrap());
+        w.flush().unwrap();
+        offsets
+    }
+    fn hps(reader: Reader) -> Result<Vec<(u64, i16)>, StreamError> {
+        reader
+            .map(|record| record.map(|(offset, buf)| (offset, buf.hp())))
+            .collect()
+    }
+    #[test]
+    fn append_across_sessions() {
+        let path = temp_path("append");
+        let offsets = write_log(&path);
+        let want: Vec<_> = offsets.iter().copied().zip(1..=4).collect();
+        assert_eq!(want, hps(Reader::open(&path).unwrap()).unwrap());
+        let mut reader = Reader::open(&path).unwrap();
+        reader.by_ref().for_each(drop);
+        assert_eq!(fs::metadata(&path).unwrap().len(), reader.position());
+        fs::remove_file(&path).unwrap();
+    }
+    #[test]
+    fn seek_to() {
+        let path = temp_path("seek");
+        let offsets = write_log(&path);
+        let mut reader = Reader::open(&path).unwrap();
+        reader.seek_to(offsets[2]).unwrap();
+        assert_eq!(vec![(offsets[2], 3), (offsets[3], 4)], hps(reader).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+    #[test]
+    fn corrupted() {
+        struct Test {
+            name: &'static str,
+            corrupt: fn(&mut Vec<u8>, &[u64]),
+            mode: ReadMode,
+            want: Result<Vec<i16>, &'static str>,
+        }
+        let tests = [
+            Test {
+                name: "crc strict",
+                corrupt: |log, offsets| log[offsets[1] as usize + 4] ^= 0xff,
+                mode: ReadMode::Strict,
+                want: Err("checksum"),
+            },
+            Test {
+                name: "crc tolerant",
+                corrupt: |log, offsets| log[offsets[1] as usize + 4] ^= 0xff,
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 3, 4]),
+            },
+            Test {
+                name: "payload tolerant",
+                corrupt: |log, offsets| log[offsets[2] as usize + HEADER_LEN] ^= 0xff,
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 2, 4]),
+            },
+            Test {
+                name: "torn strict",
+                corrupt: |log, _| log.truncate(log.len() - 3),
+                mode: ReadMode::Strict,
+                want: Err("truncated"),
+            },
+            Test {
+                name: "torn tolerant",
+                corrupt: |log, _| log.truncate(log.len() - 3),
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 2, 3]),
+            },
+            Test {
+                name: "torn header tolerant",
+                corrupt: |log, offsets| log.truncate(offsets[3] as usize + 5),
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 2, 3]),
+            },
+            Test {
+                name: "oversized tolerant",
+                corrupt: |log, offsets| log[offsets[3] as usize + 3] = 0xff,
+                mode: ReadMode::Tolerant,
+                want: Err("too large"),
+            },
+        ];
+        for t in &tests {
+            let path = temp_path("corrupted");
+            let offsets = write_log(&path);
+            let mut log = fs::read(&path).unwrap();
+            (t.corrupt)(&mut log, &offsets);
+            fs::write(&path, &log).unwrap();
+            let got = hps(Reader::open(&path).unwrap().mode(t.mode));
+            match (&t.want, got) {
+                (Ok(want), Ok(got)) => {
+                    let got: Vec<_> = got.into_iter().map(|(_, hp)| hp).collect();
+                    assert_eq!(want, &got, "{}", t.name);
+                }
+                (Err(want), Err(err)) => {
+                    assert!(err.to_string().contains(want), "{}: {}", t.name, err)
+                }
+                (want, got) => panic!("{}: want {:?}, got {:?}", t.name, want, got),
+            }
+            fs::remove_file(&path).unwrap();
+        }
+    }
+    #[test]
+    fn strict_records_before_error() {
+        let path = temp_path("strict");
+        let offsets = write_log(&path);
+        let mut log = fs::read(&path).unwrap();
+        log[offsets[2] as usize + 4] ^= 0xff;
+        fs::write(&path, &log).unwrap();
+        let mut reader = Reader::open(&path).unwrap();
+        assert_eq!(1, reader.next().unwrap().unwrap().1.hp());
+        assert_eq!(2, reader.next().unwrap().unwrap().1.hp());
+        match reader.next() {
+            Some(Err(StreamError::Crc { offset, .. })) => assert_eq!(offsets[2], offset),
+            record => panic!("{:?}", record),
+        }
+        assert!(reader.next().is_none());
+        reader.seek_to(offsets[3]).unwrap();
+        assert_eq!(4, reader.next().unwrap().unwrap().1.hp());
+        fs::remove_file(&path).unwrap();
+    }
+    #[test]
+    fn sealed() {
+        struct Test {
+            name: &'static str,
+            corrupt: fn(&mut Vec<u8>, &[u64]),
+            mode: ReadMode,
+            want: Result<Vec<i16>, &'static str>,
+        }
+        let tests = [
+            Test {
+                name: "clean",
+                corrupt: |_, _| {},
+                mode: ReadMode::Strict,
+                want: Ok(vec![1, 2, 3, 4]),
+            },
+            Test {
+                name: "payload strict",
+                corrupt: |log, offsets| log[offsets[1] as usize + SEALED_HEADER_LEN + 8] ^= 0x01,
+                mode: ReadMode::Strict,
+                want: Err("checksum"),
+            },
+            Test {
+                name: "payload tolerant",
+                corrupt: |log, offsets| log[offsets[1] as usize + SEALED_HEADER_LEN + 8] ^= 0x01,
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 3, 4]),
+            },
+            Test {
+                name: "crc tolerant",
+                corrupt: |log, offsets| log[offsets[3] as usize - 5] ^= 0x01,
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 2, 4]),
+            },
+            Test {
+                name: "magic strict",
+                corrupt: |log, _| *log.last_mut().unwrap() ^= 0x01,
+                mode: ReadMode::Strict,
+                want: Err("bad seal magic"),
+            },
+            Test {
+                name: "torn tolerant",
+                corrupt: |log, _| log.truncate(log.len() - 3),
+                mode: ReadMode::Tolerant,
+                want: Ok(vec![1, 2, 3]),
+            },
+        ];
+        for t in &tests {
+            let path = temp_path("sealed");
+            let offsets = write_log_with(&path, true);
+            let mut log = fs::read(&path).unwrap();
+            (t.corrupt)(&mut log, &offsets);
+            fs::write(&path, &log).unwrap();
+            let reader = Reader::open(&path).unwrap().with_integrity(true);
+            let got = hps(reader.mode(t.mode));
+            match (&t.want, got) {
+                (Ok(want), Ok(got)) => {
+                    let got: Vec<_> = got.into_iter().map(|(_, hp)| hp).collect();
+                    assert_eq!(want, &got, "{}", t.name);
+                }
+                (Err(want), Err(err)) => {
+                    assert!(err.to_string().contains(want), "{}: {}", t.name, err)
+                }
+                (want, got) => panic!("{}: want {:?}, got {:?}", t.name, want, got),
+            }
+            fs::remove_file(&path).unwrap();
+        }
+    }
+    #[test]
+    fn sealed_mode_mismatch() {
+        let path = temp_path("sealed-mismatch");
+        write_log(&path);
+        let mut reader = Reader::open(&path).unwrap().with_integrity(true);
+        match reader.next() {
+            Some(Err(StreamError::Unsealed { offset: 0, .. })) => {}
+            record => panic!("{:?}", record),
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
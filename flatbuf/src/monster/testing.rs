@@ -0,0 +1,333 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Seeded random `Monster` generator for the benches, the stress tests
+//! and the fuzz corpora.
+//!
+//! The same seed and knobs always generate the same monsters, on any
+//! platform, so the failure is reproduced with the seed in its message.
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use super::{Monster, MonsterData, WeaponData};
+use crate::model::my_game::sample::{Color, Vec3};
+
+/// Size class presets of [`MonsterGenerator::size_class`].
+///
+/// [`monstergenerator::size_class`]: struct.MonsterGenerator.html#method.size_class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeClass {
+    /// Fits in the 1KiB builder of the pool benches.
+    Small,
+    /// A few KiB, with the dozen weapons and waypoints.
+    Medium,
+    /// Tens of KiB, which makes the builder grow a few times.
+    Large,
+}
+
+/// Seeded random [`MonsterData`] generator.
+///
+/// All the generated monsters pass [`MonsterView::validate`] once built.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::testing::{MonsterGenerator, SizeClass};
+///
+/// let mut gen = MonsterGenerator::new(42).size_class(SizeClass::Medium);
+/// let a: Vec<_> = gen.by_ref().take(3).collect();
+/// let b: Vec<_> = MonsterGenerator::new(42)
+///     .size_class(SizeClass::Medium)
+///     .take(3)
+///     .collect();
+/// assert_eq!(a, b);
+/// assert!(a.iter().all(|orc| (2..=8).contains(&orc.weapons.len())));
+/// ```
+/// [`monsterdata`]: ../struct.MonsterData.html
+/// [`monsterview::validate`]: ../../view/struct.MonsterView.html#method.validate
+#[derive(Debug, Clone)]
+pub struct MonsterGenerator {
+    rng: SplitMix64,
+    name_len: RangeInclusive<usize>,
+    weapons: RangeInclusive<usize>,
+    path_len: RangeInclusive<usize>,
+    inventory_len: RangeInclusive<usize>,
+    optional: f64,
+}
+
+impl MonsterGenerator {
+    /// Create the generator with the `Small` knobs and all the optional
+    /// fields present.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SplitMix64(seed),
+            name_len: 3..=8,
+            weapons: 0..=3,
+            path_len: 0..=3,
+            inventory_len: 0..=7,
+            optional: 1.0,
+        }
+    }
+    /// Set the knobs to the `class` preset.  The probability of the
+    /// optional fields is kept.
+    pub fn size_class(self, class: SizeClass) -> Self {
+        let (name_len, weapons, path_len, inventory_len) = match class {
+            SizeClass::Small => (3..=8, 0..=3, 0..=3, 0..=7),
+            SizeClass::Medium => (8..=32, 2..=8, 4..=16, 8..=64),
+            SizeClass::Large => (32..=128, 16..=64, 256..=1_024, 256..=4_096),
+        };
+        Self {
+            name_len,
+            weapons,
+            path_len,
+            inventory_len,
+            ..self
+        }
+    }
+    /// Set the length of the monster and the weapon names.
+    pub fn name_len(self, name_len: RangeInclusive<usize>) -> Self {
+        Self { name_len, ..self }
+    }
+    pub fn weapons(self, weapons: RangeInclusive<usize>) -> Self {
+        Self { weapons, ..self }
+    }
+    pub fn path_len(self, path_len: RangeInclusive<usize>) -> Self {
+        Self { path_len, ..self }
+    }
+    pub fn inventory_len(self, inventory_len: RangeInclusive<usize>) -> Self {
+        Self {
+            inventory_len,
+            ..self
+        }
+    }
+    /// Set the probability of the optional `pos`, `inventory` and
+    /// `equipped` fields being present, clamped to `0.0..=1.0`.
+    pub fn optional(self, probability: f64) -> Self {
+        Self {
+            optional: probability.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+    /// Returns the next monster.
+    pub fn monster(&mut self) -> MonsterData {
+        let pos = self.present().then(|| self.rng.point());
+        let path = (0..self.len(self.path_len.clone()))
+            .map(|_| self.rng.point())
+            .collect();
+        let weapons: Vec<_> = (0..self.len(self.weapons.clone()))
+            .map(|_| WeaponData {
+                name: self.name(),
+                damage: self.rng.below(20) as i16,
+            })
+            .collect();
+        let equipped = match weapons.len() {
+            0 => None,
+            n => self.present().then(|| self.rng.below(n as u64) as usize),
+        };
+        let name = self.name();
+        let mana = self.rng.below(301) as i16;
+        let hp = self.rng.below(301) as i16;
+        let inventory = if self.present() {
+            (0..self.len(self.inventory_len.clone()))
+                .map(|_| self.rng.below(256) as u8)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        MonsterData {
+            name,
+            pos,
+            mana,
+            hp,
+            inventory,
+            color: Color::ALL[self.rng.below(3) as usize],
+            weapons,
+            equipped,
+            path,
+        }
+    }
+    /// Returns the length in `range`.
+    fn len(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = (*range.start(), *range.end());
+        if start >= end {
+            return start;
+        }
+        start + self.rng.below((end - start) as u64 + 1) as usize
+    }
+    /// Returns `true` if the optional field is present.  It doesn't draw
+    /// for the probability `1.0`, so the default sequence is kept.
+    fn present(&mut self) -> bool {
+        if self.optional >= 1.0 {
+            return true;
+        }
+        // 53 bits, which are exactly representable in `f64`.
+        let v = (self.rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+        v < self.optional
+    }
+    fn name(&mut self) -> String {
+        let len = self.len(self.name_len.clone());
+        (0..len)
+            .map(|_| char::from(b'a' + self.rng.below(26) as u8))
+            .collect()
+    }
+}
+
+impl Iterator for MonsterGenerator {
+    type Item = MonsterData;
+
+    fn next(&mut self) -> Option<MonsterData> {
+        Some(self.monster())
+    }
+}
+
+/// Write `n` finished `Monster` buffers generated from `seed` as
+/// `monster-000.bin`, `monster-001.bin`, ... under `dir`, and returns
+/// their paths.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::testing::generate_corpus;
+/// use flatbuf_tutorial::MonsterBuf;
+/// # let dir = std::env::temp_dir().join(format!("generate_corpus-{}", std::process::id()));
+///
+/// let paths = generate_corpus(&dir, 3, 42)?;
+/// assert_eq!(3, paths.len());
+/// for path in &paths {
+///     MonsterBuf::new(std::fs::read(path)?)?;
+/// }
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn generate_corpus<P: AsRef<Path>>(dir: P, n: usize, seed: u64) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    fs::create_dir_all(dir)?;
+    MonsterGenerator::new(seed)
+        .take(n)
+        .enumerate()
+        .map(|(i, data)| {
+            let path = dir.join(format!("monster-{:03}.bin", i));
+            fs::write(&path, Monster::build_pooled(&data))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// [SplitMix64] generator, to keep the output stable across the
+/// platforms and the releases.
+///
+/// [splitmix64]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    /// Returns the number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+    /// Returns the point in `-100.0..=100.0` cube.
+    fn point(&mut self) -> Vec3 {
+        let mut coord = || self.below(2001) as f32 / 10.0 - 100.0;
+        Vec3::new(coord(), coord(), coord())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        struct Test {
+            name: &'static str,
+            data: fn(u64) -> MonsterGenerator,
+        }
+        let tests = [
+            Test {
+                name: "default",
+                data: MonsterGenerator::new,
+            },
+            Test {
+                name: "large",
+                data: |seed| MonsterGenerator::new(seed).size_class(SizeClass::Large),
+            },
+            Test {
+                name: "half optional",
+                data: |seed| MonsterGenerator::new(seed).optional(0.5),
+            },
+        ];
+        for t in &tests {
+            let a: Vec<_> = (t.data)(42).take(20).collect();
+            let b: Vec<_> = (t.data)(42).take(20).collect();
+            assert_eq!(a, b, "{}", t.name);
+            assert_ne!(a, (t.data)(43).take(20).collect::<Vec<_>>(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn valid() {
+        for &class in &[SizeClass::Small, SizeClass::Medium, SizeClass::Large] {
+            for &optional in &[0.0, 0.5, 1.0] {
+                let gen = MonsterGenerator::new(7)
+                    .size_class(class)
+                    .optional(optional);
+                for data in gen.take(20) {
+                    let buf = Monster::build_pooled(&data);
+                    assert_eq!(
+                        Ok(()),
+                        buf.view().validate(),
+                        "{:?} {}: {:?}",
+                        class,
+                        optional,
+                        data
+                    );
+                    assert_eq!(data, MonsterData::from(buf.view()));
+                }
+            }
+        }
+    }
+    #[test]
+    fn knobs() {
+        let gen = MonsterGenerator::new(1)
+            .name_len(4..=4)
+            .weapons(1..=2)
+            .path_len(0..=0)
+            .inventory_len(3..=5)
+            .optional(0.0);
+        for data in gen.take(50) {
+            assert_eq!(4, data.name.len());
+            assert!((1..=2).contains(&data.weapons.len()));
+            assert!(data.weapons.iter().all(|weapon| weapon.name.len() == 4));
+            assert!(data.path.is_empty());
+            assert!(data.pos.is_none() && data.equipped.is_none());
+            assert!(data.inventory.is_empty());
+        }
+    }
+    #[test]
+    fn small_fits_in_bench_builder() {
+        // `BUFFER_CAPACITY` of the pool benches.
+        const BUFFER_CAPACITY: usize = 1_024;
+        for data in MonsterGenerator::new(0).take(100) {
+            let len = Monster::build_pooled(&data).as_ref().len();
+            assert!(len <= BUFFER_CAPACITY, "{} bytes: {:?}", len, data);
+        }
+    }
+    #[test]
+    fn corpus() {
+        let dir = std::env::temp_dir().join(format!("monster-corpus-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let paths = generate_corpus(&dir, 5, 42).unwrap();
+        assert_eq!(5, paths.len());
+        for (path, data) in paths.iter().zip(MonsterGenerator::new(42)) {
+            let buf = crate::MonsterBuf::new(fs::read(path).unwrap()).unwrap();
+            assert_eq!(data, MonsterData::from(buf.view()), "{}", path.display());
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
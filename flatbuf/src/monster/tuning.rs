@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Builder buffer capacity tuning by the finished buffer sizes.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use crate::pool::v3::FlatBufferBuilderLocalPool;
+
+/// Number of the power of two buckets, one for each bit of `usize`.
+const BUCKETS: usize = usize::BITS as usize;
+
+/// Advisor installed for [`Monster::build_pooled`].
+///
+/// [`monster::build_pooled`]: ../struct.Monster.html#method.build_pooled
+static BUILD_POOLED: OnceLock<&'static CapacityAdvisor> = OnceLock::new();
+
+/// Histogram of the finished buffer sizes, which recommends the builder
+/// buffer capacity covering the given percentile of them.
+///
+/// The sizes are counted in the power of two buckets, which is all the
+/// recommendation needs, as it's rounded up to the power of two anyway.
+/// `record` is a single relaxed atomic add, so the advisor is shared by
+/// all the threads as is.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::tuning::CapacityAdvisor;
+/// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+///
+/// let advisor = CapacityAdvisor::new();
+/// for len in [100, 200, 300, 1_000] {
+///     advisor.record(len);
+/// }
+/// assert_eq!(Some(512), advisor.recommendation(0.75));
+/// assert_eq!(Some(1_024), advisor.recommendation(1.0));
+///
+/// let pool = FlatBufferBuilderPool::new().buffer_capacity(64).build();
+/// assert_eq!(Some(512), advisor.apply_to_pool(&pool, 0.75));
+/// assert_eq!(512, pool.buffer_capacity());
+/// ```
+#[derive(Debug)]
+pub struct CapacityAdvisor {
+    /// Number of the sizes in `(2^(i-1), 2^i]` for the bucket `i`.
+    buckets: [AtomicU64; BUCKETS],
+}
+
+impl Default for CapacityAdvisor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CapacityAdvisor {
+    /// Create the empty advisor, e.g. for the `static` one.
+    pub const fn new() -> Self {
+        Self {
+            buckets: [const { AtomicU64::new(0) }; BUCKETS],
+        }
+    }
+    /// Record the finished buffer size `len`.
+    #[inline]
+    pub fn record(&self, len: usize) {
+        let bucket = len
+            .checked_next_power_of_two()
+            .map_or(BUCKETS - 1, |n| n.trailing_zeros() as usize);
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+    /// Returns the number of the sizes recorded so far.
+    pub fn samples(&self) -> u64 {
+        self.buckets
+            .iter()
+            .map(|bucket| bucket.load(Ordering::Relaxed))
+            .sum()
+    }
+    /// Returns the smallest power of two capacity which fits the
+    /// `percentile` of the recorded sizes, e.g. `0.95` for p95, or `None`
+    /// in case nothing is recorded yet.
+    ///
+    /// `percentile` is clamped to `0.0..=1.0`.
+    pub fn recommendation(&self, percentile: f64) -> Option<usize> {
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|bucket| bucket.load(Ordering::Relaxed))
+            .collect();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        // At least one sample, for `0.0` to give the smallest size.
+        let want = ((percentile.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, count) in counts.into_iter().enumerate() {
+            seen += count;
+            if seen >= want {
+                return Some(1 << bucket);
+            }
+        }
+        // Only with the concurrent `record`s, which are counted in
+        // `total` but not seen above.
+        Some(1 << (BUCKETS - 1))
+    }
+    /// Migrate `pool` to the capacity recommended for `percentile`, and
+    /// returns it, or `None` leaving `pool` as is in case nothing is
+    /// recorded yet.
+    pub fn apply_to_pool(
+        &self,
+        pool: &FlatBufferBuilderLocalPool<'_>,
+        percentile: f64,
+    ) -> Option<usize> {
+        let capacity = self.recommendation(percentile)?;
+        pool.set_buffer_capacity(capacity);
+        Some(capacity)
+    }
+    /// Record the sizes of all the buffers built by
+    /// [`Monster::build_pooled`] from now on.
+    ///
+    /// It's opt-in and once per process, and returns `false` in case the
+    /// other advisor is already installed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::tuning::CapacityAdvisor;
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::Monster;
+    ///
+    /// static ADVISOR: CapacityAdvisor = CapacityAdvisor::new();
+    ///
+    /// assert!(ADVISOR.install());
+    /// Monster::build_pooled(&MonsterData::default());
+    /// assert_eq!(1, ADVISOR.samples());
+    /// ```
+    /// [`monster::build_pooled`]: ../struct.Monster.html#method.build_pooled
+    pub fn install(&'static self) -> bool {
+        BUILD_POOLED.set(self).is_ok()
+    }
+}
+
+/// Record `len` to the advisor installed for `Monster::build_pooled`, if
+/// any.
+#[inline]
+pub(crate) fn record_build_pooled(len: usize) {
+    if let Some(advisor) = BUILD_POOLED.get() {
+        advisor.record(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommendation() {
+        struct Test {
+            name: &'static str,
+            data: f64,
+            want: Option<usize>,
+        }
+        // 90 sizes up to 128 bytes, 6 up to 1KiB, and 4 up to 64KiB.
+        let advisor = CapacityAdvisor::new();
+        for len in 30..120 {
+            advisor.record(len);
+        }
+        for len in [600, 700, 800, 900, 1_000, 1_024] {
+            advisor.record(len);
+        }
+        for len in [2_000, 10_000, 40_000, 65_000] {
+            advisor.record(len);
+        }
+        assert_eq!(100, advisor.samples());
+        let tests = [
+            Test {
+                name: "p0",
+                data: 0.0,
+                want: Some(32),
+            },
+            Test {
+                name: "p90",
+                data: 0.9,
+                want: Some(128),
+            },
+            Test {
+                name: "p95",
+                data: 0.95,
+                want: Some(1_024),
+            },
+            Test {
+                name: "p96",
+                data: 0.96,
+                want: Some(1_024),
+            },
+            Test {
+                name: "p97",
+                data: 0.97,
+                want: Some(2_048),
+            },
+            Test {
+                name: "p100",
+                data: 1.0,
+                want: Some(65_536),
+            },
+            Test {
+                name: "clamped",
+                data: 2.0,
+                want: Some(65_536),
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want, advisor.recommendation(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn empty() {
+        let advisor = CapacityAdvisor::default();
+        assert_eq!(None, advisor.recommendation(0.95));
+        let pool = crate::pool::v3::FlatBufferBuilderPool::new()
+            .buffer_capacity(64)
+            .build();
+        assert_eq!(None, advisor.apply_to_pool(&pool, 0.95));
+        assert_eq!(64, pool.buffer_capacity());
+    }
+    #[test]
+    fn record_bounds() {
+        let advisor = CapacityAdvisor::new();
+        advisor.record(0);
+        assert_eq!(Some(1), advisor.recommendation(1.0));
+        advisor.record(usize::MAX);
+        assert_eq!(Some(1 << (BUCKETS - 1)), advisor.recommendation(1.0));
+    }
+}
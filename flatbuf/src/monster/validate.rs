@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Business rules of the `Monster` beyond the buffer structure.
+use std::fmt;
+
+use super::Monster;
+use crate::error::ValidationError;
+use crate::model::my_game::sample;
+use crate::view::MonsterView;
+
+/// Maximum `hp` of the default rules.
+pub const MAX_HP: i16 = 1_000;
+
+/// Maximum `name` length in chars of the default rules.
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Rule violation, with the path to the offending field, e.g.
+/// `weapons[1].damage`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleViolation {
+    pub field: String,
+    pub message: String,
+}
+
+impl RuleViolation {
+    pub fn new<F: Into<String>, M: Into<String>>(field: F, message: M) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for RuleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+type CustomRule = dyn Fn(&MonsterView<'_>) -> Result<(), RuleViolation> + Send + Sync;
+
+enum Rule {
+    /// Default rule, which reports the violation of each element.
+    Default(fn(&MonsterView<'_>, &mut Vec<RuleViolation>)),
+    Custom(Box<CustomRule>),
+}
+
+/// Validator of the `Monster` business rules.
+///
+/// The default rules are:
+///
+/// - `hp` is between `0` and [`MAX_HP`].
+/// - `name` is present, not empty, and up to [`MAX_NAME_LEN`] chars.
+/// - `equipped` weapon is one of the `weapons`.
+/// - `path` points are finite.
+/// - `weapons` damages are positive.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::validate::{MonsterValidator, RuleViolation};
+/// use flatbuf_tutorial::monster::MonsterData;
+/// use flatbuf_tutorial::Monster;
+///
+/// let validator = MonsterValidator::builder()
+///     .rule(|orc| match orc.mana() {
+///         0..=200 => Ok(()),
+///         mana => Err(RuleViolation::new("mana", format!("{} over 200", mana))),
+///     })
+///     .build();
+/// let data = MonsterData {
+///     name: String::from("orc"),
+///     hp: -1,
+///     mana: 300,
+///     ..Default::default()
+/// };
+/// let orc = Monster::build_pooled(&data);
+/// let fields: Vec<_> = validator
+///     .validate(&orc.view())
+///     .unwrap_err()
+///     .into_iter()
+///     .map(|violation| violation.field)
+///     .collect();
+/// assert_eq!(vec!["hp", "mana"], fields);
+/// ```
+/// [`max_hp`]: constant.MAX_HP.html
+/// [`max_name_len`]: constant.MAX_NAME_LEN.html
+pub struct MonsterValidator {
+    rules: Vec<Rule>,
+}
+
+impl MonsterValidator {
+    /// Create the validator with the default rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Create the builder starting from the default rules.
+    pub fn builder() -> MonsterValidatorBuilder {
+        MonsterValidatorBuilder(Self::default())
+    }
+    /// Check all the rules and return all the violations, in the rule
+    /// order.
+    pub fn validate(&self, view: &MonsterView<'_>) -> Result<(), Vec<RuleViolation>> {
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            match rule {
+                Rule::Default(rule) => rule(view, &mut violations),
+                Rule::Custom(rule) => {
+                    if let Err(violation) = rule(view) {
+                        violations.push(violation);
+                    }
+                }
+            }
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl Default for MonsterValidator {
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                Rule::Default(hp),
+                Rule::Default(name),
+                Rule::Default(equipped),
+                Rule::Default(path),
+                Rule::Default(damage),
+            ],
+        }
+    }
+}
+
+impl fmt::Debug for MonsterValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MonsterValidator")
+            .field("rules", &self.rules.len())
+            .finish()
+    }
+}
+
+/// Builder of [`MonsterValidator`], returned by
+/// [`MonsterValidator::builder`].
+///
+/// [`monstervalidator`]: struct.MonsterValidator.html
+/// [`monstervalidator::builder`]: struct.MonsterValidator.html#method.builder
+#[derive(Debug)]
+pub struct MonsterValidatorBuilder(MonsterValidator);
+
+impl MonsterValidatorBuilder {
+    /// Drop the default rules, including the ones already added.
+    pub fn without_default_rules(mut self) -> Self {
+        self.0.rules.retain(|rule| matches!(rule, Rule::Custom(_)));
+        self
+    }
+    /// Add the custom rule, which is checked after the ones already
+    /// added.
+    pub fn rule<F>(mut self, rule: F) -> Self
+    where
+        F: Fn(&MonsterView<'_>) -> Result<(), RuleViolation> + Send + Sync + 'static,
+    {
+        self.0.rules.push(Rule::Custom(Box::new(rule)));
+        self
+    }
+    pub fn build(self) -> MonsterValidator {
+        self.0
+    }
+}
+
+fn hp(view: &MonsterView<'_>, violations: &mut Vec<RuleViolation>) {
+    let hp = view.hp();
+    if !(0..=MAX_HP).contains(&hp) {
+        let message = format!("{} not between 0 and {}", hp, MAX_HP);
+        violations.push(RuleViolation::new("hp", message));
+    }
+}
+
+fn name(view: &MonsterView<'_>, violations: &mut Vec<RuleViolation>) {
+    let message = match view.name() {
+        None => String::from("missing"),
+        Some("") => String::from("empty"),
+        Some(name) => match name.chars().count() {
+            len if len > MAX_NAME_LEN => format!("{} chars over {}", len, MAX_NAME_LEN),
+            _ => return,
+        },
+    };
+    violations.push(RuleViolation::new("name", message));
+}
+
+fn equipped(view: &MonsterView<'_>, violations: &mut Vec<RuleViolation>) {
+    let message = match view.equipped() {
+        Some(equipped) if view.weapons().any(|weapon| weapon.is_same(&equipped)) => return,
+        Some(_) => String::from("not one of the weapons"),
+        None if view.field_pos(sample::Monster::VT_EQUIPPED).is_some() => {
+            String::from("unknown equipment type")
+        }
+        None => return,
+    };
+    violations.push(RuleViolation::new("equipped", message));
+}
+
+fn path(view: &MonsterView<'_>, violations: &mut Vec<RuleViolation>) {
+    for (i, p) in view.path().enumerate() {
+        if !(p.x().is_finite() && p.y().is_finite() && p.z().is_finite()) {
+            let field = format!("path[{}]", i);
+            violations.push(RuleViolation::new(field, "not finite"));
+        }
+    }
+}
+
+fn damage(view: &MonsterView<'_>, violations: &mut Vec<RuleViolation>) {
+    for (i, weapon) in view.weapons().enumerate() {
+        if weapon.damage() <= 0 {
+            let field = format!("weapons[{}].damage", i);
+            let message = format!("{} not positive", weapon.damage());
+            violations.push(RuleViolation::new(field, message));
+        }
+    }
+}
+
+impl Monster {
+    /// Verify `buf` as [`MonsterView::from_bytes`], and then check the
+    /// business rules of `validator`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::monster::validate::MonsterValidator;
+    /// use flatbuf_tutorial::monster::MonsterData;
+    /// use flatbuf_tutorial::{Monster, ValidationError};
+    ///
+    /// let data = MonsterData {
+    ///     name: String::from("orc"),
+    ///     hp: 2_000,
+    ///     ..Default::default()
+    /// };
+    /// let orc = Monster::build_pooled(&data);
+    /// let validator = MonsterValidator::new();
+    /// assert!(Monster::from_bytes_validated(orc.as_ref(), None).is_ok());
+    /// match Monster::from_bytes_validated(orc.as_ref(), Some(&validator)) {
+    ///     Err(ValidationError::Rules(violations)) => assert_eq!("hp", violations[0].field),
+    ///     result => panic!("{:?}", result),
+    /// }
+    /// ```
+    /// [`monsterview::from_bytes`]: ../view/struct.MonsterView.html#method.from_bytes
+    pub fn from_bytes_validated<'a>(
+        buf: &'a [u8],
+        validator: Option<&MonsterValidator>,
+    ) -> Result<MonsterView<'a>, ValidationError> {
+        let view = MonsterView::from_bytes(buf)?;
+        if let Some(validator) = validator {
+            validator.validate(&view).map_err(ValidationError::Rules)?;
+        }
+        Ok(view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::{Equipment, MonsterArgs, Vec3, Weapon, WeaponArgs};
+    use crate::monster::{MonsterData, WeaponData};
+    use flatbuffers::FlatBufferBuilder;
+
+    fn orc() -> MonsterData {
+        MonsterData {
+            name: String::from("orc"),
+            hp: 80,
+            weapons: vec![WeaponData {
+                name: String::from("axe"),
+                damage: 5,
+            }],
+            equipped: Some(0),
+            path: vec![Vec3::new(1.0, 2.0, 3.0)],
+            ..Default::default()
+        }
+    }
+    fn fields(data: &MonsterData) -> Vec<String> {
+        let buf = Monster::build_pooled(data);
+        match MonsterValidator::new().validate(&buf.view()) {
+            Ok(()) => Vec::new(),
+            Err(violations) => violations.into_iter().map(|v| v.field).collect(),
+        }
+    }
+    /// Build `orc` equipped with the weapon which is not in `weapons`.
+    fn stray_equipped(equipped_type: Equipment) -> Vec<u8> {
+        let mut b = FlatBufferBuilder::new();
+        let axe = b.create_string("axe");
+        let axe = Weapon::create(
+            &mut b,
+            &WeaponArgs {
+                name: Some(axe),
+                damage: 5,
+            },
+        );
+        let name = b.create_string("orc");
+        let orc = sample::Monster::create(
+            &mut b,
+            &MonsterArgs {
+                name: Some(name),
+                equipped_type,
+                equipped: Some(axe.as_union_value()),
+                ..Default::default()
+            },
+        );
+        b.finish(orc, None);
+        b.finished_data().to_vec()
+    }
+    #[test]
+    fn default_rules() {
+        struct Test {
+            name: &'static str,
+            data: MonsterData,
+            want: &'static [&'static str],
+        }
+        let tests = [
+            Test {
+                name: "valid",
+                data: orc(),
+                want: &[],
+            },
+            Test {
+                name: "negative hp",
+                data: MonsterData { hp: -1, ..orc() },
+                want: &["hp"],
+            },
+            Test {
+                name: "hp over max",
+                data: MonsterData {
+                    hp: MAX_HP + 1,
+                    ..orc()
+                },
+                want: &["hp"],
+            },
+            Test {
+                name: "hp at max",
+                data: MonsterData {
+                    hp: MAX_HP,
+                    ..orc()
+                },
+                want: &[],
+            },
+            Test {
+                name: "empty name",
+                data: MonsterData {
+                    name: String::new(),
+                    ..orc()
+                },
+                want: &["name"],
+            },
+            Test {
+                name: "long name",
+                data: MonsterData {
+                    name: "é".repeat(MAX_NAME_LEN + 1),
+                    ..orc()
+                },
+                want: &["name"],
+            },
+            Test {
+                name: "name at max in chars",
+                data: MonsterData {
+                    name: "é".repeat(MAX_NAME_LEN),
+                    ..orc()
+                },
+                want: &[],
+            },
+            Test {
+                name: "non-finite path",
+                data: MonsterData {
+                    path: vec![
+                        Vec3::new(0.0, 0.0, 0.0),
+                        Vec3::new(f32::NAN, 0.0, 0.0),
+                        Vec3::new(0.0, f32::INFINITY, 0.0),
+                    ],
+                    ..orc()
+                },
+                want: &["path[1]", "path[2]"],
+            },
+            Test {
+                name: "zero damage",
+                data: MonsterData {
+                    weapons: vec![
+                        WeaponData {
+                            name: String::from("axe"),
+                            damage: 5,
+                        },
+                        WeaponData {
+                            name: String::from("stick"),
+                            damage: 0,
+                        },
+                    ],
+                    ..orc()
+                },
+                want: &["weapons[1].damage"],
+            },
+            Test {
+                name: "multiple violations",
+                data: MonsterData {
+                    name: String::new(),
+                    hp: -5,
+                    path: vec![Vec3::new(f32::NAN, 0.0, 0.0)],
+                    ..orc()
+                },
+                want: &["hp", "name", "path[0]"],
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.want.to_vec(), fields(&t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn equipped() {
+        let validator = MonsterValidator::new();
+        for &(kind, want) in &[
+            (Equipment::Weapon, "not one of the weapons"),
+            (Equipment(7), "unknown equipment type"),
+        ] {
+            let buf = stray_equipped(kind);
+            let view = MonsterView::from_bytes(&buf).unwrap();
+            let violations = validator.validate(&view).unwrap_err();
+            assert_eq!(vec![RuleViolation::new("equipped", want)], violations);
+        }
+    }
+    #[test]
+    fn custom_rule() {
+        let validator = MonsterValidator::builder()
+            .without_default_rules()
+            .rule(|orc| match orc.inventory() {
+                Some(inventory) if inventory.len() > 2 => {
+                    Err(RuleViolation::new("inventory", "too many items"))
+                }
+                _ => Ok(()),
+            })
+            .build();
+        let data = MonsterData {
+            hp: -1,
+            inventory: vec![1, 2, 3],
+            ..orc()
+        };
+        let buf = Monster::build_pooled(&data);
+        let want = vec![RuleViolation::new("inventory", "too many items")];
+        assert_eq!(Err(want), validator.validate(&buf.view()));
+        let buf = Monster::build_pooled(&orc());
+        assert_eq!(Ok(()), validator.validate(&buf.view()));
+    }
+    #[test]
+    fn from_bytes_validated() {
+        let validator = MonsterValidator::new();
+        assert!(matches!(
+            Monster::from_bytes_validated(&[0xff; 3], Some(&validator)),
+            Err(ValidationError::Invalid(_))
+        ));
+        let buf = Monster::build_pooled(&MonsterData { hp: -1, ..orc() });
+        assert!(Monster::from_bytes_validated(buf.as_ref(), None).is_ok());
+        match Monster::from_bytes_validated(buf.as_ref(), Some(&validator)) {
+            Err(ValidationError::Rules(violations)) => {
+                assert_eq!(
+                    vec![RuleViolation::new("hp", "-1 not between 0 and 1000")],
+                    violations
+                )
+            }
+            result => panic!("{:?}", result),
+        }
+    }
+}
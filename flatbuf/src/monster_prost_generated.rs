@@ -0,0 +1,80 @@
+// This file is @generated by prost-build.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Vec3 {
+    #[prost(float, tag = "1")]
+    pub x: f32,
+    #[prost(float, tag = "2")]
+    pub y: f32,
+    #[prost(float, tag = "3")]
+    pub z: f32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Monster {
+    #[prost(message, optional, tag = "1")]
+    pub pos: ::core::option::Option<Vec3>,
+    #[prost(int32, tag = "2")]
+    pub mana: i32,
+    #[prost(int32, tag = "3")]
+    pub hp: i32,
+    #[prost(string, tag = "4")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "6")]
+    pub inventory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration = "Color", tag = "7")]
+    pub color: i32,
+    #[prost(message, repeated, tag = "8")]
+    pub weapons: ::prost::alloc::vec::Vec<Weapon>,
+    #[prost(message, repeated, tag = "10")]
+    pub path: ::prost::alloc::vec::Vec<Vec3>,
+    #[prost(oneof = "monster::Equipped", tags = "9")]
+    pub equipped: ::core::option::Option<monster::Equipped>,
+}
+/// Nested message and enum types in `Monster`.
+pub mod monster {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Equipped {
+        #[prost(message, tag = "9")]
+        Weapon(super::Weapon),
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Weapon {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub damage: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MonsterList {
+    #[prost(message, repeated, tag = "1")]
+    pub monsters: ::prost::alloc::vec::Vec<Monster>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Color {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+impl Color {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Red => "RED",
+            Self::Green => "GREEN",
+            Self::Blue => "BLUE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RED" => Some(Self::Red),
+            "GREEN" => Some(Self::Green),
+            "BLUE" => Some(Self::Blue),
+            _ => None,
+        }
+    }
+}
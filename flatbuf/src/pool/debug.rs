@@ -0,0 +1,87 @@
+//! Leak detector of the [`v3`] global builders
+//!
+//! With the `debug` feature, each [`GlobalBuilder`] records the
+//! backtrace of its creation under its [`fingerprint`], and removes it
+//! as it's dropped.  The builder which is never dropped, e.g. forgotten
+//! with `std::mem::forget` or kept in the leaked `Box`, never goes back
+//! to the pool, and [`check_for_leaks`] reports it with the backtrace.
+//!
+//! The backtrace is captured regardless of `RUST_BACKTRACE`, which
+//! makes `get` a lot slower, so enable the feature only for the tests.
+//!
+//! [`v3`]: ../v3/index.html
+//! [`globalbuilder`]: ../v3/struct.GlobalBuilder.html
+//! [`fingerprint`]: ../v3/struct.GlobalBuilder.html#method.fingerprint
+//! [`check_for_leaks`]: fn.check_for_leaks.html
+use std::{
+    backtrace::Backtrace,
+    sync::atomic::{AtomicU64, Ordering},
+    sync::LazyLock,
+};
+
+use dashmap::DashMap;
+
+static NEXT_ORDER: AtomicU64 = AtomicU64::new(0);
+/// Order and backtrace of the outstanding builders by their fingerprints.
+static OUTSTANDING: LazyLock<DashMap<u64, (u64, Backtrace)>> = LazyLock::new(DashMap::new);
+
+/// Global builder which is not dropped yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakedBuilder {
+    /// Fingerprint of the builder.
+    pub fingerprint: u64,
+    /// Backtrace of the creation of the builder.
+    pub backtrace: String,
+}
+
+/// Returns the number of the global builders which are not dropped yet.
+pub fn outstanding() -> usize {
+    OUTSTANDING.len()
+}
+
+/// Returns the global builders which are not dropped yet, in the order of
+/// their checkout.
+///
+/// The builders in use are reported too, so call it once all of them
+/// are expected to be dropped, e.g. at the end of the test.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::debug::check_for_leaks;
+/// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+///
+/// let b = FlatBufferBuilderPool::get().unwrap();
+/// let fingerprint = b.fingerprint();
+/// std::mem::forget(b);
+/// assert!(check_for_leaks()
+///     .iter()
+///     .any(|leaked| leaked.fingerprint == fingerprint));
+/// ```
+pub fn check_for_leaks() -> Vec<LeakedBuilder> {
+    if outstanding() == 0 {
+        return Vec::new();
+    }
+    let mut leaked: Vec<_> = OUTSTANDING
+        .iter()
+        .map(|entry| {
+            let (order, backtrace) = entry.value();
+            let leaked = LeakedBuilder {
+                fingerprint: *entry.key(),
+                backtrace: backtrace.to_string(),
+            };
+            (*order, leaked)
+        })
+        .collect();
+    leaked.sort_by_key(|(order, _)| *order);
+    leaked.into_iter().map(|(_, leaked)| leaked).collect()
+}
+
+pub(crate) fn track(fingerprint: u64) {
+    let order = NEXT_ORDER.fetch_add(1, Ordering::Relaxed);
+    OUTSTANDING.insert(fingerprint, (order, Backtrace::force_capture()));
+}
+
+pub(crate) fn untrack(fingerprint: u64) {
+    OUTSTANDING.remove(&fingerprint);
+}
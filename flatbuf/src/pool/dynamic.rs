@@ -0,0 +1,233 @@
+//! `crossbeam_queue::ArrayQueue` based flatbuffer builder pool, which
+//! grows instead of dropping the returned builders
+//!
+//! The [`v3`] local pool drops the builder returned to the full queue.
+//! [`DynamicPool`] replaces the full queue with the new one of the double
+//! capacity instead, and moves the pooled builders to it.  The `get` and
+//! the returns racing with the growth keep using the old queue until the
+//! new one is swapped in.  The builders returned to the old queue in the
+//! meantime are moved over right after the swap, or dropped with the old
+//! queue if they're even later.
+//!
+//! [`v3`]: ../v3/index.html
+//! [`dynamicpool`]: struct.DynamicPool.html
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, Weak},
+};
+
+use arc_swap::ArcSwap;
+use crossbeam_queue::{ArrayQueue, PushError};
+use flatbuffers::FlatBufferBuilder;
+use parking_lot::Mutex;
+
+const BUFFER_CAPACITY: usize = 64;
+
+/// Growing `FlatBufferBuilder` pool.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::dynamic::DynamicPool;
+///
+/// let pool = DynamicPool::new(1);
+/// let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+/// drop(builders);
+/// assert_eq!(4, pool.capacity());
+/// assert_eq!(3, pool.len());
+/// ```
+pub struct DynamicPool<'a> {
+    inner: Arc<Inner<'a>>,
+}
+
+struct Inner<'a> {
+    /// Current queue.
+    queue: ArcSwap<ArrayQueue<FlatBufferBuilder<'a>>>,
+
+    /// Serializes the growth, so that the queue only doubles once for
+    /// the concurrent overflows.
+    grow: Mutex<()>,
+
+    /// Flatbuffer buffer capacity of the newly allocated builders.
+    buffer_capacity: usize,
+}
+
+impl<'a> DynamicPool<'a> {
+    /// Create the empty pool of the initial `capacity`, which is at
+    /// least one.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_buffer_capacity(capacity, BUFFER_CAPACITY)
+    }
+    /// Create the empty pool of the initial `capacity`, which allocates
+    /// the builders of `buffer_capacity`.
+    pub fn with_buffer_capacity(capacity: usize, buffer_capacity: usize) -> Self {
+        let queue = ArrayQueue::new(capacity.max(1));
+        Self {
+            inner: Arc::new(Inner {
+                queue: ArcSwap::from_pointee(queue),
+                grow: Mutex::new(()),
+                buffer_capacity,
+            }),
+        }
+    }
+    /// Get the `FlatBufferBuilder` from the pool, or allocate the new
+    /// one in case the pool is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::dynamic::DynamicPool;
+    ///
+    /// let pool = DynamicPool::new(32);
+    /// let mut b = pool.get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get(&self) -> DynamicBuilder<'a> {
+        let builder = match self.inner.queue.load().pop() {
+            Ok(builder) => builder,
+            Err(_) => FlatBufferBuilder::with_capacity(self.inner.buffer_capacity),
+        };
+        DynamicBuilder {
+            pool: Arc::downgrade(&self.inner),
+            inner: Some(builder),
+        }
+    }
+    /// Returns the current capacity of the pool.
+    pub fn capacity(&self) -> usize {
+        self.inner.queue.load().capacity()
+    }
+    /// Returns the number of the pooled builders.
+    pub fn len(&self) -> usize {
+        self.inner.queue.load().len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a> Inner<'a> {
+    /// Push `builder` back, growing the queue if it's full.
+    fn push(&self, builder: FlatBufferBuilder<'a>) {
+        let full = self.queue.load_full();
+        let builder = match full.push(builder) {
+            Ok(()) => return,
+            Err(PushError(builder)) => builder,
+        };
+        let _grow = self.grow.lock();
+        let current = self.queue.load_full();
+        if !Arc::ptr_eq(&current, &full) {
+            // Grown by the other thread in the meantime.
+            if let Err(PushError(builder)) = current.push(builder) {
+                self.grow(&current, builder);
+            }
+            return;
+        }
+        self.grow(&current, builder);
+    }
+    /// Swap `full` with the double capacity queue, holding the growth
+    /// lock.
+    fn grow(&self, full: &ArrayQueue<FlatBufferBuilder<'a>>, builder: FlatBufferBuilder<'a>) {
+        let queue = ArrayQueue::new(full.capacity() * 2);
+        while let Ok(pooled) = full.pop() {
+            let _ = queue.push(pooled);
+        }
+        let _ = queue.push(builder);
+        let queue = Arc::new(queue);
+        self.queue.store(Arc::clone(&queue));
+        // Move the builders returned to the old queue before the swap,
+        // which are dropped in case the new queue is already full.
+        while let Ok(pooled) = full.pop() {
+            if queue.push(pooled).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// `DynamicBuilder` encapsulates the `FlatBufferBuilder` instance
+/// for the [`DynamicPool`].
+///
+/// [`dynamicpool`]: struct.DynamicPool.html
+pub struct DynamicBuilder<'a> {
+    /// Pool.
+    pool: Weak<Inner<'a>>,
+
+    /// Actual builder.
+    inner: Option<FlatBufferBuilder<'a>>,
+}
+
+impl<'a> Deref for DynamicBuilder<'a> {
+    type Target = FlatBufferBuilder<'a>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+
+impl<'a> DerefMut for DynamicBuilder<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().unwrap()
+    }
+}
+
+impl<'a> Drop for DynamicBuilder<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(mut builder) = self.inner.take() {
+            builder.reset();
+            if let Some(pool) = self.pool.upgrade() {
+                pool.push(builder);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn grow_on_overflow() {
+        let pool = DynamicPool::new(2);
+        let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+        assert_eq!((2, 0), (pool.capacity(), pool.len()));
+        drop(builders);
+        assert_eq!((4, 3), (pool.capacity(), pool.len()));
+
+        // Served from the grown queue.
+        let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+        assert!(pool.is_empty());
+        drop(builders);
+        assert_eq!((4, 3), (pool.capacity(), pool.len()));
+    }
+    #[test]
+    fn grow_concurrently() {
+        const THREADS: usize = 8;
+        const BUILDERS: usize = 16;
+        let pool = DynamicPool::new(1);
+        thread::scope(|s| {
+            for _ in 0..THREADS {
+                let pool = &pool;
+                s.spawn(move || {
+                    let builders: Vec<_> = (0..BUILDERS).map(|_| pool.get()).collect();
+                    drop(builders);
+                });
+            }
+        });
+        assert!(pool.capacity().is_power_of_two());
+        assert!(pool.len() <= pool.capacity());
+        assert!(pool.capacity() > 1, "{}", pool.capacity());
+    }
+    #[test]
+    fn outlive_pool() {
+        let pool = DynamicPool::new(1);
+        let mut b = pool.get();
+        drop(pool);
+        let name = b.create_string("something fun");
+        b.finish(name, None);
+    }
+}
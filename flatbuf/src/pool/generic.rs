@@ -0,0 +1,299 @@
+//! `crossbeam_queue::ArrayQueue` based pool of any recyclable type
+//!
+//! It's the [`v3`] local pool generalized over the pooled type, without
+//! the statistics, so that the pool is also used for the other buffers
+//! than `FlatBufferBuilder`.
+//!
+//! The pooled type is `Send + 'static`, so that the pool and its guards
+//! are shareable across the threads, which rules out the builders of the
+//! borrowed allocators.  Both the pools exist, as the [`v3`] local pool
+//! keeps the builder specific features which don't fit the recycling of
+//! any type: the statistics, the return predicate and overflow handler,
+//! the buffer capacity change and the eviction by the age, as well as
+//! its builders of any lifetime.  [`FlatBuilderPool`] is for the plain
+//! pooling of the builders.
+//!
+//! [`flatbuilderpool`]: type.FlatBuilderPool.html
+//! [`v3`]: ../v3/index.html
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Weak},
+};
+
+use crossbeam_queue::ArrayQueue;
+use flatbuffers::FlatBufferBuilder;
+
+/// Type which is reset to be reused, when it goes back to the [`Pool`].
+///
+/// [`pool`]: struct.Pool.html
+pub trait Recyclable {
+    fn recycle(&mut self);
+}
+
+impl Recyclable for FlatBufferBuilder<'_> {
+    #[inline]
+    fn recycle(&mut self) {
+        self.reset();
+    }
+}
+
+impl<T> Recyclable for Vec<T> {
+    #[inline]
+    fn recycle(&mut self) {
+        self.clear();
+    }
+}
+
+impl Recyclable for String {
+    #[inline]
+    fn recycle(&mut self) {
+        self.clear();
+    }
+}
+
+/// `FlatBufferBuilder` pool, of the `'static` builders only.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::FlatBuilderPool;
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let pool = FlatBuilderPool::new(4, 64, || FlatBufferBuilder::with_capacity(1_024));
+/// let mut b = pool.get();
+/// let name = b.create_string("something fun");
+/// b.finish(name, None);
+/// ```
+pub type FlatBuilderPool<'a> = Pool<FlatBufferBuilder<'a>>;
+
+/// Bounded pool of `T`.
+///
+/// The pooled value is created by the factory when the pool is empty,
+/// and is recycled and goes back to the pool when the [`Guard`] is
+/// dropped, unless the pool is full or dropped.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::Pool;
+///
+/// let pool = Pool::new(0, 4, || Vec::<u8>::with_capacity(64));
+/// let mut buf = pool.get();
+/// buf.extend_from_slice(b"something fun");
+/// drop(buf);
+/// assert_eq!(1, pool.len());
+/// assert!(pool.get().is_empty());
+/// ```
+/// [`guard`]: struct.Guard.html
+pub struct Pool<T: Recyclable + Send + 'static> {
+    /// Idle values.
+    inner: Arc<ArrayQueue<T>>,
+
+    /// Factory of the new value.
+    factory: Box<dyn Fn() -> T + Send + Sync>,
+}
+
+impl<T: Recyclable + Send + 'static> Pool<T> {
+    /// Create a pool of up to `max` idle values, with `init` values
+    /// created upfront by `factory`.
+    ///
+    /// # Panics
+    ///
+    /// Function `new` will panic if `max` is zero.
+    pub fn new<F>(init: usize, max: usize, factory: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        let inner = ArrayQueue::new(max);
+        for _ in 0..init.min(max) {
+            let _ = inner.push(factory());
+        }
+        Self {
+            inner: Arc::new(inner),
+            factory: Box::new(factory),
+        }
+    }
+    /// Get the value from the pool, or create the new one in case the
+    /// pool is empty.
+    #[inline]
+    pub fn get(&self) -> Guard<T> {
+        let value = match self.inner.pop() {
+            Ok(value) => value,
+            Err(_) => (self.factory)(),
+        };
+        Guard {
+            pool: Arc::downgrade(&self.inner),
+            inner: Some(value),
+        }
+    }
+    /// Returns the number of the idle values in the pool.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: Recyclable + Send + 'static> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("len", &self.inner.len())
+            .field("capacity", &self.inner.capacity())
+            .finish()
+    }
+}
+
+/// Value from the [`Pool`], which goes back to the pool on drop.
+///
+/// [`pool`]: struct.Pool.html
+pub struct Guard<T: Recyclable + Send + 'static> {
+    /// Pool to go back to.
+    pool: Weak<ArrayQueue<T>>,
+
+    /// Actual value.
+    inner: Option<T>,
+}
+
+impl<T: Recyclable + Send + 'static> Deref for Guard<T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+
+impl<T: Recyclable + Send + 'static> DerefMut for Guard<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().unwrap()
+    }
+}
+
+impl<T: Recyclable + Send + 'static> Drop for Guard<T> {
+    fn drop(&mut self) {
+        if let (Some(mut value), Some(pool)) = (self.inner.take(), self.pool.upgrade()) {
+            value.recycle();
+            // Drop the value in case the pool is full.
+            let _ = pool.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    /// Resource which counts the recycles shared by all the instances.
+    struct MockResource {
+        recycles: Arc<AtomicUsize>,
+        dirty: bool,
+    }
+
+    impl Recyclable for MockResource {
+        fn recycle(&mut self) {
+            self.recycles.fetch_add(1, Ordering::Relaxed);
+            self.dirty = false;
+        }
+    }
+
+    fn pool(init: usize, max: usize) -> (Pool<MockResource>, Arc<AtomicUsize>) {
+        let recycles = Arc::new(AtomicUsize::new(0));
+        let factory = {
+            let recycles = Arc::clone(&recycles);
+            move || MockResource {
+                recycles: Arc::clone(&recycles),
+                dirty: false,
+            }
+        };
+        (Pool::new(init, max, factory), recycles)
+    }
+    #[test]
+    fn recycle_on_drop() {
+        let (pool, recycles) = pool(0, 4);
+        let mut r = pool.get();
+        r.dirty = true;
+        assert_eq!(0, recycles.load(Ordering::Relaxed));
+        drop(r);
+        assert_eq!(1, recycles.load(Ordering::Relaxed));
+        assert_eq!(1, pool.len());
+        assert!(!pool.get().dirty);
+        assert_eq!(2, recycles.load(Ordering::Relaxed));
+    }
+    #[test]
+    fn init_and_max() {
+        struct Test {
+            name: &'static str,
+            data: (usize, usize, usize),
+            want: (usize, usize, usize),
+        }
+        // (init, max, gets) and (idle before, idle after, recycles).
+        let tests = [
+            Test {
+                name: "empty",
+                data: (0, 4, 2),
+                want: (0, 2, 2),
+            },
+            Test {
+                name: "init",
+                data: (2, 4, 3),
+                want: (2, 3, 3),
+            },
+            Test {
+                name: "full",
+                data: (1, 2, 5),
+                want: (1, 2, 5),
+            },
+            Test {
+                name: "init over max",
+                data: (8, 2, 0),
+                want: (2, 2, 0),
+            },
+        ];
+        for t in &tests {
+            let (init, max, gets) = t.data;
+            let (pool, recycles) = pool(init, max);
+            let before = pool.len();
+            let guards: Vec<_> = (0..gets).map(|_| pool.get()).collect();
+            drop(guards);
+            let got = (before, pool.len(), recycles.load(Ordering::Relaxed));
+            assert_eq!(t.want, got, "{}", t.name);
+        }
+    }
+    #[test]
+    fn outlive_pool() {
+        let (pool, recycles) = pool(1, 4);
+        let r = pool.get();
+        drop(pool);
+        drop(r);
+        assert_eq!(0, recycles.load(Ordering::Relaxed));
+    }
+    #[test]
+    fn across_threads() {
+        let (pool, recycles) = pool(0, 8);
+        thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        pool.get().dirty = true;
+                    }
+                });
+            }
+        });
+        assert_eq!(400, recycles.load(Ordering::Relaxed));
+        assert!(pool.len() <= 4);
+    }
+    #[test]
+    fn flat_builder_pool() {
+        let pool: FlatBuilderPool<'static> = Pool::new(1, 2, FlatBufferBuilder::new);
+        let mut b = pool.get();
+        let name = b.create_string("something fun");
+        b.finish(name, None);
+        drop(b);
+        assert_eq!(1, pool.len());
+        assert!(pool.get().unfinished_data().is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+//! `metrics` crate instrumentation of the [`v3`] global pool
+//!
+//! With the `metrics` feature, the global pool `get` and the return of
+//! the [`GlobalBuilder`] emit the following metrics to the installed
+//! recorder, e.g. the Prometheus exporter:
+//!
+//! - [`HITS`]: counter of the `get` calls served from the pool.
+//! - [`MISSES`]: counter of the `get` calls which allocated the builder.
+//! - [`SIZE`]: gauge of the number of the builders in the pool, after
+//!   each `get` and return.
+//! - [`BUFFER_BYTES`]: histogram of the bytes used by the returned
+//!   builders, to tune the buffer capacity.
+//!
+//! Without the recorder, the metrics go to the no-op one.
+//!
+//! [`v3`]: ../v3/index.html
+//! [`globalbuilder`]: ../v3/struct.GlobalBuilder.html
+//! [`hits`]: constant.HITS.html
+//! [`misses`]: constant.MISSES.html
+//! [`size`]: constant.SIZE.html
+//! [`buffer_bytes`]: constant.BUFFER_BYTES.html
+use metrics::{counter, gauge, histogram};
+
+/// Counter of the `get` calls served from the pool.
+pub const HITS: &str = "flatbuf.pool.hits.total";
+/// Counter of the `get` calls which allocated the new builder.
+pub const MISSES: &str = "flatbuf.pool.misses.total";
+/// Gauge of the number of the builders in the pool.
+pub const SIZE: &str = "flatbuf.pool.size";
+/// Histogram of the bytes used by the returned builders.
+pub const BUFFER_BYTES: &str = "flatbuf.pool.buffer_bytes";
+
+#[inline]
+pub(crate) fn hit(pool_len: usize) {
+    counter!(HITS).increment(1);
+    gauge!(SIZE).set(pool_len as f64);
+}
+
+#[inline]
+pub(crate) fn miss(pool_len: usize) {
+    counter!(MISSES).increment(1);
+    gauge!(SIZE).set(pool_len as f64);
+}
+
+#[inline]
+pub(crate) fn returned(pool_len: usize, bytes: usize) {
+    histogram!(BUFFER_BYTES).record(bytes as f64);
+    gauge!(SIZE).set(pool_len as f64);
+}
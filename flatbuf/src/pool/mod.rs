@@ -1,5 +1,109 @@
 //! flatbuffer builder pool
+//!
+//! The crate root re-exports one of the implementations, selected by the
+//! `pool-v1`, `pool-v2` and `pool-v3` features.  `pool-v3` is the default
+//! and wins over the others, so disable the default features to pin the
+//! older ones:
+//!
+//! ```toml
+//! flatbuf-tutorial = { version = "0.1", default-features = false, features = ["pool-v1"] }
+//! ```
+//!
+//! The re-exported API is the same with all of them, so that switching
+//! the feature needs no code changes.  `FlatBufferBuilderPool::get`
+//! returns `PoolExhausted` only at the [`v3`] hard limit, and never with
+//! the others, and `get_or_alloc` is the infallible one:
+//!
+//! ```
+//! use flatbuf_tutorial::{FlatBufferBuilderPool, PoolExhausted};
+//!
+//! fn name(name: &str) -> Result<Vec<u8>, PoolExhausted> {
+//!     let mut b = FlatBufferBuilderPool::get()?;
+//!     let name = b.create_string(name);
+//!     b.finish(name, None);
+//!     Ok(b.finished_data().to_vec())
+//! }
+//!
+//! assert!(name("something fun").is_ok());
+//! let mut b = FlatBufferBuilderPool::get_or_alloc();
+//! let name = b.create_string("something fun");
+//! b.finish(name, None);
+//! ```
+//!
+//! - [`v1`]: `parking_lot::Mutex<Vec>`.  The simplest, and the fastest
+//!   without contention, but all the threads serialize on the lock.
+//! - [`v2`]: `crossbeam_queue::SegQueue`.  Lock-free and unbounded, but
+//!   allocates the queue segments as it grows, and only checks the
+//!   maximum pool size approximately.
+//! - [`v3`]: `crossbeam_queue::ArrayQueue`.  Lock-free and bounded by
+//!   the preallocated queue, and the only one with the hit and miss
+//!   statistics.  The global pool is replaceable at runtime.
+//! - [`v4`]: the thread local pool in front of the [`v3`] global pool.
+//!   The builder mostly stays in the thread which returned it, which
+//!   avoids the contention on the shared queue.  It has no local pool
+//!   and is not selectable by the features.
+//!
+//! All the implementations are always compiled, for the benches.
+//!
+//! [`generic`] is the [`v3`] local pool generalized over the pooled type,
+//! and [`dynamic`] is the one which grows instead of dropping the returned
+//! builders.  [`rayon_ext`] serializes in parallel with the global pool
+//! with the `rayon` feature.
+//!
+//! [`sharded`] spreads the builders over the shards of the queues,
+//! selected by the [`ShardingStrategy`].
+//!
+//! [`debug`] tracks the outstanding [`v3`] global builders with the
+//! `debug` feature, to find the leaked ones, and
+//! [`metrics_integration`] emits the [`v3`] global pool metrics with the
+//! `metrics` feature.
+//!
+//! On wasm32, [`v4`] and [`rayon_ext`], which are for the threads, are
+//! left out, and [`wasm`] is the single-threaded pool exported to
+//! JavaScript with the `wasm` feature.
+//!
+//! [`debug`]: debug/index.html
+//! [`dynamic`]: dynamic/index.html
+//! [`generic`]: generic/index.html
+//! [`metrics_integration`]: metrics_integration/index.html
+//! [`rayon_ext`]: rayon_ext/index.html
+//! [`sharded`]: sharded/index.html
+//! [`shardingstrategy`]: sharded/enum.ShardingStrategy.html
+//! [`v1`]: v1/index.html
+//! [`v2`]: v2/index.html
+//! [`v3`]: v3/index.html
+//! [`v4`]: v4/index.html
+//! [`wasm`]: wasm/index.html
+#[cfg(feature = "debug")]
+pub mod debug;
+pub mod dynamic;
+pub mod generic;
+#[cfg(feature = "metrics")]
+pub mod metrics_integration;
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+pub mod rayon_ext;
+pub mod sharded;
 pub mod v1;
 pub mod v2;
 pub mod v3;
-pub use v3::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool};
+#[cfg(not(target_arch = "wasm32"))]
+pub mod v4;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod wasm;
+
+pub use dynamic::{DynamicBuilder, DynamicPool};
+pub use generic::{FlatBuilderPool, Guard, Pool, Recyclable};
+pub use sharded::{ShardedBuilder, ShardedPool, ShardedPoolBuilder, ShardingStrategy};
+
+#[cfg(not(any(feature = "pool-v1", feature = "pool-v2", feature = "pool-v3")))]
+compile_error!("one of the `pool-v1`, `pool-v2` or `pool-v3` features is required");
+
+#[cfg(all(
+    feature = "pool-v1",
+    not(any(feature = "pool-v2", feature = "pool-v3"))
+))]
+pub use v1::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, GlobalBuilder};
+#[cfg(all(feature = "pool-v2", not(feature = "pool-v3")))]
+pub use v2::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, GlobalBuilder};
+#[cfg(feature = "pool-v3")]
+pub use v3::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool, GlobalBuilder, PoolStats};
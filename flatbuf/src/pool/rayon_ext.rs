@@ -0,0 +1,77 @@
+//! rayon parallel serialization with the global flatbuffer builder pool
+//!
+//! [`par_serialize`] serializes the items on the rayon threads, each
+//! with the builder from [`FlatBufferBuilderPool::get`], which goes back
+//! to the pool once the finished buffer is copied out.
+//!
+//! [`par_serialize`]: fn.par_serialize.html
+//! [`flatbufferbuilderpool::get`]: ../../struct.FlatBufferBuilderPool.html#method.get
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+use rayon::prelude::*;
+
+use crate::FlatBufferBuilderPool;
+
+/// Serialize `items` in parallel, and returns the finished buffers in
+/// the order of `items`.
+///
+/// `f` builds the root table of the item, which is finished without the
+/// file identifier.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::rayon_ext::par_serialize;
+/// use flatbuf_tutorial::{Monster, MonsterView};
+///
+/// let names: Vec<_> = (0..100).map(|i| format!("orc-{}", i)).collect();
+/// let bufs = par_serialize(&names, |name, b| {
+///     Monster::create(b, name).as_union_value()
+/// });
+/// let view = MonsterView::from_bytes(&bufs[42]).unwrap();
+/// assert_eq!(Some("orc-42"), view.name());
+/// ```
+pub fn par_serialize<T: Sync, F>(items: &[T], f: F) -> Vec<Vec<u8>>
+where
+    F: Fn(&T, &mut FlatBufferBuilder<'static>) -> WIPOffset<UnionWIPOffset> + Sync,
+{
+    items
+        .par_iter()
+        .map(|item| {
+            let mut b = FlatBufferBuilderPool::get_or_alloc();
+            let root = f(item, &mut b);
+            b.finish(root, None);
+            b.finished_data().to_vec()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::MonsterData;
+    use crate::MonsterView;
+
+    #[test]
+    fn same_as_serial() {
+        let monsters: Vec<_> = (0..1_000)
+            .map(|i| MonsterData {
+                name: format!("orc-{}", i),
+                hp: i as i16,
+                inventory: vec![i as u8; i % 16],
+                ..Default::default()
+            })
+            .collect();
+        let bufs = par_serialize(&monsters, |data, b| data.build(b).as_union_value());
+        assert_eq!(monsters.len(), bufs.len());
+        for (data, buf) in monsters.iter().zip(&bufs) {
+            let mut b = FlatBufferBuilder::new();
+            let monster = data.build(&mut b);
+            b.finish(monster, None);
+            assert_eq!(b.finished_data(), &buf[..]);
+            assert_eq!(
+                *data,
+                MonsterData::from(MonsterView::from_bytes(buf).unwrap())
+            );
+        }
+    }
+}
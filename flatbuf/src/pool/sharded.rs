@@ -0,0 +1,345 @@
+//! Sharded `crossbeam_queue::ArrayQueue` based flatbuffer builder pool
+//!
+//! [`ShardedPool`] spreads the builders over the shards, each of which is
+//! the bounded queue of the [`v3`] local pool, so that the threads
+//! contend on the different queue heads and tails.  The
+//! [`ShardingStrategy`] selects the shard of each `get` and return:
+//!
+//! - `ThreadId`: the hash of the current thread id.  The thread always
+//!   hits its own shard, which suits the thread per core workers, but
+//!   the threads of the same hash share the shard.
+//! - `Sequential`: the round robin over the shards with the counter,
+//!   which spreads the calls evenly, but all the threads hit the cache
+//!   line of the counter.
+//! - `Random`: the thread local `SmallRng`, which spreads the calls
+//!   without the shared state.
+//!
+//! The shard is empty or full independently of the others, so the
+//! `get` allocates and the return drops the builder even though the
+//! other shards may have the room.
+//!
+//! [`v3`]: ../v3/index.html
+//! [`shardedpool`]: struct.ShardedPool.html
+//! [`shardingstrategy`]: enum.ShardingStrategy.html
+use std::{
+    cell::RefCell,
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::{Arc, Weak},
+    thread,
+};
+
+use crossbeam_queue::ArrayQueue;
+use flatbuffers::FlatBufferBuilder;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const SHARDS: usize = 8;
+const MAX_POOL_SIZE: usize = 128;
+const BUFFER_CAPACITY: usize = 64;
+
+/// Shard selection of [`ShardedPool`].
+///
+/// [`shardedpool`]: struct.ShardedPool.html
+#[derive(Debug, Default)]
+pub enum ShardingStrategy {
+    /// Hash of the current thread id modulo the number of the shards.
+    #[default]
+    ThreadId,
+    /// Counter modulo the number of the shards.
+    Sequential(AtomicUsize),
+    /// Thread local `SmallRng`.
+    Random,
+}
+
+thread_local! {
+    static THREAD_HASH: usize = RandomState::new().hash_one(thread::current().id()) as usize;
+    static RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(
+        RandomState::new().hash_one(thread::current().id()),
+    ));
+}
+
+impl ShardingStrategy {
+    /// Returns the index of the shard out of `n` shards.
+    #[inline]
+    fn select(&self, n: usize) -> usize {
+        match self {
+            Self::ThreadId => THREAD_HASH.with(|hash| hash % n),
+            Self::Sequential(next) => next.fetch_add(1, Ordering::Relaxed) % n,
+            Self::Random => RNG.with(|rng| rng.borrow_mut().gen_range(0..n)),
+        }
+    }
+}
+
+/// Sharded `FlatBufferBuilder` pool builder.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+///
+/// use flatbuf_tutorial::pool::sharded::{ShardedPoolBuilder, ShardingStrategy};
+///
+/// let pool = ShardedPoolBuilder::new()
+///     .shards(4)
+///     .max_pool_size(16)
+///     .buffer_capacity(256)
+///     .sharding_strategy(ShardingStrategy::Sequential(AtomicUsize::new(0)))
+///     .build();
+/// let mut b = pool.get();
+/// let name = b.create_string("something fun");
+/// b.finish(name, None);
+/// ```
+#[derive(Debug)]
+pub struct ShardedPoolBuilder {
+    shards: usize,
+    max: usize,
+    buffer_capacity: usize,
+    strategy: ShardingStrategy,
+}
+
+impl ShardedPoolBuilder {
+    /// Create the builder of the 8 shards, with the `ThreadId` strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the number of the shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics on zero.
+    #[inline]
+    pub fn shards(mut self, shards: usize) -> Self {
+        assert!(shards > 0, "zero shards");
+        self.shards = shards;
+        self
+    }
+
+    /// Change the maximum pool size of each shard.
+    #[inline]
+    pub fn max_pool_size(mut self, size: usize) -> Self {
+        self.max = size;
+        self
+    }
+
+    /// Change the initial `FlatBufferBuilder` buffer size.
+    #[inline]
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer_capacity = capacity;
+        self
+    }
+
+    /// Change the shard selection of `get` and the return.
+    #[inline]
+    pub fn sharding_strategy(mut self, strategy: ShardingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Build the empty sharded pool.
+    pub fn build<'a>(self) -> ShardedPool<'a> {
+        let shards = (0..self.shards)
+            .map(|_| ArrayQueue::new(self.max.max(1)))
+            .collect();
+        ShardedPool {
+            inner: Arc::new(Shards {
+                shards,
+                max: self.max,
+                strategy: self.strategy,
+            }),
+            buffer_capacity: self.buffer_capacity,
+        }
+    }
+}
+
+impl Default for ShardedPoolBuilder {
+    fn default() -> Self {
+        Self {
+            shards: SHARDS,
+            max: MAX_POOL_SIZE,
+            buffer_capacity: BUFFER_CAPACITY,
+            strategy: ShardingStrategy::default(),
+        }
+    }
+}
+
+struct Shards<'a> {
+    shards: Vec<ArrayQueue<FlatBufferBuilder<'a>>>,
+    /// Maximum pool size of each shard, which is zero for no pooling.
+    max: usize,
+    strategy: ShardingStrategy,
+}
+
+impl<'a> Shards<'a> {
+    #[inline]
+    fn select(&self) -> &ArrayQueue<FlatBufferBuilder<'a>> {
+        &self.shards[self.strategy.select(self.shards.len())]
+    }
+}
+
+/// Sharded `FlatBufferBuilder` pool.
+pub struct ShardedPool<'a> {
+    inner: Arc<Shards<'a>>,
+    buffer_capacity: usize,
+}
+
+impl<'a> ShardedPool<'a> {
+    /// Get the `FlatBufferBuilder` from the selected shard, or allocate
+    /// the new one in case the shard is empty.
+    #[inline]
+    pub fn get(&self) -> ShardedBuilder<'a> {
+        let builder = self
+            .inner
+            .select()
+            .pop()
+            .unwrap_or_else(|_| FlatBufferBuilder::with_capacity(self.buffer_capacity));
+        ShardedBuilder {
+            pool: Arc::downgrade(&self.inner),
+            inner: Some(builder),
+        }
+    }
+
+    /// Returns the number of the shards.
+    pub fn shards(&self) -> usize {
+        self.inner.shards.len()
+    }
+
+    /// Returns the number of the builders in all the shards.
+    pub fn len(&self) -> usize {
+        self.inner.shards.iter().map(ArrayQueue::len).sum()
+    }
+
+    /// Returns `true` if all the shards are empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.shards.iter().all(ArrayQueue::is_empty)
+    }
+}
+
+/// `ShardedBuilder` encapsulates the `FlatBufferBuilder` instance
+/// for the [`ShardedPool`].
+///
+/// [`shardedpool`]: struct.ShardedPool.html
+pub struct ShardedBuilder<'a> {
+    pool: Weak<Shards<'a>>,
+    inner: Option<FlatBufferBuilder<'a>>,
+}
+
+impl<'a> Deref for ShardedBuilder<'a> {
+    type Target = FlatBufferBuilder<'a>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+
+impl<'a> DerefMut for ShardedBuilder<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().unwrap()
+    }
+}
+
+impl<'a> Drop for ShardedBuilder<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(mut builder) = self.inner.take() {
+            if let Some(pool) = self.pool.upgrade() {
+                if pool.max == 0 {
+                    return;
+                }
+                builder.reset();
+                // The shard reached the maximum pool size otherwise.
+                let _ = pool.select().push(builder);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shard_lens(pool: &ShardedPool<'_>) -> Vec<usize> {
+        pool.inner.shards.iter().map(ArrayQueue::len).collect()
+    }
+    #[test]
+    fn thread_id() {
+        let pool = ShardedPoolBuilder::new()
+            .shards(8)
+            .sharding_strategy(ShardingStrategy::ThreadId)
+            .build();
+        let main = ShardingStrategy::ThreadId.select(pool.shards());
+        drop(pool.get());
+        let mut want = vec![0; pool.shards()];
+        want[main] = 1;
+        assert_eq!(want, shard_lens(&pool));
+        // Served from the shard of the thread, which it went back to.
+        for _ in 0..10 {
+            let b = pool.get();
+            assert!(pool.is_empty());
+            drop(b);
+            assert_eq!(want, shard_lens(&pool));
+        }
+        thread::spawn(move || {
+            let shard = ShardingStrategy::ThreadId.select(pool.shards());
+            let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+            drop(builders);
+            // All three go back to the shard of the thread, which has
+            // taken the builder of the main thread in case it's the same
+            // shard.
+            want[shard] = 3;
+            assert_eq!(want, shard_lens(&pool));
+        })
+        .join()
+        .unwrap();
+    }
+    #[test]
+    fn sequential() {
+        let pool = ShardedPoolBuilder::new()
+            .shards(4)
+            .sharding_strategy(ShardingStrategy::Sequential(AtomicUsize::new(0)))
+            .build();
+        // The gets take 0 to 3, and the returns 4 to 7.
+        let builders: Vec<_> = (0..4).map(|_| pool.get()).collect();
+        drop(builders);
+        assert_eq!(vec![1; 4], shard_lens(&pool));
+    }
+    #[test]
+    fn random() {
+        let pool = ShardedPoolBuilder::new()
+            .shards(3)
+            .sharding_strategy(ShardingStrategy::Random)
+            .build();
+        let builders: Vec<_> = (0..100).map(|_| pool.get()).collect();
+        drop(builders);
+        assert_eq!(100, pool.len());
+        assert!(shard_lens(&pool).iter().all(|len| *len > 0));
+    }
+    #[test]
+    fn max_pool_size() {
+        let pool = ShardedPoolBuilder::new().shards(1).max_pool_size(2).build();
+        let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+        drop(builders);
+        assert_eq!(2, pool.len());
+
+        let pool = ShardedPoolBuilder::new().max_pool_size(0).build();
+        drop(pool.get());
+        assert!(pool.is_empty());
+    }
+    #[test]
+    fn outlived_by_builder() {
+        let pool = ShardedPoolBuilder::new().build();
+        let mut b = pool.get();
+        drop(pool);
+        let name = b.create_string("something fun");
+        b.finish(name, None);
+    }
+    #[test]
+    #[should_panic(expected = "zero shards")]
+    fn zero_shards() {
+        ShardedPoolBuilder::new().shards(0);
+    }
+}
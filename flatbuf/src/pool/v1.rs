@@ -1,14 +1,15 @@
 //! `parking_log::Mutex<Vec>` based flatbuffer builder pool
 use std::{
     ops::{Deref, DerefMut},
-    sync::atomic::{AtomicBool, Ordering},
-    sync::{Arc, Weak},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    sync::{Arc, LazyLock, Once, Weak},
 };
 
 use flatbuffers::FlatBufferBuilder;
-use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 
+use crate::error::PoolExhausted;
+
 /// `FlatBufferBuilder` pool.
 ///
 /// # Examples
@@ -16,7 +17,7 @@ use parking_lot::Mutex;
 /// ```
 /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
 ///
-/// let mut b = FlatBufferBuilderPool::get();
+/// let mut b = FlatBufferBuilderPool::get().unwrap();
 /// let name = b.create_string("something fun");
 /// b.finish(name, None);
 /// ```
@@ -31,24 +32,47 @@ pub struct FlatBufferBuilderPool {
     buffer_capacity: usize,
 }
 
-static mut INIT_POOL_SIZE: usize = 32;
-static mut MAX_POOL_SIZE: usize = 1_024;
-static mut BUFFER_CAPACITY: usize = 64;
+static INIT_POOL_SIZE: AtomicUsize = AtomicUsize::new(32);
+static MAX_POOL_SIZE: AtomicUsize = AtomicUsize::new(1_024);
+static BUFFER_CAPACITY: AtomicUsize = AtomicUsize::new(64);
 
 impl FlatBufferBuilderPool {
-    /// Get the `FlatBufferBuilder` from the global pool.
+    /// Get the `FlatBufferBuilder` from the global pool, same as
+    /// [`get_or_alloc`], in the `Result` of the crate root API shared
+    /// with the v3 pool, of which `get` returns `PoolExhausted` at the
+    /// hard limit.  It never fails, as there's no hard limit.
     ///
     /// # Examples
     ///
     /// ```
     /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
     ///
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
+    ///
+    /// [`get_or_alloc`]: #method.get_or_alloc
     #[inline]
-    pub fn get() -> GlobalBuilder {
+    pub fn get() -> Result<GlobalBuilder, PoolExhausted> {
+        Ok(Self::get_or_alloc())
+    }
+
+    /// Get the `FlatBufferBuilder` from the global pool, or allocate
+    /// the new one in case the pool runs out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
+    ///
+    /// let mut b = FlatBufferBuilderPool::get_or_alloc();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_or_alloc() -> GlobalBuilder {
+        POOL_INIT.call_once(init_pool);
         let mut pool = POOL.lock();
         match pool.pop() {
             Some(builder) => builder,
@@ -67,18 +91,14 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::init_global_pool_size(0);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
     #[inline]
     pub fn init_global_pool_size(size: usize) {
-        unsafe {
-            INIT_POOL_SIZE = size;
-            if MAX_POOL_SIZE < size {
-                MAX_POOL_SIZE = size;
-            }
-        }
+        INIT_POOL_SIZE.store(size, Ordering::Relaxed);
+        MAX_POOL_SIZE.fetch_max(size, Ordering::Relaxed);
     }
 
     /// Change the maximum global pool size.
@@ -92,18 +112,14 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::max_global_pool_size(4);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
     #[inline]
     pub fn max_global_pool_size(size: usize) {
-        unsafe {
-            MAX_POOL_SIZE = size;
-            if INIT_POOL_SIZE > size {
-                INIT_POOL_SIZE = size;
-            }
-        }
+        MAX_POOL_SIZE.store(size, Ordering::Relaxed);
+        INIT_POOL_SIZE.fetch_min(size, Ordering::Relaxed);
     }
 
     /// Change the initial `FlatBufferBuilder` buffer size.
@@ -117,15 +133,13 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::global_buffer_capacity(64);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
     #[inline]
     pub fn global_buffer_capacity(capacity: usize) {
-        unsafe {
-            BUFFER_CAPACITY = capacity;
-        }
+        BUFFER_CAPACITY.store(capacity, Ordering::Relaxed);
     }
 }
 
@@ -141,14 +155,14 @@ impl GlobalBuilder {
 
     #[inline]
     fn buffer_capacity() -> usize {
-        unsafe { BUFFER_CAPACITY }
+        BUFFER_CAPACITY.load(Ordering::Relaxed)
     }
 }
 
 impl Default for GlobalBuilder {
     #[inline]
     fn default() -> Self {
-        Self(Some(FlatBufferBuilder::new_with_capacity(
+        Self(Some(FlatBufferBuilder::with_capacity(
             Self::buffer_capacity(),
         )))
     }
@@ -175,7 +189,7 @@ impl Drop for GlobalBuilder {
             // resetting the builder outside of the lock
             // to reduce the pool manipulation contention.
             builder.reset();
-            let max = unsafe { MAX_POOL_SIZE };
+            let max = MAX_POOL_SIZE.load(Ordering::Relaxed);
             let mut pool = POOL.lock();
             if pool.len() < max {
                 pool.push(GlobalBuilder(Some(builder)))
@@ -184,14 +198,21 @@ impl Drop for GlobalBuilder {
     }
 }
 
-static POOL: Lazy<Mutex<Vec<GlobalBuilder>>> = Lazy::new(|| {
-    let (init, max) = unsafe { (INIT_POOL_SIZE, MAX_POOL_SIZE) };
-    let mut pool = Vec::with_capacity(max);
+static POOL: LazyLock<Mutex<Vec<GlobalBuilder>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Global pool initialization, which is done by the first `get` so that
+/// the sizes set before it are applied.
+static POOL_INIT: Once = Once::new();
+
+fn init_pool() {
+    let init = INIT_POOL_SIZE.load(Ordering::Relaxed);
+    let max = MAX_POOL_SIZE.load(Ordering::Relaxed);
+    let mut pool = POOL.lock();
+    pool.reserve(max);
     for _ in 0..init {
         pool.push(GlobalBuilder::new());
     }
-    Mutex::new(pool)
-});
+}
 
 impl FlatBufferBuilderPool {
     /// Create a local `FlatBufferBuilder` pool instance.
@@ -309,7 +330,7 @@ impl FlatBufferBuilderPool {
             let builder = LocalBuilder::new(
                 Arc::downgrade(&inner),
                 self.max,
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity),
             );
             inner.lock().push(builder);
         }
@@ -381,10 +402,39 @@ impl<'a> FlatBufferBuilderLocalPool<'a> {
             None => LocalBuilder::new(
                 Arc::downgrade(&self.inner),
                 self.max,
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity),
             ),
         }
     }
+
+    /// Get the `FlatBufferBuilder` from the local pool, or allocate
+    /// the new one in case `capacity` is larger than the local pool
+    /// buffer capacity.
+    ///
+    /// The newly allocated builder goes back to the local pool on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v1::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().buffer_capacity(64).build();
+    /// let mut b = pool.get_with_capacity(4_096);
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_with_capacity(&self, capacity: usize) -> LocalBuilder<'a> {
+        if capacity <= self.buffer_capacity {
+            self.get()
+        } else {
+            LocalBuilder::new(
+                Arc::downgrade(&self.inner),
+                self.max,
+                FlatBufferBuilder::with_capacity(capacity),
+            )
+        }
+    }
 }
 
 impl<'a> Drop for FlatBufferBuilderLocalPool<'a> {
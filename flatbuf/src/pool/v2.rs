@@ -2,12 +2,13 @@
 use std::{
     ops::{Deref, DerefMut},
     sync::atomic::{AtomicBool, Ordering},
-    sync::{Arc, Weak},
+    sync::{Arc, LazyLock, Weak},
 };
 
 use crossbeam_queue::SegQueue;
 use flatbuffers::FlatBufferBuilder;
-use once_cell::sync::Lazy;
+
+use crate::error::PoolExhausted;
 
 /// A global `FlatBufferBuilder` pool.
 ///
@@ -16,7 +17,7 @@ use once_cell::sync::Lazy;
 /// ```
 /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
 ///
-/// let mut b = FlatBufferBuilderPool::get();
+/// let mut b = FlatBufferBuilderPool::get().unwrap();
 /// let name = b.create_string("something fun");
 /// b.finish(name, None);
 /// ```
@@ -36,19 +37,41 @@ static mut MAX_POOL_SIZE: usize = 1_024;
 static mut BUFFER_CAPACITY: usize = 64;
 
 impl FlatBufferBuilderPool {
-    /// Get the `FlatBufferBuilder` from the global pool.
+    /// Get the `FlatBufferBuilder` from the global pool, same as
+    /// [`get_or_alloc`], in the `Result` of the crate root API shared
+    /// with the v3 pool, of which `get` returns `PoolExhausted` at the
+    /// hard limit.  It never fails, as there's no hard limit.
     ///
     /// # Examples
     ///
     /// ```
     /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
     ///
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
+    ///
+    /// [`get_or_alloc`]: #method.get_or_alloc
     #[inline]
-    pub fn get() -> GlobalBuilder {
+    pub fn get() -> Result<GlobalBuilder, PoolExhausted> {
+        Ok(Self::get_or_alloc())
+    }
+
+    /// Get the `FlatBufferBuilder` from the global pool, or allocate
+    /// the new one in case the pool runs out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
+    ///
+    /// let mut b = FlatBufferBuilderPool::get_or_alloc();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_or_alloc() -> GlobalBuilder {
         match POOL.pop() {
             Ok(builder) => builder,
             Err(_) => GlobalBuilder::new(),
@@ -66,7 +89,7 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::init_global_pool_size(0);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
@@ -91,7 +114,7 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::max_global_pool_size(4);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
@@ -116,7 +139,7 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
     ///
     /// FlatBufferBuilderPool::global_buffer_capacity(64);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
@@ -147,7 +170,7 @@ impl GlobalBuilder {
 impl Default for GlobalBuilder {
     #[inline]
     fn default() -> Self {
-        Self(Some(FlatBufferBuilder::new_with_capacity(Self::capacity())))
+        Self(Some(FlatBufferBuilder::with_capacity(Self::capacity())))
     }
 }
 
@@ -179,7 +202,7 @@ impl Drop for GlobalBuilder {
     }
 }
 
-static POOL: Lazy<SegQueue<GlobalBuilder>> = Lazy::new(|| {
+static POOL: LazyLock<SegQueue<GlobalBuilder>> = LazyLock::new(|| {
     let init = unsafe { INIT_POOL_SIZE };
     let pool = SegQueue::new();
     for _ in 0..init {
@@ -304,7 +327,7 @@ impl FlatBufferBuilderPool {
             let builder = LocalBuilder::new(
                 Arc::downgrade(&inner),
                 self.max,
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity),
             );
             inner.push(builder);
         }
@@ -375,10 +398,39 @@ impl<'a> FlatBufferBuilderLocalPool<'a> {
             Err(_) => LocalBuilder::new(
                 Arc::downgrade(&self.inner),
                 self.max,
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity),
             ),
         }
     }
+
+    /// Get the `FlatBufferBuilder` from the local pool, or allocate
+    /// the new one in case `capacity` is larger than the local pool
+    /// buffer capacity.
+    ///
+    /// The newly allocated builder goes back to the local pool on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v2::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().buffer_capacity(64).build();
+    /// let mut b = pool.get_with_capacity(4_096);
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_with_capacity(&self, capacity: usize) -> LocalBuilder<'a> {
+        if capacity <= self.buffer_capacity {
+            self.get()
+        } else {
+            LocalBuilder::new(
+                Arc::downgrade(&self.inner),
+                self.max,
+                FlatBufferBuilder::with_capacity(capacity),
+            )
+        }
+    }
 }
 
 impl<'a> Drop for FlatBufferBuilderLocalPool<'a> {
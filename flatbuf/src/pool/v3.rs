@@ -1,13 +1,22 @@
 //! `crossbeam_queue::ArrayQueue` based flatbuffer builder pool
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{Duration, Instant};
 use std::{
+    cell::Cell,
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
-    sync::atomic::{AtomicBool, Ordering},
+    ptr,
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
     sync::{Arc, Weak},
+    thread,
 };
 
+use arc_swap::ArcSwapOption;
 use crossbeam_queue::ArrayQueue;
-use flatbuffers::FlatBufferBuilder;
-use once_cell::sync::Lazy;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use crate::error::PoolExhausted;
 
 /// `FlatBufferBuilder` pool.
 ///
@@ -17,7 +26,7 @@ use once_cell::sync::Lazy;
 /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
 ///
 /// // Get the builder from the global pool.
-/// let mut b = FlatBufferBuilderPool::get();
+/// let mut b = FlatBufferBuilderPool::get().unwrap();
 /// let name = b.create_string("something fun");
 /// b.finish(name, None);
 /// ```
@@ -30,14 +39,40 @@ pub struct FlatBufferBuilderPool {
 
     /// Flatbuffer buffer capacity of the local pool buffer.
     buffer_capacity: usize,
+
+    /// Local pool return hooks.
+    hooks: Hooks,
+}
+
+/// Predicate of the returned builder, which is discarded on `false`.
+type ReturnPredicate = Arc<dyn Fn(&FlatBufferBuilder<'_>) -> bool + Send + Sync>;
+
+/// Handler of the returned builder which overflows the pool.
+type OverflowHandler = Arc<dyn Fn(FlatBufferBuilder<'_>) + Send + Sync>;
+
+/// Local pool return hooks, shared by the pool and its builders.
+#[derive(Clone, Default)]
+struct Hooks {
+    predicate: Option<ReturnPredicate>,
+    overflow: Option<OverflowHandler>,
 }
 
-static mut INIT_POOL_SIZE: usize = 32;
-static mut MAX_POOL_SIZE: usize = 1_024;
-static mut BUFFER_CAPACITY: usize = 64;
+static INIT_POOL_SIZE: AtomicUsize = AtomicUsize::new(32);
+static MAX_POOL_SIZE: AtomicUsize = AtomicUsize::new(1_024);
+static BUFFER_CAPACITY: AtomicUsize = AtomicUsize::new(64);
+static SHRINK_TO: AtomicUsize = AtomicUsize::new(usize::MAX);
+static HARD_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+/// Number of the global builders checked out of the global pools.
+static OUTSTANDING: AtomicUsize = AtomicUsize::new(0);
 
 impl FlatBufferBuilderPool {
-    /// Get the `FlatBufferBuilder` from the global pool.
+    /// Get the `FlatBufferBuilder` from the global pool, or allocate
+    /// the new one in case the pool runs out.
+    ///
+    /// It returns `PoolExhausted` instead, once the hard limit of the
+    /// outstanding builders is reached, see [`global_hard_limit`].  The
+    /// slot is reserved atomically before taking the builder, as the
+    /// semaphore, so the concurrent `get`s never go over the limit.
     ///
     /// # Examples
     ///
@@ -45,68 +80,205 @@ impl FlatBufferBuilderPool {
     /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
     ///
     /// // Get the builder from the global pool.
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    ///
+    /// [`global_hard_limit`]: #method.global_hard_limit
+    #[inline]
+    pub fn get() -> Result<GlobalBuilder, PoolExhausted> {
+        let hard_limit = HARD_LIMIT.load(Ordering::Relaxed);
+        OUTSTANDING
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                (n < hard_limit).then(|| n + 1)
+            })
+            .map_err(|_| PoolExhausted { hard_limit })?;
+        Ok(Self::take())
+    }
+
+    /// [`get`] regardless of the hard limit, which always allocates the
+    /// new builder in case the pool runs out, as the v1 and v2 pools.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// FlatBufferBuilderPool::global_hard_limit(0);
+    /// assert!(FlatBufferBuilderPool::get().is_err());
+    /// let mut b = FlatBufferBuilderPool::get_or_alloc();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    ///
+    /// [`get`]: #method.get
+    #[inline]
+    pub fn get_or_alloc() -> GlobalBuilder {
+        OUTSTANDING.fetch_add(1, Ordering::AcqRel);
+        Self::take()
+    }
+
+    /// Take the builder out of the global pool, or allocate the new one,
+    /// for the reserved outstanding slot.
+    #[inline]
+    fn take() -> GlobalBuilder {
+        let pool = global_pool();
+        match pool.pop() {
+            Ok(pooled) => Self::hit(pool, pooled),
+            Err(_) => Self::miss(pool, GlobalBuilder::capacity()),
+        }
+    }
+
+    #[inline]
+    fn hit(pool: Arc<ArrayQueue<Pooled>>, pooled: Pooled) -> GlobalBuilder {
+        GLOBAL_STATS.hit();
+        count_thread(|stats| stats.hits += 1);
+        #[cfg(feature = "metrics")]
+        super::metrics_integration::hit(pool.len());
+        GlobalBuilder::from_pool(pool, pooled)
+    }
+
+    #[inline]
+    fn miss(pool: Arc<ArrayQueue<Pooled>>, capacity: usize) -> GlobalBuilder {
+        GLOBAL_STATS.miss();
+        count_thread(|stats| stats.misses += 1);
+        #[cfg(feature = "metrics")]
+        super::metrics_integration::miss(pool.len());
+        GlobalBuilder::from_pool(pool, Pooled::with_capacity(capacity))
+    }
+
+    /// Get the `FlatBufferBuilder` from the global pool, or allocate
+    /// the new one in case `capacity` is larger than the global buffer
+    /// capacity.
+    ///
+    /// Same as [`get_or_alloc`], it's not capped by the hard limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let mut b = FlatBufferBuilderPool::get_with_capacity(4_096);
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
+    ///
+    /// [`get_or_alloc`]: #method.get_or_alloc
     #[inline]
-    pub fn get() -> GlobalBuilder {
-        match POOL.pop() {
-            Ok(builder) => builder,
-            Err(_) => GlobalBuilder::new(),
+    pub fn get_with_capacity(capacity: usize) -> GlobalBuilder {
+        if capacity <= GlobalBuilder::capacity() {
+            Self::get_or_alloc()
+        } else {
+            OUTSTANDING.fetch_add(1, Ordering::AcqRel);
+            Self::miss(global_pool(), capacity)
         }
     }
 
+    /// Returns the global pool statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let before = FlatBufferBuilderPool::global_stats();
+    /// drop(FlatBufferBuilderPool::get().unwrap());
+    /// let after = FlatBufferBuilderPool::global_stats();
+    /// assert!(before.hits + before.misses < after.hits + after.misses);
+    /// ```
+    pub fn global_stats() -> PoolStats {
+        GLOBAL_STATS.get()
+    }
+
+    /// Returns the global pool statistics of the current thread.
+    ///
+    /// The global statistics are the sum of the thread statistics of
+    /// all the threads, including the exited ones, other than the
+    /// returns in the thread local destructors, which aren't counted
+    /// per thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// std::thread::spawn(|| {
+    ///     drop(FlatBufferBuilderPool::get().unwrap());
+    ///     let stats = FlatBufferBuilderPool::thread_stats();
+    ///     assert_eq!(1, stats.hits + stats.misses);
+    ///     assert_eq!(1, stats.returns);
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// ```
+    pub fn thread_stats() -> PoolStats {
+        THREAD_STATS.with(Cell::get)
+    }
+
     /// Change the initial global pool size.
     ///
-    /// It should be called before calling the first `get`
-    /// function otherwise the change won't applicable.
+    /// # Panics
+    ///
+    /// It should be called before calling the first `get` function,
+    /// which initializes the global pool with the sizes.  It panics
+    /// afterwards in the debug build, and logs the warning in the
+    /// release build, as the change won't be applicable.
     ///
     /// # Examples
     ///
+    /// The example relies on rustdoc running each doctest in its own
+    /// process, where the global pool isn't initialized yet.  Call it once
+    /// at the start-up, e.g. with `std::sync::Once`, in the process of the
+    /// multiple callers, or use [`replace_global_pool`] instead.
+    ///
     /// ```
     /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
     ///
     /// // Get the builder from the global pool.
     /// FlatBufferBuilderPool::init_global_pool_size(0);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
+    ///
+    /// [`replace_global_pool`]: #method.replace_global_pool
     #[inline]
     pub fn init_global_pool_size(size: usize) {
-        unsafe {
-            INIT_POOL_SIZE = size;
-            if MAX_POOL_SIZE < size {
-                MAX_POOL_SIZE = size;
-            }
-        }
+        check_unconfigured();
+        INIT_POOL_SIZE.store(size, Ordering::Relaxed);
+        MAX_POOL_SIZE.fetch_max(size, Ordering::Relaxed);
     }
 
     /// Change the maximum global pool size.
     ///
-    /// It should be called before calling the first `get`
-    /// function otherwise the change won't applicable.
+    /// # Panics
+    ///
+    /// Same as [`init_global_pool_size`], it panics after the first
+    /// `get` in the debug build.  Use [`replace_global_pool`] instead to
+    /// resize the initialized global pool.
     ///
     /// # Examples
     ///
+    /// Same as [`init_global_pool_size`], the example relies on the
+    /// doctest process of its own.
+    ///
     /// ```
     /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
     ///
     /// // Get the builder from the global pool.
     /// FlatBufferBuilderPool::max_global_pool_size(4);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
+    ///
+    /// [`init_global_pool_size`]: #method.init_global_pool_size
+    /// [`replace_global_pool`]: #method.replace_global_pool
     #[inline]
     pub fn max_global_pool_size(size: usize) {
-        unsafe {
-            MAX_POOL_SIZE = size;
-            if INIT_POOL_SIZE > size {
-                INIT_POOL_SIZE = size;
-            }
-        }
+        check_unconfigured();
+        set_max_pool_size(size);
     }
 
     /// Change the initial `FlatBufferBuilder` buffer size.
@@ -121,38 +293,243 @@ impl FlatBufferBuilderPool {
     ///
     /// // Get the builder from the global pool.
     /// FlatBufferBuilderPool::global_buffer_capacity(64);
-    /// let mut b = FlatBufferBuilderPool::get();
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
     /// let name = b.create_string("something fun");
     /// b.finish(name, None);
     /// ```
     #[inline]
     pub fn global_buffer_capacity(capacity: usize) {
-        unsafe {
-            BUFFER_CAPACITY = capacity;
-        }
+        BUFFER_CAPACITY.store(capacity, Ordering::Relaxed);
+    }
+
+    /// Change the maximum `FlatBufferBuilder` buffer size kept in the
+    /// global pool.
+    ///
+    /// The returned builder of the larger buffer, e.g. after the large
+    /// one-off message, is replaced with the new one of the global
+    /// buffer capacity, up to `target_bytes`, so that the following `get`
+    /// doesn't inherit the inflated buffer.  It's unlimited by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// FlatBufferBuilderPool::global_shrink_to(64 * 1_024);
+    /// let mut b = FlatBufferBuilderPool::get().unwrap();
+    /// let data = b.create_byte_string(&[0; 100 * 1_024]);
+    /// b.finish(data, None);
+    /// // Shrunk to the global buffer capacity.
+    /// drop(b);
+    /// ```
+    #[inline]
+    pub fn global_shrink_to(target_bytes: usize) {
+        SHRINK_TO.store(target_bytes, Ordering::Relaxed);
+    }
+
+    /// Change the hard limit of the outstanding global builders, at
+    /// which [`get`] returns `PoolExhausted`.  It's unlimited by default,
+    /// and [`get_or_alloc`] ignores it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// FlatBufferBuilderPool::global_hard_limit(1);
+    /// let b = FlatBufferBuilderPool::get().unwrap();
+    /// assert!(FlatBufferBuilderPool::get().is_err());
+    /// drop(b);
+    /// assert!(FlatBufferBuilderPool::get().is_ok());
+    /// ```
+    ///
+    /// [`get`]: #method.get
+    /// [`get_or_alloc`]: #method.get_or_alloc
+    #[inline]
+    pub fn global_hard_limit(n: usize) {
+        HARD_LIMIT.store(n, Ordering::Relaxed);
+    }
+
+    /// Returns the number of the global builders checked out of the
+    /// global pools, including the replaced ones and the ones over the
+    /// hard limit by [`get_or_alloc`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let before = FlatBufferBuilderPool::global_outstanding();
+    /// let b = FlatBufferBuilderPool::get().unwrap();
+    /// assert!(FlatBufferBuilderPool::global_outstanding() >= 1);
+    /// drop(b);
+    /// # let _ = before;
+    /// ```
+    ///
+    /// [`get_or_alloc`]: #method.get_or_alloc
+    pub fn global_outstanding() -> usize {
+        OUTSTANDING.load(Ordering::Relaxed)
+    }
+
+    /// Replace the global pool with the new one of the maximum size
+    /// `max` and the buffer capacity `capacity`, e.g. after reloading the
+    /// configuration.
+    ///
+    /// The new pool is pre-filled with the initial global pool size, if
+    /// any, clamped to `max`.  The builders checked out of the old pool
+    /// go back to it, and it's freed once all of them are dropped.
+    /// Before the first `get`, the pool is installed as is, without
+    /// initializing the one to be replaced, and the global pool sizes
+    /// are still changeable until the first `get`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let mut old = FlatBufferBuilderPool::get().unwrap();
+    /// FlatBufferBuilderPool::replace_global_pool(16, 4_096);
+    /// let mut new = FlatBufferBuilderPool::get().unwrap();
+    /// let name = new.create_string("something fun");
+    /// new.finish(name, None);
+    /// // Goes back to the old pool.
+    /// drop(old);
+    /// ```
+    pub fn replace_global_pool(max: usize, capacity: usize) {
+        set_max_pool_size(max);
+        Self::global_buffer_capacity(capacity);
+        // Not through `global_pool`, which would initialize the pool to
+        // be replaced right away.
+        POOL.store(Some(Arc::new(new_pool())));
     }
 }
 
 /// `GlobalBuilder` encapsulates the `FlatBufferBuilder` instance
 /// for the global pool.
-pub struct GlobalBuilder(Option<FlatBufferBuilder<'static>>);
+///
+/// It holds the global pool it's checked out of, and goes back to it
+/// even after the pool is replaced.
+pub struct GlobalBuilder {
+    /// Global pool.
+    pool: Arc<ArrayQueue<Pooled>>,
+
+    /// Actual builder.
+    inner: Option<FlatBufferBuilder<'static>>,
+
+    /// Fingerprint of the actual builder.
+    fingerprint: u64,
+}
 
 impl GlobalBuilder {
     #[inline]
-    fn new() -> Self {
-        Self::default()
+    fn from_pool(pool: Arc<ArrayQueue<Pooled>>, pooled: Pooled) -> Self {
+        #[cfg(feature = "debug")]
+        super::debug::track(pooled.fingerprint);
+        Self {
+            pool,
+            inner: Some(pooled.builder),
+            fingerprint: pooled.fingerprint,
+        }
+    }
+
+    /// Returns the fingerprint of the builder, which identifies the
+    /// builder across the pool cycles, as well as in [`check_for_leaks`].
+    ///
+    /// It's the hash of the creation time and thread of the builder,
+    /// and kept through the `reset` on the return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let a = FlatBufferBuilderPool::get().unwrap();
+    /// let b = FlatBufferBuilderPool::get().unwrap();
+    /// assert_ne!(a.fingerprint(), b.fingerprint());
+    /// ```
+    ///
+    /// [`check_for_leaks`]: ../debug/fn.check_for_leaks.html
+    #[inline]
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Drop the builder instead of returning it to the pool, e.g. after
+    /// the error which may leave it in the unexpected state.
+    ///
+    /// The fingerprint of the discarded builder is logged at the debug
+    /// level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let b = FlatBufferBuilderPool::get().unwrap();
+    /// let fingerprint = b.fingerprint();
+    /// b.discard();
+    /// assert_ne!(fingerprint, FlatBufferBuilderPool::get().unwrap().fingerprint());
+    /// ```
+    pub fn discard(mut self) {
+        if self.inner.take().is_some() {
+            log::debug!("discarded the global builder {:016x}", self.fingerprint);
+        }
     }
 
     #[inline]
     fn capacity() -> usize {
-        unsafe { BUFFER_CAPACITY }
+        BUFFER_CAPACITY.load(Ordering::Relaxed)
+    }
+
+    /// Build the root table with `f`, finish the buffer, and returns the
+    /// copy of the finished data, returning the builder to the pool.
+    ///
+    /// The finished data is only reachable through the copy, which
+    /// outlives the builder.  Otherwise, the finished data borrowed from
+    /// the builder can't outlive it, e.g. the following code doesn't
+    /// compile:
+    ///
+    /// ```compile_fail
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let data = {
+    ///     let mut b = FlatBufferBuilderPool::get().unwrap();
+    ///     let name = b.create_string("something fun");
+    ///     b.finish(name, None);
+    ///     b.finished_data()
+    /// };
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    /// use flatbuf_tutorial::{Monster, MonsterView};
+    ///
+    /// let data = FlatBufferBuilderPool::get()
+    ///     .unwrap()
+    ///     .with_finish(|b| Monster::builder().name("orc").hp(80).build(b));
+    /// let orc = MonsterView::from_bytes(&data).unwrap();
+    /// assert_eq!(Some("orc"), orc.name());
+    /// ```
+    pub fn with_finish<R, F>(mut self, f: F) -> Vec<u8>
+    where
+        F: FnOnce(&mut FlatBufferBuilder<'static>) -> WIPOffset<R>,
+    {
+        let root = f(&mut self);
+        self.finish(root, None);
+        self.finished_data().to_vec()
     }
 }
 
 impl Default for GlobalBuilder {
+    /// Allocate the new builder for the current global pool, which takes
+    /// the outstanding slot regardless of the hard limit, as
+    /// `get_or_alloc`.
     #[inline]
     fn default() -> Self {
-        Self(Some(FlatBufferBuilder::new_with_capacity(Self::capacity())))
+        OUTSTANDING.fetch_add(1, Ordering::AcqRel);
+        Self::from_pool(global_pool(), Pooled::with_capacity(Self::capacity()))
     }
 }
 
@@ -160,37 +537,236 @@ impl Deref for GlobalBuilder {
     type Target = FlatBufferBuilder<'static>;
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.0.as_ref().unwrap()
+        self.inner.as_ref().unwrap()
     }
 }
 
 impl DerefMut for GlobalBuilder {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.0.as_mut().unwrap()
+        self.inner.as_mut().unwrap()
     }
 }
 
 impl Drop for GlobalBuilder {
     #[inline]
     fn drop(&mut self) {
-        if let Some(mut builder) = self.0.take() {
-            builder.reset();
-            if let Err(_err) = POOL.push(GlobalBuilder(Some(builder))) {
-                // pool reached the MAX_POOL_SIZE.
+        #[cfg(feature = "debug")]
+        super::debug::untrack(self.fingerprint);
+        OUTSTANDING.fetch_sub(1, Ordering::AcqRel);
+        if let Some(mut builder) = self.inner.take() {
+            #[cfg(feature = "metrics")]
+            let bytes = builder.unfinished_data().len();
+            let shrink_to = SHRINK_TO.load(Ordering::Relaxed);
+            let pooled = if buffer_len(&mut builder) > shrink_to {
+                log::debug!("shrunk the global builder {:016x}", self.fingerprint);
+                Pooled::with_capacity(Self::capacity().min(shrink_to))
+            } else {
+                builder.reset();
+                Pooled {
+                    fingerprint: self.fingerprint,
+                    builder,
+                }
+            };
+            match self.pool.push(pooled) {
+                Ok(()) => {
+                    GLOBAL_STATS.returned();
+                    count_thread(|stats| stats.returns += 1);
+                    #[cfg(feature = "metrics")]
+                    super::metrics_integration::returned(self.pool.len(), bytes);
+                }
+                Err(_err) => {
+                    // pool reached the MAX_POOL_SIZE.
+                    log::debug!("discarded the global builder {:016x}", self.fingerprint);
+                }
             }
         }
     }
 }
 
-static POOL: Lazy<ArrayQueue<GlobalBuilder>> = Lazy::new(|| {
-    let (init, max) = unsafe { (INIT_POOL_SIZE, MAX_POOL_SIZE) };
+/// Global pool builder with its fingerprint.
+struct Pooled {
+    fingerprint: u64,
+    builder: FlatBufferBuilder<'static>,
+}
+
+impl Pooled {
+    /// Allocate the new builder with the new fingerprint.
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            fingerprint: new_fingerprint(),
+            builder: FlatBufferBuilder::with_capacity(capacity),
+        }
+    }
+}
+
+/// Returns the hash of the current time and thread, and the count of
+/// the fingerprints, which tells apart the ones of the same time.
+///
+/// wasm32 has no `Instant`, and only hashes the thread and the count.
+fn new_fingerprint() -> u64 {
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = DefaultHasher::new();
+    #[cfg(not(target_arch = "wasm32"))]
+    Instant::now().hash(&mut hasher);
+    thread::current().id().hash(&mut hasher);
+    COUNT.fetch_add(1, Ordering::Relaxed).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the length of the whole `builder` buffer, which is the
+/// allocated capacity, as it's written from the end.
+#[inline]
+fn buffer_len(builder: &mut FlatBufferBuilder<'_>) -> usize {
+    builder.mut_finished_buffer().0.len()
+}
+
+/// Maximum bar width of [`capacity_histogram_ascii`].
+///
+/// [`capacity_histogram_ascii`]: struct.FlatBufferBuilderLocalPool.html#method.capacity_histogram_ascii
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Human readable power-of-two size, e.g. `64B` or `4KiB`.
+fn size_label(size: usize) -> String {
+    const KIB: usize = 1_024;
+    const MIB: usize = 1_024 * KIB;
+    if size >= MIB && size % MIB == 0 {
+        format!("{}MiB", size / MIB)
+    } else if size >= KIB && size % KIB == 0 {
+        format!("{}KiB", size / KIB)
+    } else {
+        format!("{}B", size)
+    }
+}
+
+/// Pool statistics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of `get` calls served from the pool.
+    pub hits: u64,
+    /// Number of `get` calls which allocated the new builder.
+    pub misses: u64,
+    /// Number of builders returned to the pool.
+    pub returns: u64,
+}
+
+/// Pool statistics counters.
+#[derive(Debug, Default)]
+struct Stats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    returns: AtomicU64,
+}
+
+impl Stats {
+    const fn new() -> Self {
+        Self {
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            returns: AtomicU64::new(0),
+        }
+    }
+    #[inline]
+    fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+    #[inline]
+    fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+    #[inline]
+    fn returned(&self) {
+        self.returns.fetch_add(1, Ordering::Relaxed);
+    }
+    fn get(&self) -> PoolStats {
+        PoolStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            returns: self.returns.load(Ordering::Relaxed),
+        }
+    }
+}
+
+static GLOBAL_STATS: Stats = Stats::new();
+
+thread_local! {
+    /// Global pool statistics of the current thread.
+    static THREAD_STATS: Cell<PoolStats> = const { Cell::new(PoolStats { hits: 0, misses: 0, returns: 0 }) };
+}
+
+/// Update the global pool statistics of the current thread.
+#[inline]
+fn count_thread(update: impl FnOnce(&mut PoolStats)) {
+    // `try_with` fails in the thread local destructors.
+    let _ = THREAD_STATS.try_with(|cell| {
+        let mut stats = cell.get();
+        update(&mut stats);
+        cell.set(stats);
+    });
+}
+
+/// Global pool, which is initialized by the first `get` with the global
+/// pool sizes at the time, and replaced by `replace_global_pool`.
+///
+/// The sizes changed before the first `get` in the same thread, or in
+/// the thread joined or otherwise synchronized with it, are applied.
+/// The sizes changed concurrently with the first `get` may or may not
+/// be applied, and the ones changed after it never are, until the pool
+/// is replaced.  Either way, each size is read once, so the pool never
+/// sees the half-applied configuration other than the initial size above
+/// the maximum one, which is clamped.
+static POOL: ArcSwapOption<ArrayQueue<Pooled>> = ArcSwapOption::const_empty();
+
+/// Whether the global pool sizes are settled by the first `get`, either
+/// for the pool initialized by it, or the one replaced before it.
+static POOL_CONFIGURED: AtomicBool = AtomicBool::new(false);
+
+/// Returns the current global pool, which is initialized on the first
+/// call unless it's already replaced.
+fn global_pool() -> Arc<ArrayQueue<Pooled>> {
+    if !POOL_CONFIGURED.load(Ordering::Relaxed) {
+        POOL_CONFIGURED.store(true, Ordering::SeqCst);
+    }
+    if let Some(pool) = POOL.load_full() {
+        return pool;
+    }
+    // The one of the racing first `get`s wins, and the others drop
+    // theirs.
+    let pool = Arc::new(new_pool());
+    let prev = POOL.compare_and_swap(ptr::null(), Some(Arc::clone(&pool)));
+    match &*prev {
+        Some(prev) => Arc::clone(prev),
+        None => pool,
+    }
+}
+
+/// Panics, or warns in the release build, on the global pool sizes
+/// changed after the global pool is initialized.
+fn check_unconfigured() {
+    const MSG: &str = "pool already initialized — call configuration before first get()";
+    if cfg!(debug_assertions) {
+        assert!(!POOL_CONFIGURED.load(Ordering::SeqCst), "{}", MSG);
+    } else if POOL_CONFIGURED.load(Ordering::SeqCst) {
+        log::warn!("{}", MSG);
+    }
+}
+
+fn set_max_pool_size(size: usize) {
+    MAX_POOL_SIZE.store(size, Ordering::Relaxed);
+    INIT_POOL_SIZE.fetch_min(size, Ordering::Relaxed);
+}
+
+/// Returns the new global pool pre-filled with the current sizes.
+fn new_pool() -> ArrayQueue<Pooled> {
+    let max = MAX_POOL_SIZE.load(Ordering::Relaxed);
+    let init = INIT_POOL_SIZE.load(Ordering::Relaxed).min(max);
+    let capacity = GlobalBuilder::capacity();
     let pool = ArrayQueue::new(max);
     for _ in 0..init {
-        pool.push(GlobalBuilder::new()).unwrap();
+        pool.push(Pooled::with_capacity(capacity)).unwrap();
     }
     pool
-});
+}
 
 impl FlatBufferBuilderPool {
     /// Create a local `FlatBufferBuilder` pool instance.
@@ -288,6 +864,75 @@ impl FlatBufferBuilderPool {
         self
     }
 
+    /// Keep the returned builders only if `pred` returns `true`, and
+    /// discard the others, e.g. the ones of the larger messages than
+    /// expected.
+    ///
+    /// `pred` is called with the builder before it's reset, so it sees
+    /// the data written by the last user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new()
+    ///     .init_pool_size(0)
+    ///     .with_return_predicate(|b| b.unfinished_data().len() <= 1_024)
+    ///     .build();
+    /// let mut b = pool.get();
+    /// let data = b.create_byte_string(&[0; 4_096]);
+    /// b.finish(data, None);
+    /// drop(b);
+    /// assert_eq!(0, pool.stats().returns);
+    /// ```
+    #[inline]
+    pub fn with_return_predicate<F>(mut self, pred: F) -> Self
+    where
+        F: Fn(&FlatBufferBuilder<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.hooks.predicate = Some(Arc::new(pred));
+        self
+    }
+
+    /// Pass the returned builders which overflow the pool to `handler`,
+    /// e.g. to inspect, log or redirect them, instead of dropping them.
+    ///
+    /// `handler` is called with the reset builder.  The builder is of
+    /// the local pool lifetime, so keep its buffer with
+    /// `FlatBufferBuilder::collapse` beyond the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let overflows = Arc::new(AtomicUsize::new(0));
+    /// let pool = FlatBufferBuilderPool::new()
+    ///     .init_pool_size(0)
+    ///     .max_pool_size(1)
+    ///     .with_overflow_handler({
+    ///         let overflows = Arc::clone(&overflows);
+    ///         move |_| {
+    ///             overflows.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     })
+    ///     .build();
+    /// drop(pool.get_array::<3>());
+    /// assert_eq!(2, overflows.load(Ordering::Relaxed));
+    /// ```
+    #[inline]
+    pub fn with_overflow_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(FlatBufferBuilder<'_>) + Send + Sync + 'static,
+    {
+        self.hooks.overflow = Some(Arc::new(handler));
+        self
+    }
+
     /// Build a local `FlatBufferBuilder` pool.
     ///
     /// # Examples
@@ -303,16 +948,21 @@ impl FlatBufferBuilderPool {
     /// ```
     pub fn build<'a>(&self) -> FlatBufferBuilderLocalPool<'a> {
         let inner = Arc::new(ArrayQueue::new(self.max));
+        let stats = Arc::new(Stats::default());
         for _ in 0..self.init {
             let builder = LocalBuilder::new(
                 Arc::downgrade(&inner),
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
+                Arc::clone(&stats),
+                self.hooks.clone(),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity),
             );
             inner.push(builder).unwrap();
         }
         FlatBufferBuilderLocalPool::<'a> {
-            buffer_capacity: self.buffer_capacity,
+            buffer_capacity: AtomicUsize::new(self.buffer_capacity),
             inner,
+            stats,
+            hooks: self.hooks.clone(),
         }
     }
 }
@@ -327,6 +977,7 @@ impl Default for FlatBufferBuilderPool {
             init: LOCAL_INIT_POOL_SIZE,
             max: LOCAL_MAX_POOL_SIZE,
             buffer_capacity: LOCAL_BUFFER_CAPACITY,
+            hooks: Hooks::default(),
         }
     }
 }
@@ -346,10 +997,16 @@ impl Default for FlatBufferBuilderPool {
 /// ```
 pub struct FlatBufferBuilderLocalPool<'a> {
     /// Flatbuffer buffer capacity for the local pool.
-    buffer_capacity: usize,
+    buffer_capacity: AtomicUsize,
 
     /// Local pool.
     inner: Arc<ArrayQueue<LocalBuilder<'a>>>,
+
+    /// Local pool statistics.
+    stats: Arc<Stats>,
+
+    /// Local pool return hooks.
+    hooks: Hooks,
 }
 
 impl<'a> FlatBufferBuilderLocalPool<'a> {
@@ -370,12 +1027,260 @@ impl<'a> FlatBufferBuilderLocalPool<'a> {
     pub fn get(&self) -> LocalBuilder<'a> {
         let pool = &self.inner;
         match pool.pop() {
-            Ok(builder) => builder,
-            Err(_) => LocalBuilder::new(
-                Arc::downgrade(pool),
-                FlatBufferBuilder::new_with_capacity(self.buffer_capacity),
-            ),
+            Ok(builder) => {
+                self.stats.hit();
+                builder
+            }
+            Err(_) => {
+                self.stats.miss();
+                LocalBuilder::new(
+                    Arc::downgrade(pool),
+                    Arc::clone(&self.stats),
+                    self.hooks.clone(),
+                    FlatBufferBuilder::with_capacity(self.buffer_capacity()),
+                )
+            }
+        }
+    }
+
+    /// Get the `FlatBufferBuilder` from the local pool, or allocate
+    /// the new one in case `capacity` is larger than the local pool
+    /// buffer capacity.
+    ///
+    /// The newly allocated builder goes back to the local pool on drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().buffer_capacity(64).build();
+    /// let mut b = pool.get_with_capacity(4_096);
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_with_capacity(&self, capacity: usize) -> LocalBuilder<'a> {
+        if capacity <= self.buffer_capacity() {
+            self.get()
+        } else {
+            self.stats.miss();
+            LocalBuilder::new(
+                Arc::downgrade(&self.inner),
+                Arc::clone(&self.stats),
+                self.hooks.clone(),
+                FlatBufferBuilder::with_capacity(capacity),
+            )
+        }
+    }
+
+    /// Get `N` `FlatBufferBuilder`s from the local pool at once, or
+    /// allocate the rest of them in case the pool runs out.
+    ///
+    /// The pool isn't popped any further after the first miss.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().init_pool_size(2).build();
+    /// let builders = pool.get_array::<4>();
+    /// assert_eq!(2, pool.stats().hits);
+    /// assert_eq!(2, pool.stats().misses);
+    /// for mut b in builders {
+    ///     let name = b.create_string("something fun");
+    ///     b.finish(name, None);
+    /// }
+    /// ```
+    #[inline]
+    pub fn get_array<const N: usize>(&self) -> [LocalBuilder<'a>; N] {
+        let mut empty = false;
+        std::array::from_fn(|_| {
+            if !empty {
+                match self.inner.pop() {
+                    Ok(builder) => {
+                        self.stats.hit();
+                        return builder;
+                    }
+                    Err(_) => empty = true,
+                }
+            }
+            self.stats.miss();
+            LocalBuilder::new(
+                Arc::downgrade(&self.inner),
+                Arc::clone(&self.stats),
+                self.hooks.clone(),
+                FlatBufferBuilder::with_capacity(self.buffer_capacity()),
+            )
+        })
+    }
+
+    /// Returns the buffer capacity of the newly allocated builders.
+    #[inline]
+    pub fn buffer_capacity(&self) -> usize {
+        self.buffer_capacity.load(Ordering::Relaxed)
+    }
+
+    /// Change the buffer capacity of the newly allocated builders to
+    /// `capacity`, and replace the pooled builders with the new ones of
+    /// it, e.g. with the size observed by the [`CapacityAdvisor`].
+    ///
+    /// The builders checked out at the time keep their buffers, and go
+    /// back to the pool as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().buffer_capacity(64).build();
+    /// pool.set_buffer_capacity(4_096);
+    /// assert_eq!(4_096, pool.buffer_capacity());
+    /// let mut b = pool.get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    /// [`capacityadvisor`]: ../../monster/tuning/struct.CapacityAdvisor.html
+    pub fn set_buffer_capacity(&self, capacity: usize) {
+        self.buffer_capacity.store(capacity, Ordering::Relaxed);
+        // Only the builders pooled now, not the ones returned meanwhile.
+        for _ in 0..self.inner.len() {
+            let mut old = match self.inner.pop() {
+                Ok(builder) => builder,
+                Err(_) => break,
+            };
+            old.drain();
+            let builder = LocalBuilder::new(
+                Arc::downgrade(&self.inner),
+                Arc::clone(&self.stats),
+                self.hooks.clone(),
+                FlatBufferBuilder::with_capacity(capacity),
+            );
+            // The pool may be filled up by the returns in the meantime.
+            let _ = self.inner.push(builder);
+        }
+    }
+
+    /// Evict the pooled builders returned more than `age` ago, and
+    /// returns the number of the evicted builders, e.g. in the idle
+    /// windows of the application.
+    ///
+    /// The builders pre-filled by `build` and never returned are kept.
+    /// It's not available on wasm32, which has no `Instant`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new().init_pool_size(0).build();
+    /// drop(pool.get());
+    /// assert_eq!(0, pool.evict_older_than(Duration::from_secs(60)));
+    /// assert_eq!(1, pool.evict_older_than(Duration::ZERO));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn evict_older_than(&self, age: Duration) -> usize {
+        let mut evicted = 0;
+        // Only the builders pooled now, not the ones returned meanwhile.
+        for _ in 0..self.inner.len() {
+            let mut builder = match self.inner.pop() {
+                Ok(builder) => builder,
+                Err(_) => break,
+            };
+            match builder.returned_at {
+                Some(returned_at) if returned_at.elapsed() > age => {
+                    builder.drain();
+                    evicted += 1;
+                }
+                // The pool may be filled up by the returns in the meantime.
+                _ => {
+                    let _ = self.inner.push(builder);
+                }
+            }
+        }
+        evicted
+    }
+
+    /// Returns the ASCII bar chart of the pooled builders' buffer
+    /// capacities in the power-of-two buckets, one line per non-empty
+    /// bucket, for the operators to see how the pool is inflated.
+    ///
+    /// The pooled builders are popped and pushed back, so the
+    /// concurrent `get`s may miss in the meantime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+    ///
+    /// let pool = FlatBufferBuilderPool::new()
+    ///     .init_pool_size(2)
+    ///     .buffer_capacity(100)
+    ///     .build();
+    /// assert_eq!("[64B-128B): ██ (2)", pool.capacity_histogram_ascii());
+    /// ```
+    pub fn capacity_histogram_ascii(&self) -> String {
+        let mut builders = Vec::with_capacity(self.inner.len());
+        while let Ok(builder) = self.inner.pop() {
+            builders.push(builder);
         }
+        // Bucket n is [2^(n-1), 2^n), and 0 is the empty buffer.
+        let mut buckets = BTreeMap::<u32, usize>::new();
+        for builder in &mut builders {
+            let capacity = buffer_len(builder);
+            *buckets
+                .entry(usize::BITS - capacity.leading_zeros())
+                .or_default() += 1;
+        }
+        for builder in builders {
+            // The pool may be filled up by the returns in the meantime.
+            let _ = self.inner.push(builder);
+        }
+
+        let max = buckets.values().copied().max().unwrap_or(0);
+        buckets
+            .into_iter()
+            .map(|(bucket, count)| {
+                let upper = 1usize << bucket;
+                let width = if max <= HISTOGRAM_WIDTH {
+                    count
+                } else {
+                    (count * HISTOGRAM_WIDTH).div_ceil(max)
+                };
+                format!(
+                    "[{}-{}): {} ({})",
+                    size_label(upper >> 1),
+                    size_label(upper),
+                    "█".repeat(width),
+                    count,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the local pool statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v3::{FlatBufferBuilderPool, PoolStats};
+    ///
+    /// let pool = FlatBufferBuilderPool::new().init_pool_size(0).build();
+    /// drop(pool.get());
+    /// drop(pool.get());
+    /// let want = PoolStats {
+    ///     hits: 1,
+    ///     misses: 1,
+    ///     returns: 2,
+    /// };
+    /// assert_eq!(want, pool.stats());
+    /// ```
+    pub fn stats(&self) -> PoolStats {
+        self.stats.get()
     }
 }
 
@@ -393,18 +1298,37 @@ pub struct LocalBuilder<'a> {
     /// Local pool.
     pool: Weak<ArrayQueue<LocalBuilder<'a>>>,
 
+    /// Local pool statistics.
+    stats: Arc<Stats>,
+
+    /// Local pool return hooks.
+    hooks: Hooks,
+
     /// Drained state.
     drained: AtomicBool,
 
+    /// Time of the last return to the local pool.
+    #[cfg(not(target_arch = "wasm32"))]
+    returned_at: Option<Instant>,
+
     /// Actual builder.
     inner: Option<FlatBufferBuilder<'a>>,
 }
 
 impl<'a> LocalBuilder<'a> {
-    fn new(pool: Weak<ArrayQueue<Self>>, builder: FlatBufferBuilder<'a>) -> Self {
+    fn new(
+        pool: Weak<ArrayQueue<Self>>,
+        stats: Arc<Stats>,
+        hooks: Hooks,
+        builder: FlatBufferBuilder<'a>,
+    ) -> Self {
         Self {
             pool,
+            stats,
+            hooks,
             drained: AtomicBool::new(false),
+            #[cfg(not(target_arch = "wasm32"))]
+            returned_at: None,
             inner: Some(builder),
         }
     }
@@ -440,13 +1364,212 @@ impl<'a> Drop for LocalBuilder<'a> {
             if self.is_drained() {
                 return;
             }
-            builder.reset();
             if let Some(pool) = &self.pool.upgrade() {
-                let builder = LocalBuilder::new(self.pool.clone(), builder);
-                if let Err(_err) = pool.push(builder) {
-                    // pool reached the MAX_POOL_SIZE.
+                if let Some(pred) = &self.hooks.predicate {
+                    if !pred(&builder) {
+                        return;
+                    }
+                }
+                builder.reset();
+                #[allow(unused_mut)]
+                let mut builder = LocalBuilder::new(
+                    self.pool.clone(),
+                    Arc::clone(&self.stats),
+                    self.hooks.clone(),
+                    builder,
+                );
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    builder.returned_at = Some(Instant::now());
+                }
+                match pool.push(builder) {
+                    Ok(()) => self.stats.returned(),
+                    Err(mut overflowed) => {
+                        // pool reached the MAX_POOL_SIZE.  Take the
+                        // builder out, not to return it again on drop.
+                        let builder = overflowed.0.inner.take().unwrap();
+                        if let Some(handler) = &self.hooks.overflow {
+                            handler(builder);
+                        }
+                    }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn return_predicate() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(1)
+            .max_pool_size(4)
+            .with_return_predicate({
+                let calls = Arc::clone(&calls);
+                move |b| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    b.unfinished_data().len() <= 1_024
+                }
+            })
+            .build();
+
+        // Kept, including the pre-filled one.
+        let mut b = pool.get();
+        let data = b.create_byte_string(&[0; 64]);
+        b.finish(data, None);
+        drop(b);
+        drop(pool.get_with_capacity(4_096));
+        assert_eq!(2, calls.load(Ordering::Relaxed));
+        assert_eq!(2, pool.stats().returns);
+        assert_eq!(2, pool.inner.len());
+
+        // Discarded.
+        let mut b = pool.get();
+        let data = b.create_byte_string(&[0; 2_048]);
+        b.finish(data, None);
+        drop(b);
+        assert_eq!(3, calls.load(Ordering::Relaxed));
+        assert_eq!(2, pool.stats().returns);
+        assert_eq!(1, pool.inner.len());
+    }
+    #[test]
+    fn return_predicate_all_discarded() {
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(2)
+            .with_return_predicate(|_| false)
+            .build();
+        let builders = pool.get_array::<3>();
+        drop(builders);
+        let want = PoolStats {
+            hits: 2,
+            misses: 1,
+            returns: 0,
+        };
+        assert_eq!(want, pool.stats());
+        assert!(pool.inner.is_empty());
+    }
+    #[test]
+    fn no_return_predicate() {
+        let pool = FlatBufferBuilderPool::new().init_pool_size(0).build();
+        let mut b = pool.get();
+        let data = b.create_byte_string(&[0; 2_048]);
+        b.finish(data, None);
+        drop(b);
+        assert_eq!(1, pool.stats().returns);
+    }
+    #[test]
+    fn overflow_handler() {
+        let (tx, rx) = mpsc::channel();
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(0)
+            .max_pool_size(1)
+            .buffer_capacity(64)
+            .with_overflow_handler(move |b| tx.send(b.collapse()).unwrap())
+            .build();
+        let a = pool.get();
+        let b = pool.get_with_capacity(4_096);
+        drop(a);
+        assert!(rx.try_recv().is_err());
+
+        // Reset, but keeps the buffer.
+        drop(b);
+        let (buf, head) = rx.try_recv().unwrap();
+        assert_eq!(4_096, buf.len());
+        assert_eq!(buf.len(), head);
+        assert!(rx.try_recv().is_err());
+        let want = PoolStats {
+            hits: 0,
+            misses: 2,
+            returns: 1,
+        };
+        assert_eq!(want, pool.stats());
+        assert_eq!(1, pool.inner.len());
+    }
+    #[test]
+    fn overflow_without_handler() {
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(0)
+            .max_pool_size(1)
+            .build();
+        drop(pool.get_array::<4>());
+        assert_eq!(1, pool.stats().returns);
+        assert_eq!(1, pool.inner.len());
+    }
+    #[test]
+    fn capacity_histogram_ascii() {
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(0)
+            .max_pool_size(8)
+            .build();
+        let capacities = [64, 100, 127, 100, 128, 255, 4_096, 8_191];
+        let builders: Vec<_> = capacities
+            .iter()
+            .map(|&capacity| pool.get_with_capacity(capacity))
+            .collect();
+        assert_eq!("", pool.capacity_histogram_ascii());
+        drop(builders);
+
+        let got = pool.capacity_histogram_ascii();
+        let want = [
+            "[64B-128B): ████ (4)",
+            "[128B-256B): ██ (2)",
+            "[4KiB-8KiB): ██ (2)",
+        ];
+        assert_eq!(want.join("\n"), got);
+        for line in &want {
+            assert!(got.contains(line), "{}", line);
+        }
+        // Pushed back.
+        assert_eq!(8, pool.inner.len());
+        assert_eq!(got, pool.capacity_histogram_ascii());
+
+        // Scaled to the width.
+        let pool = FlatBufferBuilderPool::new()
+            .init_pool_size(0)
+            .max_pool_size(HISTOGRAM_WIDTH * 2)
+            .buffer_capacity(1)
+            .build();
+        let mut builders = vec![pool.get()];
+        for _ in 1..HISTOGRAM_WIDTH * 2 {
+            builders.push(pool.get_with_capacity(1_024));
+        }
+        drop(builders);
+        let want = format!(
+            "[1B-2B): █ ({})\n[1KiB-2KiB): {} ({})",
+            1,
+            "█".repeat(HISTOGRAM_WIDTH),
+            HISTOGRAM_WIDTH * 2 - 1,
+        );
+        assert_eq!(want, pool.capacity_histogram_ascii());
+    }
+    #[test]
+    fn evict_older_than() {
+        let ttl = Duration::from_millis(10);
+        let pool = FlatBufferBuilderPool::new().init_pool_size(1).build();
+        let [a, b, c] = pool.get_array::<3>();
+        drop(a);
+        drop(b);
+        thread::sleep(ttl * 2);
+        drop(c);
+
+        // The pre-filled one was taken by `a`.
+        assert_eq!(2, pool.evict_older_than(ttl));
+        assert_eq!(1, pool.inner.len());
+        assert_eq!(0, pool.evict_older_than(ttl));
+        assert_eq!(1, pool.evict_older_than(Duration::ZERO));
+        assert!(pool.inner.is_empty());
+
+        // Never returned.
+        let pool = FlatBufferBuilderPool::new().init_pool_size(2).build();
+        thread::sleep(ttl * 2);
+        assert_eq!(0, pool.evict_older_than(ttl));
+        assert_eq!(2, pool.inner.len());
+    }
+}
@@ -0,0 +1,324 @@
+//! Two-tier, thread local and `crossbeam_queue::ArrayQueue` based
+//! flatbuffer builder pool
+//!
+//! Each thread keeps up to the local maximum builders of its own, which
+//! are taken and returned without touching the shared queue.  The global
+//! `ArrayQueue` of [`v3`] backs the thread local tier, which refills from
+//! it and overflows into it.  The builders left in the thread local tier
+//! go back to the global one as the thread exits.
+//!
+//! There is no local pool, as the thread local tier only makes sense for
+//! the global one.
+//!
+//! [`v3`]: ../v3/index.html
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::OnceLock,
+};
+
+use crossbeam_queue::ArrayQueue;
+use flatbuffers::FlatBufferBuilder;
+
+/// Global `FlatBufferBuilder` pool.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+///
+/// // Get the builder from the global pool.
+/// let mut b = FlatBufferBuilderPool::get();
+/// let name = b.create_string("something fun");
+/// b.finish(name, None);
+/// ```
+pub struct FlatBufferBuilderPool;
+
+static INIT_POOL_SIZE: AtomicUsize = AtomicUsize::new(32);
+static MAX_POOL_SIZE: AtomicUsize = AtomicUsize::new(1_024);
+static MAX_LOCAL_POOL_SIZE: AtomicUsize = AtomicUsize::new(4);
+static BUFFER_CAPACITY: AtomicUsize = AtomicUsize::new(64);
+
+impl FlatBufferBuilderPool {
+    /// Get the `FlatBufferBuilder` from the thread local pool, or from
+    /// the global pool in case the thread local one is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// // Get the builder from the global pool.
+    /// let mut b = FlatBufferBuilderPool::get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get() -> GlobalBuilder {
+        // `try_with` fails in the thread local destructors.
+        let builder = LOCAL
+            .try_with(|local| local.borrow_mut().0.pop())
+            .ok()
+            .flatten()
+            .or_else(|| pool().pop().ok());
+        match builder {
+            Some(builder) => GlobalBuilder(Some(builder)),
+            None => GlobalBuilder::new(),
+        }
+    }
+
+    /// Get the `FlatBufferBuilder` from the pool, or allocate the new
+    /// one in case `capacity` is larger than the global buffer capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// let mut b = FlatBufferBuilderPool::get_with_capacity(4_096);
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn get_with_capacity(capacity: usize) -> GlobalBuilder {
+        if capacity <= GlobalBuilder::capacity() {
+            Self::get()
+        } else {
+            GlobalBuilder(Some(FlatBufferBuilder::with_capacity(capacity)))
+        }
+    }
+
+    /// Change the initial global pool size.
+    ///
+    /// It should be called before calling the first `get`
+    /// function otherwise the change won't applicable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// // Get the builder from the global pool.
+    /// FlatBufferBuilderPool::init_global_pool_size(0);
+    /// let mut b = FlatBufferBuilderPool::get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn init_global_pool_size(size: usize) {
+        INIT_POOL_SIZE.store(size, Ordering::Relaxed);
+        MAX_POOL_SIZE.fetch_max(size, Ordering::Relaxed);
+    }
+
+    /// Change the maximum global pool size.
+    ///
+    /// It should be called before calling the first `get`
+    /// function otherwise the change won't applicable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// // Get the builder from the global pool.
+    /// FlatBufferBuilderPool::max_global_pool_size(4);
+    /// let mut b = FlatBufferBuilderPool::get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn max_global_pool_size(size: usize) {
+        MAX_POOL_SIZE.store(size, Ordering::Relaxed);
+        INIT_POOL_SIZE.fetch_min(size, Ordering::Relaxed);
+    }
+
+    /// Change the maximum thread local pool size of each thread.
+    ///
+    /// Unlike the global pool sizes, the change is applicable at any
+    /// time.  The thread local pools over the new size shrink as the
+    /// builders are taken from them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// // Return all the builders to the global pool.
+    /// FlatBufferBuilderPool::max_local_pool_size(0);
+    /// let mut b = FlatBufferBuilderPool::get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn max_local_pool_size(size: usize) {
+        MAX_LOCAL_POOL_SIZE.store(size, Ordering::Relaxed);
+    }
+
+    /// Change the initial `FlatBufferBuilder` buffer size.
+    ///
+    /// The value only applicable for the newly allocated
+    /// `FlatBufferBuilder` instances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::pool::v4::FlatBufferBuilderPool;
+    ///
+    /// // Get the builder from the global pool.
+    /// FlatBufferBuilderPool::global_buffer_capacity(64);
+    /// let mut b = FlatBufferBuilderPool::get();
+    /// let name = b.create_string("something fun");
+    /// b.finish(name, None);
+    /// ```
+    #[inline]
+    pub fn global_buffer_capacity(capacity: usize) {
+        BUFFER_CAPACITY.store(capacity, Ordering::Relaxed);
+    }
+}
+
+/// Thread local builders, which go back to the global pool as the
+/// thread exits.
+struct LocalBuilders(Vec<FlatBufferBuilder<'static>>);
+
+impl Drop for LocalBuilders {
+    fn drop(&mut self) {
+        for builder in self.0.drain(..) {
+            if pool().push(builder).is_err() {
+                // pool reached the MAX_POOL_SIZE.
+                break;
+            }
+        }
+    }
+}
+
+thread_local! {
+    static LOCAL: RefCell<LocalBuilders> = const { RefCell::new(LocalBuilders(Vec::new())) };
+}
+
+static POOL: OnceLock<ArrayQueue<FlatBufferBuilder<'static>>> = OnceLock::new();
+
+/// Returns the global pool, which is initialized by the first call with
+/// the global pool sizes at the time, the same as [`v3`].
+///
+/// [`v3`]: ../v3/index.html
+#[inline]
+fn pool() -> &'static ArrayQueue<FlatBufferBuilder<'static>> {
+    POOL.get_or_init(|| {
+        let max = MAX_POOL_SIZE.load(Ordering::Relaxed);
+        let init = INIT_POOL_SIZE.load(Ordering::Relaxed).min(max);
+        let pool = ArrayQueue::new(max);
+        for _ in 0..init {
+            pool.push(FlatBufferBuilder::with_capacity(GlobalBuilder::capacity()))
+                .unwrap();
+        }
+        pool
+    })
+}
+
+/// `GlobalBuilder` encapsulates the `FlatBufferBuilder` instance
+/// for the global pool.
+pub struct GlobalBuilder(Option<FlatBufferBuilder<'static>>);
+
+impl GlobalBuilder {
+    #[inline]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn capacity() -> usize {
+        BUFFER_CAPACITY.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for GlobalBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self(Some(FlatBufferBuilder::with_capacity(Self::capacity())))
+    }
+}
+
+impl Deref for GlobalBuilder {
+    type Target = FlatBufferBuilder<'static>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for GlobalBuilder {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut().unwrap()
+    }
+}
+
+impl Drop for GlobalBuilder {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(mut builder) = self.0.take() {
+            builder.reset();
+            let max = MAX_LOCAL_POOL_SIZE.load(Ordering::Relaxed);
+            let mut builder = Some(builder);
+            // The thread local pool is gone in the thread local
+            // destructors, hence the global pool.
+            let _ = LOCAL.try_with(|local| {
+                let local = &mut local.borrow_mut().0;
+                if local.len() < max {
+                    local.extend(builder.take());
+                }
+            });
+            if let Some(builder) = builder {
+                if pool().push(builder).is_err() {
+                    // pool reached the MAX_POOL_SIZE.
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn local_len() -> usize {
+        LOCAL.with(|local| local.borrow().0.len())
+    }
+    #[test]
+    fn two_tiers() {
+        // The other tests in this binary don't touch the v4 pool, but
+        // the global pool is still checked in the single thread only.
+        let max = MAX_LOCAL_POOL_SIZE.load(Ordering::Relaxed);
+        let global = pool().len();
+
+        // Refill from the global pool.
+        let builders: Vec<_> = (0..max + 2).map(|_| FlatBufferBuilderPool::get()).collect();
+        assert_eq!(0, local_len());
+        assert_eq!(global - max - 2, pool().len());
+
+        // Return to the thread local pool first, then overflow.
+        drop(builders);
+        assert_eq!(max, local_len());
+        assert_eq!(global - max, pool().len());
+
+        // Served from the thread local pool.
+        let mut b = FlatBufferBuilderPool::get();
+        let name = b.create_string("orc");
+        b.finish(name, None);
+        assert_eq!(max - 1, local_len());
+        drop(b);
+        assert_eq!(max, local_len());
+        assert_eq!(global - max, pool().len());
+
+        // The thread local builders go back to the global pool at exit.
+        thread::spawn(|| {
+            drop(FlatBufferBuilderPool::get());
+            assert_eq!(1, local_len());
+        })
+        .join()
+        .unwrap();
+        assert_eq!(global - max, pool().len());
+    }
+}
@@ -0,0 +1,122 @@
+//! `Rc<RefCell<Vec>>` based flatbuffer builder pool for wasm32
+//!
+//! The wasm32 module runs on the one thread of the page, so
+//! [`WasmPool`] keeps the builders in the plain `Vec` without the lock
+//! or the atomics of the other pools.  It's neither `Send` nor `Sync`,
+//! and is exported to JavaScript with `wasm-bindgen`, which keeps the
+//! pool across the calls:
+//!
+//! ```js
+//! const pool = new WasmPool(16, 1024);
+//! const buf = pool.build_monster_from_json('{"name":"orc"}');
+//! ```
+//!
+//! [`wasmpool`]: struct.WasmPool.html
+use std::{
+    cell::RefCell,
+    ops::{Deref, DerefMut},
+    rc::{Rc, Weak},
+};
+
+use flatbuffers::FlatBufferBuilder;
+use wasm_bindgen::prelude::*;
+
+use crate::monster::MonsterData;
+
+/// Single-threaded `FlatBufferBuilder` pool.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WasmPool {
+    inner: Rc<RefCell<Vec<FlatBufferBuilder<'static>>>>,
+    max_pool_size: usize,
+    buffer_capacity: usize,
+}
+
+#[wasm_bindgen]
+impl WasmPool {
+    /// Create the empty pool, which keeps up to `max_pool_size` builders
+    /// of the `buffer_capacity` initial capacity.
+    #[wasm_bindgen(constructor)]
+    pub fn new(max_pool_size: usize, buffer_capacity: usize) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Vec::with_capacity(max_pool_size))),
+            max_pool_size,
+            buffer_capacity,
+        }
+    }
+    /// Returns the number of the builders in the pool.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len()
+    }
+    /// Returns `true` if the pool has no builders.
+    pub fn is_empty(&self) -> bool {
+        self.inner.borrow().is_empty()
+    }
+    /// Build the `Monster` buffer from the `MonsterData` JSON with the
+    /// pooled builder.
+    pub fn build_monster_from_json(&self, json: &str) -> Result<Vec<u8>, JsValue> {
+        let data: MonsterData = serde_json::from_str(json)
+            .map_err(|err| JsError::new(&format!("invalid monster JSON: {}", err)))?;
+        let mut b = self.get();
+        let monster = data.build(&mut b);
+        b.finish(monster, None);
+        Ok(b.finished_data().to_vec())
+    }
+}
+
+impl WasmPool {
+    /// Get the `FlatBufferBuilder` from the pool, or allocate the new one
+    /// in case the pool is empty.
+    pub fn get(&self) -> WasmBuilder {
+        let builder = self
+            .inner
+            .borrow_mut()
+            .pop()
+            .unwrap_or_else(|| FlatBufferBuilder::with_capacity(self.buffer_capacity));
+        WasmBuilder {
+            pool: Rc::downgrade(&self.inner),
+            max_pool_size: self.max_pool_size,
+            inner: Some(builder),
+        }
+    }
+}
+
+/// `WasmBuilder` encapsulates the `FlatBufferBuilder` instance
+/// for the [`WasmPool`].
+///
+/// [`wasmpool`]: struct.WasmPool.html
+pub struct WasmBuilder {
+    pool: Weak<RefCell<Vec<FlatBufferBuilder<'static>>>>,
+    max_pool_size: usize,
+    inner: Option<FlatBufferBuilder<'static>>,
+}
+
+impl Deref for WasmBuilder {
+    type Target = FlatBufferBuilder<'static>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for WasmBuilder {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().unwrap()
+    }
+}
+
+impl Drop for WasmBuilder {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(mut builder) = self.inner.take() {
+            if let Some(pool) = self.pool.upgrade() {
+                let mut pool = pool.borrow_mut();
+                if pool.len() < self.max_pool_size {
+                    builder.reset();
+                    pool.push(builder);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Protobuf equivalent of the `Monster`, for the interop benchmarks.
+//!
+//! The messages are generated from `schema/monster.proto` by
+//! `prost-build`, and are checked in as `monster_prost_generated.rs`.
+//!
+//! The conversions from and to [`MonsterData`] are lossless for all the
+//! values representable in both:
+//!
+//! - `mana`, `hp` and the weapon `damage` are `int32` in protobuf, and
+//!   saturate to `i16` back.
+//! - `color` keeps the unknown values, which saturate to `i8` back.
+//! - The `Equipment` union is the `equipped` oneof with the copy of the
+//!   equipped weapon, as protobuf has no references.  Back, it's the
+//!   index of the first weapon equal to it, which is the same monster
+//!   under the [`MonsterData`] equality, or `None` without such weapon.
+//!
+//! # Examples
+//!
+//! ```
+//! use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+//! use flatbuf_tutorial::proto;
+//! use prost::Message;
+//!
+//! let data = MonsterData {
+//!     name: String::from("orc"),
+//!     weapons: vec![WeaponData {
+//!         name: String::from("axe"),
+//!         damage: 5,
+//!     }],
+//!     equipped: Some(0),
+//!     ..Default::default()
+//! };
+//! let buf = proto::Monster::from(data.clone()).encode_to_vec();
+//! let orc = proto::Monster::decode(&buf[..]).unwrap();
+//! assert_eq!(Some("axe"), orc.equipped_weapon().map(|axe| axe.name.as_str()));
+//! assert_eq!(data, MonsterData::from(orc));
+//! ```
+//! [`monsterdata`]: ../monster/struct.MonsterData.html
+use crate::model::my_game::sample;
+use crate::monster::{MonsterData, WeaponData};
+
+include!("monster_prost_generated.rs");
+
+impl Monster {
+    /// Returns the equipped weapon.
+    pub fn equipped_weapon(&self) -> Option<&Weapon> {
+        match &self.equipped {
+            Some(monster::Equipped::Weapon(weapon)) => Some(weapon),
+            None => None,
+        }
+    }
+}
+
+impl From<MonsterData> for Monster {
+    fn from(data: MonsterData) -> Self {
+        let equipped = data
+            .equipped
+            .and_then(|index| data.weapons.get(index))
+            .map(|weapon| monster::Equipped::Weapon(Weapon::from(weapon.clone())));
+        Self {
+            pos: data.pos.as_ref().map(Vec3::from),
+            mana: i32::from(data.mana),
+            hp: i32::from(data.hp),
+            name: data.name,
+            inventory: data.inventory,
+            color: i32::from(data.color.0),
+            weapons: data.weapons.into_iter().map(Weapon::from).collect(),
+            path: data.path.iter().map(Vec3::from).collect(),
+            equipped,
+        }
+    }
+}
+
+impl From<Monster> for MonsterData {
+    fn from(monster: Monster) -> Self {
+        let weapons: Vec<_> = monster.weapons.into_iter().map(WeaponData::from).collect();
+        let equipped = match monster.equipped {
+            Some(monster::Equipped::Weapon(equipped)) => {
+                let equipped = WeaponData::from(equipped);
+                weapons.iter().position(|weapon| *weapon == equipped)
+            }
+            None => None,
+        };
+        Self {
+            name: monster.name,
+            pos: monster.pos.as_ref().map(sample::Vec3::from),
+            mana: saturate(monster.mana),
+            hp: saturate(monster.hp),
+            inventory: monster.inventory,
+            color: sample::Color(saturate_i8(monster.color)),
+            weapons,
+            equipped,
+            path: monster.path.iter().map(sample::Vec3::from).collect(),
+        }
+    }
+}
+
+impl From<WeaponData> for Weapon {
+    fn from(weapon: WeaponData) -> Self {
+        Self {
+            name: weapon.name,
+            damage: i32::from(weapon.damage),
+        }
+    }
+}
+
+impl From<Weapon> for WeaponData {
+    fn from(weapon: Weapon) -> Self {
+        Self {
+            name: weapon.name,
+            damage: saturate(weapon.damage),
+        }
+    }
+}
+
+impl From<&sample::Vec3> for Vec3 {
+    fn from(v: &sample::Vec3) -> Self {
+        Self {
+            x: v.x(),
+            y: v.y(),
+            z: v.z(),
+        }
+    }
+}
+
+impl From<&Vec3> for sample::Vec3 {
+    fn from(v: &Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+/// Narrow `v` to `i16`, saturating at its bounds.
+fn saturate(v: i32) -> i16 {
+    v.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+}
+
+/// Narrow `v` to `i8`, saturating at its bounds.
+fn saturate_i8(v: i32) -> i8 {
+    v.clamp(i8::MIN.into(), i8::MAX.into()) as i8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    fn weapon(name: &str, damage: i16) -> WeaponData {
+        WeaponData {
+            name: name.to_string(),
+            damage,
+        }
+    }
+    /// Round-trip `data` through the encoded protobuf message.
+    fn round_trip(data: &MonsterData) -> MonsterData {
+        let buf = Monster::from(data.clone()).encode_to_vec();
+        MonsterData::from(Monster::decode(&buf[..]).unwrap())
+    }
+    #[test]
+    fn round_trip_monster_data() {
+        struct Test {
+            name: &'static str,
+            data: MonsterData,
+        }
+        let tests = [
+            Test {
+                name: "default",
+                data: MonsterData::default(),
+            },
+            Test {
+                name: "all fields",
+                data: MonsterData {
+                    name: String::from("orc"),
+                    pos: Some(sample::Vec3::new(1.0, -2.0, 3.5)),
+                    mana: i16::MIN,
+                    hp: i16::MAX,
+                    inventory: vec![0, 1, 255],
+                    color: sample::Color::Green,
+                    weapons: vec![weapon("sword", 3), weapon("axe", -5)],
+                    equipped: Some(1),
+                    path: vec![sample::Vec3::new(0.5, 0.0, -0.5); 3],
+                },
+            },
+            Test {
+                name: "unknown color",
+                data: MonsterData {
+                    color: sample::Color(-7),
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "duplicate equipped weapon",
+                data: MonsterData {
+                    weapons: vec![weapon("axe", 5), weapon("axe", 5)],
+                    equipped: Some(1),
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "out of range equipped",
+                data: MonsterData {
+                    weapons: vec![weapon("axe", 5)],
+                    equipped: Some(1),
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "zero values",
+                data: MonsterData {
+                    pos: Some(sample::Vec3::new(0.0, 0.0, 0.0)),
+                    mana: 0,
+                    hp: 0,
+                    color: sample::Color::Red,
+                    weapons: vec![weapon("", 0)],
+                    equipped: Some(0),
+                    ..Default::default()
+                },
+            },
+        ];
+        for t in &tests {
+            assert_eq!(t.data, round_trip(&t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    #[cfg(feature = "test-util")]
+    fn round_trip_generated() {
+        use crate::monster::testing::{MonsterGenerator, SizeClass};
+
+        for &class in &[SizeClass::Small, SizeClass::Medium, SizeClass::Large] {
+            let gen = MonsterGenerator::new(7).size_class(class).optional(0.5);
+            for data in gen.take(20) {
+                assert_eq!(data, round_trip(&data), "{:?}", class);
+            }
+        }
+    }
+    #[test]
+    fn equipped_weapon() {
+        let data = MonsterData {
+            weapons: vec![weapon("sword", 3), weapon("axe", 5)],
+            equipped: Some(1),
+            ..Default::default()
+        };
+        let orc = Monster::from(data);
+        assert_eq!(Some(&Weapon::from(weapon("axe", 5))), orc.equipped_weapon());
+        let orc = Monster {
+            equipped: None,
+            ..orc
+        };
+        assert_eq!(None, orc.equipped_weapon());
+        assert_eq!(None, MonsterData::from(orc).equipped);
+    }
+    #[test]
+    fn saturate_out_of_range() {
+        let orc = Monster {
+            mana: i32::MAX,
+            hp: i32::MIN,
+            color: 1_000,
+            weapons: vec![Weapon {
+                name: String::from("axe"),
+                damage: 70_000,
+            }],
+            ..Default::default()
+        };
+        let orc = MonsterData::from(orc);
+        assert_eq!(i16::MAX, orc.mana);
+        assert_eq!(i16::MIN, orc.hp);
+        assert_eq!(sample::Color(i8::MAX), orc.color);
+        assert_eq!(i16::MAX, orc.weapons[0].damage);
+    }
+}
@@ -0,0 +1,821 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Runtime reflection over the `Monster` buffers with the embedded binary
+//! schema, for the generic tooling, e.g. the field listing of the debug
+//! UI and the field path query like `weapons[1].damage`.
+//!
+//! `schema/monster.bfbs` is compiled from `schema/monster.fbs` and checked
+//! in.  Regenerate it with the schema change:
+//!
+//! ```sh
+//! $ flatc --binary --schema -o schema schema/monster.fbs
+//! ```
+//!
+//! [`Schema::load`] checks the embedded schema against the generated code,
+//! as the reflection reads the buffers verified by the generated verifier.
+//!
+//! [`schema::load`]: struct.Schema.html#method.load
+use std::sync::LazyLock;
+
+use flatbuffers::{Follow, ForwardsUOffset, Table, VOffsetT, SIZE_UOFFSET};
+use flatbuffers_reflection::reflection::{self, BaseType, Enum, Field, Object};
+
+use crate::error::ReflectError;
+use crate::model::my_game::sample;
+use crate::view::MonsterView;
+
+static BFBS: &[u8] = include_bytes!("../schema/monster.bfbs");
+
+static SCHEMA: LazyLock<Result<Schema, ReflectError>> = LazyLock::new(|| {
+    let schema = reflection::root_as_schema(BFBS).map_err(ReflectError::InvalidSchema)?;
+    let schema = Schema(schema);
+    schema.check()?;
+    Ok(schema)
+});
+
+/// Field name, vtable offset, or the struct offset, and type.
+type GeneratedField = (&'static str, VOffsetT, &'static str);
+
+/// Union member name, value and table.
+type GeneratedMember = (&'static str, i64, Option<&'static str>);
+
+/// Non-deprecated fields of the generated tables, with their vtable
+/// offsets and types.
+const GENERATED: &[(&str, &[GeneratedField])] = &[
+    (
+        "MyGame.Sample.Monster",
+        &[
+            ("pos", sample::Monster::VT_POS, "Vec3"),
+            ("mana", sample::Monster::VT_MANA, "short"),
+            ("hp", sample::Monster::VT_HP, "short"),
+            ("name", sample::Monster::VT_NAME, "string"),
+            ("inventory", sample::Monster::VT_INVENTORY, "[ubyte]"),
+            ("color", sample::Monster::VT_COLOR, "Color"),
+            ("weapons", sample::Monster::VT_WEAPONS, "[Weapon]"),
+            ("equipped_type", sample::Monster::VT_EQUIPPED_TYPE, "utype"),
+            ("equipped", sample::Monster::VT_EQUIPPED, "Equipment"),
+            ("path", sample::Monster::VT_PATH, "[Vec3]"),
+        ],
+    ),
+    (
+        "MyGame.Sample.Weapon",
+        &[
+            ("name", sample::Weapon::VT_NAME, "string"),
+            ("damage", sample::Weapon::VT_DAMAGE, "short"),
+        ],
+    ),
+    (
+        "MyGame.Sample.MonsterList",
+        &[
+            ("monsters", sample::MonsterList::VT_MONSTERS, "[Monster]"),
+            ("chunk_index", sample::MonsterList::VT_CHUNK_INDEX, "uint"),
+            ("total_chunks", sample::MonsterList::VT_TOTAL_CHUNKS, "uint"),
+        ],
+    ),
+    (
+        "MyGame.Sample.Vec3",
+        &[("x", 0, "float"), ("y", 4, "float"), ("z", 8, "float")],
+    ),
+];
+
+/// Members of the generated unions, with their tables.
+const GENERATED_UNIONS: &[(&str, &[GeneratedMember])] = &[(
+    "MyGame.Sample.Equipment",
+    &[
+        ("NONE", 0, None),
+        ("Weapon", 1, Some("MyGame.Sample.Weapon")),
+    ],
+)];
+
+/// Embedded `Monster` schema.
+#[derive(Debug, Clone, Copy)]
+pub struct Schema(reflection::Schema<'static>);
+
+impl Schema {
+    /// Returns the embedded schema, which is verified and checked against
+    /// the generated code once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::reflection::Schema;
+    ///
+    /// let schema = Schema::load().unwrap();
+    /// assert_eq!("MyGame.Sample.Monster", schema.root_table());
+    /// ```
+    pub fn load() -> Result<&'static Self, ReflectError> {
+        SCHEMA.as_ref().map_err(Clone::clone)
+    }
+    /// Returns the embedded `.bfbs` bytes.
+    pub fn bytes() -> &'static [u8] {
+        BFBS
+    }
+    pub fn root_table(&self) -> &'static str {
+        self.root().name()
+    }
+    /// Returns the paths of the fields reachable from the root table, in
+    /// the field id order and depth-first, with the vector elements as
+    /// `[]`.  The deprecated fields are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::reflection::Schema;
+    ///
+    /// let fields = Schema::load().unwrap().list_fields();
+    /// let damage = fields
+    ///     .iter()
+    ///     .find(|field| field.path == "weapons[].damage")
+    ///     .unwrap();
+    /// assert_eq!("short", damage.ty);
+    /// ```
+    pub fn list_fields(&self) -> Vec<FieldInfo> {
+        let mut fields = Vec::new();
+        self.list_object_fields(self.root(), "", &mut vec![self.root().name()], &mut fields);
+        fields
+    }
+    fn list_object_fields(
+        &self,
+        object: Object<'static>,
+        prefix: &str,
+        seen: &mut Vec<&'static str>,
+        out: &mut Vec<FieldInfo>,
+    ) {
+        let mut fields: Vec<_> = object.fields().iter().filter(|f| !f.deprecated()).collect();
+        fields.sort_by_key(|f| f.id());
+        for field in fields {
+            let path = format!("{}{}", prefix, field.name());
+            let ty = field.type_();
+            out.push(FieldInfo {
+                path: path.clone(),
+                ty: self.type_name(ty),
+            });
+            let (nested, path) = match ty.base_type() {
+                BaseType::Obj => (vec![self.object(ty.index())], path),
+                BaseType::Vector if ty.element() == BaseType::Obj => {
+                    (vec![self.object(ty.index())], path + "[]")
+                }
+                BaseType::Union => (self.union_members(self.enum_(ty.index())), path),
+                _ => continue,
+            };
+            for object in nested {
+                // Stop at the recursive table.
+                if seen.contains(&object.name()) {
+                    continue;
+                }
+                seen.push(object.name());
+                self.list_object_fields(object, &format!("{}.", path), seen, out);
+                seen.pop();
+            }
+        }
+    }
+    fn root(&self) -> Object<'static> {
+        // `root_table` is checked in `check`.
+        self.0.root_table().unwrap()
+    }
+    fn object(&self, index: i32) -> Object<'static> {
+        self.0.objects().get(index as usize)
+    }
+    fn enum_(&self, index: i32) -> Enum<'static> {
+        self.0.enums().get(index as usize)
+    }
+    fn union_members(&self, union: Enum<'static>) -> Vec<Object<'static>> {
+        union
+            .values()
+            .iter()
+            .filter_map(|value| value.union_type())
+            .filter(|ty| ty.base_type() == BaseType::Obj)
+            .map(|ty| self.object(ty.index()))
+            .collect()
+    }
+    /// Returns the type in the schema syntax, e.g. `[Weapon]`.
+    fn type_name(&self, ty: reflection::Type<'static>) -> String {
+        let base = |base: BaseType| match base {
+            BaseType::Obj => short_name(self.object(ty.index()).name()).to_string(),
+            base if ty.index() >= 0 && base != BaseType::UType && base.0 <= BaseType::Double.0 => {
+                short_name(self.enum_(ty.index()).name()).to_string()
+            }
+            base => scalar_name(base).to_string(),
+        };
+        match ty.base_type() {
+            BaseType::Vector => format!("[{}]", base(ty.element())),
+            BaseType::Union => short_name(self.enum_(ty.index()).name()).to_string(),
+            base_type => base(base_type),
+        }
+    }
+    /// Check the schema against the generated code, so that the reads
+    /// through the schema stay within the verified buffer.
+    fn check(&self) -> Result<(), ReflectError> {
+        let mismatch = |what: String| Err(ReflectError::SchemaMismatch(what));
+        let objects = self.0.objects();
+        let enums = self.0.enums();
+        let in_range = |index: i32, len: usize| index >= 0 && (index as usize) < len;
+        for object in objects {
+            for field in object.fields() {
+                let ty = field.type_();
+                let index_len = match (ty.base_type(), ty.element()) {
+                    (BaseType::Obj, _) | (BaseType::Vector, BaseType::Obj) => objects.len(),
+                    (BaseType::Union, _) | (BaseType::UType, _) => enums.len(),
+                    _ if ty.index() >= 0 => enums.len(),
+                    _ => continue,
+                };
+                if !in_range(ty.index(), index_len) {
+                    return mismatch(format!("{}.{}", object.name(), field.name()));
+                }
+            }
+        }
+        for union in enums.iter().filter(|e| e.is_union()) {
+            for value in union.values() {
+                if let Some(ty) = value.union_type() {
+                    if ty.base_type() == BaseType::Obj && !in_range(ty.index(), objects.len()) {
+                        return mismatch(format!("{}.{}", union.name(), value.name()));
+                    }
+                }
+            }
+        }
+        match self.0.root_table() {
+            Some(root) if root.name() == "MyGame.Sample.Monster" => {}
+            _ => return mismatch(String::from("root_type")),
+        }
+        for &(name, want) in GENERATED {
+            let object = match objects.iter().find(|object| object.name() == name) {
+                Some(object) => object,
+                None => return mismatch(name.to_string()),
+            };
+            let fields: Vec<_> = object.fields().iter().filter(|f| !f.deprecated()).collect();
+            if fields.len() != want.len() {
+                return mismatch(name.to_string());
+            }
+            for &(field_name, offset, ty) in want {
+                let ok = fields.iter().any(|field| {
+                    field.name() == field_name
+                        && field.offset() == offset
+                        && self.type_name(field.type_()) == ty
+                });
+                if !ok {
+                    return mismatch(format!("{}.{}", name, field_name));
+                }
+            }
+            if object.is_struct()
+                && object.bytesize() as usize != std::mem::size_of::<sample::Vec3>()
+            {
+                return mismatch(name.to_string());
+            }
+        }
+        for &(name, want) in GENERATED_UNIONS {
+            let union = match enums.iter().find(|e| e.name() == name && e.is_union()) {
+                Some(union) => union,
+                None => return mismatch(name.to_string()),
+            };
+            let values: Vec<_> = union
+                .values()
+                .iter()
+                .map(|value| {
+                    let member = value
+                        .union_type()
+                        .filter(|ty| ty.base_type() == BaseType::Obj)
+                        .map(|ty| self.object(ty.index()).name());
+                    (value.name(), value.value(), member)
+                })
+                .collect();
+            if values != want {
+                return mismatch(name.to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Field path and type returned by [`Schema::list_fields`].
+///
+/// [`schema::list_fields`]: struct.Schema.html#method.list_fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Path for [`MonsterView::field_by_path`], with `[]` in place of the
+    /// vector index.
+    ///
+    /// [`monsterview::field_by_path`]: ../view/struct.MonsterView.html#method.field_by_path
+    pub path: String,
+    /// Type in the schema syntax, e.g. `short`, `[Weapon]`.
+    pub ty: String,
+}
+
+/// Field value returned by [`MonsterView::field_by_path`].
+///
+/// The enum values are returned as `Int`, and the absent fields without
+/// the default value, e.g. `string` and tables, are `None`.
+///
+/// [`monsterview::field_by_path`]: ../view/struct.MonsterView.html#method.field_by_path
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue<'a> {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(&'a str),
+    Vector(Vec<FieldValue<'a>>),
+    Table(TableValue<'a>),
+    None,
+}
+
+/// Table or struct in the [`FieldValue`].
+///
+/// [`fieldvalue`]: enum.FieldValue.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TableValue<'a> {
+    object: Object<'static>,
+    buf: &'a [u8],
+    loc: usize,
+}
+
+impl TableValue<'_> {
+    /// Returns the fully qualified name, e.g. `MyGame.Sample.Weapon`.
+    pub fn object_name(&self) -> &'static str {
+        self.object.name()
+    }
+}
+
+impl<'a> MonsterView<'a> {
+    /// Returns the field at `path`, which is the `.` separated field names
+    /// with the optional `[index]` on the vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::reflection::FieldValue;
+    /// use flatbuf_tutorial::{Monster, MonsterView, ReflectError};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder()
+    ///     .name("orc")
+    ///     .weapon("sword", 3)
+    ///     .weapon("axe", 5)
+    ///     .build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// assert_eq!(Ok(FieldValue::Int(5)), orc.field_by_path("weapons[1].damage"));
+    /// assert_eq!(Ok(FieldValue::String("orc")), orc.field_by_path("name"));
+    /// assert_eq!(
+    ///     Err(ReflectError::IndexOutOfRange {
+    ///         path: String::from("weapons[2]"),
+    ///         index: 2,
+    ///         len: 2,
+    ///     }),
+    ///     orc.field_by_path("weapons[2].damage"),
+    /// );
+    /// ```
+    pub fn field_by_path(&self, path: &str) -> Result<FieldValue<'a>, ReflectError> {
+        let schema = Schema::load()?;
+        let table = self.table();
+        let mut value = FieldValue::Table(TableValue {
+            object: schema.root(),
+            buf: table.buf(),
+            loc: table.loc(),
+        });
+        let mut parent_end = 0;
+        for segment in path.split('.') {
+            let start = if parent_end == 0 { 0 } else { parent_end + 1 };
+            let (parent, end) = (&path[..parent_end], start + segment.len());
+            parent_end = end;
+            let (name, index) = parse_segment(segment)
+                .ok_or_else(|| ReflectError::InvalidPath(path.to_string()))?;
+            let table = match value {
+                FieldValue::Table(table) => table,
+                FieldValue::None => return Err(ReflectError::Absent(parent.to_string())),
+                _ => return Err(ReflectError::NotATable(parent.to_string())),
+            };
+            value = schema.read(table, name).ok_or_else(|| {
+                ReflectError::UnknownField(path[..start + name.len()].to_string())
+            })?;
+            if let Some(index) = index {
+                value = match value {
+                    FieldValue::Vector(mut values) if index < values.len() => {
+                        values.swap_remove(index)
+                    }
+                    FieldValue::Vector(values) => {
+                        return Err(ReflectError::IndexOutOfRange {
+                            path: path[..end].to_string(),
+                            index,
+                            len: values.len(),
+                        })
+                    }
+                    FieldValue::None => FieldValue::None,
+                    _ => {
+                        return Err(ReflectError::NotAVector(
+                            path[..start + name.len()].to_string(),
+                        ))
+                    }
+                };
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Parse `name` or `name[index]`.
+fn parse_segment(segment: &str) -> Option<(&str, Option<usize>)> {
+    let (name, index) = match segment.find('[') {
+        None => (segment, None),
+        Some(open) => {
+            let index = segment[open + 1..].strip_suffix(']')?.parse().ok()?;
+            (&segment[..open], Some(index))
+        }
+    };
+    let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Some((name, index))
+    } else {
+        None
+    }
+}
+
+impl Schema {
+    /// Read the non-deprecated field `name` of `table`.
+    fn read<'a>(&self, table: TableValue<'a>, name: &str) -> Option<FieldValue<'a>> {
+        let field = table
+            .object
+            .fields()
+            .iter()
+            .find(|field| field.name() == name && !field.deprecated())?;
+        Some(if table.object.is_struct() {
+            self.read_scalar(
+                table.buf,
+                table.loc + field.offset() as usize,
+                field.type_().base_type(),
+            )
+        } else {
+            self.read_table_field(table, field)
+        })
+    }
+    fn read_table_field<'a>(&self, table: TableValue<'a>, field: Field<'static>) -> FieldValue<'a> {
+        let ty = field.type_();
+        // Safety: `table` is in the buffer verified by the generated
+        // verifier, which `check` matched against the schema.
+        let pos = match unsafe { Table::new(table.buf, table.loc) }
+            .vtable()
+            .get(field.offset())
+        {
+            0 => {
+                return match ty.base_type() {
+                    BaseType::Bool => FieldValue::Bool(field.default_integer() != 0),
+                    BaseType::Float | BaseType::Double => FieldValue::Float(field.default_real()),
+                    base if base.0 <= BaseType::ULong.0 => FieldValue::Int(field.default_integer()),
+                    _ => FieldValue::None,
+                }
+            }
+            off => table.loc + off as usize,
+        };
+        match ty.base_type() {
+            BaseType::Vector => {
+                // Safety: verified as above.
+                let loc = unsafe { follow(table.buf, pos) };
+                let len = unsafe { flatbuffers::read_scalar_at::<u32>(table.buf, loc) } as usize;
+                let start = loc + SIZE_UOFFSET;
+                let size = ty.element_size() as usize;
+                let values = (0..len)
+                    .map(|i| self.read_element(table.buf, start + i * size, ty))
+                    .collect();
+                FieldValue::Vector(values)
+            }
+            BaseType::Union => {
+                let type_field = format!("{}_type", field.name());
+                let kind = match self.read(table, &type_field) {
+                    Some(FieldValue::Int(kind)) => kind,
+                    _ => return FieldValue::None,
+                };
+                let union = self.enum_(ty.index());
+                let member = union
+                    .values()
+                    .iter()
+                    .find(|value| value.value() == kind)
+                    .and_then(|value| value.union_type())
+                    .filter(|ty| ty.base_type() == BaseType::Obj);
+                match member {
+                    // The unknown member is not verified.
+                    None => FieldValue::None,
+                    Some(member) => FieldValue::Table(TableValue {
+                        object: self.object(member.index()),
+                        buf: table.buf,
+                        // Safety: verified as above.
+                        loc: unsafe { follow(table.buf, pos) },
+                    }),
+                }
+            }
+            _ => self.read_element(table.buf, pos, ty),
+        }
+    }
+    /// Read the non-vector value, or the vector element, at `loc`.
+    fn read_element<'a>(
+        &self,
+        buf: &'a [u8],
+        loc: usize,
+        ty: reflection::Type<'static>,
+    ) -> FieldValue<'a> {
+        let base = match ty.base_type() {
+            BaseType::Vector => ty.element(),
+            base => base,
+        };
+        match base {
+            BaseType::String => {
+                // Safety: verified as above.
+                FieldValue::String(unsafe { <ForwardsUOffset<&str>>::follow(buf, loc) })
+            }
+            BaseType::Obj => {
+                let object = self.object(ty.index());
+                let loc = if object.is_struct() {
+                    loc
+                } else {
+                    // Safety: verified as above.
+                    unsafe { follow(buf, loc) }
+                };
+                FieldValue::Table(TableValue { object, buf, loc })
+            }
+            base => self.read_scalar(buf, loc, base),
+        }
+    }
+    fn read_scalar<'a>(&self, buf: &'a [u8], loc: usize, base: BaseType) -> FieldValue<'a> {
+        use flatbuffers::read_scalar_at;
+        // Safety: verified as above.
+        unsafe {
+            match base {
+                BaseType::Bool => FieldValue::Bool(read_scalar_at::<u8>(buf, loc) != 0),
+                BaseType::UType | BaseType::UByte => {
+                    FieldValue::Int(read_scalar_at::<u8>(buf, loc).into())
+                }
+                BaseType::Byte => FieldValue::Int(read_scalar_at::<i8>(buf, loc).into()),
+                BaseType::Short => FieldValue::Int(read_scalar_at::<i16>(buf, loc).into()),
+                BaseType::UShort => FieldValue::Int(read_scalar_at::<u16>(buf, loc).into()),
+                BaseType::Int => FieldValue::Int(read_scalar_at::<i32>(buf, loc).into()),
+                BaseType::UInt => FieldValue::Int(read_scalar_at::<u32>(buf, loc).into()),
+                BaseType::Long => FieldValue::Int(read_scalar_at::<i64>(buf, loc)),
+                BaseType::ULong => FieldValue::Int(read_scalar_at::<u64>(buf, loc) as i64),
+                BaseType::Float => FieldValue::Float(read_scalar_at::<f32>(buf, loc).into()),
+                BaseType::Double => FieldValue::Float(read_scalar_at::<f64>(buf, loc)),
+                _ => FieldValue::None,
+            }
+        }
+    }
+}
+
+/// Returns the location the `uoffset` at `loc` points to.
+///
+/// # Safety
+///
+/// `loc` must be the verified `uoffset` in `buf`.
+unsafe fn follow(buf: &[u8], loc: usize) -> usize {
+    loc + flatbuffers::read_scalar_at::<u32>(buf, loc) as usize
+}
+
+fn short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn scalar_name(base: BaseType) -> &'static str {
+    match base {
+        BaseType::UType => "utype",
+        BaseType::Bool => "bool",
+        BaseType::Byte => "byte",
+        BaseType::UByte => "ubyte",
+        BaseType::Short => "short",
+        BaseType::UShort => "ushort",
+        BaseType::Int => "int",
+        BaseType::UInt => "uint",
+        BaseType::Long => "long",
+        BaseType::ULong => "ulong",
+        BaseType::Float => "float",
+        BaseType::Double => "double",
+        BaseType::String => "string",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::{Color, Vec3};
+    use crate::monster::{MonsterData, WeaponData};
+    use crate::Monster;
+
+    fn orc() -> crate::MonsterBuf {
+        Monster::build_pooled(&MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            hp: 80,
+            inventory: vec![1, 2, 3],
+            color: Color::Red,
+            weapons: vec![
+                WeaponData {
+                    name: String::from("sword"),
+                    damage: 3,
+                },
+                WeaponData {
+                    name: String::from("axe"),
+                    damage: 5,
+                },
+            ],
+            equipped: Some(1),
+            path: vec![Vec3::new(0.5, 0.0, -0.5)],
+            ..Default::default()
+        })
+    }
+    #[test]
+    fn field_by_path() {
+        struct Test {
+            name: &'static str,
+            data: &'static str,
+            want: Result<FieldValue<'static>, ReflectError>,
+        }
+        let err = |f: fn(String) -> ReflectError, path: &str| Err(f(path.to_string()));
+        let tests = [
+            Test {
+                name: "string",
+                data: "name",
+                want: Ok(FieldValue::String("orc")),
+            },
+            Test {
+                name: "scalar",
+                data: "hp",
+                want: Ok(FieldValue::Int(80)),
+            },
+            Test {
+                name: "default scalar",
+                data: "mana",
+                want: Ok(FieldValue::Int(150)),
+            },
+            Test {
+                name: "enum",
+                data: "color",
+                want: Ok(FieldValue::Int(0)),
+            },
+            Test {
+                name: "struct field",
+                data: "pos.y",
+                want: Ok(FieldValue::Float(2.0)),
+            },
+            Test {
+                name: "vector of scalars",
+                data: "inventory",
+                want: Ok(FieldValue::Vector(vec![
+                    FieldValue::Int(1),
+                    FieldValue::Int(2),
+                    FieldValue::Int(3),
+                ])),
+            },
+            Test {
+                name: "vector of scalars element",
+                data: "inventory[2]",
+                want: Ok(FieldValue::Int(3)),
+            },
+            Test {
+                name: "nested weapon name",
+                data: "weapons[0].name",
+                want: Ok(FieldValue::String("sword")),
+            },
+            Test {
+                name: "nested weapon damage",
+                data: "weapons[1].damage",
+                want: Ok(FieldValue::Int(5)),
+            },
+            Test {
+                name: "vector of structs element",
+                data: "path[0].z",
+                want: Ok(FieldValue::Float(-0.5)),
+            },
+            Test {
+                name: "union type",
+                data: "equipped_type",
+                want: Ok(FieldValue::Int(1)),
+            },
+            Test {
+                name: "union",
+                data: "equipped.name",
+                want: Ok(FieldValue::String("axe")),
+            },
+            Test {
+                name: "out of range",
+                data: "weapons[2].damage",
+                want: Err(ReflectError::IndexOutOfRange {
+                    path: String::from("weapons[2]"),
+                    index: 2,
+                    len: 2,
+                }),
+            },
+            Test {
+                name: "out of range struct vector",
+                data: "path[1]",
+                want: Err(ReflectError::IndexOutOfRange {
+                    path: String::from("path[1]"),
+                    index: 1,
+                    len: 1,
+                }),
+            },
+            Test {
+                name: "unknown field",
+                data: "weapons[0].weight",
+                want: err(ReflectError::UnknownField, "weapons[0].weight"),
+            },
+            Test {
+                name: "deprecated field",
+                data: "friedly",
+                want: err(ReflectError::UnknownField, "friedly"),
+            },
+            Test {
+                name: "field of scalar",
+                data: "hp.value",
+                want: err(ReflectError::NotATable, "hp"),
+            },
+            Test {
+                name: "index of scalar",
+                data: "hp[0]",
+                want: err(ReflectError::NotAVector, "hp"),
+            },
+            Test {
+                name: "field of vector",
+                data: "weapons.name",
+                want: err(ReflectError::NotATable, "weapons"),
+            },
+            Test {
+                name: "empty segment",
+                data: "weapons[0]..name",
+                want: err(ReflectError::InvalidPath, "weapons[0]..name"),
+            },
+            Test {
+                name: "bad index",
+                data: "weapons[-1]",
+                want: err(ReflectError::InvalidPath, "weapons[-1]"),
+            },
+        ];
+        let orc = orc();
+        for t in &tests {
+            assert_eq!(t.want, orc.view().field_by_path(t.data), "{}", t.name);
+        }
+    }
+    #[test]
+    fn absent_fields() {
+        let mut b = flatbuffers::FlatBufferBuilder::new();
+        let orc = Monster::builder().name("orc").build(&mut b);
+        b.finish(orc, None);
+        let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+        assert_eq!(Ok(FieldValue::None), orc.field_by_path("pos"));
+        assert_eq!(Ok(FieldValue::None), orc.field_by_path("equipped"));
+        assert_eq!(Ok(FieldValue::None), orc.field_by_path("weapons[0]"));
+        assert_eq!(Ok(FieldValue::Int(2)), orc.field_by_path("color"));
+        assert_eq!(
+            Err(ReflectError::Absent(String::from("pos"))),
+            orc.field_by_path("pos.x")
+        );
+    }
+    #[test]
+    fn table_value() {
+        let orc = orc();
+        match orc.view().field_by_path("equipped") {
+            Ok(FieldValue::Table(weapon)) => {
+                assert_eq!("MyGame.Sample.Weapon", weapon.object_name())
+            }
+            got => panic!("unexpected {:?}", got),
+        }
+    }
+    #[test]
+    fn list_fields() {
+        let got: Vec<_> = Schema::load()
+            .unwrap()
+            .list_fields()
+            .into_iter()
+            .map(|field| format!("{}: {}", field.path, field.ty))
+            .collect();
+        let want = [
+            "pos: Vec3",
+            "pos.x: float",
+            "pos.y: float",
+            "pos.z: float",
+            "mana: short",
+            "hp: short",
+            "name: string",
+            "inventory: [ubyte]",
+            "color: Color",
+            "weapons: [Weapon]",
+            "weapons[].name: string",
+            "weapons[].damage: short",
+            "equipped_type: utype",
+            "equipped: Equipment",
+            "equipped.name: string",
+            "equipped.damage: short",
+            "path: [Vec3]",
+            "path[].x: float",
+            "path[].y: float",
+            "path[].z: float",
+        ];
+        assert_eq!(&want[..], &got[..]);
+    }
+    #[test]
+    fn schema_matches_generated_code() {
+        let schema = Schema::load().unwrap();
+        assert_eq!(Ok(()), schema.check());
+        assert!(reflection::schema_buffer_has_identifier(Schema::bytes()));
+        // Every listed path is queryable on the full monster.
+        let orc = orc();
+        for field in schema.list_fields() {
+            let path = field.path.replace("[]", "[0]");
+            assert!(orc.view().field_by_path(&path).is_ok(), "{}", path);
+        }
+    }
+}
@@ -0,0 +1,717 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Read-only, non-panicking view of the `Monster` buffer.
+use flatbuffers::VerifierOptions;
+
+use crate::error::MonsterError;
+use crate::model::my_game::sample::{self, root_as_monster_with_opts, Color, Equipment, Vec3};
+use crate::weapon::WeaponView;
+
+/// Limits of the flatbuffers verifier.
+///
+/// [`strict`] is the default, and is meant for the untrusted input.
+/// [`relaxed`] accepts the deeply nested and the large buffers from the
+/// trusted sources.
+///
+/// [`strict`]: struct.VerifyOpts.html#method.strict
+/// [`relaxed`]: struct.VerifyOpts.html#method.relaxed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyOpts {
+    /// Maximum depth of the nested tables.
+    pub max_depth: usize,
+    /// Maximum number of the tables, counted every time they're visited.
+    pub max_tables: usize,
+    /// Maximum size of the buffer with the shared objects expanded.
+    pub max_apparent_size: usize,
+}
+
+impl VerifyOpts {
+    /// Limits for the untrusted input, which still accept the monster
+    /// with a few hundred weapons.
+    pub const fn strict() -> Self {
+        Self {
+            max_depth: 8,
+            max_tables: 1_024,
+            max_apparent_size: 16 * 1024 * 1024,
+        }
+    }
+    /// Limits for the trusted input, which are looser than the
+    /// flatbuffers defaults.
+    pub const fn relaxed() -> Self {
+        Self {
+            max_depth: 256,
+            max_tables: 16_000_000,
+            max_apparent_size: usize::MAX,
+        }
+    }
+}
+
+impl Default for VerifyOpts {
+    fn default() -> Self {
+        Self::strict()
+    }
+}
+
+impl From<VerifyOpts> for VerifierOptions {
+    fn from(opts: VerifyOpts) -> Self {
+        Self {
+            max_depth: opts.max_depth,
+            max_tables: opts.max_tables,
+            max_apparent_size: opts.max_apparent_size,
+            ..Default::default()
+        }
+    }
+}
+
+/// Verified `Monster` buffer.
+///
+/// The verifier only guarantees the buffer is safe to read, e.g. the
+/// required `name` field may still be absent.  None of the accessors
+/// panics on such a buffer and [`validate`] reports the semantic errors.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::{Monster, MonsterView};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().name("orc").hp(80).build(&mut b);
+/// b.finish(orc, None);
+/// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+/// assert_eq!(Ok("orc"), orc.name_or_err());
+/// assert_eq!(80, orc.hp());
+/// assert_eq!(Ok(()), orc.validate());
+/// ```
+/// [`validate`]: struct.MonsterView.html#method.validate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonsterView<'a>(sample::Monster<'a>);
+
+impl<'a> MonsterView<'a> {
+    /// Verify `buf` under the [`VerifyOpts::strict`] limits and return the
+    /// view of the root `Monster`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::MonsterView;
+    ///
+    /// assert!(MonsterView::from_bytes(&[0xff; 3]).is_err());
+    /// ```
+    /// [`verifyopts::strict`]: struct.VerifyOpts.html#method.strict
+    pub fn from_bytes(buf: &'a [u8]) -> Result<Self, MonsterError> {
+        Self::from_bytes_with_opts(buf, VerifyOpts::strict())
+    }
+    /// Verify `buf` under the `opts` limits and return the view of the
+    /// root `Monster`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterView, VerifyOpts};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").weapon("axe", 5).build(&mut b);
+    /// b.finish(orc, None);
+    /// let opts = VerifyOpts {
+    ///     max_tables: 1,
+    ///     ..VerifyOpts::strict()
+    /// };
+    /// assert!(MonsterView::from_bytes_with_opts(b.finished_data(), opts).is_err());
+    /// ```
+    pub fn from_bytes_with_opts(buf: &'a [u8], opts: VerifyOpts) -> Result<Self, MonsterError> {
+        let opts = VerifierOptions::from(opts);
+        Ok(Self(root_as_monster_with_opts(&opts, buf)?))
+    }
+    /// Returns the `name` in place, which borrows the buffer instead of
+    /// copying it out.
+    pub fn name(&self) -> Option<&'a str> {
+        self.0.name()
+    }
+    /// Returns [`MonsterError::MissingName`] for the absent `name` field.
+    ///
+    /// [`monstererror::missingname`]: ../error/enum.MonsterError.html#variant.MissingName
+    pub fn name_or_err(&self) -> Result<&'a str, MonsterError> {
+        self.0.name().ok_or(MonsterError::MissingName)
+    }
+    pub fn pos(&self) -> Option<Vec3> {
+        self.0.pos().copied()
+    }
+    pub fn mana(&self) -> i16 {
+        self.0.mana()
+    }
+    pub fn hp(&self) -> i16 {
+        self.0.hp()
+    }
+    /// Returns `true` if `mana` is physically present in the buffer.
+    pub fn has_mana(&self) -> bool {
+        self.field_pos(sample::Monster::VT_MANA).is_some()
+    }
+    /// Returns `true` if `hp` is physically present in the buffer.
+    pub fn has_hp(&self) -> bool {
+        self.field_pos(sample::Monster::VT_HP).is_some()
+    }
+    /// Returns `true` if `color` is physically present in the buffer.
+    pub fn has_color(&self) -> bool {
+        self.field_pos(sample::Monster::VT_COLOR).is_some()
+    }
+    /// Returns the buffer position of the field at the vtable offset
+    /// `voffset`, or `None` for the absent field.
+    pub(crate) fn field_pos(&self, voffset: flatbuffers::VOffsetT) -> Option<usize> {
+        match self.0._tab.vtable().get(voffset) {
+            0 => None,
+            off => Some(self.0._tab.loc() + off as usize),
+        }
+    }
+    /// Returns the underlying table, for the reflection.
+    #[cfg(feature = "reflection")]
+    pub(crate) fn table(&self) -> flatbuffers::Table<'a> {
+        self.0._tab
+    }
+    /// Returns the raw `color` value, which may not be the known variant.
+    pub fn color(&self) -> Color {
+        self.0.color()
+    }
+    pub fn inventory(&self) -> Option<&'a [u8]> {
+        self.0.inventory().map(|inventory| inventory.bytes())
+    }
+    /// Returns the iterator over the `weapons`, which is empty
+    /// for the absent field.
+    pub fn weapons(&self) -> impl Iterator<Item = WeaponView<'a>> {
+        self.0
+            .weapons()
+            .into_iter()
+            .flat_map(|weapons| weapons.iter().map(WeaponView::from))
+    }
+    /// Returns the equipped weapon, or `None` for the unequipped
+    /// monster or the unknown equipment type.
+    pub fn equipped(&self) -> Option<WeaponView<'a>> {
+        self.0.equipped_as_weapon().map(WeaponView::from)
+    }
+    /// Returns the iterator over the `path`, which is empty
+    /// for the absent field.
+    pub fn path(&self) -> impl Iterator<Item = Vec3> + 'a {
+        self.0
+            .path()
+            .into_iter()
+            .flat_map(|path| path.iter().copied())
+    }
+    /// Returns the iterator over the `path` as the `glam` vectors, which
+    /// is empty for the absent field.
+    #[cfg(feature = "glam")]
+    pub fn path_glam(&self) -> impl Iterator<Item = glam::Vec3> + 'a {
+        self.path().map(glam::Vec3::from)
+    }
+    /// Returns the min and the max corners of the axis-aligned bounding
+    /// box of the `path`, or `None` for the empty `path`.
+    ///
+    /// The corners convert to the math library vectors with the `glam`
+    /// or `nalgebra` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::model::my_game::sample::Vec3;
+    /// use flatbuf_tutorial::{Monster, MonsterView};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder()
+    ///     .name("orc")
+    ///     .path_points([[0.0, 5.0, -1.0], [2.0, -5.0, 1.0]])
+    ///     .build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// let want = (Vec3::new(0.0, -5.0, -1.0), Vec3::new(2.0, 5.0, 1.0));
+    /// assert_eq!(Some(want), orc.path_bounding_box());
+    /// ```
+    pub fn path_bounding_box(&self) -> Option<(Vec3, Vec3)> {
+        let mut path = self.path();
+        let first = path.next()?;
+        let (min, max) = path.fold((first, first), |(min, max), p| {
+            (
+                Vec3::new(min.x().min(p.x()), min.y().min(p.y()), min.z().min(p.z())),
+                Vec3::new(max.x().max(p.x()), max.y().max(p.y()), max.z().max(p.z())),
+            )
+        });
+        Some((min, max))
+    }
+    /// Returns the `path` in place, for the copy without the per element
+    /// reads.
+    pub(crate) fn path_slice(&self) -> Option<&'a [Vec3]> {
+        self.0.path().map(|path| {
+            // SAFETY: the verified vector holds `len` of the `Vec3` byte
+            // arrays, which have the alignment of 1.
+            unsafe { std::slice::from_raw_parts(path.bytes().as_ptr().cast::<Vec3>(), path.len()) }
+        })
+    }
+    /// Check the invariants beyond the verifier and return all the
+    /// errors found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::{Monster, MonsterError, MonsterView};
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let orc = Monster::builder().name("orc").hp(-1).build(&mut b);
+    /// b.finish(orc, None);
+    /// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+    /// assert_eq!(Err(vec![MonsterError::NegativeHp(-1)]), orc.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<MonsterError>> {
+        let mut errs = Vec::new();
+        if self.name().is_none() {
+            errs.push(MonsterError::MissingName);
+        }
+        if self.hp() < 0 {
+            errs.push(MonsterError::NegativeHp(self.hp()));
+        }
+        match self.0.equipped_type() {
+            Equipment::NONE => {}
+            Equipment::Weapon => {
+                if let Some(equipped) = self.equipped() {
+                    if !self.weapons().any(|weapon| weapon.is_same(&equipped)) {
+                        errs.push(MonsterError::EquippedNotInWeapons);
+                    }
+                }
+            }
+            Equipment(kind) => errs.push(MonsterError::UnknownEquipment(kind)),
+        }
+        for (i, p) in self.path().enumerate() {
+            if !(p.x().is_finite() && p.y().is_finite() && p.z().is_finite()) {
+                errs.push(MonsterError::NonFinitePath(i));
+            }
+        }
+        if errs.is_empty() {
+            Ok(())
+        } else {
+            Err(errs)
+        }
+    }
+}
+
+// `path_slice` reinterprets the vector bytes as `Vec3`.
+const _: () = assert!(std::mem::align_of::<Vec3>() == 1);
+
+impl<'a> From<sample::Monster<'a>> for MonsterView<'a> {
+    fn from(monster: sample::Monster<'a>) -> Self {
+        Self(monster)
+    }
+}
+
+/// Serialized the same as [`MonsterData`], straight from the buffer
+/// without the owned copy, e.g. the absent `name` is the empty string and
+/// `equipped` is the index in `weapons`.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::monster::MonsterData;
+/// use flatbuf_tutorial::Monster;
+///
+/// let orc = Monster::build_pooled(&MonsterData {
+///     name: String::from("orc"),
+///     ..Default::default()
+/// });
+/// let json = serde_json::to_string(&orc.view()).unwrap();
+/// assert_eq!(json, serde_json::to_string(&MonsterData::from(orc.view())).unwrap());
+/// ```
+/// [`monsterdata`]: ../monster/struct.MonsterData.html
+#[cfg(feature = "serde")]
+impl serde::Serialize for MonsterView<'_> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        struct Seq<I>(std::cell::Cell<Option<I>>);
+        impl<I> serde::Serialize for Seq<I>
+        where
+            I: Iterator,
+            I::Item: serde::Serialize,
+        {
+            fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_seq(self.0.take().into_iter().flatten())
+            }
+        }
+        fn seq<I: Iterator>(iter: I) -> Seq<I> {
+            Seq(std::cell::Cell::new(Some(iter)))
+        }
+
+        let equipped = self
+            .equipped()
+            .and_then(|equipped| self.weapons().position(|weapon| weapon.is_same(&equipped)));
+        let mut monster = s.serialize_struct("MonsterData", 9)?;
+        monster.serialize_field("name", self.name().unwrap_or_default())?;
+        monster.serialize_field("pos", &self.pos())?;
+        monster.serialize_field("mana", &self.mana())?;
+        monster.serialize_field("hp", &self.hp())?;
+        monster.serialize_field("inventory", self.inventory().unwrap_or_default())?;
+        monster.serialize_field("color", &self.color())?;
+        monster.serialize_field("weapons", &seq(self.weapons()))?;
+        monster.serialize_field("equipped", &equipped)?;
+        monster.serialize_field("path", &seq(self.path()))?;
+        monster.end()
+    }
+}
+
+/// Serialized as the `{ x, y, z }` map, the same as the `MonsterData`
+/// points.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Vec3 {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut point = s.serialize_struct("Point", 3)?;
+        point.serialize_field("x", &self.x())?;
+        point.serialize_field("y", &self.y())?;
+        point.serialize_field("z", &self.z())?;
+        point.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::my_game::sample::{MonsterArgs, Weapon, WeaponArgs};
+    use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+    fn weapon<'b>(b: &mut FlatBufferBuilder<'b>, name: &str) -> WIPOffset<Weapon<'b>> {
+        let name = b.create_string(name);
+        Weapon::create(
+            b,
+            &WeaponArgs {
+                name: Some(name),
+                damage: 1,
+            },
+        )
+    }
+    fn finish<F>(f: F) -> Vec<u8>
+    where
+        F: FnOnce(&mut FlatBufferBuilder<'static>) -> WIPOffset<sample::Monster<'static>>,
+    {
+        let mut b = FlatBufferBuilder::new();
+        let monster = f(&mut b);
+        b.finish(monster, None);
+        b.finished_data().to_vec()
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "unnamed",
+                data: finish(|b| sample::Monster::create(b, &Default::default())),
+                want: r#"{"name":"","pos":null,"mana":150,"hp":100,"inventory":[],"color":"Blue","weapons":[],"equipped":null,"path":[]}"#,
+            },
+            Test {
+                name: "equipped",
+                data: finish(|b| {
+                    let sword = weapon(b, "sword");
+                    let axe = weapon(b, "axe");
+                    let weapons = b.create_vector(&[sword, axe]);
+                    let path = b.create_vector(&[Vec3::new(1.0, 2.0, 3.0)]);
+                    let name = b.create_string("orc");
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            pos: Some(&Vec3::new(0.5, 0.0, -0.5)),
+                            weapons: Some(weapons),
+                            equipped_type: Equipment::Weapon,
+                            equipped: Some(axe.as_union_value()),
+                            path: Some(path),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: concat!(
+                    r#"{"name":"orc","pos":{"x":0.5,"y":0.0,"z":-0.5},"mana":150,"hp":100,"#,
+                    r#""inventory":[],"color":"Blue","weapons":[{"name":"sword","damage":1},"#,
+                    r#"{"name":"axe","damage":1}],"equipped":1,"path":[{"x":1.0,"y":2.0,"z":3.0}]}"#,
+                ),
+            },
+        ];
+        for t in &tests {
+            let view = MonsterView::from_bytes(&t.data).unwrap();
+            let data = crate::monster::MonsterData::from(view);
+            assert_eq!(t.want, serde_json::to_string(&data).unwrap(), "{}", t.name);
+            assert_eq!(t.want, serde_json::to_string(&view).unwrap(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn from_bytes_invalid() {
+        struct Test {
+            name: &'static str,
+            data: &'static [u8],
+        }
+        let tests = [
+            Test {
+                name: "empty buffer",
+                data: &[],
+            },
+            Test {
+                name: "short buffer",
+                data: &[0xff, 0xff, 0xff],
+            },
+            Test {
+                name: "out of range root offset",
+                data: &[0xff, 0xff, 0xff, 0x7f, 0, 0, 0, 0],
+            },
+        ];
+        for t in &tests {
+            match MonsterView::from_bytes(t.data) {
+                Err(MonsterError::Invalid(_)) => {}
+                got => panic!("{}: unexpected {:?}", t.name, got),
+            }
+        }
+    }
+    #[test]
+    fn from_bytes_with_opts() {
+        let weapons = |n: usize| {
+            finish(|b| {
+                let weapons: Vec<_> = (0..n).map(|_| weapon(b, "axe")).collect();
+                let weapons = b.create_vector(&weapons);
+                let name = b.create_string("orc");
+                sample::Monster::create(
+                    b,
+                    &MonsterArgs {
+                        name: Some(name),
+                        weapons: Some(weapons),
+                        ..Default::default()
+                    },
+                )
+            })
+        };
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: [bool; 2],
+        }
+        let max = VerifyOpts::strict().max_tables;
+        let tests = [
+            Test {
+                name: "few weapons",
+                data: weapons(3),
+                want: [true, true],
+            },
+            Test {
+                // The monster itself is the table.
+                name: "strict table limit",
+                data: weapons(max - 1),
+                want: [true, true],
+            },
+            Test {
+                name: "over strict table limit",
+                data: weapons(max),
+                want: [false, true],
+            },
+            Test {
+                name: "short buffer",
+                data: vec![0xff; 3],
+                want: [false, false],
+            },
+            Test {
+                name: "out of range root offset",
+                data: vec![0xff, 0xff, 0xff, 0x7f, 0, 0, 0, 0],
+                want: [false, false],
+            },
+            Test {
+                name: "truncated weapons",
+                data: {
+                    let mut buf = weapons(max);
+                    buf.truncate(buf.len() / 2);
+                    buf
+                },
+                want: [false, false],
+            },
+        ];
+        for t in &tests {
+            let got = [VerifyOpts::strict(), VerifyOpts::relaxed()]
+                .map(|opts| MonsterView::from_bytes_with_opts(&t.data, opts).is_ok());
+            assert_eq!(t.want, got, "{}", t.name);
+            assert_eq!(
+                t.want[0],
+                MonsterView::from_bytes(&t.data).is_ok(),
+                "{}",
+                t.name
+            );
+        }
+    }
+    #[test]
+    fn accessors_on_empty_table() {
+        let buf = finish(|b| sample::MonsterBuilder::new(b).finish());
+        let monster = MonsterView::from_bytes(&buf).unwrap();
+        assert_eq!(None, monster.name());
+        assert_eq!(Err(MonsterError::MissingName), monster.name_or_err());
+        assert_eq!(None, monster.pos());
+        assert_eq!(150, monster.mana());
+        assert_eq!(100, monster.hp());
+        assert_eq!(Color::Blue, monster.color());
+        assert_eq!(None, monster.inventory());
+        assert_eq!(0, monster.weapons().count());
+        assert_eq!(None, monster.equipped());
+        assert_eq!(0, monster.path().count());
+        let _ = format!("{:?}", monster);
+    }
+    #[test]
+    fn validate() {
+        struct Test {
+            name: &'static str,
+            data: Vec<u8>,
+            want: Result<(), Vec<MonsterError>>,
+        }
+        let tests = [
+            Test {
+                name: "valid monster",
+                data: finish(|b| {
+                    let axe = weapon(b, "axe");
+                    let weapons = b.create_vector(&[axe]);
+                    let name = b.create_string("orc");
+                    let path = b.create_vector(&[Vec3::new(1.0, 2.0, 3.0)]);
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            hp: 0,
+                            weapons: Some(weapons),
+                            equipped_type: Equipment::Weapon,
+                            equipped: Some(axe.as_union_value()),
+                            path: Some(path),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Ok(()),
+            },
+            Test {
+                name: "missing name",
+                data: finish(|b| sample::Monster::create(b, &MonsterArgs::default())),
+                want: Err(vec![MonsterError::MissingName]),
+            },
+            Test {
+                name: "negative hp",
+                data: finish(|b| {
+                    let name = b.create_string("orc");
+                    let mut m = sample::MonsterBuilder::new(b);
+                    m.add_name(name);
+                    m.add_hp(-1);
+                    m.finish()
+                }),
+                want: Err(vec![MonsterError::NegativeHp(-1)]),
+            },
+            Test {
+                name: "equipped weapon not in weapons",
+                data: finish(|b| {
+                    let axe = weapon(b, "axe");
+                    let sword = weapon(b, "sword");
+                    let weapons = b.create_vector(&[sword]);
+                    let name = b.create_string("orc");
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            weapons: Some(weapons),
+                            equipped_type: Equipment::Weapon,
+                            equipped: Some(axe.as_union_value()),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Err(vec![MonsterError::EquippedNotInWeapons]),
+            },
+            Test {
+                name: "equipped weapon without weapons",
+                data: finish(|b| {
+                    let axe = weapon(b, "axe");
+                    let name = b.create_string("orc");
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            equipped_type: Equipment::Weapon,
+                            equipped: Some(axe.as_union_value()),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Err(vec![MonsterError::EquippedNotInWeapons]),
+            },
+            Test {
+                name: "unknown equipment type",
+                data: finish(|b| {
+                    let axe = weapon(b, "axe");
+                    let weapons = b.create_vector(&[axe]);
+                    let name = b.create_string("orc");
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            weapons: Some(weapons),
+                            equipped_type: Equipment(7),
+                            equipped: Some(axe.as_union_value()),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Err(vec![MonsterError::UnknownEquipment(7)]),
+            },
+            Test {
+                name: "non-finite path",
+                data: finish(|b| {
+                    let name = b.create_string("orc");
+                    let path = b.create_vector(&[
+                        Vec3::new(1.0, 2.0, 3.0),
+                        Vec3::new(f32::NAN, 0.0, 0.0),
+                        Vec3::new(0.0, 0.0, f32::INFINITY),
+                    ]);
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            name: Some(name),
+                            path: Some(path),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Err(vec![
+                    MonsterError::NonFinitePath(1),
+                    MonsterError::NonFinitePath(2),
+                ]),
+            },
+            Test {
+                name: "multiple errors",
+                data: finish(|b| {
+                    let path = b.create_vector(&[Vec3::new(f32::NEG_INFINITY, 0.0, 0.0)]);
+                    sample::Monster::create(
+                        b,
+                        &MonsterArgs {
+                            hp: -5,
+                            path: Some(path),
+                            ..Default::default()
+                        },
+                    )
+                }),
+                want: Err(vec![
+                    MonsterError::MissingName,
+                    MonsterError::NegativeHp(-5),
+                    MonsterError::NonFinitePath(0),
+                ]),
+            },
+        ];
+        for t in &tests {
+            let monster = MonsterView::from_bytes(&t.data).unwrap();
+            assert_eq!(t.want, monster.validate(), "{}", t.name);
+            // None of the accessors panics on the pathological buffer.
+            let _ = (monster.name(), monster.pos(), monster.color());
+            let _ = (monster.inventory(), monster.equipped());
+            let _ = (monster.weapons().count(), monster.path().count());
+            let _ = format!("{:?}", monster);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-2.0
+//! wasm-bindgen bindings to build and inspect the `Monster` buffers in
+//! the browser, over the [`MonsterData`] JSON.
+//!
+//! ```sh
+//! $ wasm-pack build --target web -- --features wasm
+//! ```
+//!
+//! The errors are thrown as the JavaScript `Error` with the message of
+//! the underlying error.
+//!
+//! [`monsterdata`]: ../monster/struct.MonsterData.html
+use wasm_bindgen::prelude::*;
+
+use crate::monster::MonsterData;
+use crate::{Monster, MonsterView};
+
+/// Build the `Monster` buffer from the `MonsterData` JSON.
+#[wasm_bindgen]
+pub fn build_monster_from_json(json: &str) -> Result<Vec<u8>, JsValue> {
+    from_json(json).map_err(js_error)
+}
+
+/// Returns the `MonsterData` JSON of the `Monster` buffer.
+#[wasm_bindgen]
+pub fn monster_to_json(bytes: &[u8]) -> Result<String, JsValue> {
+    to_json(bytes).map_err(js_error)
+}
+
+/// Returns `true` if the `Monster` buffer passes the verifier.
+#[wasm_bindgen]
+pub fn verify_monster(bytes: &[u8]) -> bool {
+    MonsterView::from_bytes(bytes).is_ok()
+}
+
+fn from_json(json: &str) -> Result<Vec<u8>, String> {
+    let data: MonsterData =
+        serde_json::from_str(json).map_err(|err| format!("invalid monster JSON: {}", err))?;
+    Ok(Monster::build_pooled(&data).into_inner())
+}
+
+fn to_json(bytes: &[u8]) -> Result<String, String> {
+    let monster = MonsterView::from_bytes(bytes).map_err(|err| err.to_string())?;
+    serde_json::to_string(&monster).map_err(|err| err.to_string())
+}
+
+fn js_error(message: String) -> JsValue {
+    JsError::new(&message).into()
+}
+
+// The bindings themselves only run on wasm32, see `tests/wasm.rs`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let json = r#"{"name":"orc","hp":80,"weapons":[{"name":"axe","damage":5}],"equipped":0}"#;
+        let buf = from_json(json).unwrap();
+        assert!(verify_monster(&buf));
+        let got: MonsterData = serde_json::from_str(&to_json(&buf).unwrap()).unwrap();
+        assert_eq!(serde_json::from_str::<MonsterData>(json).unwrap(), got);
+    }
+    #[test]
+    fn errors() {
+        let err = from_json(r#"{"name":"orc","hp":"full"}"#).unwrap_err();
+        assert!(err.starts_with("invalid monster JSON: "), "{}", err);
+
+        let mut buf = from_json(r#"{"name":"orc"}"#).unwrap();
+        buf.truncate(buf.len() / 2);
+        assert!(!verify_monster(&buf));
+        let err = to_json(&buf).unwrap_err();
+        assert!(err.starts_with("invalid monster buffer: "), "{}", err);
+    }
+}
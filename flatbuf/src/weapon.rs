@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Standalone `Weapon`, which can be the buffer root as well as the
+//! `Monster` weapon.
+use std::fmt;
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use crate::error::MonsterError;
+use crate::model::my_game::sample::{self, WeaponArgs};
+
+pub struct Weapon;
+
+impl Weapon {
+    /// Create a [`WeaponBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::Weapon;
+    /// use flatbuffers::FlatBufferBuilder;
+    ///
+    /// let mut b = FlatBufferBuilder::new();
+    /// let axe = Weapon::builder().name("Axe").damage(5).build(&mut b);
+    /// Weapon::finish(&mut b, axe);
+    /// let axe = Weapon::from_bytes(b.finished_data()).unwrap();
+    /// assert_eq!(Some("Axe"), axe.name());
+    /// assert_eq!(5, axe.damage());
+    /// ```
+    /// [`weaponbuilder`]: struct.WeaponBuilder.html
+    pub fn builder<'a>() -> WeaponBuilder<'a> {
+        WeaponBuilder::default()
+    }
+    /// Finish the buffer with the `weapon` as the root.
+    pub fn finish<'b>(b: &mut FlatBufferBuilder<'b>, weapon: WIPOffset<sample::Weapon<'b>>) {
+        b.finish(weapon, None);
+    }
+    /// Verify `buf` and return the view of the root `Weapon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use flatbuf_tutorial::Weapon;
+    ///
+    /// assert!(Weapon::from_bytes(&[0xff; 3]).is_err());
+    /// ```
+    pub fn from_bytes(buf: &[u8]) -> Result<WeaponView<'_>, MonsterError> {
+        Ok(WeaponView(flatbuffers::root::<sample::Weapon>(buf)?))
+    }
+}
+
+/// `Weapon` builder.
+#[derive(Debug, Clone, Default)]
+pub struct WeaponBuilder<'a> {
+    name: Option<&'a str>,
+    damage: i16,
+}
+
+impl<'a> WeaponBuilder<'a> {
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+    pub fn damage(mut self, damage: i16) -> Self {
+        self.damage = damage;
+        self
+    }
+    /// Build the `Weapon` table with the provided builder.
+    pub fn build<'b>(&self, b: &mut FlatBufferBuilder<'b>) -> WIPOffset<sample::Weapon<'b>> {
+        let name = self.name.map(|name| b.create_string(name));
+        sample::Weapon::create(
+            b,
+            &WeaponArgs {
+                name,
+                damage: self.damage,
+            },
+        )
+    }
+}
+
+/// Read-only view of the `Weapon`, either standalone or embedded in
+/// the `Monster`.
+///
+/// Two views are equal if those have the same name and damage,
+/// regardless of the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use flatbuf_tutorial::{Monster, MonsterView, Weapon};
+/// use flatbuffers::FlatBufferBuilder;
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let orc = Monster::builder().name("orc").weapon("Axe", 5).build(&mut b);
+/// b.finish(orc, None);
+/// let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+///
+/// let mut b = FlatBufferBuilder::new();
+/// let axe = Weapon::builder().name("Axe").damage(5).build(&mut b);
+/// Weapon::finish(&mut b, axe);
+/// let axe = Weapon::from_bytes(b.finished_data()).unwrap();
+///
+/// assert_eq!(Some(axe), orc.weapons().next());
+/// assert_eq!("Axe (5)", axe.to_string());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WeaponView<'a>(sample::Weapon<'a>);
+
+impl<'a> WeaponView<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.0.name()
+    }
+    pub fn damage(&self) -> i16 {
+        self.0.damage()
+    }
+    /// Returns `true` if both views point to the same table.
+    pub(crate) fn is_same(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<'a> From<sample::Weapon<'a>> for WeaponView<'a> {
+    fn from(weapon: sample::Weapon<'a>) -> Self {
+        Self(weapon)
+    }
+}
+
+impl PartialEq for WeaponView<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name() && self.damage() == other.damage()
+    }
+}
+
+/// Serialized the same as `WeaponData`, straight from the buffer.
+#[cfg(feature = "serde")]
+impl serde::Serialize for WeaponView<'_> {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut weapon = s.serialize_struct("WeaponData", 2)?;
+        weapon.serialize_field("name", self.name().unwrap_or_default())?;
+        weapon.serialize_field("damage", &self.damage())?;
+        weapon.end()
+    }
+}
+
+impl fmt::Display for WeaponView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.name().unwrap_or("unnamed"),
+            self.damage()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Monster, MonsterView};
+
+    fn standalone(name: Option<&str>, damage: i16) -> Vec<u8> {
+        let mut builder = Weapon::builder().damage(damage);
+        if let Some(name) = name {
+            builder = builder.name(name);
+        }
+        let mut b = FlatBufferBuilder::new();
+        let weapon = builder.build(&mut b);
+        Weapon::finish(&mut b, weapon);
+        b.finished_data().to_vec()
+    }
+    #[test]
+    fn standalone_round_trip() {
+        struct Test {
+            name: &'static str,
+            weapon: Option<&'static str>,
+            damage: i16,
+            want: &'static str,
+        }
+        let tests = [
+            Test {
+                name: "axe",
+                weapon: Some("Axe"),
+                damage: 5,
+                want: "Axe (5)",
+            },
+            Test {
+                name: "negative damage",
+                weapon: Some("Healing staff"),
+                damage: -10,
+                want: "Healing staff (-10)",
+            },
+            Test {
+                name: "no name",
+                weapon: None,
+                damage: 0,
+                want: "unnamed (0)",
+            },
+        ];
+        for t in &tests {
+            let buf = standalone(t.weapon, t.damage);
+            let got = Weapon::from_bytes(&buf).unwrap();
+            assert_eq!(t.weapon, got.name(), "{}", t.name);
+            assert_eq!(t.damage, got.damage(), "{}", t.name);
+            assert_eq!(t.want, got.to_string(), "{}", t.name);
+        }
+    }
+    #[test]
+    fn from_invalid_bytes() {
+        match Weapon::from_bytes(&[]) {
+            Err(MonsterError::Invalid(_)) => {}
+            got => panic!("unexpected {:?}", got),
+        }
+    }
+    #[test]
+    fn embedded_and_standalone_share_the_view() {
+        fn describe(weapon: WeaponView<'_>) -> String {
+            weapon.to_string()
+        }
+        let mut b = FlatBufferBuilder::new();
+        let orc = Monster::builder()
+            .name("orc")
+            .weapon("Sword", 3)
+            .weapon("Axe", 5)
+            .equipped(1)
+            .build(&mut b);
+        b.finish(orc, None);
+        let orc = MonsterView::from_bytes(b.finished_data()).unwrap();
+        let axe = standalone(Some("Axe"), 5);
+        let axe = Weapon::from_bytes(&axe).unwrap();
+        let sword = standalone(Some("Sword"), 3);
+        let sword = Weapon::from_bytes(&sword).unwrap();
+
+        let embedded: Vec<_> = orc.weapons().map(describe).collect();
+        assert_eq!(vec![describe(sword), describe(axe)], embedded);
+        assert_eq!(Some(axe), orc.equipped());
+        assert_ne!(Some(sword), orc.equipped());
+    }
+}
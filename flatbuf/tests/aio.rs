@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: GPL-2.0
+#![cfg(feature = "async")]
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use flatbuf_tutorial::monster::aio::{self, append_size_prefixed, FrameReader};
+use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+use flatbuf_tutorial::{AioError, Monster, MonsterBuf, MonsterError};
+use tokio::fs::{self, File};
+use tokio::io::{AsyncRead, ReadBuf};
+
+fn temp_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+fn monster(i: usize) -> MonsterBuf {
+    Monster::build_pooled(&MonsterData {
+        name: format!("monster{}", i),
+        hp: i as i16,
+        weapons: vec![WeaponData {
+            name: String::from("axe"),
+            damage: 5,
+        }],
+        equipped: Some(0),
+        ..Default::default()
+    })
+}
+
+type Frame = Result<MonsterBuf, AioError>;
+
+async fn collect<R: AsyncRead + Unpin>(r: R) -> Vec<Frame> {
+    let mut frames = FrameReader::new(r);
+    let mut got = Vec::new();
+    while let Some(frame) = frames.next_frame().await {
+        got.push(frame);
+    }
+    got
+}
+
+/// Reader returning at most `chunk` bytes for each read, to split the
+/// frames at the arbitrary positions.
+struct Chunked<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl AsyncRead for Chunked<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let n = self.chunk.min(self.data.len()).min(buf.remaining());
+        buf.put_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn write_and_read_monster() {
+    let path = temp_path("aio-orc.bin");
+    let orc = monster(1);
+    aio::write_monster(&path, &orc).await.unwrap();
+    assert_eq!(orc, aio::read_monster(&path).await.unwrap());
+
+    fs::write(&path, [0xff; 3]).await.unwrap();
+    match aio::read_monster(&path).await {
+        Err(AioError::Invalid(MonsterError::Invalid(_))) => {}
+        got => panic!("unexpected {:?}", got),
+    }
+    fs::remove_file(&path).await.unwrap();
+    match aio::read_monster(&path).await {
+        Err(AioError::Io(_)) => {}
+        got => panic!("unexpected {:?}", got),
+    }
+}
+
+#[tokio::test]
+async fn multi_frame_file() {
+    let path = temp_path("aio-frames.bin");
+    let mut file = File::create(&path).await.unwrap();
+    let want: Vec<_> = (0..100).map(monster).collect();
+    for buf in &want {
+        append_size_prefixed(&mut file, buf).await.unwrap();
+    }
+    file.sync_data().await.unwrap();
+    drop(file);
+
+    let got = collect(File::open(&path).await.unwrap()).await;
+    let got: Vec<_> = got.into_iter().map(Result::unwrap).collect();
+    assert_eq!(want, got);
+}
+
+#[tokio::test]
+async fn partial_reads() {
+    let mut stream = Vec::new();
+    let want: Vec<_> = (0..5).map(monster).collect();
+    for buf in &want {
+        append_size_prefixed(&mut stream, buf).await.unwrap();
+    }
+    for &chunk in &[1, 3, 4, 7, 64] {
+        let got = collect(Chunked {
+            data: &stream,
+            chunk,
+        })
+        .await;
+        let got: Vec<_> = got.into_iter().map(Result::unwrap).collect();
+        assert_eq!(want, got, "chunk {}", chunk);
+    }
+}
+
+#[tokio::test]
+async fn stream_errors() {
+    let frame = |buf: &[u8]| {
+        let mut frame = (buf.len() as u32).to_le_bytes().to_vec();
+        frame.extend(buf);
+        frame
+    };
+    let orc = frame(monster(1).as_ref());
+    struct Test {
+        name: &'static str,
+        data: Vec<u8>,
+        want: &'static [fn(&Frame) -> bool],
+    }
+    let tests = [
+        Test {
+            name: "empty stream",
+            data: Vec::new(),
+            want: &[],
+        },
+        Test {
+            name: "corrupted frame in the middle",
+            data: [&orc[..], &frame(&[0xff; 8]), &orc].concat(),
+            want: &[
+                |got| got.is_ok(),
+                |got| matches!(got, Err(AioError::Invalid(_))),
+                |got| got.is_ok(),
+            ],
+        },
+        Test {
+            name: "truncated final frame",
+            data: [&orc[..], &orc[..orc.len() - 1]].concat(),
+            want: &[
+                |got| got.is_ok(),
+                |got| matches!(got, Err(AioError::Truncated { got, want }) if got + 1 == *want),
+            ],
+        },
+        Test {
+            name: "truncated final prefix",
+            data: [&orc[..], &orc[..2]].concat(),
+            want: &[
+                |got| got.is_ok(),
+                |got| matches!(got, Err(AioError::Truncated { want: 4, got: 2 })),
+            ],
+        },
+        Test {
+            name: "frame too large",
+            data: [&orc[..], &u32::MAX.to_le_bytes(), &orc].concat(),
+            want: &[
+                |got| got.is_ok(),
+                |got| matches!(got, Err(AioError::FrameTooLarge(len)) if *len == u32::MAX as usize),
+            ],
+        },
+    ];
+    for t in &tests {
+        let got = collect(&t.data[..]).await;
+        assert_eq!(t.want.len(), got.len(), "{}: {:?}", t.name, got);
+        for (want, got) in t.want.iter().zip(&got) {
+            assert!(want(got), "{}: {:?}", t.name, got);
+        }
+    }
+}
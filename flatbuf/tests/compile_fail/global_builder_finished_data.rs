@@ -0,0 +1,11 @@
+use flatbuf_tutorial::FlatBufferBuilderPool;
+
+fn main() {
+    let data = {
+        let mut b = FlatBufferBuilderPool::get_or_alloc();
+        let name = b.create_string("something fun");
+        b.finish(name, None);
+        b.finished_data()
+    };
+    println!("{:?}", data);
+}
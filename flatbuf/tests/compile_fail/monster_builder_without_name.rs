@@ -0,0 +1,8 @@
+use flatbuf_tutorial::Monster;
+use flatbuffers::FlatBufferBuilder;
+
+fn main() {
+    let mut b = FlatBufferBuilder::new();
+    let orc = Monster::builder().hp(80).weapon("axe", 5).build(&mut b);
+    b.finish(orc, None);
+}
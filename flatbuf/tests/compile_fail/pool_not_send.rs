@@ -0,0 +1,13 @@
+use std::rc::Rc;
+
+use flatbuf_tutorial::pool::{Pool, Recyclable};
+
+struct Shared(Rc<Vec<u8>>);
+
+impl Recyclable for Shared {
+    fn recycle(&mut self) {}
+}
+
+fn main() {
+    let _pool = Pool::new(0, 4, || Shared(Rc::new(Vec::new())));
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: GPL-2.0
+#![cfg(all(feature = "yaml", feature = "toml"))]
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use flatbuf_tutorial::model::my_game::sample::{Color, Vec3};
+use flatbuf_tutorial::monster::{self, Format, MonsterData, WeaponData};
+use flatbuf_tutorial::{FixtureError, MonsterView};
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+#[test]
+fn load_dir() {
+    let weapon = |name: &str, damage| WeaponData {
+        name: name.to_string(),
+        damage,
+    };
+    let want = vec![
+        MonsterData {
+            name: String::from("goblin"),
+            hp: 30,
+            color: Color::Green,
+            inventory: vec![1, 2],
+            weapons: vec![weapon("Dagger", 2)],
+            equipped: Some(0),
+            ..Default::default()
+        },
+        MonsterData {
+            name: String::from("orc"),
+            pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+            hp: 300,
+            inventory: (0..10).collect(),
+            color: Color::Red,
+            weapons: vec![weapon("Sword", 3), weapon("Axe", 5)],
+            equipped: Some(1),
+            path: vec![Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)],
+            ..Default::default()
+        },
+    ];
+    let got = monster::load_dir(fixtures()).unwrap();
+    let got: Vec<_> = got
+        .iter()
+        .map(|buf| MonsterData::from(buf.view()))
+        .collect();
+    assert_eq!(want, got);
+    assert_eq!(150, got[0].mana);
+}
+
+#[test]
+fn from_reader() {
+    struct Test {
+        name: &'static str,
+        file: &'static str,
+        want: &'static str,
+    }
+    let tests = [
+        Test {
+            name: "yaml",
+            file: "orc.yaml",
+            want: "orc",
+        },
+        Test {
+            name: "toml",
+            file: "goblin.toml",
+            want: "goblin",
+        },
+    ];
+    for t in &tests {
+        let path = fixtures().join(t.file);
+        let format = Format::from_path(&path).unwrap();
+        let buf = monster::from_reader(format, File::open(&path).unwrap()).unwrap();
+        let got = MonsterView::from_bytes(&buf).unwrap();
+        assert_eq!(Some(t.want), got.name(), "{}", t.name);
+    }
+}
+
+#[test]
+fn load_malformed_dir() {
+    match monster::load_dir(fixtures().join("malformed")) {
+        Err(FixtureError::File(path, err)) => {
+            // `unknown_field.toml` is the first one in the file name order.
+            assert_eq!(Some("unknown_field.toml".as_ref()), path.file_name());
+            match *err {
+                FixtureError::Parse { location, message } => {
+                    let location = location.unwrap();
+                    assert_eq!((3, 1), (location.line, location.column));
+                    assert!(message.contains("colour"), "{}", message);
+                }
+                err => panic!("unexpected {:?}", err),
+            }
+        }
+        got => panic!("unexpected {:?}", got),
+    }
+}
+
+#[test]
+fn malformed_fixtures() {
+    struct Test {
+        name: &'static str,
+        file: &'static str,
+        want: (usize, usize),
+    }
+    let tests = [
+        Test {
+            name: "wrong type",
+            file: "wrong_type.yaml",
+            want: (3, 7),
+        },
+        Test {
+            name: "unknown field",
+            file: "unknown_field.toml",
+            want: (3, 1),
+        },
+    ];
+    for t in &tests {
+        let path = fixtures().join("malformed").join(t.file);
+        let format = Format::from_path(&path).unwrap();
+        let err = monster::from_reader(format, File::open(&path).unwrap()).unwrap_err();
+        let got = err.location().map(|loc| (loc.line, loc.column));
+        assert_eq!(Some(t.want), got, "{}: {}", t.name, err);
+    }
+}
@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+# SPDX-License-Identifier: GPL-2.0
+"""Regenerate monster_python.bin with the official Python flatbuffers
+library, for tests/interop_test.rs.
+
+Not run in CI, which only checks the committed binary.  Run it from the
+crate directory after changing the schema:
+
+    $ pip install flatbuffers
+    $ python3 tests/fixtures/generate_fixture.py
+
+It needs flatc in PATH, or in FLATC_PATH, the same as build.rs.
+"""
+import os
+import subprocess
+import sys
+import tempfile
+
+SCHEMA = "schema/monster.fbs"
+FIXTURE = "tests/fixtures/monster_python.bin"
+
+
+def main():
+    import flatbuffers
+
+    flatc = os.environ.get("FLATC_PATH", "flatc")
+    with tempfile.TemporaryDirectory() as out:
+        subprocess.run([flatc, "--python", "-o", out, SCHEMA], check=True)
+        sys.path.insert(0, out)
+        from MyGame.Sample import Monster
+
+        b = flatbuffers.Builder(0)
+        name = b.CreateString("Orc")
+        Monster.Start(b)
+        Monster.AddName(b, name)
+        Monster.AddHp(b, 300)
+        # The schema default, which the builder omits.
+        Monster.AddMana(b, 150)
+        b.Finish(Monster.End(b))
+
+    with open(FIXTURE, "wb") as f:
+        f.write(b.Output())
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Replay the fuzz seeds and the crash artifacts checked into
+//! `fuzz/regressions/<target>/`.
+use std::fs;
+use std::path::Path;
+
+use flatbuf_tutorial::fuzzing;
+use proptest::prelude::*;
+
+/// Returns the contents of the files under `fuzz/<dir>/<target>`.
+fn inputs(dir: &str, target: &str) -> Vec<Vec<u8>> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fuzz")
+        .join(dir)
+        .join(target);
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        // Skip `.gitkeep`.
+        .filter(|path| !path.file_name().unwrap().to_string_lossy().starts_with('.'))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| fs::read(path).unwrap()).collect()
+}
+
+#[test]
+fn monster_regressions() {
+    for data in inputs("corpus", "monster")
+        .iter()
+        .chain(&inputs("regressions", "monster"))
+    {
+        fuzzing::monster(data);
+    }
+}
+
+#[test]
+fn size_prefixed_regressions() {
+    for data in inputs("corpus", "size_prefixed")
+        .iter()
+        .chain(&inputs("regressions", "size_prefixed"))
+    {
+        fuzzing::size_prefixed_frames(data);
+    }
+}
+
+/// Flip, truncate and extend the seeds on the stable toolchain, where
+/// `cargo fuzz` is not available.
+fn mutated(target: &'static str) -> impl Strategy<Value = Vec<u8>> {
+    let seeds = inputs("corpus", target);
+    (
+        0..seeds.len(),
+        proptest::collection::vec((any::<prop::sample::Index>(), any::<u8>()), 0..8),
+        any::<prop::sample::Index>(),
+        proptest::collection::vec(any::<u8>(), 0..16),
+    )
+        .prop_map(move |(seed, flips, truncate, extend)| {
+            let mut data = seeds[seed].clone();
+            for (i, byte) in flips {
+                let i = i.index(data.len());
+                data[i] ^= byte;
+            }
+            data.truncate(truncate.index(data.len() + 1));
+            data.extend(extend);
+            data
+        })
+}
+
+proptest! {
+    #[test]
+    fn mutated_monster(data in mutated("monster")) {
+        fuzzing::monster(&data);
+    }
+    #[test]
+    fn mutated_size_prefixed(data in mutated("size_prefixed")) {
+        fuzzing::size_prefixed_frames(&data);
+    }
+}
@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Golden files of the serialized monsters, which catch the wire format
+//! changes, e.g. the reshuffled field ids or the changed default
+//! handling, in the review.
+//!
+//! Rewrite the fixtures in `tests/golden/` after the intended change:
+//!
+//! ```sh
+//! $ UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+//!
+//! `typical_flatc.bin` is built by the upstream flatc from the same
+//! monster in JSON:
+//!
+//! ```sh
+//! $ flatc --binary -o tests/golden schema/monster.fbs tests/golden/typical_flatc.json
+//! ```
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use flatbuf_tutorial::model::my_game::sample::{Color, Vec3};
+use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+use flatbuf_tutorial::{MonsterError, MonsterView};
+use flatbuffers::FlatBufferBuilder;
+
+fn golden(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name)
+}
+
+/// Build `data` with the fresh builder, without the forced defaults.
+fn build(data: &MonsterData) -> Vec<u8> {
+    let mut b = FlatBufferBuilder::new();
+    let monster = data.build(&mut b);
+    b.finish(monster, None);
+    b.finished_data().to_vec()
+}
+
+/// Returns the fields which differ between the `want` and `got`
+/// buffers, one per line.
+fn field_diff(want: &[u8], got: &[u8]) -> String {
+    let (want, got) = match (MonsterView::from_bytes(want), MonsterView::from_bytes(got)) {
+        (Ok(want), Ok(got)) => (want, got),
+        (want, got) => return format!("  want: {:?}\n  got:  {:?}\n", want.err(), got.err()),
+    };
+    let mut diff = String::new();
+    let mut field = |name: &str, want: &dyn Debug, got: &dyn Debug| {
+        let (want, got) = (format!("{:?}", want), format!("{:?}", got));
+        if want != got {
+            diff += &format!("  {}: want {}, got {}\n", name, want, got);
+        }
+    };
+    field("has_mana", &want.has_mana(), &got.has_mana());
+    field("has_hp", &want.has_hp(), &got.has_hp());
+    field("has_color", &want.has_color(), &got.has_color());
+    let (want, got) = (MonsterData::from(want), MonsterData::from(got));
+    field("name", &want.name, &got.name);
+    field("pos", &want.pos, &got.pos);
+    field("mana", &want.mana, &got.mana);
+    field("hp", &want.hp, &got.hp);
+    field("inventory", &want.inventory, &got.inventory);
+    field("color", &want.color, &got.color);
+    field("weapons", &want.weapons, &got.weapons);
+    field("equipped", &want.equipped, &got.equipped);
+    field("path", &want.path, &got.path);
+    if diff.is_empty() {
+        diff += "  same fields, different layout\n";
+    }
+    diff
+}
+
+/// Compare `got` with the golden file `name`, or rewrite the file with
+/// `UPDATE_GOLDEN=1`.
+fn check(name: &str, got: &[u8]) {
+    let path = golden(name);
+    if env::var_os("UPDATE_GOLDEN").is_some_and(|v| v == "1") {
+        fs::write(&path, got).unwrap();
+        return;
+    }
+    let want = fs::read(&path).unwrap_or_else(|err| {
+        panic!(
+            "{}: {}, run with UPDATE_GOLDEN=1 to create it",
+            path.display(),
+            err
+        )
+    });
+    if want == got {
+        return;
+    }
+    let offset = want
+        .iter()
+        .zip(got)
+        .position(|(a, b)| a != b)
+        .unwrap_or_else(|| want.len().min(got.len()));
+    panic!(
+        "{}: first differing byte at offset {:#x} ({} bytes, want {}):\n{}",
+        name,
+        offset,
+        got.len(),
+        want.len(),
+        field_diff(&want, got),
+    );
+}
+
+fn typical() -> MonsterData {
+    MonsterData {
+        name: String::from("Orc"),
+        pos: Some(Vec3::new(1.0, 2.0, 3.0)),
+        mana: 150,
+        hp: 300,
+        inventory: (0..10).collect(),
+        color: Color::Red,
+        weapons: vec![
+            WeaponData {
+                name: String::from("Sword"),
+                damage: 3,
+            },
+            WeaponData {
+                name: String::from("Axe"),
+                damage: 5,
+            },
+        ],
+        equipped: Some(1),
+        path: vec![Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)],
+    }
+}
+
+#[test]
+fn golden_files() {
+    struct Test {
+        name: &'static str,
+        data: MonsterData,
+    }
+    let tests = [
+        Test {
+            name: "minimal.bin",
+            data: MonsterData::default(),
+        },
+        Test {
+            name: "typical.bin",
+            data: typical(),
+        },
+        Test {
+            name: "maximal.bin",
+            data: MonsterData {
+                name: "M".repeat(256),
+                pos: Some(Vec3::new(f32::MIN, f32::MAX, f32::EPSILON)),
+                mana: i16::MAX,
+                hp: i16::MIN,
+                inventory: (0..=255).collect(),
+                color: Color::Green,
+                weapons: (0..16)
+                    .map(|i| WeaponData {
+                        name: format!("Weapon{}", i),
+                        damage: i16::MAX - i,
+                    })
+                    .collect(),
+                equipped: Some(15),
+                path: (0..32)
+                    .map(|i| Vec3::new(i as f32, -(i as f32), i as f32 * 0.5))
+                    .collect(),
+            },
+        },
+        Test {
+            name: "unicode_name.bin",
+            data: MonsterData {
+                name: String::from("Ørk オーク 🐉"),
+                ..Default::default()
+            },
+        },
+    ];
+    for t in &tests {
+        let got = build(&t.data);
+        check(t.name, &got);
+        // The golden file is still the monster it was built from.
+        let view = MonsterView::from_bytes(&got).unwrap();
+        assert_eq!(t.data, MonsterData::from(view), "{}", t.name);
+    }
+}
+
+#[test]
+fn flatc_binary() {
+    let buf = fs::read(golden("typical_flatc.bin")).unwrap();
+    let orc = MonsterView::from_bytes(&buf).unwrap();
+    // flatc builds the `equipped` union as the copy of the weapon, which
+    // is not the one in `weapons`.
+    assert_eq!(
+        Err(vec![MonsterError::EquippedNotInWeapons]),
+        orc.validate()
+    );
+    let want = MonsterData {
+        equipped: None,
+        ..typical()
+    };
+    assert_eq!(want, MonsterData::from(orc));
+    assert_eq!(
+        Some(("Axe", 5)),
+        orc.equipped().map(|w| (w.name().unwrap(), w.damage()))
+    );
+    // Same fields as the Rust built one, though flatc may lay them out
+    // in another order.
+    let got = build(&typical());
+    assert_eq!(
+        "  equipped: want None, got Some(1)\n",
+        field_diff(&buf, &got)
+    );
+}
+
+#[test]
+fn diff_on_mismatch() {
+    let want = build(&typical());
+    let got = build(&MonsterData {
+        hp: 301,
+        ..typical()
+    });
+    assert_eq!("  hp: want 300, got 301\n", field_diff(&want, &got));
+    assert_eq!(
+        "  same fields, different layout\n",
+        field_diff(&want, &want)
+    );
+    assert!(field_diff(&want, &[0xff; 3]).contains("got:  Some("));
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Interop with the flatc generated code, e.g. the C++ service.
+//!
+//! `tests/fixtures/flatc/<name>.bin` is built by flatc from `<name>.json`
+//! in the flatc JSON, and `<name>.expected.json` is the same monster as
+//! `MonsterData` in JSON.  Rebuild the binaries after the schema change
+//! with [`regenerate_fixtures`]:
+//!
+//! ```sh
+//! $ UPDATE_FLATC=1 cargo test --features serde --test interop
+//! ```
+#![cfg(feature = "serde")]
+use std::env;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+use flatbuf_tutorial::{MonsterError, MonsterView, VerifyOpts};
+use flatbuffers::FlatBufferBuilder;
+
+/// Limits of the flatc generated C++ `Verifier` by default.
+const FLATC_VERIFY_OPTS: VerifyOpts = VerifyOpts {
+    max_depth: 64,
+    max_tables: 1_000_000,
+    max_apparent_size: 1 << 31,
+};
+
+fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Returns the `<name>.json` fixtures in the name order.
+fn fixtures() -> Vec<PathBuf> {
+    let mut paths: Vec<_> = fs::read_dir(crate_dir().join("tests/fixtures/flatc"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.ends_with(".json") && !name.ends_with(".expected.json")
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn expected(json: &Path) -> MonsterData {
+    let path = json.with_extension("expected.json");
+    let file = File::open(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+    serde_json::from_reader(file).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+}
+
+/// Find flatc through `FLATC_PATH`, then `PATH`, the same as `build.rs`.
+fn flatc() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("FLATC_PATH") {
+        return Some(PathBuf::from(path)).filter(|path| path.is_file());
+    }
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join("flatc"))
+        .find(|path| path.is_file())
+}
+
+/// Rebuild `<name>.bin` of all the fixtures with flatc, which is
+///
+/// ```sh
+/// $ flatc --binary -o tests/fixtures/flatc schema/monster.fbs tests/fixtures/flatc/*.json
+/// ```
+///
+/// without the `*.expected.json`.  Returns the rebuilt binaries, or
+/// `None` without flatc.
+fn regenerate_fixtures() -> Option<io::Result<Vec<PathBuf>>> {
+    let flatc = flatc()?;
+    let jsons = fixtures();
+    let status = Command::new(&flatc)
+        .current_dir(crate_dir())
+        .args([
+            "--binary",
+            "-o",
+            "tests/fixtures/flatc",
+            "schema/monster.fbs",
+        ])
+        .args(&jsons)
+        .status();
+    Some(status.and_then(|status| {
+        if !status.success() {
+            return Err(io::Error::other(format!("{}: {}", flatc.display(), status)));
+        }
+        jsons
+            .iter()
+            .map(|json| {
+                let bin = json.with_extension("bin");
+                match fs::metadata(&bin) {
+                    Ok(_) => Ok(bin),
+                    Err(err) => Err(io::Error::new(
+                        err.kind(),
+                        format!("{}: {}", bin.display(), err),
+                    )),
+                }
+            })
+            .collect()
+    }))
+}
+
+/// Compare the monster read from the flatc built `buf` with `want`.
+///
+/// flatc builds the `equipped` union from the JSON as the copy of the
+/// weapon, which is not the one in `weapons`, so it's compared by the
+/// value instead of the index, and isn't valid for `validate`.
+fn assert_flatc_monster(name: &str, want: &MonsterData, buf: &[u8]) {
+    let view = MonsterView::from_bytes(buf).unwrap_or_else(|err| {
+        panic!(
+            "{}: {}, the schema and the model may have drifted",
+            name, err
+        )
+    });
+    let valid = match want.equipped {
+        Some(_) => Err(vec![MonsterError::EquippedNotInWeapons]),
+        None => Ok(()),
+    };
+    assert_eq!(valid, view.validate(), "{}", name);
+    let equipped = view.equipped().map(|weapon| WeaponData {
+        name: weapon.name().unwrap_or_default().to_string(),
+        damage: weapon.damage(),
+    });
+    assert_eq!(
+        want.equipped.map(|i| &want.weapons[i]),
+        equipped.as_ref(),
+        "{}",
+        name
+    );
+    let got = MonsterData {
+        equipped: want.equipped,
+        ..MonsterData::from(view)
+    };
+    assert_eq!(*want, got, "{}", name);
+}
+
+#[test]
+fn read_flatc_fixtures() {
+    if env::var_os("UPDATE_FLATC").is_some_and(|v| v == "1") {
+        regenerate_fixtures()
+            .expect("flatc not found")
+            .expect("regenerate the flatc fixtures");
+    }
+    for json in fixtures() {
+        let bin = json.with_extension("bin");
+        let buf = fs::read(&bin).unwrap_or_else(|err| panic!("{}: {}", bin.display(), err));
+        assert_flatc_monster(&bin.display().to_string(), &expected(&json), &buf);
+    }
+}
+
+#[test]
+fn build_for_flatc() {
+    let jsons = fixtures();
+    assert!(!jsons.is_empty());
+    for json in jsons {
+        let want = expected(&json);
+        let mut b = FlatBufferBuilder::new();
+        let monster = want.build(&mut b);
+        b.finish(monster, None);
+        let view = MonsterView::from_bytes_with_opts(b.finished_data(), FLATC_VERIFY_OPTS)
+            .unwrap_or_else(|err| panic!("{}: {}", json.display(), err));
+        assert_eq!(want, MonsterData::from(view), "{}", json.display());
+    }
+}
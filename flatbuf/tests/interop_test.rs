@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Read the monster built by the official Python flatbuffers library, see
+//! `tests/fixtures/generate_fixture.py`.
+use std::path::Path;
+
+use flatbuf_tutorial::model::my_game::sample::Color;
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::MonsterView;
+
+#[test]
+fn python_monster() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/monster_python.bin");
+    let buf = std::fs::read(path).unwrap();
+    let orc = MonsterView::from_bytes(&buf).unwrap();
+    assert_eq!(Ok(()), orc.validate());
+    assert_eq!(Some("Orc"), orc.name());
+    assert_eq!(300, orc.hp());
+    assert_eq!(150, orc.mana());
+    assert_eq!(None, orc.pos());
+    assert_eq!(Color::Blue, orc.color());
+    assert_eq!(None, orc.inventory());
+    assert_eq!(0, orc.weapons().count());
+    assert!(orc.equipped().is_none());
+    assert_eq!(0, orc.path().count());
+
+    // Same monster as the Rust built one, regardless of the layout.
+    let want = MonsterData {
+        name: String::from("Orc"),
+        hp: 300,
+        ..Default::default()
+    };
+    assert_eq!(want, MonsterData::from(orc));
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: GPL-2.0
+#![cfg(feature = "mmap")]
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use flatbuf_tutorial::model::my_game::sample::{Color, Vec3};
+use flatbuf_tutorial::monster::{MonsterData, WeaponData};
+use flatbuf_tutorial::{MmapError, Monster, MonsterBuf, MonsterError};
+use flatbuffers::FlatBufferBuilder;
+
+fn temp_file(name: &str, data: &[u8]) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, data).unwrap();
+    path
+}
+
+fn monster(i: usize) -> MonsterData {
+    let weapons: Vec<_> = (0..i % 3)
+        .map(|j| WeaponData {
+            name: format!("weapon{}", j),
+            damage: j as i16,
+        })
+        .collect();
+    MonsterData {
+        name: format!("monster{}", i),
+        pos: Some(Vec3::new(i as f32, 0.0, -(i as f32))),
+        hp: (i % 300) as i16,
+        inventory: (0..(i % 5) as u8).collect(),
+        color: Color::ALL[i % 3],
+        equipped: if weapons.is_empty() { None } else { Some(0) },
+        weapons,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn open_mmap_list() {
+    let monsters: Vec<_> = (0..10_000).map(monster).collect();
+    let mut b = FlatBufferBuilder::new();
+    let list = MonsterData::build_list(&monsters, &mut b);
+    b.finish(list, None);
+    let path = temp_file("open_mmap_list.bin", b.finished_data());
+
+    let list = MonsterBuf::open_mmap_list(&path).unwrap();
+    assert_eq!(monsters.len(), list.len());
+    assert_eq!(b.finished_data(), list.as_ref());
+    // Xorshift to pick the elements reproducibly.
+    let mut x = 0x2545_f491_4f6c_dd1du64;
+    for _ in 0..1_000 {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        let i = (x % monsters.len() as u64) as usize;
+        assert_eq!(
+            monsters[i],
+            MonsterData::from(list.get(i).unwrap()),
+            "{}",
+            i
+        );
+    }
+    assert!(list.get(monsters.len()).is_none());
+    assert_eq!(monsters.len(), list.iter().count());
+    assert_eq!(
+        monsters.last(),
+        list.iter().last().map(MonsterData::from).as_ref()
+    );
+}
+
+#[test]
+fn open_mmap_empty_list() {
+    let mut b = FlatBufferBuilder::new();
+    let list = MonsterData::build_list(&[], &mut b);
+    b.finish(list, None);
+    let path = temp_file("open_mmap_empty_list.bin", b.finished_data());
+
+    let list = MonsterBuf::open_mmap_list(&path).unwrap();
+    assert!(list.is_empty());
+    assert!(list.get(0).is_none());
+    assert_eq!(0, list.iter().count());
+}
+
+#[test]
+fn open_mmap() {
+    let data = monster(7);
+    let want = Monster::build_pooled(&data);
+    let path = temp_file("open_mmap.bin", want.as_ref());
+
+    let got = MonsterBuf::open_mmap(&path).unwrap();
+    assert_eq!(want.as_ref(), got.as_ref());
+    assert_eq!(Some("monster7"), got.name());
+    assert_eq!(7, got.hp());
+    assert_eq!(data, MonsterData::from(got.view()));
+}
+
+#[test]
+fn open_mmap_errors() {
+    let orc = Monster::build_pooled(&monster(1));
+    let mut list = FlatBufferBuilder::new();
+    let root = MonsterData::build_list(&[monster(1), monster(2)], &mut list);
+    list.finish(root, None);
+    let list = list.finished_data();
+
+    struct Test {
+        name: &'static str,
+        data: Vec<u8>,
+        list: bool,
+        want: fn(&MmapError) -> bool,
+    }
+    let tests = [
+        Test {
+            name: "empty",
+            data: vec![],
+            list: true,
+            want: |err| matches!(err, MmapError::TooShort(0)),
+        },
+        Test {
+            name: "shorter than header",
+            data: orc.as_ref()[..7].to_vec(),
+            list: false,
+            want: |err| matches!(err, MmapError::TooShort(7)),
+        },
+        Test {
+            name: "truncated",
+            data: orc.as_ref()[..orc.as_ref().len() / 2].to_vec(),
+            list: false,
+            want: |err| matches!(err, MmapError::Invalid(MonsterError::Invalid(_))),
+        },
+        Test {
+            name: "truncated list",
+            data: list[..list.len() - 8].to_vec(),
+            list: true,
+            want: |err| matches!(err, MmapError::Invalid(MonsterError::Invalid(_))),
+        },
+    ];
+    for t in &tests {
+        let path = temp_file(&format!("open_mmap_errors-{}.bin", t.name), &t.data);
+        let got = if t.list {
+            MonsterBuf::open_mmap_list(&path).map(drop)
+        } else {
+            MonsterBuf::open_mmap(&path).map(drop)
+        };
+        match got {
+            Err(err) => assert!((t.want)(&err), "{}: {:?}", t.name, err),
+            Ok(()) => panic!("{}: unexpected success", t.name),
+        }
+    }
+    let got = MonsterBuf::open_mmap("does/not/exist.bin").unwrap_err();
+    assert!(matches!(got, MmapError::Io(_)), "{:?}", got);
+}
@@ -0,0 +1,65 @@
+use std::{collections::BTreeSet, fs};
+
+use flatbuf_tutorial::model::my_game::sample::{
+    finish_monster_buffer, root_as_monster, Color, Equipment, Monster, MonsterArgs, MonsterBuilder,
+    Vec3, Weapon, WeaponArgs, WeaponBuilder,
+};
+use flatbuf_tutorial::model::{CHECKED_IN_SCHEMA_HASH, SCHEMA_HASH};
+use flatbuffers::FlatBufferBuilder;
+
+/// Names of the public items declared in the generated `code`.
+fn public_items(code: &str) -> BTreeSet<String> {
+    code.lines()
+        .map(str::trim_start)
+        .filter_map(|line| line.strip_prefix("pub "))
+        .map(|line| line.trim_start_matches("unsafe "))
+        .filter_map(|line| line.split_whitespace().nth(1).or(Some(line)))
+        .map(|name| {
+            name.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn model_public_items() {
+    let mut b = FlatBufferBuilder::new();
+    let name = b.create_string("axe");
+    let axe = Weapon::create(
+        &mut b,
+        &WeaponArgs {
+            name: Some(name),
+            damage: 5,
+        },
+    );
+    let mut w = WeaponBuilder::new(&mut b);
+    w.add_damage(3);
+    let _sword = w.finish();
+    let name = b.create_string("orc");
+    let mut m = MonsterBuilder::new(&mut b);
+    m.add_name(name);
+    m.add_pos(&Vec3::new(1.0, 2.0, 3.0));
+    m.add_color(Color::Red);
+    m.add_equipped_type(Equipment::Weapon);
+    m.add_equipped(axe.as_union_value());
+    let orc = m.finish();
+    finish_monster_buffer(&mut b, orc);
+    let orc: Monster = root_as_monster(b.finished_data()).unwrap();
+    assert_eq!(Some("orc"), orc.name());
+    assert_eq!(Some("axe"), orc.equipped_as_weapon().unwrap().name());
+    assert_eq!(MonsterArgs::default().hp, orc.hp());
+    assert_eq!(SCHEMA_HASH, CHECKED_IN_SCHEMA_HASH);
+}
+
+#[test]
+#[cfg_attr(not(flatc_model), ignore)]
+fn regenerated_model_public_items() {
+    let checked_in = include_str!("../src/monster_generated.rs");
+    let regenerated =
+        fs::read_to_string(concat!(env!("OUT_DIR"), "/monster_generated.rs")).unwrap();
+    let want = public_items(checked_in);
+    assert!(want.contains("root_as_monster"));
+    assert_eq!(want, public_items(&regenerated));
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: GPL-2.0
+#![cfg(feature = "cli")]
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn monster_gen(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_monster-gen"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+/// Returns the empty directory for the test.
+fn tempdir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn gen_and_dump() {
+    let dir = tempdir("gen_and_dump");
+    let orc = dir.join("orc.bin");
+    let orc = orc.to_str().unwrap();
+    let args = [
+        "gen",
+        "--name",
+        "Orc",
+        "--hp",
+        "80",
+        "--color",
+        "green",
+        "--weapons",
+        "Sword:3,Axe:5",
+        "--equipped",
+        "1",
+        "-o",
+        orc,
+    ];
+    let got = monster_gen(&args);
+    assert!(got.status.success(), "{:?}", got);
+
+    let got = monster_gen(&["dump", orc]);
+    assert!(got.status.success(), "{:?}", got);
+    let got = stdout(&got);
+    for want in &[
+        r#""name": "Orc""#,
+        r#""hp": 80"#,
+        r#""color": "Green""#,
+        r#""name": "Axe""#,
+        r#""equipped": 1"#,
+    ] {
+        assert!(got.contains(want), "{} not in {}", want, got);
+    }
+
+    let got = monster_gen(&["verify", orc]);
+    assert!(got.status.success(), "{:?}", got);
+    assert_eq!(format!("{}: ok\n", orc), stdout(&got));
+}
+
+#[test]
+fn corrupted_file() {
+    let dir = tempdir("corrupted_file");
+    let orc = dir.join("orc.bin");
+    let got = monster_gen(&["gen", "--name", "Orc", "-o", orc.to_str().unwrap()]);
+    assert!(got.status.success(), "{:?}", got);
+    let mut buf = fs::read(&orc).unwrap();
+    buf.truncate(buf.len() / 2);
+    fs::write(&orc, buf).unwrap();
+
+    for command in &["verify", "dump"] {
+        let got = monster_gen(&[command, orc.to_str().unwrap()]);
+        assert_eq!(Some(1), got.status.code(), "{}", command);
+        assert!(got.stdout.is_empty(), "{}", command);
+        let stderr = String::from_utf8(got.stderr).unwrap();
+        assert!(stderr.contains("invalid monster buffer"), "{}", stderr);
+    }
+}
+
+#[test]
+fn rand_is_reproducible() {
+    let a = tempdir("rand_a");
+    let b = tempdir("rand_b");
+    for dir in &[&a, &b] {
+        let got = monster_gen(&[
+            "rand",
+            "--seed",
+            "42",
+            "-n",
+            "10",
+            "-o",
+            dir.to_str().unwrap(),
+        ]);
+        assert!(got.status.success(), "{:?}", got);
+    }
+    let mut files: Vec<_> = fs::read_dir(&a)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    files.sort();
+    assert_eq!(10, files.len());
+    for file in &files {
+        assert_eq!(
+            fs::read(a.join(file)).unwrap(),
+            fs::read(b.join(file)).unwrap()
+        );
+        let got = monster_gen(&["verify", a.join(file).to_str().unwrap()]);
+        assert!(got.status.success(), "{:?}", got);
+    }
+}
+
+#[test]
+fn usage() {
+    for args in &[&[][..], &["gen", "-o", "orc.bin"], &["dump"]] {
+        let got = monster_gen(args);
+        assert_eq!(Some(2), got.status.code(), "{:?}", args);
+        let stderr = String::from_utf8(got.stderr).unwrap();
+        assert!(stderr.contains("usage: monster-gen"), "{}", stderr);
+    }
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: GPL-2.0
+#![cfg(feature = "async")]
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::Monster;
+use tokio::net::TcpListener;
+
+#[path = "../examples/monster_tcp_client.rs"]
+#[allow(dead_code)]
+mod client;
+#[path = "../examples/monster_tcp_server.rs"]
+#[allow(dead_code)]
+mod server;
+
+#[tokio::test]
+async fn echo_doubles_hp() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(server::serve(listener));
+
+    let orc = client::request(addr, &client::build_orc()).await.unwrap();
+    assert_eq!(Some("Orc"), orc.name());
+    assert_eq!(600, orc.hp());
+    assert_eq!(150, orc.view().mana());
+
+    // Omitted as the default, and re-serialized by the server.
+    let goblin = Monster::build_pooled(&MonsterData {
+        name: String::from("goblin"),
+        ..Default::default()
+    });
+    assert!(!goblin.view().has_hp());
+    let goblin = client::request(addr, &goblin).await.unwrap();
+    assert_eq!(Some("goblin"), goblin.name());
+    assert_eq!(200, goblin.hp());
+}
@@ -0,0 +1,58 @@
+//! Reject the global pool sizes changed after the first `get`, in its own
+//! process so that the global pool is not initialized yet.
+use std::panic;
+
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+
+#[test]
+#[cfg_attr(not(debug_assertions), ignore = "only warns in the release build")]
+fn configure_after_get() {
+    // Sequentially, as both share the global pool.
+    replace_before_get();
+    reject_after_get();
+}
+
+/// The pool replaced before the first `get` doesn't settle the sizes.
+fn replace_before_get() {
+    FlatBufferBuilderPool::init_global_pool_size(2);
+    FlatBufferBuilderPool::replace_global_pool(4, 128);
+    FlatBufferBuilderPool::init_global_pool_size(1);
+    FlatBufferBuilderPool::max_global_pool_size(4);
+
+    // From the replaced pool, pre-filled with the two builders.
+    let a = FlatBufferBuilderPool::get().unwrap();
+    let b = FlatBufferBuilderPool::get().unwrap();
+    let c = FlatBufferBuilderPool::get().unwrap();
+    let stats = FlatBufferBuilderPool::global_stats();
+    assert_eq!((2, 1), (stats.hits, stats.misses));
+    drop((a, b, c));
+}
+
+fn reject_after_get() {
+    for (name, configure) in [
+        (
+            "init_global_pool_size",
+            FlatBufferBuilderPool::init_global_pool_size as fn(usize),
+        ),
+        (
+            "max_global_pool_size",
+            FlatBufferBuilderPool::max_global_pool_size,
+        ),
+    ]
+    .iter()
+    {
+        let err = panic::catch_unwind(|| configure(8)).unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert_eq!(
+            "pool already initialized — call configuration before first get()", msg,
+            "{}",
+            name
+        );
+    }
+
+    // The buffer capacity is still applicable to the new builders.
+    FlatBufferBuilderPool::global_buffer_capacity(128);
+    // And the global pool is replaceable.
+    FlatBufferBuilderPool::replace_global_pool(8, 128);
+    drop(FlatBufferBuilderPool::get().unwrap());
+}
@@ -0,0 +1,58 @@
+//! Fingerprints of the global builders across the pool cycles, in its
+//! own process so that the other tests don't share the global pool or
+//! the logger.
+use std::sync::Mutex;
+
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Logger which captures the debug messages.
+struct Capture(Mutex<Vec<String>>);
+
+impl Log for Capture {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= Level::Debug
+    }
+    fn log(&self, record: &Record<'_>) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn fingerprint_across_pool_cycles() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+    FlatBufferBuilderPool::init_global_pool_size(1);
+    FlatBufferBuilderPool::max_global_pool_size(1);
+
+    // The same builder is reused.
+    let mut b = FlatBufferBuilderPool::get().unwrap();
+    let fingerprint = b.fingerprint();
+    let name = b.create_string("something fun");
+    b.finish(name, None);
+    drop(b);
+    let b = FlatBufferBuilderPool::get().unwrap();
+    assert_eq!(fingerprint, b.fingerprint());
+
+    // The new one overflows the pool.
+    let other = FlatBufferBuilderPool::get().unwrap();
+    let overflowed = other.fingerprint();
+    assert_ne!(fingerprint, overflowed);
+    drop(b);
+    drop(other);
+    let b = FlatBufferBuilderPool::get().unwrap();
+    assert_eq!(fingerprint, b.fingerprint());
+
+    b.discard();
+    assert_ne!(fingerprint, FlatBufferBuilderPool::get().unwrap().fingerprint());
+    assert_eq!(
+        vec![
+            format!("discarded the global builder {:016x}", overflowed),
+            format!("discarded the global builder {:016x}", fingerprint),
+        ],
+        *LOGGER.0.lock().unwrap()
+    );
+}
@@ -0,0 +1,112 @@
+//! Cap the outstanding global builders with `get`, in its own process so
+//! that the other tests don't share the global pool.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Barrier;
+use std::thread;
+
+use flatbuf_tutorial::pool::v3::{FlatBufferBuilderPool, GlobalBuilder};
+use flatbuf_tutorial::PoolExhausted;
+
+const HARD_LIMIT: usize = 4;
+const THREADS: usize = 16;
+const GETS: usize = 1_000;
+
+#[test]
+fn global_hard_limit() {
+    // Sequentially, as both share the global pool.
+    exhausted();
+    concurrent();
+    defaulted();
+}
+
+fn exhausted() {
+    FlatBufferBuilderPool::init_global_pool_size(1);
+    FlatBufferBuilderPool::max_global_pool_size(1);
+    FlatBufferBuilderPool::global_hard_limit(2);
+    assert_eq!(0, FlatBufferBuilderPool::global_outstanding());
+
+    // The pooled one, and the allocated one under the limit.
+    let a = FlatBufferBuilderPool::get().unwrap();
+    let b = FlatBufferBuilderPool::get().unwrap();
+    assert_eq!(2, FlatBufferBuilderPool::global_outstanding());
+    let before = FlatBufferBuilderPool::global_stats();
+    let err = FlatBufferBuilderPool::get().err().unwrap();
+    assert_eq!(PoolExhausted { hard_limit: 2 }, err);
+    assert_eq!(
+        "global pool exhausted at 2 outstanding builders",
+        err.to_string()
+    );
+    // Not counted as the miss, as nothing is allocated.
+    assert_eq!(before, FlatBufferBuilderPool::global_stats());
+    assert_eq!(2, FlatBufferBuilderPool::global_outstanding());
+
+    // get_or_alloc still allocates over the limit, and get_with_capacity
+    // as well.
+    let c = FlatBufferBuilderPool::get_or_alloc();
+    let d = FlatBufferBuilderPool::get_with_capacity(4_096);
+    assert_eq!(4, FlatBufferBuilderPool::global_outstanding());
+
+    // Capped by the outstanding ones, even with the pooled one.
+    drop(a);
+    assert_eq!(3, FlatBufferBuilderPool::global_outstanding());
+    assert!(FlatBufferBuilderPool::get().is_err());
+    drop((b, c));
+    let a = FlatBufferBuilderPool::get().unwrap();
+    assert!(FlatBufferBuilderPool::get().is_err());
+
+    drop((a, d));
+    assert_eq!(0, FlatBufferBuilderPool::global_outstanding());
+    FlatBufferBuilderPool::global_hard_limit(usize::MAX);
+    assert!(FlatBufferBuilderPool::get().is_ok());
+}
+
+/// Concurrent `get`s never go over the limit.
+fn concurrent() {
+    FlatBufferBuilderPool::global_hard_limit(HARD_LIMIT);
+    let held = AtomicUsize::new(0);
+    let max_held = AtomicUsize::new(0);
+    let exhausted = AtomicUsize::new(0);
+    let barrier = Barrier::new(THREADS);
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                barrier.wait();
+                for _ in 0..GETS {
+                    match FlatBufferBuilderPool::get() {
+                        Ok(b) => {
+                            let n = held.fetch_add(1, Ordering::SeqCst) + 1;
+                            max_held.fetch_max(n, Ordering::SeqCst);
+                            assert!(FlatBufferBuilderPool::global_outstanding() <= HARD_LIMIT);
+                            thread::yield_now();
+                            held.fetch_sub(1, Ordering::SeqCst);
+                            drop(b);
+                        }
+                        Err(err) => {
+                            assert_eq!(
+                                PoolExhausted {
+                                    hard_limit: HARD_LIMIT
+                                },
+                                err
+                            );
+                            exhausted.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    let max_held = max_held.into_inner();
+    assert!(max_held <= HARD_LIMIT, "{} held", max_held);
+    assert!(exhausted.into_inner() > 0);
+    assert_eq!(0, FlatBufferBuilderPool::global_outstanding());
+}
+
+/// The defaulted builder takes the outstanding slot, and gives it back.
+fn defaulted() {
+    let before = FlatBufferBuilderPool::global_outstanding();
+    let b = GlobalBuilder::default();
+    assert_eq!(before + 1, FlatBufferBuilderPool::global_outstanding());
+    drop(b);
+    assert_eq!(before, FlatBufferBuilderPool::global_outstanding());
+    assert!(FlatBufferBuilderPool::get().is_ok());
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Leak detector of the global builders, in its own process so that
+//! the builders of the other tests are not reported.
+#![cfg(feature = "debug")]
+use flatbuf_tutorial::pool::debug::{check_for_leaks, outstanding};
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+
+#[test]
+fn forgotten_builder() {
+    assert!(check_for_leaks().is_empty());
+
+    let builders: Vec<_> = (0..4).map(|_| FlatBufferBuilderPool::get().unwrap()).collect();
+    assert_eq!(4, outstanding());
+    drop(builders);
+    assert_eq!(0, outstanding());
+    assert!(check_for_leaks().is_empty());
+
+    let b = FlatBufferBuilderPool::get().unwrap();
+    let fingerprint = b.fingerprint();
+    std::mem::forget(b);
+    let leaked = check_for_leaks();
+    assert_eq!(1, leaked.len());
+    assert_eq!(fingerprint, leaked[0].fingerprint);
+    assert!(!leaked[0].backtrace.is_empty());
+    assert!(
+        leaked[0].backtrace.contains("forgotten_builder"),
+        "{}",
+        leaked[0].backtrace
+    );
+}
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: GPL-2.0
+//! Global pool metrics, in its own process so that the other tests
+//! don't share the global pool.
+#![cfg(feature = "metrics")]
+use std::sync::{Arc, Mutex};
+
+use flatbuf_tutorial::pool::metrics_integration::{BUFFER_BYTES, HITS, MISSES, SIZE};
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+use metrics::{
+    Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+    SharedString, Unit,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Counter(&'static str, u64),
+    Gauge(&'static str, f64),
+    Histogram(&'static str, f64),
+}
+
+type Events = Arc<Mutex<Vec<Event>>>;
+
+/// Recorder which captures the emitted metrics in the order.
+#[derive(Default)]
+struct Capture(Events);
+
+struct Handle {
+    name: &'static str,
+    events: Events,
+}
+
+impl Capture {
+    fn handle(&self, key: &Key) -> Arc<Handle> {
+        let name = [HITS, MISSES, SIZE, BUFFER_BYTES]
+            .iter()
+            .copied()
+            .find(|&name| name == key.name())
+            .unwrap_or_else(|| panic!("unexpected metric {}", key.name()));
+        Arc::new(Handle {
+            name,
+            events: Arc::clone(&self.0),
+        })
+    }
+}
+
+impl Recorder for Capture {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        Counter::from_arc(self.handle(key))
+    }
+    fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::from_arc(self.handle(key))
+    }
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        Histogram::from_arc(self.handle(key))
+    }
+}
+
+impl Handle {
+    fn push(&self, event: Event) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl CounterFn for Handle {
+    fn increment(&self, value: u64) {
+        self.push(Event::Counter(self.name, value));
+    }
+    fn absolute(&self, _: u64) {
+        unreachable!();
+    }
+}
+
+impl GaugeFn for Handle {
+    fn increment(&self, _: f64) {
+        unreachable!();
+    }
+    fn decrement(&self, _: f64) {
+        unreachable!();
+    }
+    fn set(&self, value: f64) {
+        self.push(Event::Gauge(self.name, value));
+    }
+}
+
+impl HistogramFn for Handle {
+    fn record(&self, value: f64) {
+        self.push(Event::Histogram(self.name, value));
+    }
+}
+
+#[test]
+fn get_and_return() {
+    FlatBufferBuilderPool::init_global_pool_size(2);
+    FlatBufferBuilderPool::max_global_pool_size(2);
+    let recorder = Capture::default();
+    metrics::with_local_recorder(&recorder, || {
+        let a = FlatBufferBuilderPool::get().unwrap();
+        let b = FlatBufferBuilderPool::get().unwrap();
+        let mut c = FlatBufferBuilderPool::get().unwrap();
+        let name = c.create_string("orc");
+        c.finish(name, None);
+        let bytes = c.finished_data().len();
+        drop(c);
+        drop(b);
+        // Dropped, as the pool is full.
+        drop(a);
+        use Event::*;
+        assert_eq!(
+            vec![
+                Counter(HITS, 1),
+                Gauge(SIZE, 1.0),
+                Counter(HITS, 1),
+                Gauge(SIZE, 0.0),
+                Counter(MISSES, 1),
+                Gauge(SIZE, 0.0),
+                Histogram(BUFFER_BYTES, bytes as f64),
+                Gauge(SIZE, 1.0),
+                Histogram(BUFFER_BYTES, 0.0),
+                Gauge(SIZE, 2.0),
+            ],
+            *recorder.0.lock().unwrap()
+        );
+    });
+}
@@ -0,0 +1,46 @@
+//! Race the global pool configuration with the first `get`, in its own
+//! process so that the global pool is not initialized yet.
+use std::panic;
+use std::sync::Barrier;
+use std::thread;
+
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+
+const THREADS: usize = 4;
+const GETS: usize = 1_000;
+
+#[test]
+fn configure_while_getting() {
+    let barrier = Barrier::new(THREADS * 2);
+    thread::scope(|s| {
+        for i in 0..THREADS {
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                for n in 0..GETS {
+                    // Sweep the initial size across the maximum, until the
+                    // first `get` initializes the global pool.
+                    let configured = panic::catch_unwind(|| {
+                        FlatBufferBuilderPool::init_global_pool_size((i + n) % 16);
+                        FlatBufferBuilderPool::max_global_pool_size(1 + (i * n) % 8);
+                    });
+                    if configured.is_err() {
+                        break;
+                    }
+                    FlatBufferBuilderPool::global_buffer_capacity(64 + n);
+                }
+            });
+            s.spawn(move || {
+                barrier.wait();
+                for n in 0..GETS {
+                    let mut b = FlatBufferBuilderPool::get().unwrap();
+                    let name = b.create_string(&n.to_string());
+                    b.finish(name, None);
+                }
+            });
+        }
+    });
+    let stats = FlatBufferBuilderPool::global_stats();
+    assert_eq!((THREADS * GETS) as u64, stats.hits + stats.misses);
+    assert!(stats.returns <= stats.hits + stats.misses, "{:?}", stats);
+}
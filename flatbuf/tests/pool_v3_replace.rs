@@ -0,0 +1,69 @@
+//! Replace the global pool while the builders are checked out, in its
+//! own process so that the other tests don't share the global pool.
+use std::sync::Barrier;
+use std::thread;
+
+use flatbuf_tutorial::pool::v3::{FlatBufferBuilderPool, PoolStats};
+
+const THREADS: usize = 4;
+const GETS: usize = 1_000;
+
+fn delta(before: PoolStats) -> PoolStats {
+    let after = FlatBufferBuilderPool::global_stats();
+    PoolStats {
+        hits: after.hits - before.hits,
+        misses: after.misses - before.misses,
+        returns: after.returns - before.returns,
+    }
+}
+
+#[test]
+fn replace_global_pool() {
+    FlatBufferBuilderPool::init_global_pool_size(2);
+    FlatBufferBuilderPool::max_global_pool_size(4);
+    let old: Vec<_> = (0..2).map(|_| FlatBufferBuilderPool::get().unwrap()).collect();
+
+    // The new pool is pre-filled with the initial size.
+    FlatBufferBuilderPool::replace_global_pool(8, 128);
+    let before = FlatBufferBuilderPool::global_stats();
+    let new = FlatBufferBuilderPool::get().unwrap();
+    let want = PoolStats {
+        hits: 1,
+        misses: 0,
+        returns: 0,
+    };
+    assert_eq!(want, delta(before));
+
+    // The old builders go back to the old pool, not to the new one.
+    drop(old);
+    drop(new);
+    let before = FlatBufferBuilderPool::global_stats();
+    let builders: Vec<_> = (0..3).map(|_| FlatBufferBuilderPool::get().unwrap()).collect();
+    let want = PoolStats {
+        hits: 2,
+        misses: 1,
+        returns: 0,
+    };
+    assert_eq!(want, delta(before));
+    drop(builders);
+
+    // Replace the pool mid-flight.
+    let barrier = Barrier::new(THREADS + 1);
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                for n in 0..GETS {
+                    let mut b = FlatBufferBuilderPool::get().unwrap();
+                    let name = b.create_string(&n.to_string());
+                    b.finish(name, None);
+                }
+            });
+        }
+        barrier.wait();
+        for n in 0..GETS / 10 {
+            FlatBufferBuilderPool::replace_global_pool(1 + n % 8, 64 + n);
+        }
+    });
+}
@@ -0,0 +1,37 @@
+//! Shrink the inflated global builders on the return, in its own process
+//! so that the other tests don't share the global pool.
+use flatbuf_tutorial::pool::v3::FlatBufferBuilderPool;
+
+const TARGET_BYTES: usize = 4_096;
+
+fn buffer_len(b: &mut flatbuffers::FlatBufferBuilder<'_>) -> usize {
+    b.mut_finished_buffer().0.len()
+}
+
+#[test]
+fn global_shrink_to() {
+    FlatBufferBuilderPool::init_global_pool_size(1);
+    FlatBufferBuilderPool::max_global_pool_size(1);
+    FlatBufferBuilderPool::global_shrink_to(TARGET_BYTES);
+
+    let mut b = FlatBufferBuilderPool::get().unwrap();
+    let data = b.create_byte_string(&[0xff; 100 * 1_024]);
+    b.finish(data, None);
+    assert!(buffer_len(&mut b) > 100 * 1_024);
+    drop(b);
+
+    let before = FlatBufferBuilderPool::global_stats();
+    let mut b = FlatBufferBuilderPool::get().unwrap();
+    // Still pooled, but reallocated.
+    assert_eq!(before.hits + 1, FlatBufferBuilderPool::global_stats().hits);
+    assert!(buffer_len(&mut b) <= TARGET_BYTES);
+    assert!(b.unfinished_data().is_empty());
+
+    // The builder within the target is kept as is.
+    let data = b.create_byte_string(&[0xff; 1_024]);
+    b.finish(data, None);
+    let len = buffer_len(&mut b);
+    drop(b);
+    let mut b = FlatBufferBuilderPool::get().unwrap();
+    assert_eq!(len, buffer_len(&mut b));
+}
@@ -0,0 +1,49 @@
+//! Per-thread global pool statistics, in its own process so that the
+//! other tests don't share the global pool.
+use std::sync::Barrier;
+use std::thread;
+
+use flatbuf_tutorial::pool::v3::{FlatBufferBuilderPool, PoolStats};
+
+const THREADS: usize = 4;
+const GETS: usize = 100;
+
+#[test]
+fn thread_stats_sum_up() {
+    FlatBufferBuilderPool::init_global_pool_size(4);
+    FlatBufferBuilderPool::max_global_pool_size(8);
+    let barrier = Barrier::new(THREADS);
+    let stats: Vec<PoolStats> = thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|i| {
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    // Hold a few of them at once to miss the pool.
+                    for _ in 0..GETS {
+                        let builders: Vec<_> =
+                            (0..=i).map(|_| FlatBufferBuilderPool::get().unwrap()).collect();
+                        drop(builders);
+                    }
+                    FlatBufferBuilderPool::thread_stats()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(PoolStats::default(), FlatBufferBuilderPool::thread_stats());
+
+    let global = FlatBufferBuilderPool::global_stats();
+    let sum = stats
+        .iter()
+        .fold(PoolStats::default(), |sum, stats| PoolStats {
+            hits: sum.hits + stats.hits,
+            misses: sum.misses + stats.misses,
+            returns: sum.returns + stats.returns,
+        });
+    assert_eq!(global, sum);
+    for (i, stats) in stats.iter().enumerate() {
+        let gets = (GETS * (i + 1)) as u64;
+        assert_eq!(gets, stats.hits + stats.misses, "{}", i);
+    }
+}
@@ -0,0 +1,51 @@
+//! Compile-time `Send` and `Sync` assertions of the pool types, so that
+//! the pools keep working across the threads.
+use flatbuf_tutorial::pool::{
+    v1, v2, v3, v4, DynamicBuilder, DynamicPool, FlatBuilderPool, Guard, Pool, ShardedBuilder,
+    ShardedPool,
+};
+use flatbuf_tutorial::MonsterBuf;
+use static_assertions::assert_impl_all;
+
+assert_impl_all!(v1::FlatBufferBuilderLocalPool<'static>: Send, Sync);
+assert_impl_all!(v1::GlobalBuilder: Send);
+assert_impl_all!(v1::LocalBuilder<'static>: Send);
+
+assert_impl_all!(v2::FlatBufferBuilderLocalPool<'static>: Send, Sync);
+assert_impl_all!(v2::GlobalBuilder: Send);
+assert_impl_all!(v2::LocalBuilder<'static>: Send);
+
+assert_impl_all!(v3::FlatBufferBuilderLocalPool<'static>: Send, Sync);
+assert_impl_all!(v3::GlobalBuilder: Send);
+assert_impl_all!(v3::LocalBuilder<'static>: Send);
+
+assert_impl_all!(v4::GlobalBuilder: Send);
+
+assert_impl_all!(DynamicPool<'static>: Send, Sync);
+assert_impl_all!(DynamicBuilder<'static>: Send);
+
+assert_impl_all!(ShardedPool<'static>: Send, Sync);
+assert_impl_all!(ShardedBuilder<'static>: Send);
+
+assert_impl_all!(Pool<Vec<u8>>: Send, Sync);
+assert_impl_all!(Guard<Vec<u8>>: Send);
+assert_impl_all!(FlatBuilderPool<'static>: Send, Sync);
+
+assert_impl_all!(MonsterBuf: Send, Sync);
+
+#[test]
+fn local_pool_across_threads() {
+    let pool = flatbuf_tutorial::FlatBufferBuilderPool::new().build();
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let pool = &pool;
+            s.spawn(move || {
+                let mut b = pool.get();
+                let name = b.create_string(&i.to_string());
+                b.finish(name, None);
+                // Move the builder to the other thread.
+                std::thread::spawn(move || drop(b)).join().unwrap();
+            });
+        }
+    });
+}
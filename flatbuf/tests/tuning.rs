@@ -0,0 +1,84 @@
+//! `CapacityAdvisor` hooked into `Monster::build_pooled`, in its own
+//! process for the process wide hook and the counting allocator.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use flatbuf_tutorial::monster::tuning::CapacityAdvisor;
+use flatbuf_tutorial::monster::MonsterData;
+use flatbuf_tutorial::pool::v3::{FlatBufferBuilderLocalPool, FlatBufferBuilderPool};
+use flatbuf_tutorial::Monster;
+
+const BUILDERS: usize = 8;
+
+/// System allocator counting the reallocations of the current thread,
+/// e.g. the builder buffer growth.
+struct Counting;
+
+thread_local! {
+    static REALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = REALLOCS.try_with(|n| n.set(n.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static ADVISOR: CapacityAdvisor = CapacityAdvisor::new();
+
+fn monsters() -> Vec<MonsterData> {
+    (0..BUILDERS)
+        .map(|i| MonsterData {
+            name: format!("orc-{}", i),
+            inventory: vec![1; 100 + 50 * i],
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Build all the monsters with the builders from `pool` at once, and
+/// returns the number of the reallocations.
+fn reallocs(pool: &FlatBufferBuilderLocalPool<'_>) -> usize {
+    let monsters = monsters();
+    let mut builders = pool.get_array::<BUILDERS>();
+    let before = REALLOCS.with(Cell::get);
+    for (b, data) in builders.iter_mut().zip(&monsters) {
+        let monster = data.build(b);
+        b.finish(monster, None);
+    }
+    REALLOCS.with(Cell::get) - before
+}
+
+#[test]
+fn build_pooled_tunes_pool() {
+    assert!(ADVISOR.install());
+    assert!(!CapacityAdvisor::install(Box::leak(Box::default())));
+    for data in &monsters() {
+        Monster::build_pooled(data);
+    }
+    assert_eq!(BUILDERS as u64, ADVISOR.samples());
+
+    let pool = || {
+        FlatBufferBuilderPool::new()
+            .init_pool_size(BUILDERS)
+            .max_pool_size(BUILDERS)
+            .buffer_capacity(64)
+            .build()
+    };
+    let untuned = reallocs(&pool());
+    let tuned = pool();
+    assert_eq!(Some(512), ADVISOR.apply_to_pool(&tuned, 1.0));
+    let tuned = reallocs(&tuned);
+    assert!(untuned >= 2 * BUILDERS, "{}", untuned);
+    assert_eq!(0, tuned);
+}
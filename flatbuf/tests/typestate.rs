@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: GPL-2.0
+#[test]
+fn monster_builder_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
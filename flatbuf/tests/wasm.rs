@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: GPL-2.0
+//! wasm-bindgen bindings, run on node with the `wasm-bindgen-cli` runner:
+//!
+//! ```sh
+//! $ CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//!     cargo test --target wasm32-unknown-unknown --features wasm --test wasm
+//! ```
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use flatbuf_tutorial::wasm::{build_monster_from_json, monster_to_json, verify_monster};
+
+const ORC: &str = r#"{"name":"orc","pos":null,"mana":150,"hp":80,"inventory":[1,2],"color":"Red","weapons":[{"name":"axe","damage":5}],"equipped":0,"path":[]}"#;
+
+fn message(err: wasm_bindgen::JsValue) -> String {
+    err.dyn_into::<js_sys::Error>().unwrap().message().into()
+}
+
+#[wasm_bindgen_test]
+fn round_trip() {
+    let buf = build_monster_from_json(ORC).unwrap();
+    assert!(verify_monster(&buf));
+    assert_eq!(ORC, monster_to_json(&buf).unwrap());
+}
+
+#[wasm_bindgen_test]
+fn corrupted() {
+    let err = build_monster_from_json(r#"{"name":"orc","hp":"full"}"#).unwrap_err();
+    assert!(message(err).starts_with("invalid monster JSON: "));
+
+    let mut buf = build_monster_from_json(ORC).unwrap();
+    buf.truncate(buf.len() / 2);
+    assert!(!verify_monster(&buf));
+    let err = monster_to_json(&buf).unwrap_err();
+    assert!(message(err).starts_with("invalid monster buffer: "));
+    assert!(!verify_monster(&[]));
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: GPL-2.0
+//! wasm32 builder pool, run in the headless browser:
+//!
+//! ```sh
+//! $ wasm-pack test --headless --firefox -- --features wasm --test wasm_pool
+//! ```
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use flatbuf_tutorial::pool::wasm::WasmPool;
+use flatbuf_tutorial::wasm::monster_to_json;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+static_assertions::assert_not_impl_any!(WasmPool: Send, Sync);
+
+#[wasm_bindgen_test]
+fn reuse() {
+    let pool = WasmPool::new(2, 64);
+    assert!(pool.is_empty());
+    let builders: Vec<_> = (0..3).map(|_| pool.get()).collect();
+    assert!(pool.is_empty());
+    drop(builders);
+    // Up to the maximum pool size.
+    assert_eq!(2, pool.len());
+
+    let mut b = pool.get();
+    assert_eq!(1, pool.len());
+    let name = b.create_string("orc");
+    b.finish(name, None);
+    drop(b);
+    let b = pool.get();
+    assert!(b.unfinished_data().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn build_monster_from_json() {
+    let pool = WasmPool::new(2, 64);
+    let json = r#"{"name":"orc","pos":null,"mana":150,"hp":80,"inventory":[],"color":"Blue","weapons":[],"equipped":null,"path":[]}"#;
+    let buf = pool.build_monster_from_json(json).unwrap();
+    assert_eq!(json, monster_to_json(&buf).unwrap());
+    assert_eq!(1, pool.len());
+    assert!(pool.build_monster_from_json(r#"{"hp":"full"}"#).is_err());
+}
+
+#[wasm_bindgen_test]
+fn outlived_by_builder() {
+    let pool = WasmPool::new(2, 64);
+    let b = pool.get();
+    drop(pool);
+    drop(b);
+}